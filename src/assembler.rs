@@ -0,0 +1,414 @@
+//! Assembler for the textual listing format produced by `impl Display for Instruction`.
+//!
+//! Each line holds at most one instruction of the form `MNEMONIC [ARG]`. Arguments are decimal
+//! or `0x`-prefixed hexadecimal numbers, or the name of a label when the instruction is a jump.
+//! Labels are defined by a name followed by a colon, either on their own line or before an
+//! instruction. Everything following a `#` or `;` is a comment.
+//!
+//! ```text
+//! # while x: pass
+//! loop:
+//!     LOAD_NAME 0
+//!     POP_JUMP_IF_FALSE end
+//!     JUMP_ABSOLUTE loop
+//! end:
+//!     LOAD_CONST 0      ; None
+//!     RETURN_VALUE
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::builder::{CodeBuilder, Label};
+use crate::error::{AssembleError, BuildError};
+use crate::opcode::{ExtendedInstruction, Instruction, Opcode};
+
+enum Operand {
+    None,
+    Value(u32),
+    Label(String),
+}
+
+struct LabelInfo {
+    label: Label,
+    defined: bool,
+    /// The line this label was first referenced on
+    first_use: Option<usize>,
+}
+
+/// Assembles a textual listing into bytecode for the opcode table `O`. Mnemonics are parsed
+/// with `O::Mnemonic::from_str` and converted to opcodes with `O::from`.
+///
+/// The listing is lowered onto a [`CodeBuilder`], so label arguments are resolved to relative or
+/// absolute offsets depending on the jump type of the instruction, and `EXTENDED_ARG` prefixes
+/// are emitted for any argument which does not fit in a single instruction.
+pub fn assemble<O>(source: &str) -> Result<Vec<u8>, AssembleError>
+where
+    O: Opcode,
+    O::Mnemonic: FromStr,
+{
+    let mut builder = CodeBuilder::<O>::new();
+    let mut labels: BTreeMap<String, LabelInfo> = BTreeMap::new();
+    // The line and label operand (if any) of each instruction, used for error reporting
+    let mut statements: Vec<(usize, Option<String>)> = Vec::new();
+
+    for (idx, line) in source.lines().enumerate() {
+        let line_number = idx + 1;
+        let line = match line.find(['#', ';']) {
+            Some(comment_start) => &line[..comment_start],
+            None => line,
+        };
+
+        let mut tokens = line.split_whitespace().peekable();
+        while let Some(label) = tokens.peek().and_then(|token| token.strip_suffix(':')) {
+            if !is_label(label) {
+                return Err(AssembleError::InvalidArgument {
+                    line: line_number,
+                    arg: label.to_string(),
+                });
+            }
+
+            let info = label_info(&mut builder, &mut labels, label);
+            if info.defined {
+                return Err(AssembleError::DuplicateLabel {
+                    line: line_number,
+                    label: label.to_string(),
+                });
+            }
+
+            info.defined = true;
+            builder
+                .bind(info.label)
+                .expect("label should only be bound once");
+
+            tokens.next();
+        }
+
+        let mnemonic = match tokens.next() {
+            Some(mnemonic) => mnemonic,
+            None => continue,
+        };
+
+        let opcode = O::from(O::Mnemonic::from_str(mnemonic).map_err(|_| {
+            AssembleError::UnknownMnemonic {
+                line: line_number,
+                mnemonic: mnemonic.to_string(),
+            }
+        })?);
+
+        let operand = match tokens.next() {
+            Some(arg) => parse_operand(arg).ok_or_else(|| AssembleError::InvalidArgument {
+                line: line_number,
+                arg: arg.to_string(),
+            })?,
+            None => Operand::None,
+        };
+
+        if let Some(extra) = tokens.next() {
+            return Err(AssembleError::InvalidArgument {
+                line: line_number,
+                arg: extra.to_string(),
+            });
+        }
+
+        match operand {
+            Operand::None if opcode.has_arg() => {
+                return Err(AssembleError::MissingArgument {
+                    line: line_number,
+                    mnemonic: mnemonic.to_string(),
+                });
+            }
+            Operand::Value(_) | Operand::Label(_) if !opcode.has_arg() => {
+                return Err(AssembleError::UnexpectedArgument {
+                    line: line_number,
+                    mnemonic: mnemonic.to_string(),
+                });
+            }
+            Operand::Label(label) if !opcode.is_jump() => {
+                return Err(AssembleError::LabelNotAllowed {
+                    line: line_number,
+                    label,
+                });
+            }
+            Operand::None => {
+                builder.push(Instruction { opcode, arg: None });
+                statements.push((line_number, None));
+            }
+            Operand::Value(value) => {
+                builder.push(ExtendedInstruction {
+                    opcode,
+                    arg: Some(value),
+                    extended_args: 0,
+                });
+                statements.push((line_number, None));
+            }
+            Operand::Label(label) => {
+                let info = label_info(&mut builder, &mut labels, &label);
+                info.first_use.get_or_insert(line_number);
+                builder.push_jump(opcode, info.label);
+                statements.push((line_number, Some(label)));
+            }
+        }
+    }
+
+    for (name, info) in &labels {
+        if let (false, Some(line)) = (info.defined, info.first_use) {
+            return Err(AssembleError::UndefinedLabel {
+                line,
+                label: name.clone(),
+            });
+        }
+    }
+
+    builder.finish().map_err(|e| match e {
+        BuildError::BackwardRelativeJump { instruction } => {
+            let (line, label) = statements[instruction].clone();
+            AssembleError::BackwardRelativeJump {
+                line,
+                label: label.unwrap_or_default(),
+            }
+        }
+        BuildError::ForwardJump { instruction } => {
+            let (line, label) = statements[instruction].clone();
+            AssembleError::ForwardJump {
+                line,
+                label: label.unwrap_or_default(),
+            }
+        }
+        e => unreachable!("listing was validated before layout: {}", e),
+    })
+}
+
+fn label_info<'a, O: Opcode>(
+    builder: &mut CodeBuilder<O>,
+    labels: &'a mut BTreeMap<String, LabelInfo>,
+    name: &str,
+) -> &'a mut LabelInfo {
+    labels.entry(name.to_string()).or_insert_with(|| LabelInfo {
+        label: builder.new_label(),
+        defined: false,
+        first_use: None,
+    })
+}
+
+fn parse_operand(arg: &str) -> Option<Operand> {
+    if let Some(hex) = arg.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16).ok().map(Operand::Value)
+    } else if arg.starts_with(|c: char| c.is_ascii_digit()) {
+        arg.parse().ok().map(Operand::Value)
+    } else if is_label(arg) {
+        Some(Operand::Label(arg.to_string()))
+    } else {
+        None
+    }
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '.')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disassembler::Disassembler;
+    use crate::opcode::{py27, py311};
+    use alloc::format;
+
+    fn assemble27(source: &str) -> Result<Vec<u8>, AssembleError> {
+        assemble::<py27::Standard>(source)
+    }
+
+    #[test]
+    fn assembles_labels_and_comments() {
+        let source = "
+            # while x: pass
+            loop:
+                LOAD_NAME 0
+                POP_JUMP_IF_FALSE end
+                JUMP_ABSOLUTE loop
+            end:
+                LOAD_CONST 0      ; None
+                RETURN_VALUE
+        ";
+        assert_eq!(
+            assemble27(source).unwrap(),
+            [101, 0, 0, 114, 9, 0, 113, 0, 0, 100, 0, 0, 83]
+        );
+    }
+
+    #[test]
+    fn labels_before_instructions() {
+        let source = "JUMP_FORWARD a\na: b: NOP\nJUMP_ABSOLUTE b";
+        assert_eq!(assemble27(source).unwrap(), [110, 0, 0, 9, 113, 3, 0]);
+    }
+
+    #[test]
+    fn decimal_and_hex_arguments() {
+        assert_eq!(
+            assemble27("LOAD_CONST 0x10\nLOAD_CONST 16").unwrap(),
+            [100, 16, 0, 100, 16, 0]
+        );
+        assert_eq!(
+            assemble27("LOAD_CONST 0x10002").unwrap(),
+            [145, 1, 0, 100, 2, 0]
+        );
+    }
+
+    #[test]
+    fn aliases_are_accepted() {
+        assert_eq!(assemble27("SLICE+1").unwrap(), [31]);
+    }
+
+    #[test]
+    fn round_trips_disassembly() {
+        let code = [
+            124, 0, 0, 100, 1, 0, 107, 2, 0, 114, 21, 0, 116, 0, 0, 131, 0, 0, 1, 110, 0, 0, 100,
+            0, 0, 83,
+        ];
+        let listing: String = Disassembler::<py27::Standard>::new(&code)
+            .map(|result| format!("{}\n", result.unwrap().1))
+            .collect();
+        assert_eq!(assemble27(&listing).unwrap(), code);
+
+        let code = [151, 0, 100, 1, 125, 0, 140, 3, 100, 0, 83, 0];
+        let listing: String = Disassembler::<py311::Standard>::new(&code)
+            .map(|result| format!("{}\n", result.unwrap().1))
+            .collect();
+        assert_eq!(assemble::<py311::Standard>(&listing).unwrap(), code);
+    }
+
+    #[test]
+    fn reports_errors_with_lines() {
+        let cases = [
+            (
+                "NOP\nFOO 1",
+                AssembleError::UnknownMnemonic {
+                    line: 2,
+                    mnemonic: "FOO".into(),
+                },
+            ),
+            (
+                "LOAD_CONST",
+                AssembleError::MissingArgument {
+                    line: 1,
+                    mnemonic: "LOAD_CONST".into(),
+                },
+            ),
+            (
+                "NOP 1",
+                AssembleError::UnexpectedArgument {
+                    line: 1,
+                    mnemonic: "NOP".into(),
+                },
+            ),
+            (
+                "LOAD_CONST 0xZZ",
+                AssembleError::InvalidArgument {
+                    line: 1,
+                    arg: "0xZZ".into(),
+                },
+            ),
+            (
+                "LOAD_CONST 1x",
+                AssembleError::InvalidArgument {
+                    line: 1,
+                    arg: "1x".into(),
+                },
+            ),
+            (
+                "LOAD_CONST 1 2",
+                AssembleError::InvalidArgument {
+                    line: 1,
+                    arg: "2".into(),
+                },
+            ),
+            (
+                "1a: NOP",
+                AssembleError::InvalidArgument {
+                    line: 1,
+                    arg: "1a".into(),
+                },
+            ),
+            (
+                "a: NOP\nLOAD_CONST a",
+                AssembleError::LabelNotAllowed {
+                    line: 2,
+                    label: "a".into(),
+                },
+            ),
+            (
+                "a:\nNOP\na: NOP",
+                AssembleError::DuplicateLabel {
+                    line: 3,
+                    label: "a".into(),
+                },
+            ),
+            (
+                "NOP\nJUMP_FORWARD a\nJUMP_ABSOLUTE a",
+                AssembleError::UndefinedLabel {
+                    line: 2,
+                    label: "a".into(),
+                },
+            ),
+            (
+                "a: NOP\nJUMP_FORWARD a",
+                AssembleError::BackwardRelativeJump {
+                    line: 2,
+                    label: "a".into(),
+                },
+            ),
+        ];
+
+        for (source, error) in cases {
+            assert_eq!(assemble27(source), Err(error), "{}", source);
+        }
+
+        assert_eq!(
+            assemble::<py311::Standard>("JUMP_BACKWARD a\nNOP\na: NOP"),
+            Err(AssembleError::ForwardJump {
+                line: 1,
+                label: "a".into(),
+            })
+        );
+    }
+
+    /// Every combination of a few lines either assembles or is reported as an error, which
+    /// checks that validation leaves no build error for `assemble` to treat as unreachable
+    #[test]
+    fn never_panics_on_label_combinations() {
+        let lines = [
+            "a:",
+            "b:",
+            "NOP",
+            "JUMP_FORWARD a",
+            "JUMP_ABSOLUTE b",
+            "JUMP_BACKWARD a",
+            "LOAD_CONST b",
+            "POP_JUMP_IF_FALSE c",
+        ];
+
+        let mut indexes = Vec::new();
+        loop {
+            let source: Vec<&str> = indexes.iter().map(|&index| lines[index]).collect();
+            let source = source.join("\n");
+            let _ = assemble27(&source.replace("JUMP_BACKWARD", "JUMP_ABSOLUTE"));
+            let _ = assemble::<py311::Standard>(&source.replace("JUMP_ABSOLUTE", "JUMP_FORWARD"));
+
+            // Advance to the next combination of up to 4 lines
+            match indexes.iter().rposition(|&index| index + 1 < lines.len()) {
+                Some(position) => {
+                    indexes[position] += 1;
+                    for index in &mut indexes[position + 1..] {
+                        *index = 0;
+                    }
+                }
+                None if indexes.len() < 4 => indexes = alloc::vec![0; indexes.len() + 1],
+                None => break,
+            }
+        }
+    }
+}
@@ -0,0 +1,353 @@
+//! Programmatic construction of bytecode with symbolic jump targets.
+//!
+//! ```
+//! use pydis::builder::CodeBuilder;
+//! use pydis::opcode::py27::Standard;
+//! use pydis::Instr;
+//!
+//! let mut builder = CodeBuilder::<Standard>::new();
+//! let end = builder.new_label();
+//! builder.push(Instr!(Standard::LOAD_NAME, 0));
+//! builder.push_jump(Standard::POP_JUMP_IF_FALSE, end);
+//! builder.push(Instr!(Standard::LOAD_CONST, 1));
+//! builder.push(Instr!(Standard::PRINT_ITEM));
+//! builder.bind(end).unwrap();
+//! builder.push(Instr!(Standard::LOAD_CONST, 0));
+//! builder.push(Instr!(Standard::RETURN_VALUE));
+//!
+//! let code = builder.finish().unwrap();
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::error::BuildError;
+use crate::opcode::{ExtendedInstruction, Opcode};
+
+/// A position in the code being built which jumps may target. Created with
+/// [`CodeBuilder::new_label`] and placed with [`CodeBuilder::bind`].
+///
+/// Labels are numbered per builder, so a label from another builder is only detected as unknown
+/// if this builder has created fewer labels.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Label(usize);
+
+enum Entry<O: Opcode> {
+    Instruction(ExtendedInstruction<O>),
+    Jump(O, Label),
+}
+
+/// Builds bytecode from a sequence of instructions and labels. Jump arguments are computed when
+/// [`CodeBuilder::finish`] lays out the code, using [`Opcode::is_relative_jump`] and
+/// [`Opcode::is_absolute_jump`] to decide how the target is encoded, and `EXTENDED_ARG` prefixes
+/// are inserted for any argument that does not fit in a single instruction. Room is left for the
+/// inline cache entries of each instruction, which are filled with zeroes.
+pub struct CodeBuilder<O: Opcode> {
+    entries: Vec<Entry<O>>,
+    /// The index of the entry each label was bound before, if it has been bound
+    labels: Vec<Option<usize>>,
+}
+
+impl<O: Opcode> Default for CodeBuilder<O> {
+    fn default() -> Self {
+        CodeBuilder {
+            entries: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl<O: Opcode> CodeBuilder<O> {
+    /// Creates a builder with no instructions or labels
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new label which has not yet been bound to a position
+    pub fn new_label(&mut self) -> Label {
+        self.labels.push(None);
+        Label(self.labels.len() - 1)
+    }
+
+    /// Binds `label` to the position of the next instruction pushed
+    pub fn bind(&mut self, label: Label) -> Result<(), BuildError> {
+        let position = self.entries.len();
+        match self.labels.get_mut(label.0) {
+            Some(slot @ None) => {
+                *slot = Some(position);
+                Ok(())
+            }
+            Some(Some(_)) => Err(BuildError::LabelAlreadyBound(label)),
+            None => Err(BuildError::UnknownLabel(label)),
+        }
+    }
+
+    /// Appends an instruction with a fixed argument
+    pub fn push<I: Into<ExtendedInstruction<O>>>(&mut self, instr: I) {
+        self.entries.push(Entry::Instruction(instr.into()));
+    }
+
+    /// Appends a jump instruction whose argument will be computed from the position of `target`
+    pub fn push_jump(&mut self, opcode: O, target: Label) {
+        self.entries.push(Entry::Jump(opcode, target));
+    }
+
+    /// The number of instructions pushed so far
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether or not any instructions have been pushed
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Lays out the code and returns the final instructions with all jump arguments resolved.
+    /// Inline cache entries are not included.
+    pub fn finish_instructions(self) -> Result<Vec<ExtendedInstruction<O>>, BuildError> {
+        let mut instructions = Vec::with_capacity(self.entries.len());
+        for (idx, entry) in self.entries.iter().enumerate() {
+            instructions.push(match entry {
+                Entry::Instruction(instr) => {
+                    // Count the prefixes a large argument needs, which may be more than were given
+                    let prefixes = O::encoding().prefixes_for(instr.arg.unwrap_or(0));
+                    ExtendedInstruction {
+                        extended_args: core::cmp::max(instr.extended_args, prefixes),
+                        ..instr.clone()
+                    }
+                }
+                Entry::Jump(opcode, target) => {
+                    if !opcode.is_jump() {
+                        return Err(BuildError::NotAJump { instruction: idx });
+                    }
+
+                    match self.labels.get(target.0) {
+                        Some(Some(_)) => {}
+                        Some(None) => return Err(BuildError::UnboundLabel(*target)),
+                        None => return Err(BuildError::UnknownLabel(*target)),
+                    }
+
+                    ExtendedInstruction {
+                        opcode: *opcode,
+                        arg: Some(0),
+                        extended_args: 0,
+                    }
+                }
+            });
+        }
+
+        // Growing an instruction with an `EXTENDED_ARG` prefix may push later labels further
+        // out, so layout is repeated until no instruction changes size
+        loop {
+            let mut offsets = Vec::with_capacity(instructions.len() + 1);
+            let mut offset = 0;
+            for instr in &instructions {
+                offsets.push(offset);
+                offset += instr.len() + instr.cache_len();
+            }
+            // A label bound after the last instruction refers to the end of the code
+            offsets.push(offset);
+
+            let mut changed = false;
+            for (idx, entry) in self.entries.iter().enumerate() {
+                let target = match entry {
+                    Entry::Jump(_, target) => offsets[self.labels[target.0].unwrap()],
+                    Entry::Instruction(_) => continue,
+                };
+
+                let instr = &mut instructions[idx];
+                let next_instr = offsets[idx] + instr.len() + instr.cache_len();
+                let arg = if instr.opcode.is_backward_jump() {
+                    next_instr
+                        .checked_sub(target)
+                        .ok_or(BuildError::ForwardJump { instruction: idx })?
+                } else if instr.opcode.is_relative_jump() {
+                    target
+                        .checked_sub(next_instr)
+                        .ok_or(BuildError::BackwardRelativeJump { instruction: idx })?
+                } else {
+                    target
+                } / O::jump_unit();
+
+                instr.arg = Some(arg as u32);
+                let prefixes = O::encoding().prefixes_for(arg as u32);
+                if prefixes > instr.extended_args {
+                    instr.extended_args = prefixes;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                return Ok(instructions);
+            }
+        }
+    }
+
+    /// Lays out the code and encodes it
+    pub fn finish(self) -> Result<Vec<u8>, BuildError> {
+        let mut code = Vec::new();
+        for instr in self.finish_instructions()? {
+            instr.encode_into(&mut code);
+            code.resize(code.len() + instr.cache_len(), 0);
+        }
+
+        Ok(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disassembler::tests::read_cache_fixture;
+    use crate::disassembler::ExtendedDisassembler;
+    use crate::opcode::{py27, py311, py312, py36};
+
+    /// Builds `LOAD_CONST arg; JUMP_ABSOLUTE end; LOAD_CONST 1; end: RETURN_VALUE` and checks
+    /// that the jump lands on the `RETURN_VALUE`
+    fn check_jump_after_large_arg<O: Opcode + PartialEq>(
+        load_const: O,
+        jump_absolute: O,
+        return_value: O,
+        arg: u32,
+    ) {
+        let mut builder = CodeBuilder::<O>::new();
+        let end = builder.new_label();
+        builder.push(ExtendedInstruction {
+            opcode: load_const,
+            arg: Some(arg),
+            extended_args: 0,
+        });
+        builder.push_jump(jump_absolute, end);
+        builder.push(ExtendedInstruction {
+            opcode: load_const,
+            arg: Some(1),
+            extended_args: 0,
+        });
+        builder.bind(end).unwrap();
+        builder.push(ExtendedInstruction {
+            opcode: return_value,
+            arg: None,
+            extended_args: 0,
+        });
+        let code = builder.finish().unwrap();
+
+        let instructions: Vec<_> = ExtendedDisassembler::<O>::new(&code)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(instructions.len(), 4);
+        assert_eq!(instructions[0].1.arg, Some(arg));
+        let (offset, jump) = &instructions[1];
+        let (end_offset, last) = &instructions[3];
+        assert_eq!(last.opcode, return_value);
+        assert_eq!(jump.jump_target(*offset), Some(*end_offset));
+    }
+
+    #[test]
+    fn label_errors() {
+        use py27::Standard::*;
+
+        let mut other = CodeBuilder::<py27::Standard>::new();
+        other.new_label();
+        let foreign = other.new_label();
+
+        let mut builder = CodeBuilder::<py27::Standard>::new();
+        let label = builder.new_label();
+        assert_eq!(
+            builder.bind(foreign),
+            Err(BuildError::UnknownLabel(foreign))
+        );
+        builder.bind(label).unwrap();
+        assert_eq!(
+            builder.bind(label),
+            Err(BuildError::LabelAlreadyBound(label))
+        );
+
+        let mut builder = CodeBuilder::<py27::Standard>::new();
+        builder.push_jump(JUMP_ABSOLUTE, foreign);
+        assert_eq!(builder.finish(), Err(BuildError::UnknownLabel(foreign)));
+
+        let mut builder = CodeBuilder::<py27::Standard>::new();
+        let label = builder.new_label();
+        builder.push_jump(JUMP_ABSOLUTE, label);
+        assert_eq!(builder.finish(), Err(BuildError::UnboundLabel(label)));
+
+        let mut builder = CodeBuilder::<py27::Standard>::new();
+        let label = builder.new_label();
+        builder.bind(label).unwrap();
+        builder.push_jump(LOAD_CONST, label);
+        assert_eq!(
+            builder.finish(),
+            Err(BuildError::NotAJump { instruction: 0 })
+        );
+    }
+
+    #[test]
+    fn large_argument_before_label() {
+        use py27::Standard as Py27;
+        use py36::Standard as Py36;
+
+        check_jump_after_large_arg(
+            Py27::LOAD_CONST,
+            Py27::JUMP_ABSOLUTE,
+            Py27::RETURN_VALUE,
+            70000,
+        );
+        check_jump_after_large_arg(
+            Py36::LOAD_CONST,
+            Py36::JUMP_ABSOLUTE,
+            Py36::RETURN_VALUE,
+            300,
+        );
+        check_jump_after_large_arg(
+            Py36::LOAD_CONST,
+            Py36::JUMP_ABSOLUTE,
+            Py36::RETURN_VALUE,
+            70000,
+        );
+    }
+
+    /// Rebuilds each code object of a fixture of `testdata/caches` with a label for every
+    /// instruction, which must reproduce its `co_code` along with the zeroed cache entries
+    fn check_rebuild<O>(fixture: &str)
+    where
+        O: Opcode,
+        O::Mnemonic: core::str::FromStr,
+    {
+        for (code, listed) in read_cache_fixture(fixture) {
+            let instructions: Vec<_> = listed
+                .iter()
+                .filter(|listed| listed.name != "CACHE")
+                .collect();
+            let mut builder = CodeBuilder::<O>::new();
+            let labels: Vec<_> = instructions.iter().map(|_| builder.new_label()).collect();
+            for (instr, label) in instructions.iter().zip(&labels) {
+                builder.bind(*label).unwrap();
+                let mnemonic = instr
+                    .name
+                    .parse()
+                    .unwrap_or_else(|_| panic!("unknown mnemonic {}", instr.name));
+                let opcode = O::from(mnemonic);
+                match instr.target {
+                    Some(target) => {
+                        let index = instructions
+                            .iter()
+                            .position(|instr| instr.offset == target)
+                            .unwrap();
+                        builder.push_jump(opcode, labels[index]);
+                    }
+                    None => builder.push(ExtendedInstruction {
+                        opcode,
+                        arg: instr.arg.map(u32::from),
+                        extended_args: 0,
+                    }),
+                }
+            }
+            assert_eq!(builder.finish().unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn leaves_room_for_caches() {
+        check_rebuild::<py311::Standard>(include_str!("../testdata/caches/py311.txt"));
+        check_rebuild::<py312::Standard>(include_str!("../testdata/caches/py312.txt"));
+    }
+}
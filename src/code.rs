@@ -0,0 +1,443 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::FromPrimitive;
+
+use crate::disassembler::{Disassembler, ExtendedDisassembler};
+use crate::error::DecodeError;
+use crate::exceptiontable::ExceptionTable;
+use crate::flags::CodeFlags;
+use crate::linetable::{LineTable, LineTableFormat};
+use crate::marshal::PyObject;
+use crate::opcode::{ExtendedInstruction, Opcode};
+use crate::pyc::PythonVersion;
+
+/// A Python code object, as found in marshalled modules and the `co_consts` of other code
+/// objects. Field names follow the `co_*` attributes of the same name.
+///
+/// String-valued fields are kept as [`PyObject`]s so that whether they were interned is
+/// preserved. Fields which the version that produced the code object does not have are empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodeObject {
+    pub argcount: u32,
+    /// Python 3.8+
+    pub posonlyargcount: u32,
+    /// Python 3+
+    pub kwonlyargcount: u32,
+    pub nlocals: u32,
+    pub stacksize: u32,
+    pub flags: u32,
+    pub code: Vec<u8>,
+    pub consts: Vec<PyObject>,
+    pub names: Vec<PyObject>,
+    pub varnames: Vec<PyObject>,
+    pub freevars: Vec<PyObject>,
+    pub cellvars: Vec<PyObject>,
+    /// The names of all local, cell and free variables in the order instructions refer to them
+    /// by. Only Python 3.11+ stores these, `varnames`, `cellvars` and `freevars` are derived from
+    /// them.
+    pub localsplusnames: Vec<PyObject>,
+    /// The `CO_FAST_*` kind of each of `localsplusnames`. Python 3.11+
+    pub localspluskinds: Vec<u8>,
+    pub filename: PyObject,
+    pub name: PyObject,
+    /// Python 3.11+
+    pub qualname: Option<PyObject>,
+    pub firstlineno: u32,
+    /// `co_lnotab`, or `co_linetable` from Python 3.10. See [`LineTableFormat`] for the format
+    /// each version uses.
+    pub linetable: Vec<u8>,
+    /// Python 3.11+
+    pub exceptiontable: Vec<u8>,
+}
+
+/// `co_localspluskinds` bit of a local variable
+pub const CO_FAST_LOCAL: u8 = 0x20;
+/// `co_localspluskinds` bit of a cell variable
+pub const CO_FAST_CELL: u8 = 0x40;
+/// `co_localspluskinds` bit of a free variable
+pub const CO_FAST_FREE: u8 = 0x80;
+
+/// The value an instruction's argument refers to
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Argument<'a> {
+    /// An entry in `co_consts`
+    Const(&'a PyObject),
+    /// An entry in `co_names`
+    Name(&'a PyObject),
+    /// An entry in `co_varnames`
+    Local(&'a PyObject),
+    /// Two entries in `co_varnames`, for instructions which access a pair of locals
+    LocalPair(&'a PyObject, &'a PyObject),
+    /// An entry in `co_cellvars`, or in `co_freevars` if the index is past the end of the cell
+    /// variables
+    Free(&'a PyObject),
+}
+
+impl CodeObject {
+    /// Iterates over the instructions in `co_code` using the opcode table `O`
+    pub fn instructions<O: Opcode + FromPrimitive>(&self) -> Disassembler<'_, O> {
+        Disassembler::new(&self.code)
+    }
+
+    /// Iterates over the logical instructions in `co_code` using the opcode table `O`, folding
+    /// `EXTENDED_ARG` prefixes into the instruction that follows them
+    pub fn extended_instructions<O: Opcode + FromPrimitive>(&self) -> ExtendedDisassembler<'_, O> {
+        self.instructions().extended()
+    }
+
+    /// `co_flags` interpreted for the Python version which produced this code object
+    pub fn code_flags(&self, version: PythonVersion) -> CodeFlags {
+        CodeFlags::new(self.flags, version)
+    }
+
+    /// The code objects nested in `co_consts`, such as function and class bodies
+    pub fn children(&self) -> impl Iterator<Item = &CodeObject> {
+        self.consts.iter().filter_map(PyObject::as_code)
+    }
+
+    /// Iterates over this code object and every code object nested inside it, depth first
+    pub fn walk(&self) -> Walk<'_> {
+        Walk {
+            stack: vec![(String::new(), self)],
+        }
+    }
+
+    /// Calls `f` with the qualified name, code object, offset and instruction of every logical
+    /// instruction in this code object and the code objects nested inside it. Stops at the first
+    /// instruction which cannot be decoded.
+    pub fn for_each_instruction<O, F>(&self, mut f: F) -> Result<(), DecodeError>
+    where
+        O: Opcode + FromPrimitive,
+        F: FnMut(&str, &CodeObject, usize, &ExtendedInstruction<O>),
+    {
+        for (path, code) in self.walk() {
+            for item in code.extended_instructions::<O>() {
+                let (offset, instr) = item?;
+                f(&path, code, offset, &instr);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decodes the line number table, which is stored in the given format
+    pub fn line_table(&self, format: LineTableFormat) -> LineTable {
+        LineTable::new(format, &self.linetable, self.firstlineno, self.code.len())
+    }
+
+    /// Decodes the exception table. This is empty before Python 3.11.
+    pub fn exception_table(&self) -> ExceptionTable {
+        ExceptionTable::new(&self.exceptiontable)
+    }
+
+    /// Resolves the argument of `instr` into the const, name, local or free variable it refers
+    /// to. Returns `None` if the instruction's argument is not an index into one of these tables
+    /// or the index is out of range.
+    ///
+    /// From Python 3.11, locals and free variables are both indexes into `localsplusnames`.
+    pub fn argument<O: Opcode>(&self, instr: &ExtendedInstruction<O>) -> Option<Argument<'_>> {
+        let opcode = &instr.opcode;
+        let arg = instr.arg?;
+        let index = opcode.argument_index(arg) as usize;
+
+        if let Some((first, second)) = opcode.local_pair(arg) {
+            let locals = if self.localsplusnames.is_empty() {
+                &self.varnames
+            } else {
+                &self.localsplusnames
+            };
+            Some(Argument::LocalPair(
+                locals.get(first as usize)?,
+                locals.get(second as usize)?,
+            ))
+        } else if opcode.has_const() {
+            self.consts.get(index).map(Argument::Const)
+        } else if opcode.has_name() {
+            self.names.get(index).map(Argument::Name)
+        } else if !self.localsplusnames.is_empty() && (opcode.has_local() || opcode.has_free()) {
+            let name = self.localsplusnames.get(index)?;
+            Some(if opcode.has_local() {
+                Argument::Local(name)
+            } else {
+                Argument::Free(name)
+            })
+        } else if opcode.has_local() {
+            self.varnames.get(index).map(Argument::Local)
+        } else if opcode.has_free() {
+            self.cellvars
+                .get(index)
+                .or_else(|| self.freevars.get(index.checked_sub(self.cellvars.len())?))
+                .map(Argument::Free)
+        } else {
+            None
+        }
+    }
+}
+
+/// Depth first iterator over a tree of code objects, created by [`CodeObject::walk`].
+///
+/// Each code object is yielded along with its qualified name: the `co_name` of each of its
+/// ancestors and itself joined by `.`, e.g. `<module>.Class.method.<lambda>`. Nested code objects
+/// are visited in the order they appear in `co_consts`.
+pub struct Walk<'a> {
+    stack: Vec<(String, &'a CodeObject)>,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (String, &'a CodeObject);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (parent_path, code) = self.stack.pop()?;
+
+        let mut path = parent_path;
+        if !path.is_empty() {
+            path.push('.');
+        }
+        match code.name.as_str() {
+            Some(name) => path.push_str(name),
+            None => path.push('?'),
+        }
+
+        // Pushed in reverse so that they are popped in order
+        let start = self.stack.len();
+        self.stack
+            .extend(code.children().map(|child| (path.clone(), child)));
+        self.stack[start..].reverse();
+
+        Some((path, code))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opcode::{py27, py311, py312, py313};
+    use alloc::boxed::Box;
+
+    fn names(names: &[&str]) -> Vec<PyObject> {
+        names
+            .iter()
+            .map(|name| PyObject::Interned(name.as_bytes().to_vec()))
+            .collect()
+    }
+
+    fn code_object(name: &str, consts: Vec<PyObject>) -> CodeObject {
+        CodeObject {
+            argcount: 0,
+            posonlyargcount: 0,
+            kwonlyargcount: 0,
+            nlocals: 0,
+            stacksize: 0,
+            flags: 0,
+            code: Vec::new(),
+            consts,
+            names: Vec::new(),
+            varnames: Vec::new(),
+            freevars: Vec::new(),
+            cellvars: Vec::new(),
+            localsplusnames: Vec::new(),
+            localspluskinds: Vec::new(),
+            filename: PyObject::String(b"m.py".to_vec()),
+            name: PyObject::Interned(name.as_bytes().to_vec()),
+            qualname: None,
+            firstlineno: 1,
+            linetable: Vec::new(),
+            exceptiontable: Vec::new(),
+        }
+    }
+
+    fn instr<O: Opcode>(opcode: O, arg: u32) -> ExtendedInstruction<O> {
+        ExtendedInstruction {
+            opcode,
+            arg: Some(arg),
+            extended_args: 0,
+        }
+    }
+
+    #[test]
+    fn py27_arguments() {
+        type Op = py27::Standard;
+
+        let mut code = code_object("f", vec![PyObject::None, PyObject::Int(1)]);
+        code.names = names(&["len"]);
+        code.varnames = names(&["a", "b"]);
+        code.cellvars = names(&["c"]);
+        code.freevars = names(&["d"]);
+
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_CONST, 1)),
+            Some(Argument::Const(&code.consts[1]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_GLOBAL, 0)),
+            Some(Argument::Name(&code.names[0]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op::STORE_FAST, 1)),
+            Some(Argument::Local(&code.varnames[1]))
+        );
+        // Free variable indexes continue on from the cell variables
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_CLOSURE, 0)),
+            Some(Argument::Free(&code.cellvars[0]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_DEREF, 1)),
+            Some(Argument::Free(&code.freevars[0]))
+        );
+
+        assert_eq!(code.argument(&instr(Op::LOAD_CONST, 2)), None);
+        assert_eq!(code.argument(&instr(Op::LOAD_DEREF, 2)), None);
+        assert_eq!(code.argument(&instr(Op::JUMP_ABSOLUTE, 0)), None);
+        let return_value = ExtendedInstruction {
+            opcode: Op::RETURN_VALUE,
+            arg: None,
+            extended_args: 0,
+        };
+        assert_eq!(code.argument(&return_value), None);
+    }
+
+    #[test]
+    fn py311_localsplus() {
+        type Op = py311::Standard;
+
+        // def f(a): b = 1; c = 2; def g(): return a, c
+        let mut code = code_object("f", vec![PyObject::None]);
+        code.names = names(&["print", "len"]);
+        code.localsplusnames = names(&["a", "b", "c", "d"]);
+        code.localspluskinds = vec![
+            CO_FAST_LOCAL | CO_FAST_CELL,
+            CO_FAST_LOCAL,
+            CO_FAST_CELL,
+            CO_FAST_FREE,
+        ];
+        // These are derived from `localsplusnames` and must not be used for indexing
+        code.varnames = names(&["a", "b"]);
+        code.cellvars = names(&["a", "c"]);
+        code.freevars = names(&["d"]);
+
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_FAST, 1)),
+            Some(Argument::Local(&code.localsplusnames[1]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_DEREF, 3)),
+            Some(Argument::Free(&code.localsplusnames[3]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op::MAKE_CELL, 2)),
+            Some(Argument::Free(&code.localsplusnames[2]))
+        );
+        assert_eq!(code.argument(&instr(Op::LOAD_FAST, 4)), None);
+
+        // The low bit of LOAD_GLOBAL's argument is a flag, including in its specializations
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_GLOBAL, 3)),
+            Some(Argument::Name(&code.names[1]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_GLOBAL_BUILTIN, 2)),
+            Some(Argument::Name(&code.names[1]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op::LOAD_ATTR, 1)),
+            Some(Argument::Name(&code.names[1]))
+        );
+    }
+
+    #[test]
+    fn shifted_arguments() {
+        let mut code = code_object("f", Vec::new());
+        code.names = names(&["a", "b", "c"]);
+        code.localsplusnames = names(&["x", "y", "z"]);
+        code.localspluskinds = vec![CO_FAST_LOCAL; 3];
+
+        type Op312 = py312::Standard;
+        assert_eq!(
+            code.argument(&instr(Op312::LOAD_ATTR, 5)),
+            Some(Argument::Name(&code.names[2]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op312::LOAD_ATTR_METHOD_WITH_VALUES, 3)),
+            Some(Argument::Name(&code.names[1]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op312::LOAD_SUPER_ATTR, 7)),
+            Some(Argument::Name(&code.names[1]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op312::LOAD_GLOBAL_BUILTIN, 4)),
+            Some(Argument::Name(&code.names[2]))
+        );
+
+        type Op313 = py313::Standard;
+        assert_eq!(
+            code.argument(&instr(Op313::LOAD_SUPER_ATTR, 10)),
+            Some(Argument::Name(&code.names[2]))
+        );
+        assert_eq!(
+            code.argument(&instr(Op313::LOAD_FAST_LOAD_FAST, 0x21)),
+            Some(Argument::LocalPair(
+                &code.localsplusnames[2],
+                &code.localsplusnames[1]
+            ))
+        );
+        assert_eq!(
+            code.argument(&instr(Op313::STORE_FAST_STORE_FAST, 0x02)),
+            Some(Argument::LocalPair(
+                &code.localsplusnames[0],
+                &code.localsplusnames[2]
+            ))
+        );
+        assert_eq!(
+            code.argument(&instr(Op313::STORE_FAST_LOAD_FAST, 0x30)),
+            None
+        );
+    }
+
+    #[test]
+    fn walk_order_and_names() {
+        let data = include_bytes!("../testdata/py27/sample.pyc");
+        let module = crate::marshal::loads(&data[8..], PythonVersion::new(2, 7)).unwrap();
+        let module = module.as_code().unwrap();
+
+        // Depth first, in `co_consts` order, as walking the code objects in Python gives
+        let walked: Vec<(String, u32)> = module
+            .walk()
+            .map(|(path, code)| (path, code.firstlineno))
+            .collect();
+        let expected = [
+            ("<module>", 1),
+            ("<module>.Greeter", 15),
+            ("<module>.Greeter.__init__", 18),
+            ("<module>.Greeter.greet", 21),
+            ("<module>.counter", 25),
+            ("<module>.counter.step", 28),
+            ("<module>.<lambda>", 35),
+            ("<module>.<dictcomp>", 36),
+        ];
+        assert_eq!(walked.len(), expected.len());
+        for ((path, line), (expected_path, expected_line)) in walked.iter().zip(&expected) {
+            assert_eq!((path.as_str(), *line), (*expected_path, *expected_line));
+        }
+
+        let children: Vec<_> = module.children().map(|code| code.firstlineno).collect();
+        assert_eq!(children, [15, 25, 35, 36]);
+    }
+
+    #[test]
+    fn walk_unnamed_code() {
+        let mut inner = code_object("inner", Vec::new());
+        inner.name = PyObject::None;
+        let leaf = code_object("leaf", Vec::new());
+        inner.consts.push(PyObject::Code(Box::new(leaf)));
+        let outer = code_object(
+            "outer",
+            vec![PyObject::Int(1), PyObject::Code(Box::new(inner))],
+        );
+
+        let paths: Vec<String> = outer.walk().map(|(path, _)| path).collect();
+        assert_eq!(paths, ["outer", "outer.?", "outer.?.leaf"]);
+    }
+}
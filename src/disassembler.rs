@@ -0,0 +1,606 @@
+use alloc::collections::VecDeque;
+use core::marker::PhantomData;
+use num_traits::FromPrimitive;
+
+use crate::error::DecodeError;
+use crate::opcode::{ExtendedInstruction, Instruction, Opcode};
+use crate::{decode_at, decode_extended_at};
+
+/// Iterator over the instructions in a buffer of bytecode. Each item is the byte offset the
+/// instruction started at along with the decoded instruction.
+///
+/// Iteration stops once the end of the buffer is reached. If an instruction cannot be decoded
+/// the error is yielded once and iteration stops.
+///
+/// The inline cache entries which follow instructions since Python 3.11 are skipped unless they
+/// are enabled with [`Disassembler::show_caches`].
+pub struct Disassembler<'a, O> {
+    code: &'a [u8],
+    offset: usize,
+    done: bool,
+    show_caches: bool,
+    /// Cache entries of the previous instruction which are yet to be yielded
+    caches: usize,
+    _opcode: PhantomData<O>,
+}
+
+impl<'a, O: Opcode + FromPrimitive> Disassembler<'a, O> {
+    /// Creates a new disassembler over `code`, starting at offset 0
+    pub fn new(code: &'a [u8]) -> Self {
+        Disassembler {
+            code,
+            offset: 0,
+            done: false,
+            show_caches: false,
+            caches: 0,
+            _opcode: PhantomData,
+        }
+    }
+
+    /// Whether or not to yield each inline cache entry as an instruction with the opcode
+    /// table's [`Opcode::cache`] opcode, like `dis` does with `show_caches=True`. Cache entries
+    /// are skipped if the opcode table has no `CACHE` opcode.
+    pub fn show_caches(mut self, enabled: bool) -> Self {
+        self.show_caches = enabled;
+        self
+    }
+
+    /// The offset of the next instruction to be decoded
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Converts this disassembler into one which folds `EXTENDED_ARG` prefixes into the
+    /// instruction that follows them. Decoding resumes from the current offset.
+    pub fn extended(self) -> ExtendedDisassembler<'a, O> {
+        ExtendedDisassembler { inner: self }
+    }
+}
+
+impl<'a, O: Opcode + FromPrimitive> Iterator for Disassembler<'a, O> {
+    type Item = Result<(usize, Instruction<O>), DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.offset >= self.code.len() {
+            return None;
+        }
+
+        let offset = self.offset;
+        if let Some(cache) = self.next_cache() {
+            let instr = Instruction {
+                opcode: cache,
+                arg: None,
+            };
+            return Some(Ok((offset, instr)));
+        }
+
+        match decode_at::<O>(self.code, offset) {
+            Ok(instr) => {
+                self.offset += instr.len();
+                self.skip_caches(instr.opcode);
+                Some(Ok((offset, instr)))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<O: Opcode> Disassembler<'_, O> {
+    /// Steps over the inline cache entries of an instruction with `opcode`, or queues them up
+    /// to be yielded if they are shown
+    fn skip_caches(&mut self, opcode: O) {
+        let entries = opcode.cache_entries();
+        if self.show_caches && O::cache().is_some() {
+            self.caches = entries;
+        } else {
+            self.offset += entries * O::encoding().code_unit();
+        }
+    }
+
+    /// Consumes the next pending cache entry
+    fn next_cache(&mut self) -> Option<O> {
+        if self.caches == 0 {
+            return None;
+        }
+
+        self.caches -= 1;
+        self.offset += O::encoding().code_unit();
+        O::cache()
+    }
+}
+
+/// Iterator over the logical instructions in a buffer of bytecode, folding `EXTENDED_ARG`
+/// prefixes into the instruction they apply to. The yielded offset is that of the first prefix.
+///
+/// Created with [`Disassembler::extended`].
+pub struct ExtendedDisassembler<'a, O> {
+    inner: Disassembler<'a, O>,
+}
+
+impl<'a, O: Opcode + FromPrimitive> ExtendedDisassembler<'a, O> {
+    /// Creates a new disassembler over `code`, starting at offset 0
+    pub fn new(code: &'a [u8]) -> Self {
+        Disassembler::new(code).extended()
+    }
+
+    /// The offset of the next instruction to be decoded
+    pub fn offset(&self) -> usize {
+        self.inner.offset
+    }
+
+    /// Whether or not to yield inline cache entries. See [`Disassembler::show_caches`].
+    pub fn show_caches(mut self, enabled: bool) -> Self {
+        self.inner.show_caches = enabled;
+        self
+    }
+}
+
+impl<'a, O: Opcode + FromPrimitive> Iterator for ExtendedDisassembler<'a, O> {
+    type Item = Result<(usize, ExtendedInstruction<O>), DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let inner = &mut self.inner;
+        if inner.done || inner.offset >= inner.code.len() {
+            return None;
+        }
+
+        let offset = inner.offset;
+        if let Some(cache) = inner.next_cache() {
+            let instr = ExtendedInstruction {
+                opcode: cache,
+                arg: None,
+                extended_args: 0,
+            };
+            return Some(Ok((offset, instr)));
+        }
+
+        match decode_extended_at::<O>(inner.code, offset) {
+            Ok(instr) => {
+                inner.offset += instr.len();
+                inner.skip_caches(instr.opcode);
+                Some(Ok((offset, instr)))
+            }
+            Err(e) => {
+                inner.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// An item produced by a [`Sweep`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SweepItem<O: Opcode> {
+    /// An instruction that was decoded on the main sweep path
+    Instruction {
+        offset: usize,
+        instruction: Instruction<O>,
+    },
+    /// A byte which is not a valid opcode. The sweep resynchronizes at the following code unit.
+    InvalidByte { offset: usize, byte: u8 },
+    /// An instruction whose argument runs past the end of the buffer. This is always the last
+    /// item produced.
+    Truncated { offset: usize, opcode: u8 },
+    /// A valid instruction which starts inside the argument bytes of the preceding
+    /// [`SweepItem::Instruction`]. Only produced when alternatives are enabled with
+    /// [`Sweep::alternatives`].
+    Alternative {
+        offset: usize,
+        instruction: Instruction<O>,
+    },
+}
+
+/// Error-recovering linear sweep over a buffer of bytecode. Unlike [`Disassembler`], a sweep
+/// does not stop at the first invalid byte: it emits a [`SweepItem::InvalidByte`] and carries on
+/// decoding from the next code unit, so the remainder of damaged or obfuscated code can still be
+/// inspected. Inline cache entries are always skipped.
+pub struct Sweep<'a, O: Opcode> {
+    code: &'a [u8],
+    offset: usize,
+    alternatives: bool,
+    pending: VecDeque<SweepItem<O>>,
+}
+
+impl<'a, O: Opcode + FromPrimitive> Sweep<'a, O> {
+    /// Creates a new sweep over `code`, starting at offset 0
+    pub fn new(code: &'a [u8]) -> Self {
+        Sweep {
+            code,
+            offset: 0,
+            alternatives: false,
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Whether or not to also report the instructions that decode from the argument bytes of
+    /// each instruction. Code that jumps into the middle of an instruction executes these
+    /// overlapping decodings instead of the ones on the main sweep path. Wordcode instructions
+    /// are a single code unit, so there are none to report for them.
+    pub fn alternatives(mut self, enabled: bool) -> Self {
+        self.alternatives = enabled;
+        self
+    }
+}
+
+impl<'a, O: Opcode + FromPrimitive> Iterator for Sweep<'a, O> {
+    type Item = SweepItem<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.pending.pop_front() {
+            return Some(item);
+        }
+
+        if self.offset >= self.code.len() {
+            return None;
+        }
+
+        let offset = self.offset;
+        match decode_at::<O>(self.code, offset) {
+            Ok(instruction) => {
+                self.offset += instruction.len();
+                let caches = instruction.cache_len();
+
+                if self.alternatives {
+                    let code_unit = O::encoding().code_unit();
+                    for alt_offset in ((offset + code_unit)..self.offset).step_by(code_unit) {
+                        if let Ok(instruction) = decode_at::<O>(self.code, alt_offset) {
+                            self.pending.push_back(SweepItem::Alternative {
+                                offset: alt_offset,
+                                instruction,
+                            });
+                        }
+                    }
+                }
+
+                self.offset += caches;
+                Some(SweepItem::Instruction {
+                    offset,
+                    instruction,
+                })
+            }
+            Err(DecodeError::TruncatedArgument { opcode, offset }) => {
+                self.offset = self.code.len();
+                Some(SweepItem::Truncated { offset, opcode })
+            }
+            Err(_) => {
+                self.offset += O::encoding().code_unit();
+                Some(SweepItem::InvalidByte {
+                    offset,
+                    byte: self.code[offset],
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::opcode::{py27, py311, py312, py36};
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    /// An instruction or inline cache entry listed by a fixture of `testdata/caches`
+    pub(crate) struct Listed<'a> {
+        pub offset: usize,
+        /// `CACHE` for a cache entry
+        pub name: &'a str,
+        pub arg: Option<u16>,
+        pub target: Option<usize>,
+    }
+
+    /// Reads a fixture of `testdata/caches`, which lists the `co_code` of each code object as
+    /// `code NAME HEX`, followed by what `dis.get_instructions(show_caches=True)` yields for it as
+    /// `instr OFFSET NAME ARG TARGET` and `cache OFFSET`
+    pub(crate) fn read_cache_fixture(fixture: &str) -> Vec<(Vec<u8>, Vec<Listed<'_>>)> {
+        let mut codes: Vec<(Vec<u8>, Vec<Listed<'_>>)> = Vec::new();
+        for record in fixture.lines() {
+            let values: Vec<&str> = record.split(' ').collect();
+            let listed = match values[..] {
+                ["code", _, hex] => {
+                    let code = (0..hex.len())
+                        .step_by(2)
+                        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                        .collect();
+                    codes.push((code, Vec::new()));
+                    continue;
+                }
+                ["instr", offset, name, arg, target] => Listed {
+                    offset: offset.parse().unwrap(),
+                    name,
+                    arg: arg.parse().ok(),
+                    target: target.parse().ok(),
+                },
+                ["cache", offset] => Listed {
+                    offset: offset.parse().unwrap(),
+                    name: "CACHE",
+                    arg: None,
+                    target: None,
+                },
+                _ => panic!("unexpected record {}", record),
+            };
+            codes.last_mut().unwrap().1.push(listed);
+        }
+
+        codes
+    }
+
+    fn summarize<O: Opcode>(offset: usize, instr: &Instruction<O>) -> (usize, String, Option<u16>) {
+        (offset, format!("{:?}", instr.opcode), instr.arg)
+    }
+
+    /// Checks that disassembling and sweeping each code object of a fixture yields the
+    /// instructions `dis` does, with and without the cache entries
+    fn check_caches<O: Opcode + PartialEq>(fixture: &str) {
+        for (code, listed) in read_cache_fixture(fixture) {
+            let with_caches: Vec<_> = listed
+                .iter()
+                .map(|listed| (listed.offset, String::from(listed.name), listed.arg))
+                .collect();
+            let without_caches: Vec<_> = with_caches
+                .iter()
+                .filter(|(_, name, _)| name != "CACHE")
+                .cloned()
+                .collect();
+
+            let shown: Vec<_> = Disassembler::<O>::new(&code)
+                .show_caches(true)
+                .map(|result| result.map(|(offset, instr)| summarize(offset, &instr)))
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(shown, with_caches);
+
+            let decoded: Vec<_> = Disassembler::<O>::new(&code)
+                .collect::<Result<_, _>>()
+                .unwrap();
+            let skipped: Vec<_> = decoded
+                .iter()
+                .map(|(offset, instr)| summarize(*offset, instr))
+                .collect();
+            assert_eq!(skipped, without_caches);
+
+            // The sample has no `EXTENDED_ARG` prefixes to fold
+            let extended: Vec<_> = ExtendedDisassembler::<O>::new(&code)
+                .show_caches(true)
+                .map(|result| result.map(|(offset, instr)| (offset, instr.opcode, instr.arg)))
+                .collect::<Result<_, _>>()
+                .unwrap();
+            let expected: Vec<_> = Disassembler::<O>::new(&code)
+                .show_caches(true)
+                .map(|result| {
+                    result.map(|(offset, instr)| (offset, instr.opcode, instr.arg.map(u32::from)))
+                })
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(extended, expected);
+
+            let swept: Vec<_> = Sweep::<O>::new(&code).collect();
+            let expected: Vec<_> = decoded
+                .iter()
+                .map(|(offset, instr)| SweepItem::Instruction {
+                    offset: *offset,
+                    instruction: instr.clone(),
+                })
+                .collect();
+            assert_eq!(swept, expected);
+
+            let instructions = listed.iter().filter(|listed| listed.name != "CACHE");
+            for ((offset, instr), listed) in decoded.iter().zip(instructions) {
+                assert_eq!(instr.jump_target(*offset), listed.target, "{}", offset);
+            }
+        }
+    }
+
+    #[test]
+    fn caches_match_dis() {
+        check_caches::<py311::Standard>(include_str!("../testdata/caches/py311.txt"));
+        check_caches::<py312::Standard>(include_str!("../testdata/caches/py312.txt"));
+    }
+
+    #[test]
+    fn offsets_follow_instruction_lengths() {
+        use py27::Standard::*;
+
+        // LOAD_CONST 1; POP_TOP; LOAD_CONST 0; RETURN_VALUE
+        let code = [100, 1, 0, 1, 100, 0, 0, 83];
+        let decoded: Vec<_> = Disassembler::<py27::Standard>::new(&code)
+            .map(|result| result.map(|(offset, instr)| (offset, instr.opcode, instr.arg)))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            decoded,
+            [
+                (0, LOAD_CONST, Some(1)),
+                (3, POP_TOP, None),
+                (4, LOAD_CONST, Some(0)),
+                (7, RETURN_VALUE, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn wordcode_offsets() {
+        use py36::Standard::*;
+
+        // LOAD_CONST 1; POP_TOP; LOAD_CONST 0; RETURN_VALUE
+        let code = [100, 1, 1, 0, 100, 0, 83, 0];
+        let decoded: Vec<_> = Disassembler::<py36::Standard>::new(&code)
+            .map(|result| result.map(|(offset, instr)| (offset, instr.opcode)))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            decoded,
+            [
+                (0, LOAD_CONST),
+                (2, POP_TOP),
+                (4, LOAD_CONST),
+                (6, RETURN_VALUE)
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_after_first_error() {
+        // LOAD_CONST 1; an unknown opcode; RETURN_VALUE
+        let code = [100, 1, 0, 7, 83];
+        let mut disassembler = Disassembler::<py27::Standard>::new(&code);
+        assert_eq!(disassembler.next().unwrap().unwrap().0, 0);
+        assert_eq!(disassembler.offset(), 3);
+        assert!(matches!(
+            disassembler.next(),
+            Some(Err(DecodeError::UnknownOpcode {
+                opcode: 7,
+                offset: 3
+            }))
+        ));
+        assert!(disassembler.next().is_none());
+        assert!(disassembler.next().is_none());
+    }
+
+    #[test]
+    fn extended_folds_prefixes() {
+        use py27::Standard::*;
+
+        // POP_TOP; EXTENDED_ARG 1; LOAD_CONST 2; RETURN_VALUE
+        let code = [1, 145, 1, 0, 100, 2, 0, 83];
+        let decoded: Vec<_> = ExtendedDisassembler::<py27::Standard>::new(&code)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let summary: Vec<_> = decoded
+            .iter()
+            .map(|(offset, instr)| (*offset, instr.opcode, instr.arg, instr.extended_args))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (0, POP_TOP, None, 0),
+                (1, LOAD_CONST, Some(0x10002), 1),
+                (7, RETURN_VALUE, None, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_resumes_from_current_offset() {
+        use py36::Standard::*;
+
+        // POP_TOP; EXTENDED_ARG 1; LOAD_CONST 2
+        let code = [1, 0, 144, 1, 100, 2];
+        let mut disassembler = Disassembler::<py36::Standard>::new(&code);
+        disassembler.next().unwrap().unwrap();
+
+        let mut extended = disassembler.extended();
+        let (offset, instr) = extended.next().unwrap().unwrap();
+        assert_eq!(
+            (offset, instr.opcode, instr.arg),
+            (2, LOAD_CONST, Some(0x102))
+        );
+        assert_eq!(extended.offset(), code.len());
+        assert!(extended.next().is_none());
+    }
+
+    fn instruction<O: Opcode>(offset: usize, opcode: O, arg: Option<u16>) -> SweepItem<O> {
+        SweepItem::Instruction {
+            offset,
+            instruction: Instruction { opcode, arg },
+        }
+    }
+
+    #[test]
+    fn sweep_resynchronizes_after_invalid_bytes() {
+        use py27::Standard::*;
+
+        // LOAD_CONST 1; an unknown opcode; RETURN_VALUE
+        let items: Vec<_> = Sweep::<py27::Standard>::new(&[100, 1, 0, 7, 83]).collect();
+        assert_eq!(
+            items,
+            [
+                instruction(0, LOAD_CONST, Some(1)),
+                SweepItem::InvalidByte { offset: 3, byte: 7 },
+                instruction(4, RETURN_VALUE, None),
+            ]
+        );
+
+        // Wordcode resumes at the next code unit rather than the next byte
+        let items: Vec<_> = Sweep::<py36::Standard>::new(&[100, 1, 7, 83, 83, 0]).collect();
+        assert_eq!(
+            items,
+            [
+                instruction(0, py36::Standard::LOAD_CONST, Some(1)),
+                SweepItem::InvalidByte { offset: 2, byte: 7 },
+                instruction(4, py36::Standard::RETURN_VALUE, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn sweep_ends_with_truncated_instruction() {
+        // POP_TOP; LOAD_CONST with one argument byte
+        let items: Vec<_> = Sweep::<py27::Standard>::new(&[1, 100, 1]).collect();
+        assert_eq!(
+            items,
+            [
+                instruction(0, py27::Standard::POP_TOP, None),
+                SweepItem::Truncated {
+                    offset: 1,
+                    opcode: 100
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn sweep_alternatives() {
+        use py27::Standard::*;
+
+        // LOAD_CONST 0x5301 hides a POP_TOP and a RETURN_VALUE in its argument. LOAD_CONST 7
+        // hides an unknown opcode, which is not reported, and a STOP_CODE.
+        let code = [100, 1, 83, 100, 7, 0, 83];
+        let items: Vec<_> = Sweep::<py27::Standard>::new(&code)
+            .alternatives(true)
+            .collect();
+        assert_eq!(
+            items,
+            [
+                instruction(0, LOAD_CONST, Some(0x5301)),
+                SweepItem::Alternative {
+                    offset: 1,
+                    instruction: Instruction {
+                        opcode: POP_TOP,
+                        arg: None
+                    },
+                },
+                SweepItem::Alternative {
+                    offset: 2,
+                    instruction: Instruction {
+                        opcode: RETURN_VALUE,
+                        arg: None
+                    },
+                },
+                instruction(3, LOAD_CONST, Some(7)),
+                SweepItem::Alternative {
+                    offset: 5,
+                    instruction: Instruction {
+                        opcode: STOP_CODE,
+                        arg: None
+                    },
+                },
+                instruction(6, RETURN_VALUE, None),
+            ]
+        );
+
+        let without: Vec<_> = Sweep::<py27::Standard>::new(&code).collect();
+        assert_eq!(without.len(), 3);
+
+        // Wordcode arguments never hold another instruction
+        let code = [100, 83, 83, 0];
+        let items: Vec<_> = Sweep::<py36::Standard>::new(&code)
+            .alternatives(true)
+            .collect();
+        assert_eq!(items.len(), 2);
+    }
+}
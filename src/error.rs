@@ -1,11 +1,134 @@
+use alloc::string::String;
 use thiserror::Error;
 
+use crate::builder::Label;
+use crate::marshal::Limit;
+use crate::pyc::PythonVersion;
+
+/// Errors that may occur while decoding instructions. Offsets are relative to the position
+/// decoding started from: the start of the source or slice passed to a `decode` function, or the
+/// start of the buffer for [`crate::disassembler::Disassembler`].
 #[derive(Error, Debug)]
 pub enum DecodeError {
-    #[error("unknown opcode: 0x{0:X} ({0})")]
-    UnknownOpcode(u8),
+    #[error("unknown opcode 0x{opcode:X} ({opcode}) at offset {offset}")]
+    UnknownOpcode { opcode: u8, offset: usize },
+    #[error("argument of opcode 0x{opcode:X} ({opcode}) at offset {offset} is truncated")]
+    TruncatedArgument { opcode: u8, offset: usize },
+    #[error("unexpected end of input at offset {offset}")]
+    UnexpectedEof { offset: usize },
+    #[cfg(feature = "std")]
     #[error("an IO error occurred while reading data: {0}")]
     IoError(#[from] std::io::Error),
-    #[error("could not read required number of bytes")]
-    InvalidBytesRead,
+}
+
+impl DecodeError {
+    /// The offset of the instruction that failed to decode, if known
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            DecodeError::UnknownOpcode { offset, .. }
+            | DecodeError::TruncatedArgument { offset, .. }
+            | DecodeError::UnexpectedEof { offset } => Some(*offset),
+            #[cfg(feature = "std")]
+            DecodeError::IoError(_) => None,
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("unknown mnemonic: {0}")]
+pub struct ParseMnemonicError(pub String);
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AssembleError {
+    #[error("line {line}: unknown mnemonic `{mnemonic}`")]
+    UnknownMnemonic { line: usize, mnemonic: String },
+    #[error("line {line}: `{mnemonic}` requires an argument")]
+    MissingArgument { line: usize, mnemonic: String },
+    #[error("line {line}: `{mnemonic}` does not take an argument")]
+    UnexpectedArgument { line: usize, mnemonic: String },
+    #[error("line {line}: invalid argument `{arg}`")]
+    InvalidArgument { line: usize, arg: String },
+    #[error("line {line}: label `{label}` is only valid as the argument of a jump")]
+    LabelNotAllowed { line: usize, label: String },
+    #[error("line {line}: label `{label}` is already defined")]
+    DuplicateLabel { line: usize, label: String },
+    #[error("line {line}: label `{label}` is not defined")]
+    UndefinedLabel { line: usize, label: String },
+    #[error("line {line}: relative jump to `{label}` would jump backwards")]
+    BackwardRelativeJump { line: usize, label: String },
+    #[error("line {line}: backward jump to `{label}` would jump forwards")]
+    ForwardJump { line: usize, label: String },
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    #[error("{0:?} is already bound")]
+    LabelAlreadyBound(Label),
+    #[error("{0:?} was jumped to but never bound")]
+    UnboundLabel(Label),
+    #[error("{0:?} was not created by this builder")]
+    UnknownLabel(Label),
+    #[error("instruction {instruction} targets a label but is not a jump")]
+    NotAJump { instruction: usize },
+    #[error("relative jump at instruction {instruction} would jump backwards")]
+    BackwardRelativeJump { instruction: usize },
+    #[error("backward jump at instruction {instruction} would jump forwards")]
+    ForwardJump { instruction: usize },
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PycError {
+    #[error("pyc data is too short: expected at least {expected} bytes, found {actual}")]
+    TooShort { expected: usize, actual: usize },
+    #[error("invalid pyc magic: {0:02X?}")]
+    InvalidMagic([u8; 4]),
+    #[error("unknown pyc magic number: {0}")]
+    UnknownMagic(u16),
+    #[error("invalid marshal data: {0}")]
+    Marshal(#[from] MarshalError),
+}
+
+/// Errors that may occur while opening a memory-mapped `.pyc` file
+#[cfg(feature = "mmap")]
+#[derive(Error, Debug)]
+pub enum LoadError {
+    #[error("io error: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    Pyc(#[from] PycError),
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MarshalError {
+    #[error("unexpected end of marshal data at offset {offset}")]
+    UnexpectedEof { offset: usize },
+    #[error("unknown marshal type code 0x{type_code:X} ({}) at offset {offset}", *type_code as char)]
+    UnknownType { type_code: u8, offset: usize },
+    #[error("unexpected NULL object at offset {offset}")]
+    UnexpectedNull { offset: usize },
+    #[error("invalid length {length} at offset {offset}")]
+    InvalidLength { length: i32, offset: usize },
+    #[error("string reference {index} at offset {offset} does not refer to an interned string")]
+    InvalidStringRef { index: i32, offset: usize },
+    #[error("reference {index} at offset {offset} does not refer to a previously read object")]
+    InvalidRef { index: i32, offset: usize },
+    #[error("invalid float at offset {offset}")]
+    InvalidFloat { offset: usize },
+    #[error("{limit} limit exceeded at offset {offset}")]
+    LimitExceeded { limit: Limit, offset: usize },
+    #[error("expected {expected} at offset {offset}, found {found}")]
+    UnexpectedType {
+        expected: &'static str,
+        found: &'static str,
+        offset: usize,
+    },
+    #[error("writing Python {0} marshal data is not supported")]
+    UnsupportedVersion(PythonVersion),
+    #[error("code object field `{field}` cannot be written for Python {version}")]
+    UnsupportedField {
+        field: &'static str,
+        version: PythonVersion,
+    },
+    #[error("object of length {length} is too large to marshal")]
+    TooLarge { length: usize },
 }
@@ -0,0 +1,183 @@
+//! Exception tables of Python 3.11 and later.
+//!
+//! Since Python 3.11 `try` blocks no longer push blocks onto a runtime block stack. Instead,
+//! `co_exceptiontable` maps ranges of bytecode to the handler that an exception raised inside
+//! them unwinds to. Each entry is a sequence of big-endian varints made of 6-bit chunks, and the
+//! first byte of every entry has its high bit set.
+//!
+//! Tables are decoded as far as they are valid; a truncated entry at the end of a table is
+//! ignored.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A range of bytecode whose exceptions are handled by the same handler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionTableEntry {
+    /// The bytecode offsets this entry covers
+    pub range: Range<usize>,
+    /// The offset of the handler
+    pub target: usize,
+    /// The stack depth to unwind to before the exception is pushed
+    pub depth: u32,
+    /// Whether or not the offset of the raising instruction is pushed before the exception
+    pub lasti: bool,
+}
+
+/// A decoded exception table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionTable {
+    entries: Vec<ExceptionTableEntry>,
+}
+
+impl ExceptionTable {
+    /// Decodes `table`, which is the `co_exceptiontable` of a code object
+    pub fn new(table: &[u8]) -> Self {
+        let mut reader = EntryReader { table, offset: 0 };
+        let mut entries = Vec::new();
+        while let Some(entry) = reader.next_entry() {
+            entries.push(entry);
+        }
+
+        ExceptionTable { entries }
+    }
+
+    /// The entries in this table, ordered by offset. Entries never overlap.
+    pub fn entries(&self) -> &[ExceptionTableEntry] {
+        &self.entries
+    }
+
+    /// The entry which handles exceptions raised by the instruction at `offset`, if any
+    pub fn handler_at(&self, offset: usize) -> Option<&ExceptionTableEntry> {
+        let index = self
+            .entries
+            .partition_point(|entry| entry.range.end <= offset);
+
+        self.entries
+            .get(index)
+            .filter(|entry| entry.range.contains(&offset))
+    }
+}
+
+/// Offsets and lengths in the table count 2-byte code units
+const CODE_UNIT: usize = 2;
+
+struct EntryReader<'a> {
+    table: &'a [u8],
+    offset: usize,
+}
+
+impl EntryReader<'_> {
+    fn next_entry(&mut self) -> Option<ExceptionTableEntry> {
+        let start = self.read_varint()? as usize * CODE_UNIT;
+        let len = self.read_varint()? as usize * CODE_UNIT;
+        let target = self.read_varint()? as usize * CODE_UNIT;
+        let depth_lasti = self.read_varint()?;
+
+        Some(ExceptionTableEntry {
+            range: start..start.checked_add(len)?,
+            target,
+            depth: depth_lasti >> 1,
+            lasti: depth_lasti & 1 != 0,
+        })
+    }
+
+    fn read_varint(&mut self) -> Option<u32> {
+        let mut value = 0u32;
+        loop {
+            let byte = *self.table.get(self.offset)?;
+            self.offset += 1;
+
+            // Values which overflow are treated as the end of the table
+            if value >> 26 != 0 {
+                return None;
+            }
+            value = (value << 6) | u32::from(byte & 0x3F);
+            if byte & 0x40 == 0 {
+                return Some(value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks a fixture of `testdata/exceptiontable`, which lists the exception table of each
+    /// code object as `code NAME HEX`, followed by the entries `dis._parse_exception_table`
+    /// decodes from it as `entry START END TARGET DEPTH LASTI`
+    fn check_fixture(fixture: &str) {
+        let mut tables = Vec::new();
+        for record in fixture.lines() {
+            let values: Vec<&str> = record.split(' ').collect();
+            match values[..] {
+                ["code", name, hex] => {
+                    let bytes: Vec<u8> = match hex {
+                        "-" => Vec::new(),
+                        hex => (0..hex.len())
+                            .step_by(2)
+                            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                            .collect(),
+                    };
+                    tables.push((name, bytes, Vec::new()));
+                }
+                ["entry", start, end, target, depth, lasti] => {
+                    let entry = ExceptionTableEntry {
+                        range: start.parse().unwrap()..end.parse().unwrap(),
+                        target: target.parse().unwrap(),
+                        depth: depth.parse().unwrap(),
+                        lasti: lasti == "1",
+                    };
+                    tables.last_mut().unwrap().2.push(entry);
+                }
+                _ => panic!("unexpected record {}", record),
+            }
+        }
+
+        for (name, bytes, expected) in tables {
+            let table = ExceptionTable::new(&bytes);
+            assert_eq!(table.entries(), &expected[..], "{}", name);
+            for entry in &expected {
+                assert_eq!(table.handler_at(entry.range.start), Some(entry), "{}", name);
+                assert_eq!(
+                    table.handler_at(entry.range.end - 2),
+                    Some(entry),
+                    "{}",
+                    name
+                );
+                assert_ne!(table.handler_at(entry.range.end), Some(entry), "{}", name);
+            }
+
+            // The entry cut short by truncating the table is dropped
+            if let Some((_, complete)) = expected.split_last() {
+                let truncated = ExceptionTable::new(&bytes[..bytes.len() - 1]);
+                assert_eq!(truncated.entries(), complete, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn entries() {
+        check_fixture(include_str!("../testdata/exceptiontable/py311.txt"));
+        check_fixture(include_str!("../testdata/exceptiontable/py312.txt"));
+        check_fixture(include_str!("../testdata/exceptiontable/py313.txt"));
+    }
+
+    #[test]
+    fn overflowing_varints_end_the_table() {
+        // The start of the second entry needs more than 32 bits
+        let table = ExceptionTable::new(&[
+            0x80, 0x01, 0x02, 0x03, 0xC1, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x3F, 0x01, 0x02, 0x03,
+        ]);
+        assert_eq!(
+            table.entries(),
+            &[ExceptionTableEntry {
+                range: 0..2,
+                target: 4,
+                depth: 1,
+                lasti: true,
+            }]
+        );
+    }
+}
@@ -0,0 +1,356 @@
+//! Typed access to the `co_flags` of a code object.
+//!
+//! The meaning of most bits has stayed the same across versions, but the `__future__` flags moved
+//! in Python 3.8 and some flags only exist in some versions, so flags are always interpreted
+//! relative to a [`PythonVersion`].
+
+use core::fmt;
+
+use crate::pyc::PythonVersion;
+
+/// A single `CO_*` flag
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CodeFlag {
+    Optimized,
+    NewLocals,
+    VarArgs,
+    VarKeywords,
+    Nested,
+    Generator,
+    NoFree,
+    /// Python 3.5+
+    Coroutine,
+    /// Python 3.5+
+    IterableCoroutine,
+    /// Python 3.6+
+    AsyncGenerator,
+    /// Python 2 only
+    GeneratorAllowed,
+    FutureDivision,
+    FutureAbsoluteImport,
+    FutureWithStatement,
+    FuturePrintFunction,
+    FutureUnicodeLiterals,
+    /// Python 3.1+
+    FutureBarryAsBdfl,
+    /// Python 3.5+
+    FutureGeneratorStop,
+    /// Python 3.7+
+    FutureAnnotations,
+}
+
+impl CodeFlag {
+    pub const ALL: &'static [CodeFlag] = &[
+        CodeFlag::Optimized,
+        CodeFlag::NewLocals,
+        CodeFlag::VarArgs,
+        CodeFlag::VarKeywords,
+        CodeFlag::Nested,
+        CodeFlag::Generator,
+        CodeFlag::NoFree,
+        CodeFlag::Coroutine,
+        CodeFlag::IterableCoroutine,
+        CodeFlag::AsyncGenerator,
+        CodeFlag::GeneratorAllowed,
+        CodeFlag::FutureDivision,
+        CodeFlag::FutureAbsoluteImport,
+        CodeFlag::FutureWithStatement,
+        CodeFlag::FuturePrintFunction,
+        CodeFlag::FutureUnicodeLiterals,
+        CodeFlag::FutureBarryAsBdfl,
+        CodeFlag::FutureGeneratorStop,
+        CodeFlag::FutureAnnotations,
+    ];
+
+    /// The name of the flag without its `CO_` prefix, as shown by `dis`
+    pub fn name(self) -> &'static str {
+        match self {
+            CodeFlag::Optimized => "OPTIMIZED",
+            CodeFlag::NewLocals => "NEWLOCALS",
+            CodeFlag::VarArgs => "VARARGS",
+            CodeFlag::VarKeywords => "VARKEYWORDS",
+            CodeFlag::Nested => "NESTED",
+            CodeFlag::Generator => "GENERATOR",
+            CodeFlag::NoFree => "NOFREE",
+            CodeFlag::Coroutine => "COROUTINE",
+            CodeFlag::IterableCoroutine => "ITERABLE_COROUTINE",
+            CodeFlag::AsyncGenerator => "ASYNC_GENERATOR",
+            CodeFlag::GeneratorAllowed => "GENERATOR_ALLOWED",
+            CodeFlag::FutureDivision => "FUTURE_DIVISION",
+            CodeFlag::FutureAbsoluteImport => "FUTURE_ABSOLUTE_IMPORT",
+            CodeFlag::FutureWithStatement => "FUTURE_WITH_STATEMENT",
+            CodeFlag::FuturePrintFunction => "FUTURE_PRINT_FUNCTION",
+            CodeFlag::FutureUnicodeLiterals => "FUTURE_UNICODE_LITERALS",
+            CodeFlag::FutureBarryAsBdfl => "FUTURE_BARRY_AS_BDFL",
+            CodeFlag::FutureGeneratorStop => "FUTURE_GENERATOR_STOP",
+            CodeFlag::FutureAnnotations => "FUTURE_ANNOTATIONS",
+        }
+    }
+
+    /// The bit this flag is stored in by the given Python version, or `None` if the version
+    /// does not have this flag
+    pub fn bit(self, version: PythonVersion) -> Option<u32> {
+        let py3 = version >= PythonVersion::new(3, 0);
+        let at_least = |minor| py3 && version >= PythonVersion::new(3, minor);
+        // The __future__ flags were moved up to make room for new compiler flags in 3.8
+        let future_shift = if at_least(8) { 4 } else { 0 };
+
+        let bit = match self {
+            CodeFlag::Optimized => 0x1,
+            CodeFlag::NewLocals => 0x2,
+            CodeFlag::VarArgs => 0x4,
+            CodeFlag::VarKeywords => 0x8,
+            CodeFlag::Nested => 0x10,
+            CodeFlag::Generator => 0x20,
+            CodeFlag::NoFree => 0x40,
+            CodeFlag::Coroutine if at_least(5) => 0x80,
+            CodeFlag::IterableCoroutine if at_least(5) => 0x100,
+            CodeFlag::AsyncGenerator if at_least(6) => 0x200,
+            CodeFlag::GeneratorAllowed if !py3 => 0x1000,
+            CodeFlag::FutureDivision => 0x2000 << future_shift,
+            CodeFlag::FutureAbsoluteImport => 0x4000 << future_shift,
+            CodeFlag::FutureWithStatement => 0x8000 << future_shift,
+            CodeFlag::FuturePrintFunction => 0x10000 << future_shift,
+            CodeFlag::FutureUnicodeLiterals => 0x20000 << future_shift,
+            CodeFlag::FutureBarryAsBdfl if at_least(1) => 0x40000 << future_shift,
+            CodeFlag::FutureGeneratorStop if at_least(5) => 0x80000 << future_shift,
+            CodeFlag::FutureAnnotations if at_least(7) => 0x100000 << future_shift,
+            _ => return None,
+        };
+
+        Some(bit)
+    }
+}
+
+impl fmt::Display for CodeFlag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The `co_flags` of a code object, interpreted for a specific Python version.
+///
+/// Displays as the names of the flags which are set separated by `, `, followed by any bits
+/// which are not known flags in hex, like `dis.pretty_flags`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CodeFlags {
+    bits: u32,
+    version: PythonVersion,
+}
+
+impl CodeFlags {
+    pub fn new(bits: u32, version: PythonVersion) -> Self {
+        CodeFlags { bits, version }
+    }
+
+    /// The raw flags value
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// The version the flags are interpreted for
+    pub fn version(&self) -> PythonVersion {
+        self.version
+    }
+
+    /// Whether `flag` is set. Always false for flags the version does not have.
+    pub fn contains(&self, flag: CodeFlag) -> bool {
+        flag.bit(self.version)
+            .is_some_and(|bit| self.bits & bit != 0)
+    }
+
+    /// Sets or clears `flag`. Does nothing for flags the version does not have.
+    pub fn set(&mut self, flag: CodeFlag, value: bool) {
+        if let Some(bit) = flag.bit(self.version) {
+            if value {
+                self.bits |= bit;
+            } else {
+                self.bits &= !bit;
+            }
+        }
+    }
+
+    /// The flags which are set
+    pub fn iter(&self) -> impl Iterator<Item = CodeFlag> + '_ {
+        CodeFlag::ALL
+            .iter()
+            .copied()
+            .filter(move |&flag| self.contains(flag))
+    }
+
+    /// The bits which are set but are not flags known for the version
+    pub fn unknown_bits(&self) -> u32 {
+        let known = CodeFlag::ALL
+            .iter()
+            .filter_map(|flag| flag.bit(self.version))
+            .fold(0, |known, bit| known | bit);
+
+        self.bits & !known
+    }
+}
+
+impl fmt::Display for CodeFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, flag) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", flag)?;
+        }
+
+        let unknown = self.unknown_bits();
+        if unknown != 0 || self.bits == 0 {
+            if unknown != self.bits {
+                f.write_str(", ")?;
+            }
+            write!(f, "0x{:x}", unknown)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    const PY27: PythonVersion = PythonVersion::new(2, 7);
+    const PY37: PythonVersion = PythonVersion::new(3, 7);
+    const PY38: PythonVersion = PythonVersion::new(3, 8);
+
+    // The `compiler_flag` of each `__future__` feature in Python 2.7, 3.7 and 3.8
+    #[test]
+    fn future_flags_move_in_py38() {
+        let future = [
+            (CodeFlag::FutureDivision, Some(0x2000), 0x20000),
+            (CodeFlag::FutureAbsoluteImport, Some(0x4000), 0x40000),
+            (CodeFlag::FutureWithStatement, Some(0x8000), 0x80000),
+            (CodeFlag::FuturePrintFunction, Some(0x10000), 0x100000),
+            (CodeFlag::FutureUnicodeLiterals, Some(0x20000), 0x200000),
+            (CodeFlag::FutureBarryAsBdfl, None, 0x400000),
+            (CodeFlag::FutureGeneratorStop, None, 0x800000),
+            (CodeFlag::FutureAnnotations, None, 0x1000000),
+        ];
+        for (flag, py27, py38) in future {
+            assert_eq!(flag.bit(PY27), py27, "{}", flag);
+            assert_eq!(flag.bit(PY37), Some(py38 >> 4), "{}", flag);
+            assert_eq!(flag.bit(PY38), Some(py38), "{}", flag);
+        }
+        assert_eq!(
+            CodeFlag::FutureGeneratorStop.bit(PythonVersion::new(3, 4)),
+            None
+        );
+        assert_eq!(
+            CodeFlag::FutureAnnotations.bit(PythonVersion::new(3, 6)),
+            None
+        );
+    }
+
+    // `dis.COMPILER_FLAG_NAMES`, which is the same from Python 3.6 to 3.13
+    #[test]
+    fn compiler_flags() {
+        let flags = [
+            (CodeFlag::Optimized, 0x1),
+            (CodeFlag::NewLocals, 0x2),
+            (CodeFlag::VarArgs, 0x4),
+            (CodeFlag::VarKeywords, 0x8),
+            (CodeFlag::Nested, 0x10),
+            (CodeFlag::Generator, 0x20),
+            (CodeFlag::NoFree, 0x40),
+            (CodeFlag::Coroutine, 0x80),
+            (CodeFlag::IterableCoroutine, 0x100),
+            (CodeFlag::AsyncGenerator, 0x200),
+        ];
+        for minor in 6..=13 {
+            let version = PythonVersion::new(3, minor);
+            for &(flag, bit) in &flags {
+                assert_eq!(flag.bit(version), Some(bit), "{} in {}", flag, version);
+            }
+            assert_eq!(CodeFlag::GeneratorAllowed.bit(version), None);
+        }
+
+        assert_eq!(CodeFlag::GeneratorAllowed.bit(PY27), Some(0x1000));
+        assert_eq!(CodeFlag::Coroutine.bit(PY27), None);
+        assert_eq!(CodeFlag::AsyncGenerator.bit(PythonVersion::new(3, 5)), None);
+        assert_eq!(
+            CodeFlag::IterableCoroutine.bit(PythonVersion::new(3, 5)),
+            Some(0x100)
+        );
+
+        // Every flag a version has is in a different bit
+        for version in [PY27, PY37, PY38] {
+            let bits = CodeFlag::ALL.iter().filter_map(|flag| flag.bit(version));
+            let combined = bits.clone().fold(0, |all, bit| {
+                assert_eq!(all & bit, 0);
+                all | bit
+            });
+            assert_eq!(combined.count_ones() as usize, bits.count());
+        }
+    }
+
+    #[test]
+    fn contains_and_set() {
+        let mut flags = CodeFlags::new(0x20003, PY37);
+        assert!(flags.contains(CodeFlag::Optimized));
+        assert!(flags.contains(CodeFlag::FutureUnicodeLiterals));
+        assert!(!flags.contains(CodeFlag::FutureDivision));
+        assert_eq!(flags.unknown_bits(), 0);
+
+        let flags38 = CodeFlags::new(0x20003, PY38);
+        assert!(flags38.contains(CodeFlag::FutureDivision));
+        assert!(!flags38.contains(CodeFlag::FutureUnicodeLiterals));
+
+        flags.set(CodeFlag::Generator, true);
+        flags.set(CodeFlag::Optimized, false);
+        // Flags the version does not have are ignored
+        flags.set(CodeFlag::GeneratorAllowed, true);
+        assert_eq!(flags.bits(), 0x20022);
+        assert_eq!(
+            flags.iter().collect::<alloc::vec::Vec<_>>(),
+            [
+                CodeFlag::NewLocals,
+                CodeFlag::Generator,
+                CodeFlag::FutureUnicodeLiterals
+            ]
+        );
+
+        let flags27 = CodeFlags::new(0x1000 | 0x80, PY27);
+        assert!(flags27.contains(CodeFlag::GeneratorAllowed));
+        assert!(!flags27.contains(CodeFlag::Coroutine));
+        assert_eq!(flags27.unknown_bits(), 0x80);
+    }
+
+    // Flags without `__future__` bits display the same as `dis.pretty_flags`
+    #[test]
+    fn display() {
+        let cases = [
+            (0x0, "0x0"),
+            (0x43, "OPTIMIZED, NEWLOCALS, NOFREE"),
+            (0x2b, "OPTIMIZED, NEWLOCALS, VARKEYWORDS, GENERATOR"),
+            (0x1020, "GENERATOR, 0x1000"),
+            (0x2000001, "OPTIMIZED, 0x2000000"),
+            (0x4000000, "0x4000000"),
+        ];
+        for (bits, expected) in cases {
+            assert_eq!(CodeFlags::new(bits, PY38).to_string(), expected);
+        }
+
+        assert_eq!(
+            CodeFlags::new(0x1000003, PY38).to_string(),
+            "OPTIMIZED, NEWLOCALS, FUTURE_ANNOTATIONS"
+        );
+        assert_eq!(
+            CodeFlags::new(0x20003, PY37).to_string(),
+            "OPTIMIZED, NEWLOCALS, FUTURE_UNICODE_LITERALS"
+        );
+        assert_eq!(
+            CodeFlags::new(0x1020, PY27).to_string(),
+            "GENERATOR, GENERATOR_ALLOWED"
+        );
+        assert_eq!(
+            CodeFlag::FutureBarryAsBdfl.to_string(),
+            "FUTURE_BARRY_AS_BDFL"
+        );
+    }
+}
@@ -1,37 +1,344 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod assembler;
+pub mod builder;
+pub mod code;
+pub mod disassembler;
 pub mod error;
+pub mod exceptiontable;
+pub mod flags;
+pub mod linetable;
+pub mod marshal;
 pub mod opcode;
 pub mod prelude;
+pub mod pyc;
 
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
 use num_traits::FromPrimitive;
-use opcode::py27::{Mnemonic, Standard};
+#[cfg(feature = "std")]
 use std::io::Read;
 
 use crate::error::DecodeError;
-use crate::opcode::{Instruction, Opcode};
+use crate::opcode::{ExtendedInstruction, Instruction, Opcode};
 
 /// Decodes a single instruction from a source and returns its result or an error
+#[cfg(feature = "std")]
 pub fn decode<O: Opcode + FromPrimitive, R: Read>(
     source: &mut R,
 ) -> Result<Instruction<O>, DecodeError> {
+    let mut buffer = Vec::with_capacity(3);
+    read_instruction::<O, _>(source, &mut buffer)?;
+
+    decode_at(&buffer, 0)
+}
+
+/// Decodes a single logical instruction from a source, consuming any `EXTENDED_ARG` prefixes
+/// and folding their arguments into the argument of the instruction they precede
+#[cfg(feature = "std")]
+pub fn decode_extended<O: Opcode + FromPrimitive, R: Read>(
+    source: &mut R,
+) -> Result<ExtendedInstruction<O>, DecodeError> {
+    let mut buffer = Vec::with_capacity(3);
+    loop {
+        match read_instruction::<O, _>(source, &mut buffer) {
+            Ok(true) => continue,
+            Ok(false) => break,
+            // Running out of data after a prefix is reported as a truncated argument below
+            Err(e) if !buffer.is_empty() && e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    decode_extended_at(&buffer, 0)
+}
+
+/// Decodes the first instruction in `code`. This does not require `std`.
+pub fn decode_slice<O: Opcode + FromPrimitive>(
+    code: &[u8],
+) -> Result<Instruction<O>, DecodeError> {
+    decode_at(code, 0)
+}
+
+/// Decodes the first logical instruction in `code`, folding any `EXTENDED_ARG` prefixes into its
+/// argument. This does not require `std`.
+pub fn decode_extended_slice<O: Opcode + FromPrimitive>(
+    code: &[u8],
+) -> Result<ExtendedInstruction<O>, DecodeError> {
+    decode_extended_at(code, 0)
+}
+
+/// Reads the bytes of a single instruction from `source` and appends them to `buffer`, stopping
+/// early if the opcode is unknown or the argument is truncated so that the slice decoder can
+/// report the problem. Returns whether or not the instruction was an `EXTENDED_ARG` prefix.
+#[cfg(feature = "std")]
+fn read_instruction<O: Opcode + FromPrimitive, R: Read>(
+    source: &mut R,
+    buffer: &mut Vec<u8>,
+) -> std::io::Result<bool> {
     let mut opcode_buffer = [0u8];
     source.read_exact(&mut opcode_buffer)?;
+    buffer.push(opcode_buffer[0]);
 
-    let opcode = O::from_u8(opcode_buffer[0])
-        .map_or(Err(DecodeError::UnknownOpcode(opcode_buffer[0])), Ok)?;
+    let opcode = match O::from_u8(opcode_buffer[0]) {
+        Some(opcode) => opcode,
+        None => return Ok(false),
+    };
 
-    let arg = if opcode.has_arg() {
+    let arg_len = O::encoding().instruction_len(opcode.has_arg()) - 1;
+    if arg_len > 0 {
         let mut argument_buffer = [0u8, 0u8];
-        source.read_exact(&mut argument_buffer)?;
+        match source.read_exact(&mut argument_buffer[..arg_len]) {
+            Ok(()) => buffer.extend_from_slice(&argument_buffer[..arg_len]),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(false),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(opcode.is_extended_arg())
+}
+
+/// Decodes the instruction at `offset` in `code`
+pub(crate) fn decode_at<O: Opcode + FromPrimitive>(
+    code: &[u8],
+    offset: usize,
+) -> Result<Instruction<O>, DecodeError> {
+    let opcode_byte = *code.get(offset).ok_or(DecodeError::UnexpectedEof { offset })?;
 
-        Some(u16::from_le_bytes(argument_buffer))
-    } else {
-        None
+    let opcode = O::from_u8(opcode_byte).ok_or(DecodeError::UnknownOpcode {
+        opcode: opcode_byte,
+        offset,
+    })?;
+
+    let len = O::encoding().instruction_len(opcode.has_arg());
+    let argument_bytes = code
+        .get(offset + 1..offset + len)
+        .ok_or(DecodeError::TruncatedArgument {
+            opcode: opcode_byte,
+            offset,
+        })?;
+
+    let arg = match argument_bytes {
+        _ if !opcode.has_arg() => None,
+        [low, high] => Some(u16::from_le_bytes([*low, *high])),
+        [arg] => Some(u16::from(*arg)),
+        _ => unreachable!("instructions have at most 2 argument bytes"),
     };
 
     Ok(Instruction { opcode, arg })
 }
 
+/// Decodes the logical instruction starting at `offset` in `code`
+pub(crate) fn decode_extended_at<O: Opcode + FromPrimitive>(
+    code: &[u8],
+    offset: usize,
+) -> Result<ExtendedInstruction<O>, DecodeError> {
+    let mut extended_arg = 0u32;
+    let mut extended_args = 0;
+    let mut offset = offset;
+    let mut last_prefix = None;
+
+    loop {
+        let instr = match (decode_at::<O>(code, offset), last_prefix) {
+            (Ok(instr), _) => instr,
+            // Running out of data after a prefix means the extended argument is truncated
+            (Err(DecodeError::UnexpectedEof { .. }), Some(prefix_offset)) => {
+                return Err(DecodeError::TruncatedArgument {
+                    opcode: code[prefix_offset],
+                    offset: prefix_offset,
+                });
+            }
+            (Err(e), _) => return Err(e),
+        };
+
+        if instr.opcode.is_extended_arg() {
+            extended_arg = (extended_arg | u32::from(instr.arg.unwrap_or(0)))
+                .wrapping_shl(O::encoding().arg_bits());
+            extended_args += 1;
+            last_prefix = Some(offset);
+            offset += instr.len();
+            continue;
+        }
+
+        return Ok(ExtendedInstruction {
+            opcode: instr.opcode,
+            arg: instr.arg.map(|arg| extended_arg | u32::from(arg)),
+            extended_args,
+        });
+    }
+}
+
 /// Convenience wrapper around [`decode`] for decoding Python 2.7 instructions
+#[cfg(feature = "std")]
 pub fn decode_py27<O: Opcode<Mnemonic = crate::opcode::py27::Mnemonic>, R: Read>(source: &mut R) -> Result<Instruction<O>, DecodeError> {
     decode::<O, _>(source)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disassembler::{Disassembler, ExtendedDisassembler};
+    use crate::opcode::{py27, py36};
+
+    type Py27 = py27::Standard;
+    type Py36 = py36::Standard;
+
+    // The slice decoders are the only decoders without `std`, so these also run with
+    // `--no-default-features`
+    #[test]
+    fn slice_decoders() {
+        let instr = decode_slice::<Py27>(&[100, 2, 1, 83]).unwrap();
+        assert_eq!(
+            instr,
+            Instruction {
+                opcode: Py27::LOAD_CONST,
+                arg: Some(0x102)
+            }
+        );
+        let instr = decode_slice::<Py36>(&[83, 0]).unwrap();
+        assert_eq!(
+            instr,
+            Instruction {
+                opcode: Py36::RETURN_VALUE,
+                arg: None
+            }
+        );
+
+        let code = [144, 1, 144, 2, 144, 3, 100, 4, 83, 0];
+        let instr = decode_extended_slice::<Py36>(&code).unwrap();
+        assert_eq!(
+            instr,
+            ExtendedInstruction {
+                opcode: Py36::LOAD_CONST,
+                arg: Some(0x01020304),
+                extended_args: 3,
+            }
+        );
+        assert_eq!(instr.len(), 8);
+
+        let instr = decode_extended_slice::<Py27>(&[1, 145, 1, 0]).unwrap();
+        assert_eq!(instr.opcode, Py27::POP_TOP);
+        assert_eq!(instr.extended_args, 0);
+    }
+
+    #[test]
+    fn slice_errors_report_the_failing_byte() {
+        assert!(matches!(
+            decode_slice::<Py27>(&[]),
+            Err(DecodeError::UnexpectedEof { offset: 0 })
+        ));
+        assert!(matches!(
+            decode_slice::<Py27>(&[7]),
+            Err(DecodeError::UnknownOpcode {
+                opcode: 7,
+                offset: 0
+            })
+        ));
+        assert!(matches!(
+            decode_slice::<Py27>(&[100, 1]),
+            Err(DecodeError::TruncatedArgument {
+                opcode: 100,
+                offset: 0
+            })
+        ));
+        assert!(matches!(
+            decode_slice::<Py36>(&[100]),
+            Err(DecodeError::TruncatedArgument {
+                opcode: 100,
+                offset: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn extended_errors_report_the_failing_prefix() {
+        assert!(matches!(
+            decode_extended_slice::<Py27>(&[145, 1, 0]),
+            Err(DecodeError::TruncatedArgument {
+                opcode: 145,
+                offset: 0
+            })
+        ));
+        assert!(matches!(
+            decode_extended_slice::<Py27>(&[145, 1, 0, 145, 2, 0]),
+            Err(DecodeError::TruncatedArgument {
+                opcode: 145,
+                offset: 3
+            })
+        ));
+        assert!(matches!(
+            decode_extended_slice::<Py27>(&[145, 1, 0, 7]),
+            Err(DecodeError::UnknownOpcode {
+                opcode: 7,
+                offset: 3
+            })
+        ));
+        assert!(matches!(
+            decode_extended_slice::<Py36>(&[144, 1, 100]),
+            Err(DecodeError::TruncatedArgument {
+                opcode: 100,
+                offset: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn disassembler_errors_are_relative_to_the_buffer() {
+        // LOAD_CONST 1; RETURN_VALUE; a truncated LOAD_CONST
+        let code = [100, 1, 0, 83, 100, 1];
+        let error = Disassembler::<Py27>::new(&code)
+            .find_map(Result::err)
+            .unwrap();
+        assert!(matches!(
+            error,
+            DecodeError::TruncatedArgument {
+                opcode: 100,
+                offset: 4
+            }
+        ));
+        assert_eq!(error.offset(), Some(4));
+
+        // POP_TOP; EXTENDED_ARG 1 with nothing after it
+        let code = [1, 145, 1, 0];
+        let error = ExtendedDisassembler::<Py27>::new(&code)
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(error.offset(), Some(1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_errors() {
+        use std::io::Cursor;
+
+        assert!(matches!(
+            decode::<Py27, _>(&mut Cursor::new([100, 1])),
+            Err(DecodeError::TruncatedArgument {
+                opcode: 100,
+                offset: 0
+            })
+        ));
+        assert!(matches!(
+            decode::<Py27, _>(&mut Cursor::new([7])),
+            Err(DecodeError::UnknownOpcode {
+                opcode: 7,
+                offset: 0
+            })
+        ));
+        assert!(matches!(
+            decode_extended::<Py27, _>(&mut Cursor::new([145, 1, 0])),
+            Err(DecodeError::TruncatedArgument {
+                opcode: 145,
+                offset: 0
+            })
+        ));
+
+        let error = decode::<Py27, _>(&mut Cursor::new([])).unwrap_err();
+        assert!(matches!(error, DecodeError::IoError(_)));
+        assert_eq!(error.offset(), None);
+    }
+}
@@ -0,0 +1,447 @@
+//! Mapping between bytecode offsets and source lines.
+//!
+//! The table which stores this mapping has changed format several times:
+//!
+//! | Version   | Attribute      | Format                                                     |
+//! |-----------|----------------|------------------------------------------------------------|
+//! | < 3.6     | `co_lnotab`    | pairs of unsigned offset and line increments               |
+//! | 3.6 - 3.9 | `co_lnotab`    | pairs of unsigned offset and signed line increments        |
+//! | 3.10      | `co_linetable` | pairs of offset and signed line increments, with no-line   |
+//! | >= 3.11   | `co_linetable` | variable length location entries with end lines and columns |
+//!
+//! All of these are decoded into a [`LineTable`]. Tables are decoded as far as they are valid;
+//! a truncated entry at the end of a table is ignored.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::Range;
+
+use crate::pyc::PythonVersion;
+
+/// The format of a code object's line number table
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LineTableFormat {
+    /// `co_lnotab` with unsigned line increments, used before Python 3.6
+    Lnotab,
+    /// `co_lnotab` with signed line increments, used by Python 3.6 - 3.9
+    SignedLnotab,
+    /// `co_linetable` as used by Python 3.10
+    LineTable,
+    /// `co_linetable` as used by Python 3.11 and later, which also stores end lines and columns
+    Locations,
+}
+
+impl LineTableFormat {
+    /// The format used by the given Python version
+    pub fn for_version(version: PythonVersion) -> Self {
+        if version >= PythonVersion::new(3, 11) {
+            LineTableFormat::Locations
+        } else if version >= PythonVersion::new(3, 10) {
+            LineTableFormat::LineTable
+        } else if version >= PythonVersion::new(3, 6) {
+            LineTableFormat::SignedLnotab
+        } else {
+            LineTableFormat::Lnotab
+        }
+    }
+}
+
+/// The source location of a range of bytecode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineEntry {
+    /// The bytecode offsets this entry covers
+    pub range: Range<usize>,
+    /// The line the bytecode belongs to, if it has one
+    pub line: Option<u32>,
+    /// The last line of the expression the bytecode belongs to. Only available from Python 3.11.
+    pub end_line: Option<u32>,
+    /// The 0-based UTF-8 byte column the expression starts at. Only available from Python 3.11.
+    pub column: Option<u32>,
+    /// The 0-based UTF-8 byte column the expression ends at. Only available from Python 3.11.
+    pub end_column: Option<u32>,
+}
+
+impl LineEntry {
+    fn new(range: Range<usize>, line: Option<u32>) -> Self {
+        LineEntry {
+            range,
+            line,
+            end_line: None,
+            column: None,
+            end_column: None,
+        }
+    }
+}
+
+/// A decoded line number table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineTable {
+    entries: Vec<LineEntry>,
+}
+
+impl LineTable {
+    /// Decodes `table` for a code object whose first line is `firstlineno` and whose bytecode is
+    /// `code_len` bytes long
+    pub fn new(format: LineTableFormat, table: &[u8], firstlineno: u32, code_len: usize) -> Self {
+        let entries = match format {
+            LineTableFormat::Lnotab => decode_lnotab(table, firstlineno, code_len, false),
+            LineTableFormat::SignedLnotab => decode_lnotab(table, firstlineno, code_len, true),
+            LineTableFormat::LineTable => decode_linetable(table, firstlineno),
+            LineTableFormat::Locations => decode_locations(table, firstlineno),
+        };
+
+        LineTable { entries }
+    }
+
+    /// The entries in this table, ordered by offset. Bytecode offsets are covered by at most one
+    /// entry, but an offset may not be covered at all.
+    pub fn entries(&self) -> &[LineEntry] {
+        &self.entries
+    }
+
+    /// The entry covering the instruction at `offset`
+    pub fn entry_at(&self, offset: usize) -> Option<&LineEntry> {
+        let index = self
+            .entries
+            .partition_point(|entry| entry.range.end <= offset);
+
+        self.entries
+            .get(index)
+            .filter(|entry| entry.range.contains(&offset))
+    }
+
+    /// The line of the instruction at `offset`
+    pub fn line_at(&self, offset: usize) -> Option<u32> {
+        self.entry_at(offset).and_then(|entry| entry.line)
+    }
+
+    /// The line of each range of bytecode, with adjacent entries which have the same line merged,
+    /// like Python's `co_lines`
+    pub fn line_ranges(&self) -> impl Iterator<Item = (Range<usize>, Option<u32>)> + '_ {
+        let mut entries = self.entries.iter().peekable();
+        core::iter::from_fn(move || {
+            let first = entries.next()?;
+            let mut range = first.range.clone();
+            while let Some(next) = entries.next_if(|next| {
+                next.line == first.line && next.range.start == range.end
+            }) {
+                range.end = next.range.end;
+            }
+
+            Some((range, first.line))
+        })
+    }
+
+    /// The bytecode ranges which belong to `line`
+    pub fn ranges_for_line(&self, line: u32) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.line_ranges()
+            .filter(move |(_, range_line)| *range_line == Some(line))
+            .map(|(range, _)| range)
+    }
+
+    /// The offsets at which the line number changes along with the new line, like Python's
+    /// `dis.findlinestarts`
+    pub fn line_starts(&self) -> impl Iterator<Item = (usize, u32)> + '_ {
+        let mut last_line = None;
+        self.entries.iter().filter_map(move |entry| {
+            let line = entry.line?;
+            if last_line == Some(line) {
+                return None;
+            }
+            last_line = Some(line);
+
+            Some((entry.range.start, line))
+        })
+    }
+}
+
+/// Converts a computed line number to a line, treating ones which are out of range as missing
+fn to_line(line: i64) -> Option<u32> {
+    u32::try_from(line).ok()
+}
+
+fn decode_lnotab(table: &[u8], firstlineno: u32, code_len: usize, signed: bool) -> Vec<LineEntry> {
+    let mut starts: Vec<(usize, i64)> = Vec::new();
+    let mut line = i64::from(firstlineno);
+    let mut offset = 0;
+
+    for pair in table.chunks_exact(2) {
+        let (offset_delta, line_delta) = (pair[0], pair[1]);
+
+        // A line only starts once bytecode has been emitted for it, and increments larger than
+        // a byte are split across several pairs
+        if offset_delta != 0 {
+            if starts.last().map(|&(_, last)| last) != Some(line) {
+                starts.push((offset, line));
+            }
+            offset += usize::from(offset_delta);
+        }
+
+        line += if signed {
+            i64::from(line_delta as i8)
+        } else {
+            i64::from(line_delta)
+        };
+    }
+    if starts.last().map(|&(_, last)| last) != Some(line) {
+        starts.push((offset, line));
+    }
+
+    let mut entries = Vec::with_capacity(starts.len());
+    for (i, &(start, line)) in starts.iter().enumerate() {
+        let end = starts.get(i + 1).map_or(code_len, |&(next, _)| next);
+        if start < end {
+            entries.push(LineEntry::new(start..end, to_line(line)));
+        }
+    }
+
+    entries
+}
+
+/// The line increment which marks a range of bytecode as having no line in Python 3.10 tables
+const NO_LINE_DELTA: i8 = -128;
+
+fn decode_linetable(table: &[u8], firstlineno: u32) -> Vec<LineEntry> {
+    let mut entries = Vec::new();
+    let mut line = i64::from(firstlineno);
+    let mut offset = 0;
+
+    for pair in table.chunks_exact(2) {
+        let (offset_delta, line_delta) = (pair[0], pair[1] as i8);
+        let start = offset;
+        offset += usize::from(offset_delta);
+
+        let entry_line = if line_delta == NO_LINE_DELTA {
+            None
+        } else {
+            line += i64::from(line_delta);
+            to_line(line)
+        };
+
+        if start < offset {
+            entries.push(LineEntry::new(start..offset, entry_line));
+        }
+    }
+
+    entries
+}
+
+/// Location entry codes used by Python 3.11+ tables
+const LOCATION_SHORT_MAX: u8 = 9;
+const LOCATION_ONE_LINE_0: u8 = 10;
+const LOCATION_ONE_LINE_2: u8 = 12;
+const LOCATION_NO_COLUMNS: u8 = 13;
+const LOCATION_LONG: u8 = 14;
+const LOCATION_NONE: u8 = 15;
+
+fn decode_locations(table: &[u8], firstlineno: u32) -> Vec<LineEntry> {
+    let mut entries = Vec::new();
+    let mut reader = LocationReader { table, offset: 0 };
+    let mut line = i64::from(firstlineno);
+    let mut offset = 0;
+
+    while let Some(entry) = reader.read_entry(&mut line, offset) {
+        offset = entry.range.end;
+        entries.push(entry);
+    }
+
+    entries
+}
+
+struct LocationReader<'a> {
+    table: &'a [u8],
+    offset: usize,
+}
+
+impl LocationReader<'_> {
+    /// Reads the entry for the bytecode starting at `start`, updating the current `line`
+    fn read_entry(&mut self, line: &mut i64, start: usize) -> Option<LineEntry> {
+        let first = self.read_u8()?;
+        // Every entry starts with a byte with the high bit set
+        if first & 0x80 == 0 {
+            return None;
+        }
+
+        let code = (first >> 3) & 0xF;
+        // The length is in 2 byte code units
+        let range = start..start + (usize::from(first & 0x7) + 1) * 2;
+
+        let mut entry = LineEntry::new(range, None);
+        match code {
+            0..=LOCATION_SHORT_MAX => {
+                let second = self.read_u8()?;
+                let column = u32::from(code) << 3 | u32::from(second >> 4);
+                entry.line = to_line(*line);
+                entry.end_line = entry.line;
+                entry.column = Some(column);
+                entry.end_column = Some(column + u32::from(second & 0xF));
+            }
+            LOCATION_ONE_LINE_0..=LOCATION_ONE_LINE_2 => {
+                *line += i64::from(code - LOCATION_ONE_LINE_0);
+                entry.line = to_line(*line);
+                entry.end_line = entry.line;
+                entry.column = Some(u32::from(self.read_u8()?));
+                entry.end_column = Some(u32::from(self.read_u8()?));
+            }
+            LOCATION_NO_COLUMNS => {
+                *line += self.read_svarint()?;
+                entry.line = to_line(*line);
+                entry.end_line = entry.line;
+            }
+            LOCATION_LONG => {
+                *line += self.read_svarint()?;
+                entry.line = to_line(*line);
+                entry.end_line = to_line(*line + i64::from(self.read_varint()?));
+                // Columns are stored plus one so that 0 can mean there is no column
+                entry.column = self.read_varint()?.checked_sub(1);
+                entry.end_column = self.read_varint()?.checked_sub(1);
+            }
+            LOCATION_NONE => {}
+            _ => unreachable!(),
+        }
+
+        Some(entry)
+    }
+
+    /// Reads an unsigned integer stored in chunks of 6 bits, least significant first, where
+    /// bit 6 of each byte marks that another chunk follows
+    fn read_varint(&mut self) -> Option<u32> {
+        let mut value = 0u32;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            value |= u32::from(byte & 0x3F).checked_shl(shift)?;
+            if byte & 0x40 == 0 {
+                return Some(value);
+            }
+            shift += 6;
+        }
+    }
+
+    /// Reads a signed integer stored as a varint with the sign in the lowest bit
+    fn read_svarint(&mut self) -> Option<i64> {
+        let value = self.read_varint()?;
+        let magnitude = i64::from(value >> 1);
+
+        Some(if value & 1 != 0 { -magnitude } else { magnitude })
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        let byte = *self.table.get(self.offset)?;
+        self.offset += 1;
+
+        Some(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_line(line: &str) -> Option<u32> {
+        match line {
+            "-" => None,
+            line => Some(line.parse().unwrap()),
+        }
+    }
+
+    /// Checks every code object in a fixture written by `testdata/linetable/generate.py`, which
+    /// records the line table of each code object along with what `dis.findlinestarts`,
+    /// `co_lines` and `co_positions` return for it
+    fn check_fixture(format: LineTableFormat, fixture: &str) {
+        let mut table = None;
+        for record in fixture.lines() {
+            let (kind, values) = record.split_once(' ').unwrap();
+            let mut values = values.split(' ').filter(|value| !value.is_empty());
+            if kind == "code" {
+                let firstlineno = values.next().unwrap().parse().unwrap();
+                let code_len = values.next().unwrap().parse().unwrap();
+                let bytes: Vec<u8> = match values.next().unwrap() {
+                    "-" => Vec::new(),
+                    hex => (0..hex.len())
+                        .step_by(2)
+                        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                        .collect(),
+                };
+                table = Some(LineTable::new(format, &bytes, firstlineno, code_len));
+                continue;
+            }
+
+            let table = table.as_ref().unwrap();
+            match kind {
+                "starts" => {
+                    let expected: Vec<(usize, u32)> = values
+                        .map(|start| {
+                            let (offset, line) = start.split_once(':').unwrap();
+                            (offset.parse().unwrap(), line.parse().unwrap())
+                        })
+                        .collect();
+                    let actual: Vec<_> = table.line_starts().collect();
+                    assert_eq!(actual, expected, "{}", record);
+                }
+                "lines" => {
+                    // `co_lines` does not always merge ranges which have the same line
+                    let mut expected: Vec<(Range<usize>, Option<u32>)> = Vec::new();
+                    for range in values {
+                        let (range, line) = range.split_once(':').unwrap();
+                        let (start, end) = range.split_once('-').unwrap();
+                        let range = start.parse().unwrap()..end.parse().unwrap();
+                        let line = parse_line(line);
+                        match expected.last_mut() {
+                            Some(last) if last.1 == line && last.0.end == range.start => {
+                                last.0.end = range.end
+                            }
+                            _ => expected.push((range, line)),
+                        }
+                    }
+                    let actual: Vec<_> = table.line_ranges().collect();
+                    assert_eq!(actual, expected, "{}", record);
+                }
+                "positions" => {
+                    for (i, position) in values.enumerate() {
+                        let expected: Vec<Option<u32>> =
+                            position.split(',').map(parse_line).collect();
+                        let actual = table.entry_at(i * 2).map_or([None; 4], |entry| {
+                            [entry.line, entry.end_line, entry.column, entry.end_column]
+                        });
+                        assert_eq!(actual, *expected, "offset {} of {}", i * 2, record);
+                    }
+                }
+                _ => panic!("unknown fixture line {}", record),
+            }
+        }
+    }
+
+    #[test]
+    fn lnotab() {
+        // Line increments of more than 255 are split into several pairs
+        check_fixture(
+            LineTableFormat::Lnotab,
+            include_str!("../testdata/linetable/py27.txt"),
+        );
+        check_fixture(
+            LineTableFormat::SignedLnotab,
+            include_str!("../testdata/linetable/py38.txt"),
+        );
+    }
+
+    #[test]
+    fn linetable() {
+        check_fixture(
+            LineTableFormat::LineTable,
+            include_str!("../testdata/linetable/py310.txt"),
+        );
+    }
+
+    #[test]
+    fn locations() {
+        // These use every entry code from 0 to 15
+        for fixture in [
+            include_str!("../testdata/linetable/py311.txt"),
+            include_str!("../testdata/linetable/py312.txt"),
+            include_str!("../testdata/linetable/py313.txt"),
+        ] {
+            check_fixture(LineTableFormat::Locations, fixture);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! The layout of code objects in marshal data, which is shared by [`Reader`](super::Reader) and
+//! the [`lazy`](super::lazy) scanner.
+
+use alloc::vec::Vec;
+
+use crate::code::{CO_FAST_CELL, CO_FAST_FREE, CO_FAST_LOCAL};
+use crate::error::MarshalError;
+use crate::pyc::PythonVersion;
+
+/// Reads the individual fields of a code object
+pub(super) trait FieldReader {
+    /// A byte string field such as `co_code`
+    type Bytes: AsRef<[u8]> + Default;
+    /// A string such as `co_name` or an item of `co_names`
+    type Name: Clone;
+    /// `co_consts`
+    type Consts;
+
+    fn version(&self) -> PythonVersion;
+    fn u32_field(&mut self) -> Result<u32, MarshalError>;
+    fn bytes_field(&mut self) -> Result<Self::Bytes, MarshalError>;
+    fn name_field(&mut self) -> Result<Self::Name, MarshalError>;
+    fn names_field(&mut self) -> Result<Vec<Self::Name>, MarshalError>;
+    fn consts_field(&mut self) -> Result<Self::Consts, MarshalError>;
+}
+
+/// The fields of a code object, named after the `co_*` attributes. Fields which the version does
+/// not store are empty.
+pub(super) struct CodeFields<R: FieldReader> {
+    pub argcount: u32,
+    pub posonlyargcount: u32,
+    pub kwonlyargcount: u32,
+    pub nlocals: u32,
+    pub stacksize: u32,
+    pub flags: u32,
+    pub code: R::Bytes,
+    pub consts: R::Consts,
+    pub names: Vec<R::Name>,
+    pub varnames: Vec<R::Name>,
+    pub freevars: Vec<R::Name>,
+    pub cellvars: Vec<R::Name>,
+    pub localsplusnames: Vec<R::Name>,
+    pub localspluskinds: R::Bytes,
+    pub filename: R::Name,
+    pub name: R::Name,
+    pub qualname: Option<R::Name>,
+    pub firstlineno: u32,
+    pub linetable: R::Bytes,
+    pub exceptiontable: R::Bytes,
+}
+
+/// Reads the fields of a code object in the order the reader's version stores them, after its
+/// type code. From Python 3.11, the variable names and `co_nlocals` are derived from the combined
+/// locals, so that they can be used the same way for every version.
+pub(super) fn read_code<R: FieldReader>(reader: &mut R) -> Result<CodeFields<R>, MarshalError> {
+    let version = reader.version();
+    let py3 = version >= PythonVersion::new(3, 0);
+    let localsplus = version >= PythonVersion::new(3, 11);
+
+    let argcount = reader.u32_field()?;
+    let posonlyargcount = if version >= PythonVersion::new(3, 8) {
+        reader.u32_field()?
+    } else {
+        0
+    };
+    let kwonlyargcount = if py3 { reader.u32_field()? } else { 0 };
+    let nlocals = if localsplus { 0 } else { reader.u32_field()? };
+    let stacksize = reader.u32_field()?;
+    let flags = reader.u32_field()?;
+    let code = reader.bytes_field()?;
+    let consts = reader.consts_field()?;
+    let names = reader.names_field()?;
+
+    let (varnames, freevars, cellvars, localsplusnames, localspluskinds) = if localsplus {
+        let localsplusnames = reader.names_field()?;
+        let localspluskinds = reader.bytes_field()?;
+        (
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            localsplusnames,
+            localspluskinds,
+        )
+    } else {
+        let varnames = reader.names_field()?;
+        let freevars = reader.names_field()?;
+        let cellvars = reader.names_field()?;
+        (
+            varnames,
+            freevars,
+            cellvars,
+            Vec::new(),
+            R::Bytes::default(),
+        )
+    };
+    let filename = reader.name_field()?;
+    let name = reader.name_field()?;
+    let qualname = if localsplus {
+        Some(reader.name_field()?)
+    } else {
+        None
+    };
+    let firstlineno = reader.u32_field()?;
+    let linetable = reader.bytes_field()?;
+    let exceptiontable = if localsplus {
+        reader.bytes_field()?
+    } else {
+        R::Bytes::default()
+    };
+
+    let mut fields = CodeFields {
+        argcount,
+        posonlyargcount,
+        kwonlyargcount,
+        nlocals,
+        stacksize,
+        flags,
+        code,
+        consts,
+        names,
+        varnames,
+        freevars,
+        cellvars,
+        localsplusnames,
+        localspluskinds,
+        filename,
+        name,
+        qualname,
+        firstlineno,
+        linetable,
+        exceptiontable,
+    };
+    if localsplus {
+        fields.split_localsplus();
+    }
+
+    Ok(fields)
+}
+
+impl<R: FieldReader> CodeFields<R> {
+    /// Fills in `co_varnames`, `co_cellvars`, `co_freevars` and `co_nlocals` from the combined
+    /// locals of a Python 3.11+ code object. A name may be both a local and a cell variable.
+    fn split_localsplus(&mut self) {
+        let kinds = self.localspluskinds.as_ref();
+        for (name, &kind) in self.localsplusnames.iter().zip(kinds) {
+            if kind & CO_FAST_LOCAL != 0 {
+                self.varnames.push(name.clone());
+            }
+            if kind & CO_FAST_CELL != 0 {
+                self.cellvars.push(name.clone());
+            }
+            if kind & CO_FAST_FREE != 0 {
+                self.freevars.push(name.clone());
+            }
+        }
+
+        self.nlocals = self.varnames.len() as u32;
+    }
+}
@@ -0,0 +1,508 @@
+//! Indexing of the code objects in marshal data without deserializing it.
+//!
+//! Instead of building [`PyObject`](super::PyObject)s, the data is scanned once and every code
+//! object is recorded as a [`LazyCode`] which borrows its bytecode, tables and names from the
+//! data. Constants other than nested code objects are skipped, and instructions are only decoded
+//! when they are iterated over, so memory use stays close to the size of the data.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use num_traits::FromPrimitive;
+
+use super::layout::{self, FieldReader};
+use super::{
+    Limits, Reader, FLAG_REF, TYPE_ASCII, TYPE_ASCII_INTERNED, TYPE_BINARY_COMPLEX,
+    TYPE_BINARY_FLOAT, TYPE_CODE, TYPE_COMPLEX, TYPE_DICT, TYPE_ELLIPSIS, TYPE_FALSE, TYPE_FLOAT,
+    TYPE_FROZENSET, TYPE_INT, TYPE_INT64, TYPE_INTERNED, TYPE_LIST, TYPE_LONG, TYPE_NONE,
+    TYPE_NULL, TYPE_REF, TYPE_SET, TYPE_SHORT_ASCII, TYPE_SHORT_ASCII_INTERNED, TYPE_SMALL_TUPLE,
+    TYPE_STOPITER, TYPE_STRING, TYPE_STRINGREF, TYPE_TRUE, TYPE_TUPLE, TYPE_UNICODE,
+};
+use crate::disassembler::{Disassembler, ExtendedDisassembler};
+use crate::error::MarshalError;
+use crate::exceptiontable::ExceptionTable;
+use crate::flags::CodeFlags;
+use crate::linetable::{LineTable, LineTableFormat};
+use crate::opcode::Opcode;
+use crate::pyc::PythonVersion;
+
+/// A code object whose contents are borrowed from the marshal data it was found in. Fields have
+/// the same meaning as in [`CodeObject`](crate::code::CodeObject), but `co_consts` is not kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LazyCode<'a> {
+    /// The index of the code object whose `co_consts` contains this one
+    pub parent: Option<usize>,
+    pub argcount: u32,
+    pub posonlyargcount: u32,
+    pub kwonlyargcount: u32,
+    pub nlocals: u32,
+    pub stacksize: u32,
+    pub flags: u32,
+    pub code: &'a [u8],
+    pub names: Vec<&'a [u8]>,
+    pub varnames: Vec<&'a [u8]>,
+    pub freevars: Vec<&'a [u8]>,
+    pub cellvars: Vec<&'a [u8]>,
+    pub localsplusnames: Vec<&'a [u8]>,
+    pub localspluskinds: &'a [u8],
+    pub filename: &'a [u8],
+    pub name: &'a [u8],
+    pub qualname: Option<&'a [u8]>,
+    pub firstlineno: u32,
+    pub linetable: &'a [u8],
+    pub exceptiontable: &'a [u8],
+}
+
+impl<'a> LazyCode<'a> {
+    /// Iterates over the instructions in `co_code` using the opcode table `O`
+    pub fn instructions<O: Opcode + FromPrimitive>(&self) -> Disassembler<'a, O> {
+        Disassembler::new(self.code)
+    }
+
+    /// Iterates over the logical instructions in `co_code` using the opcode table `O`, folding
+    /// `EXTENDED_ARG` prefixes into the instruction that follows them
+    pub fn extended_instructions<O: Opcode + FromPrimitive>(&self) -> ExtendedDisassembler<'a, O> {
+        self.instructions().extended()
+    }
+
+    /// `co_flags` interpreted for the Python version which produced this code object
+    pub fn code_flags(&self, version: PythonVersion) -> CodeFlags {
+        CodeFlags::new(self.flags, version)
+    }
+
+    /// Decodes the line number table, which is stored in the given format
+    pub fn line_table(&self, format: LineTableFormat) -> LineTable {
+        LineTable::new(format, self.linetable, self.firstlineno, self.code.len())
+    }
+
+    /// Decodes the exception table. This is empty before Python 3.11.
+    pub fn exception_table(&self) -> ExceptionTable {
+        ExceptionTable::new(self.exceptiontable)
+    }
+}
+
+/// Finds every code object in `data`, which was written by the given Python version. Code
+/// objects are returned depth first in the order they appear, so the module is first and every
+/// code object comes after its parent.
+pub fn code_objects(
+    data: &[u8],
+    version: PythonVersion,
+    limits: Limits,
+) -> Result<Vec<LazyCode<'_>>, MarshalError> {
+    let mut scanner = Scanner {
+        reader: Reader::with_limits(data, version, limits),
+        interned: Vec::new(),
+        refs: Vec::new(),
+        codes: Vec::new(),
+        parents: Vec::new(),
+    };
+    scanner.skip_object()?;
+
+    // Every slot is filled in once the code object it was reserved for has been read
+    Ok(scanner.codes.into_iter().flatten().collect())
+}
+
+/// The qualified name of `codes[index]`: the names of it and its ancestors joined by `.`, the
+/// same as [`CodeObject::walk`](crate::code::CodeObject::walk) produces
+pub fn qualified_name(codes: &[LazyCode<'_>], index: usize) -> String {
+    let mut names = Vec::new();
+    let mut next = Some(index);
+    while let Some(code) = next.and_then(|index| codes.get(index)) {
+        names.push(core::str::from_utf8(code.name).unwrap_or("?"));
+        next = code.parent;
+    }
+
+    let mut path = String::new();
+    for (i, name) in names.iter().rev().enumerate() {
+        if i > 0 {
+            path.push('.');
+        }
+        path.push_str(name);
+    }
+
+    path
+}
+
+/// The parts of a skipped object which code objects need
+#[derive(Debug, Clone)]
+enum Value<'a> {
+    Str(&'a [u8]),
+    /// A tuple made up only of strings
+    Strs(Vec<&'a [u8]>),
+    Other(&'static str),
+}
+
+impl Value<'_> {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Str(_) => "str",
+            Value::Strs(_) => "tuple",
+            Value::Other(type_name) => type_name,
+        }
+    }
+}
+
+struct Scanner<'a> {
+    reader: Reader<'a>,
+    /// Interned strings in the order they were read, used to resolve Python 2 string references
+    interned: Vec<&'a [u8]>,
+    /// Objects which may be referred back to. Containers are added before their contents are
+    /// read and filled in afterwards.
+    refs: Vec<Option<Value<'a>>>,
+    /// The code objects found so far. Code objects are added before their contents are read so
+    /// that parents come before their children.
+    codes: Vec<Option<LazyCode<'a>>>,
+    /// The indexes of the code objects currently being read
+    parents: Vec<usize>,
+}
+
+impl<'a> Scanner<'a> {
+    fn skip_object(&mut self) -> Result<Value<'a>, MarshalError> {
+        self.reader.enter()?;
+        let result = self.skip_object_inner();
+        self.reader.leave();
+
+        result
+    }
+
+    fn skip_object_inner(&mut self) -> Result<Value<'a>, MarshalError> {
+        let offset = self.reader.offset();
+        let code = self.reader.read_u8()?;
+        let type_code = code & !FLAG_REF;
+
+        if type_code == TYPE_REF {
+            let index = self.reader.read_i32()?;
+            let value = usize::try_from(index)
+                .ok()
+                .and_then(|index| self.refs.get(index))
+                .and_then(Option::as_ref)
+                .ok_or(MarshalError::InvalidRef { index, offset })?
+                .clone();
+            if let Value::Strs(strs) = &value {
+                self.reader.allocate(vec_size(strs), offset)?;
+            }
+
+            return Ok(value);
+        }
+
+        let reference = if code & FLAG_REF != 0 {
+            self.refs.push(None);
+            Some(self.refs.len() - 1)
+        } else {
+            None
+        };
+
+        let value = match type_code {
+            TYPE_TUPLE => {
+                let len = self.reader.read_len()?;
+                self.skip_items(len, offset)?
+            }
+            TYPE_SMALL_TUPLE => {
+                let len = usize::from(self.reader.read_u8()?);
+                self.skip_items(len, offset)?
+            }
+            TYPE_LIST | TYPE_SET | TYPE_FROZENSET => {
+                let len = self.reader.read_len()?;
+                self.skip_items(len, offset)?;
+                Value::Other(match type_code {
+                    TYPE_LIST => "list",
+                    TYPE_SET => "set",
+                    _ => "frozenset",
+                })
+            }
+            TYPE_DICT => {
+                while self.reader.peek_u8()? != TYPE_NULL {
+                    self.skip_object()?;
+                    self.skip_object()?;
+                }
+                self.reader.read_u8()?;
+                Value::Other("dict")
+            }
+            TYPE_CODE => {
+                self.read_code(offset)?;
+                Value::Other("code")
+            }
+            _ => self.skip_atom(type_code, offset)?,
+        };
+
+        if let Some(index) = reference {
+            if let Value::Strs(strs) = &value {
+                self.reader.allocate(vec_size(strs), offset)?;
+            }
+            self.refs[index] = Some(value.clone());
+        }
+
+        Ok(value)
+    }
+
+    /// Skips an object which cannot contain other objects
+    fn skip_atom(&mut self, type_code: u8, offset: usize) -> Result<Value<'a>, MarshalError> {
+        let value = match type_code {
+            TYPE_NONE => Value::Other("NoneType"),
+            TYPE_FALSE | TYPE_TRUE => Value::Other("bool"),
+            TYPE_STOPITER => Value::Other("StopIteration"),
+            TYPE_ELLIPSIS => Value::Other("ellipsis"),
+            TYPE_INT => {
+                self.reader.read_bytes(4)?;
+                Value::Other("int")
+            }
+            TYPE_INT64 => {
+                self.reader.read_bytes(8)?;
+                Value::Other("int")
+            }
+            TYPE_LONG => {
+                let len = self.reader.read_i32()?.unsigned_abs() as usize;
+                self.reader.check_length(len, offset)?;
+                self.reader.read_bytes(len.saturating_mul(2))?;
+                Value::Other("long")
+            }
+            TYPE_FLOAT => {
+                self.skip_float_str()?;
+                Value::Other("float")
+            }
+            TYPE_BINARY_FLOAT => {
+                self.reader.read_bytes(8)?;
+                Value::Other("float")
+            }
+            TYPE_COMPLEX => {
+                self.skip_float_str()?;
+                self.skip_float_str()?;
+                Value::Other("complex")
+            }
+            TYPE_BINARY_COMPLEX => {
+                self.reader.read_bytes(16)?;
+                Value::Other("complex")
+            }
+            TYPE_STRING | TYPE_UNICODE | TYPE_ASCII | TYPE_ASCII_INTERNED => {
+                let len = self.reader.read_len()?;
+                Value::Str(self.read_string(len, offset)?)
+            }
+            TYPE_SHORT_ASCII | TYPE_SHORT_ASCII_INTERNED => {
+                let len = usize::from(self.reader.read_u8()?);
+                Value::Str(self.read_string(len, offset)?)
+            }
+            TYPE_INTERNED => {
+                let len = self.reader.read_len()?;
+                let s = self.read_string(len, offset)?;
+                // Python 3 uses back-references instead of string references
+                if self.reader.version() < PythonVersion::new(3, 0) {
+                    self.interned.push(s);
+                }
+                Value::Str(s)
+            }
+            TYPE_STRINGREF => {
+                let index = self.reader.read_i32()?;
+                let s = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| self.interned.get(index))
+                    .ok_or(MarshalError::InvalidStringRef { index, offset })?;
+                Value::Str(s)
+            }
+            TYPE_NULL => return Err(MarshalError::UnexpectedNull { offset }),
+            _ => return Err(MarshalError::UnknownType { type_code, offset }),
+        };
+
+        Ok(value)
+    }
+
+    /// Skips the items of a tuple or other container, keeping them if they are all strings
+    fn skip_items(&mut self, len: usize, offset: usize) -> Result<Value<'a>, MarshalError> {
+        self.reader.check_length(len, offset)?;
+
+        let mut strs = Some(Vec::new());
+        for _ in 0..len {
+            match (self.skip_object()?, &mut strs) {
+                (Value::Str(s), Some(strs)) => {
+                    self.reader
+                        .allocate(core::mem::size_of::<&[u8]>(), offset)?;
+                    strs.push(s);
+                }
+                _ => strs = None,
+            }
+        }
+
+        Ok(strs.map_or(Value::Other("tuple"), Value::Strs))
+    }
+
+    fn read_code(&mut self, offset: usize) -> Result<(), MarshalError> {
+        self.reader.count_code_object(offset)?;
+        self.reader
+            .allocate(core::mem::size_of::<LazyCode<'_>>(), offset)?;
+
+        let index = self.codes.len();
+        let parent = self.parents.last().copied();
+        self.codes.push(None);
+        self.parents.push(index);
+
+        let fields = layout::read_code(self)?;
+        let code = LazyCode {
+            parent,
+            argcount: fields.argcount,
+            posonlyargcount: fields.posonlyargcount,
+            kwonlyargcount: fields.kwonlyargcount,
+            nlocals: fields.nlocals,
+            stacksize: fields.stacksize,
+            flags: fields.flags,
+            code: fields.code,
+            names: fields.names,
+            varnames: fields.varnames,
+            freevars: fields.freevars,
+            cellvars: fields.cellvars,
+            localsplusnames: fields.localsplusnames,
+            localspluskinds: fields.localspluskinds,
+            filename: fields.filename,
+            name: fields.name,
+            qualname: fields.qualname,
+            firstlineno: fields.firstlineno,
+            linetable: fields.linetable,
+            exceptiontable: fields.exceptiontable,
+        };
+
+        self.parents.pop();
+        self.codes[index] = Some(code);
+
+        Ok(())
+    }
+
+    /// Reads an object which must be a string
+    fn read_str(&mut self) -> Result<&'a [u8], MarshalError> {
+        let offset = self.reader.offset();
+        match self.skip_object()? {
+            Value::Str(s) => Ok(s),
+            value => Err(MarshalError::UnexpectedType {
+                expected: "str",
+                found: value.type_name(),
+                offset,
+            }),
+        }
+    }
+
+    /// Reads an object which must be a tuple of strings
+    fn read_strs(&mut self) -> Result<Vec<&'a [u8]>, MarshalError> {
+        let offset = self.reader.offset();
+        match self.skip_object()? {
+            Value::Strs(strs) => Ok(strs),
+            value => Err(MarshalError::UnexpectedType {
+                expected: "tuple of str",
+                found: value.type_name(),
+                offset,
+            }),
+        }
+    }
+
+    fn read_string(&mut self, len: usize, offset: usize) -> Result<&'a [u8], MarshalError> {
+        self.reader.check_length(len, offset)?;
+        self.reader.read_bytes(len)
+    }
+
+    fn skip_float_str(&mut self) -> Result<(), MarshalError> {
+        let len = usize::from(self.reader.read_u8()?);
+        self.reader.read_bytes(len)?;
+
+        Ok(())
+    }
+}
+
+fn vec_size<T>(items: &[T]) -> usize {
+    items.len().saturating_mul(core::mem::size_of::<T>())
+}
+
+impl<'a> FieldReader for Scanner<'a> {
+    type Bytes = &'a [u8];
+    type Name = &'a [u8];
+    /// Constants are skipped, apart from the code objects among them
+    type Consts = ();
+
+    fn version(&self) -> PythonVersion {
+        self.reader.version()
+    }
+
+    fn u32_field(&mut self) -> Result<u32, MarshalError> {
+        self.reader.read_u32()
+    }
+
+    fn bytes_field(&mut self) -> Result<&'a [u8], MarshalError> {
+        self.read_str()
+    }
+
+    fn name_field(&mut self) -> Result<&'a [u8], MarshalError> {
+        self.read_str()
+    }
+
+    fn names_field(&mut self) -> Result<Vec<&'a [u8]>, MarshalError> {
+        self.read_strs()
+    }
+
+    fn consts_field(&mut self) -> Result<(), MarshalError> {
+        self.skip_object()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::code::CodeObject;
+    use crate::marshal::PyObject;
+
+    fn bytes(obj: &PyObject) -> &[u8] {
+        match obj {
+            PyObject::String(bytes) | PyObject::Interned(bytes) | PyObject::Unicode(bytes) => bytes,
+            _ => panic!("expected a string, found {}", obj.type_name()),
+        }
+    }
+
+    fn all_bytes(objs: &[PyObject]) -> Vec<&[u8]> {
+        objs.iter().map(bytes).collect()
+    }
+
+    fn check_agrees(lazy: &LazyCode<'_>, code: &CodeObject) {
+        assert_eq!(lazy.argcount, code.argcount);
+        assert_eq!(lazy.posonlyargcount, code.posonlyargcount);
+        assert_eq!(lazy.kwonlyargcount, code.kwonlyargcount);
+        assert_eq!(lazy.nlocals, code.nlocals);
+        assert_eq!(lazy.stacksize, code.stacksize);
+        assert_eq!(lazy.flags, code.flags);
+        assert_eq!(lazy.code, code.code);
+        assert_eq!(lazy.names, all_bytes(&code.names));
+        assert_eq!(lazy.varnames, all_bytes(&code.varnames));
+        assert_eq!(lazy.freevars, all_bytes(&code.freevars));
+        assert_eq!(lazy.cellvars, all_bytes(&code.cellvars));
+        assert_eq!(lazy.localsplusnames, all_bytes(&code.localsplusnames));
+        assert_eq!(lazy.localspluskinds, code.localspluskinds);
+        assert_eq!(lazy.filename, bytes(&code.filename));
+        assert_eq!(lazy.name, bytes(&code.name));
+        assert_eq!(lazy.qualname, code.qualname.as_ref().map(bytes));
+        assert_eq!(lazy.firstlineno, code.firstlineno);
+        assert_eq!(lazy.linetable, code.linetable);
+        assert_eq!(lazy.exceptiontable, code.exceptiontable);
+    }
+
+    #[test]
+    fn agrees_with_reader() {
+        let files: [&[u8]; 6] = [
+            include_bytes!("../../testdata/py27/sample.pyc"),
+            include_bytes!("../../testdata/py27/colorsys.pyc"),
+            include_bytes!("../../testdata/py3/py38.pyc"),
+            include_bytes!("../../testdata/py3/py311.pyc"),
+            include_bytes!("../../testdata/py3/py312.pyc"),
+            include_bytes!("../../testdata/py3/py313.pyc"),
+        ];
+        for data in files {
+            let (header, module) = crate::pyc::load(data, Limits::default()).unwrap();
+            let (lazy_header, codes) =
+                crate::pyc::lazy_code_objects(data, Limits::default()).unwrap();
+            assert_eq!(lazy_header, header);
+
+            let module = module.as_code().unwrap();
+            let walked: Vec<_> = module.walk().collect();
+            assert_eq!(codes.len(), walked.len());
+            assert!(codes.len() > 1);
+            for (i, (lazy, (path, code))) in codes.iter().zip(&walked).enumerate() {
+                check_agrees(lazy, code);
+                assert_eq!(qualified_name(&codes, i), *path);
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Python's `marshal` serialization format, which is used to store the module code object in
+//! `.pyc` files.
+//!
+//! The format of code objects changes between Python versions, so reading requires the version
+//! which wrote the data. Writing only supports the Python 2 format.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::code::CodeObject;
+use crate::error::MarshalError;
+use crate::pyc::PythonVersion;
+
+pub use self::reader::Reader;
+pub use self::writer::Writer;
+
+mod layout;
+pub mod lazy;
+pub mod reader;
+pub mod writer;
+
+pub(crate) const TYPE_NULL: u8 = b'0';
+pub(crate) const TYPE_NONE: u8 = b'N';
+pub(crate) const TYPE_FALSE: u8 = b'F';
+pub(crate) const TYPE_TRUE: u8 = b'T';
+pub(crate) const TYPE_STOPITER: u8 = b'S';
+pub(crate) const TYPE_ELLIPSIS: u8 = b'.';
+pub(crate) const TYPE_INT: u8 = b'i';
+pub(crate) const TYPE_INT64: u8 = b'I';
+pub(crate) const TYPE_FLOAT: u8 = b'f';
+pub(crate) const TYPE_BINARY_FLOAT: u8 = b'g';
+pub(crate) const TYPE_COMPLEX: u8 = b'x';
+pub(crate) const TYPE_BINARY_COMPLEX: u8 = b'y';
+pub(crate) const TYPE_LONG: u8 = b'l';
+pub(crate) const TYPE_STRING: u8 = b's';
+pub(crate) const TYPE_INTERNED: u8 = b't';
+pub(crate) const TYPE_STRINGREF: u8 = b'R';
+pub(crate) const TYPE_TUPLE: u8 = b'(';
+pub(crate) const TYPE_LIST: u8 = b'[';
+pub(crate) const TYPE_DICT: u8 = b'{';
+pub(crate) const TYPE_CODE: u8 = b'c';
+pub(crate) const TYPE_UNICODE: u8 = b'u';
+pub(crate) const TYPE_SET: u8 = b'<';
+pub(crate) const TYPE_FROZENSET: u8 = b'>';
+// Added in Python 3
+pub(crate) const TYPE_REF: u8 = b'r';
+pub(crate) const TYPE_ASCII: u8 = b'a';
+pub(crate) const TYPE_ASCII_INTERNED: u8 = b'A';
+pub(crate) const TYPE_SMALL_TUPLE: u8 = b')';
+pub(crate) const TYPE_SHORT_ASCII: u8 = b'z';
+pub(crate) const TYPE_SHORT_ASCII_INTERNED: u8 = b'Z';
+
+/// Set on the type code of objects which later `TYPE_REF`s may refer back to (Python 3.4+)
+pub(crate) const FLAG_REF: u8 = 0x80;
+
+/// Limits on the resources used to deserialize marshal data, for reading data from untrusted
+/// sources. Exceeding any of them makes reading fail with [`MarshalError::LimitExceeded`].
+///
+/// The defaults are large enough for any real module. Each level of nesting uses stack space, so
+/// raising `max_depth` far beyond the default may require running on a larger stack.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Limits {
+    /// How deeply containers and code objects may be nested
+    pub max_depth: usize,
+    /// The approximate number of bytes which may be allocated for the objects read. Each string
+    /// reference and back-reference counts as a copy of the object it refers to.
+    pub max_allocation: usize,
+    /// The maximum number of bytes in a string or items in a container
+    pub max_length: usize,
+    /// The maximum number of code objects
+    pub max_code_objects: usize,
+}
+
+impl Limits {
+    /// Limits which never restrict reading
+    pub const fn unlimited() -> Self {
+        Limits {
+            max_depth: usize::MAX,
+            max_allocation: usize::MAX,
+            max_length: usize::MAX,
+            max_code_objects: usize::MAX,
+        }
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_depth: 256,
+            max_allocation: 512 * 1024 * 1024,
+            max_length: 64 * 1024 * 1024,
+            max_code_objects: 1024 * 1024,
+        }
+    }
+}
+
+/// The limit which was exceeded when reading marshal data
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Limit {
+    Depth,
+    Allocation,
+    Length,
+    CodeObjects,
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Limit::Depth => "nesting depth",
+            Limit::Allocation => "allocation",
+            Limit::Length => "length",
+            Limit::CodeObjects => "code object count",
+        })
+    }
+}
+
+/// An arbitrary precision integer, stored the same way marshal stores it: as a sign and a
+/// sequence of 15-bit digits, least significant first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PyLong {
+    pub negative: bool,
+    pub digits: Vec<u16>,
+}
+
+impl PyLong {
+    /// The value of this integer if it fits in an `i128`
+    pub fn to_i128(&self) -> Option<i128> {
+        let mut value: i128 = 0;
+        for &digit in self.digits.iter().rev() {
+            value = value.checked_mul(1 << 15)?.checked_add(i128::from(digit))?;
+        }
+
+        Some(if self.negative { -value } else { value })
+    }
+}
+
+/// A value deserialized from marshal data
+#[derive(Debug, Clone, PartialEq)]
+pub enum PyObject {
+    None,
+    True,
+    False,
+    Ellipsis,
+    StopIteration,
+    Int(i32),
+    Int64(i64),
+    Long(PyLong),
+    Float(f64),
+    Complex { real: f64, imag: f64 },
+    /// A byte string (`str` in Python 2, `bytes` in Python 3)
+    String(Vec<u8>),
+    /// An interned `str`: a byte string in Python 2 and a UTF-8 encoded unicode string in
+    /// Python 3. String references are resolved to the string they refer to.
+    Interned(Vec<u8>),
+    /// A unicode string (`unicode` in Python 2, `str` in Python 3), encoded as UTF-8. Lone
+    /// surrogates are encoded the same way as any other code point, so this is not necessarily
+    /// valid UTF-8.
+    Unicode(Vec<u8>),
+    Tuple(Vec<PyObject>),
+    List(Vec<PyObject>),
+    /// Dictionary entries in the order they were serialized
+    Dict(Vec<(PyObject, PyObject)>),
+    Set(Vec<PyObject>),
+    FrozenSet(Vec<PyObject>),
+    Code(Box<CodeObject>),
+}
+
+impl PyObject {
+    /// The Python name of this object's type
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            PyObject::None => "NoneType",
+            PyObject::True | PyObject::False => "bool",
+            PyObject::Ellipsis => "ellipsis",
+            PyObject::StopIteration => "StopIteration",
+            PyObject::Int(_) | PyObject::Int64(_) => "int",
+            PyObject::Long(_) => "long",
+            PyObject::Float(_) => "float",
+            PyObject::Complex { .. } => "complex",
+            PyObject::String(_) | PyObject::Interned(_) => "str",
+            PyObject::Unicode(_) => "unicode",
+            PyObject::Tuple(_) => "tuple",
+            PyObject::List(_) => "list",
+            PyObject::Dict(_) => "dict",
+            PyObject::Set(_) => "set",
+            PyObject::FrozenSet(_) => "frozenset",
+            PyObject::Code(_) => "code",
+        }
+    }
+
+    /// The contents of this object if it is any kind of string and is valid UTF-8
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PyObject::String(bytes) | PyObject::Interned(bytes) | PyObject::Unicode(bytes) => {
+                core::str::from_utf8(bytes).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// The contents of this object if it is a byte string
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            PyObject::String(bytes) | PyObject::Interned(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// The code object this object holds, if any
+    pub fn as_code(&self) -> Option<&CodeObject> {
+        match self {
+            PyObject::Code(code) => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// Deserializes a single object written by the given Python version from the start of `data`,
+/// like Python's `marshal.loads`, using the default [`Limits`]
+pub fn loads(data: &[u8], version: PythonVersion) -> Result<PyObject, MarshalError> {
+    Reader::new(data, version).read_object()
+}
+
+/// Deserializes a single object from the start of `data` with the given limits
+pub fn loads_with_limits(
+    data: &[u8],
+    version: PythonVersion,
+    limits: Limits,
+) -> Result<PyObject, MarshalError> {
+    Reader::with_limits(data, version, limits).read_object()
+}
+
+/// Serializes `obj` in the format read by the given Python version, like Python's
+/// `marshal.dumps`
+pub fn dumps(obj: &PyObject, version: PythonVersion) -> Result<Vec<u8>, MarshalError> {
+    let mut writer = Writer::new(version);
+    writer.write_object(obj)?;
+
+    Ok(writer.into_inner())
+}
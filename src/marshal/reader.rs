@@ -0,0 +1,995 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use super::layout::{self, FieldReader};
+use super::{
+    Limit, Limits, PyLong, PyObject, FLAG_REF, TYPE_ASCII, TYPE_ASCII_INTERNED,
+    TYPE_BINARY_COMPLEX, TYPE_BINARY_FLOAT, TYPE_CODE, TYPE_COMPLEX, TYPE_DICT, TYPE_ELLIPSIS,
+    TYPE_FALSE, TYPE_FLOAT, TYPE_FROZENSET, TYPE_INT, TYPE_INT64, TYPE_INTERNED, TYPE_LIST,
+    TYPE_LONG, TYPE_NONE, TYPE_NULL, TYPE_REF, TYPE_SET, TYPE_SHORT_ASCII,
+    TYPE_SHORT_ASCII_INTERNED, TYPE_SMALL_TUPLE, TYPE_STOPITER, TYPE_STRING, TYPE_STRINGREF,
+    TYPE_TRUE, TYPE_TUPLE, TYPE_UNICODE,
+};
+use crate::code::CodeObject;
+use crate::error::MarshalError;
+use crate::pyc::PythonVersion;
+
+/// Deserializes marshal data produced by Python 2.3 or later.
+///
+/// Objects which are referred to more than once, by Python 2 string references or Python 3
+/// back-references, are copied to each place they are referred to from. Back-references are
+/// copied by reading the object again from where it was first read.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    version: PythonVersion,
+    /// Interned strings in the order they were read, used to resolve Python 2 string references
+    interned: Vec<Vec<u8>>,
+    /// The offsets of objects which may be referred back to. Containers are added before their
+    /// contents are read and filled in afterwards.
+    refs: Vec<Option<usize>>,
+    /// Whether a back-reference is being read, in which case no new references are recorded
+    replaying: bool,
+    limits: Limits,
+    depth: usize,
+    allocated: usize,
+    code_objects: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a reader for data written by the given Python version with the default [`Limits`]
+    pub fn new(data: &'a [u8], version: PythonVersion) -> Self {
+        Self::with_limits(data, version, Limits::default())
+    }
+
+    pub fn with_limits(data: &'a [u8], version: PythonVersion, limits: Limits) -> Self {
+        Reader {
+            data,
+            offset: 0,
+            version,
+            interned: Vec::new(),
+            refs: Vec::new(),
+            replaying: false,
+            limits,
+            depth: 0,
+            allocated: 0,
+            code_objects: 0,
+        }
+    }
+
+    /// The offset of the next byte to be read
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Reads the next object
+    pub fn read_object(&mut self) -> Result<PyObject, MarshalError> {
+        self.enter()?;
+        let result = self.read_object_inner();
+        self.leave();
+
+        result
+    }
+
+    pub(super) fn version(&self) -> PythonVersion {
+        self.version
+    }
+
+    /// Enters a level of nesting, failing if that exceeds the depth limit
+    pub(super) fn enter(&mut self) -> Result<(), MarshalError> {
+        if self.depth >= self.limits.max_depth {
+            return Err(MarshalError::LimitExceeded {
+                limit: Limit::Depth,
+                offset: self.offset,
+            });
+        }
+        self.depth += 1;
+
+        Ok(())
+    }
+
+    pub(super) fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Counts a code object, failing if that exceeds the code object limit
+    pub(super) fn count_code_object(&mut self, offset: usize) -> Result<(), MarshalError> {
+        if self.code_objects >= self.limits.max_code_objects {
+            return Err(MarshalError::LimitExceeded {
+                limit: Limit::CodeObjects,
+                offset,
+            });
+        }
+        self.code_objects += 1;
+
+        Ok(())
+    }
+
+    fn read_object_inner(&mut self) -> Result<PyObject, MarshalError> {
+        let offset = self.offset;
+        let code = self.read_u8()?;
+        let type_code = code & !FLAG_REF;
+
+        if type_code == TYPE_REF {
+            return self.read_ref(offset);
+        }
+
+        // Objects get their reference index before their contents are read, the same as CPython
+        let reference = if code & FLAG_REF != 0 && !self.replaying {
+            self.refs.push(None);
+            Some(self.refs.len() - 1)
+        } else {
+            None
+        };
+
+        // Only containers recurse, so everything else is read by a separate function to keep
+        // the stack frame which is repeated for every level of nesting small
+        let obj = match type_code {
+            TYPE_TUPLE => PyObject::Tuple(self.read_sequence(offset)?),
+            TYPE_SMALL_TUPLE => PyObject::Tuple(self.read_small_tuple(offset)?),
+            TYPE_LIST => PyObject::List(self.read_sequence(offset)?),
+            TYPE_SET => PyObject::Set(self.read_sequence(offset)?),
+            TYPE_FROZENSET => PyObject::FrozenSet(self.read_sequence(offset)?),
+            TYPE_DICT => PyObject::Dict(self.read_dict(offset)?),
+            TYPE_CODE => PyObject::Code(Box::new(self.read_code(offset)?)),
+            _ => self.read_atom(type_code, offset)?,
+        };
+
+        if let Some(index) = reference {
+            self.refs[index] = Some(offset);
+        }
+
+        Ok(obj)
+    }
+
+    fn read_ref(&mut self, offset: usize) -> Result<PyObject, MarshalError> {
+        let index = self.read_i32()?;
+        let start = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.refs.get(index).copied())
+            .flatten()
+            .ok_or(MarshalError::InvalidRef { index, offset })?;
+
+        // Reading the object again makes the copy count towards the limits like the original did
+        let end = core::mem::replace(&mut self.offset, start);
+        let replaying = core::mem::replace(&mut self.replaying, true);
+        let result = self.read_object_inner();
+        self.offset = end;
+        self.replaying = replaying;
+
+        result
+    }
+
+    /// Reads an object which cannot contain other objects
+    fn read_atom(&mut self, type_code: u8, offset: usize) -> Result<PyObject, MarshalError> {
+        let obj = match type_code {
+            TYPE_NONE => PyObject::None,
+            TYPE_FALSE => PyObject::False,
+            TYPE_TRUE => PyObject::True,
+            TYPE_STOPITER => PyObject::StopIteration,
+            TYPE_ELLIPSIS => PyObject::Ellipsis,
+            TYPE_INT => PyObject::Int(self.read_i32()?),
+            TYPE_INT64 => PyObject::Int64(i64::from_le_bytes(self.read_array()?)),
+            TYPE_LONG => PyObject::Long(self.read_long(offset)?),
+            TYPE_FLOAT => PyObject::Float(self.read_float_str()?),
+            TYPE_BINARY_FLOAT => PyObject::Float(f64::from_le_bytes(self.read_array()?)),
+            TYPE_COMPLEX => PyObject::Complex {
+                real: self.read_float_str()?,
+                imag: self.read_float_str()?,
+            },
+            TYPE_BINARY_COMPLEX => PyObject::Complex {
+                real: f64::from_le_bytes(self.read_array()?),
+                imag: f64::from_le_bytes(self.read_array()?),
+            },
+            TYPE_STRING => PyObject::String(self.read_string(offset)?),
+            TYPE_INTERNED => PyObject::Interned(self.read_interned(offset)?),
+            TYPE_STRINGREF => PyObject::Interned(self.read_string_ref(offset)?),
+            TYPE_UNICODE | TYPE_ASCII => PyObject::Unicode(self.read_string(offset)?),
+            TYPE_ASCII_INTERNED => PyObject::Interned(self.read_string(offset)?),
+            TYPE_SHORT_ASCII => PyObject::Unicode(self.read_short_string(offset)?),
+            TYPE_SHORT_ASCII_INTERNED => PyObject::Interned(self.read_short_string(offset)?),
+            TYPE_NULL => return Err(MarshalError::UnexpectedNull { offset }),
+            _ => return Err(MarshalError::UnknownType { type_code, offset }),
+        };
+
+        Ok(obj)
+    }
+
+    fn read_long(&mut self, offset: usize) -> Result<PyLong, MarshalError> {
+        let size = self.read_i32()?;
+        let len = size.unsigned_abs() as usize;
+        self.check_length(len, offset)?;
+        self.allocate(len.saturating_mul(2), offset)?;
+
+        let mut digits = Vec::with_capacity(core::cmp::min(len, self.remaining() / 2));
+        for _ in 0..len {
+            digits.push(u16::from_le_bytes(self.read_array()?));
+        }
+
+        Ok(PyLong {
+            negative: size < 0,
+            digits,
+        })
+    }
+
+    fn read_interned(&mut self, offset: usize) -> Result<Vec<u8>, MarshalError> {
+        let bytes = self.read_string(offset)?;
+        // Python 3 uses back-references instead of string references
+        if self.version < PythonVersion::new(3, 0) && !self.replaying {
+            self.allocate(bytes.len(), offset)?;
+            self.interned.push(bytes.clone());
+        }
+
+        Ok(bytes)
+    }
+
+    fn read_string_ref(&mut self, offset: usize) -> Result<Vec<u8>, MarshalError> {
+        let index = self.read_i32()?;
+        let bytes = usize::try_from(index)
+            .ok()
+            .and_then(|index| self.interned.get(index))
+            .ok_or(MarshalError::InvalidStringRef { index, offset })?
+            .clone();
+        self.allocate(bytes.len(), offset)?;
+
+        Ok(bytes)
+    }
+
+    fn read_dict(&mut self, offset: usize) -> Result<Vec<(PyObject, PyObject)>, MarshalError> {
+        let mut entries = Vec::new();
+        // Dictionaries are terminated by a NULL key rather than prefixed with a length
+        while self.peek_u8()? != TYPE_NULL {
+            self.check_length(entries.len() + 1, offset)?;
+            self.allocate(2 * core::mem::size_of::<PyObject>(), offset)?;
+            let key = self.read_object()?;
+            let value = self.read_object()?;
+            entries.push((key, value));
+        }
+        self.read_u8()?;
+
+        Ok(entries)
+    }
+
+    fn read_code(&mut self, offset: usize) -> Result<CodeObject, MarshalError> {
+        self.count_code_object(offset)?;
+        self.allocate(core::mem::size_of::<CodeObject>(), offset)?;
+
+        let fields = layout::read_code(self)?;
+        Ok(CodeObject {
+            argcount: fields.argcount,
+            posonlyargcount: fields.posonlyargcount,
+            kwonlyargcount: fields.kwonlyargcount,
+            nlocals: fields.nlocals,
+            stacksize: fields.stacksize,
+            flags: fields.flags,
+            code: fields.code,
+            consts: fields.consts,
+            names: fields.names,
+            varnames: fields.varnames,
+            freevars: fields.freevars,
+            cellvars: fields.cellvars,
+            localsplusnames: fields.localsplusnames,
+            localspluskinds: fields.localspluskinds,
+            filename: fields.filename,
+            name: fields.name,
+            qualname: fields.qualname,
+            firstlineno: fields.firstlineno,
+            linetable: fields.linetable,
+            exceptiontable: fields.exceptiontable,
+        })
+    }
+
+    /// Reads an object which must be a byte string
+    fn read_bytes_object(&mut self) -> Result<Vec<u8>, MarshalError> {
+        let offset = self.offset;
+        match self.read_object()? {
+            PyObject::String(bytes) | PyObject::Interned(bytes) => Ok(bytes),
+            obj => Err(MarshalError::UnexpectedType {
+                expected: "str",
+                found: obj.type_name(),
+                offset,
+            }),
+        }
+    }
+
+    /// Reads an object which must be a tuple
+    fn read_tuple(&mut self) -> Result<Vec<PyObject>, MarshalError> {
+        let offset = self.offset;
+        match self.read_object()? {
+            PyObject::Tuple(items) => Ok(items),
+            obj => Err(MarshalError::UnexpectedType {
+                expected: "tuple",
+                found: obj.type_name(),
+                offset,
+            }),
+        }
+    }
+
+    /// Reads the contents of a length-prefixed string
+    fn read_string(&mut self, offset: usize) -> Result<Vec<u8>, MarshalError> {
+        let len = self.read_len()?;
+        self.read_string_contents(len, offset)
+    }
+
+    /// Reads the contents of a string with a one byte length
+    fn read_short_string(&mut self, offset: usize) -> Result<Vec<u8>, MarshalError> {
+        let len = usize::from(self.read_u8()?);
+        self.read_string_contents(len, offset)
+    }
+
+    fn read_string_contents(&mut self, len: usize, offset: usize) -> Result<Vec<u8>, MarshalError> {
+        self.check_length(len, offset)?;
+        self.allocate(len, offset)?;
+
+        Ok(self.read_bytes(len)?.to_vec())
+    }
+
+    fn read_sequence(&mut self, offset: usize) -> Result<Vec<PyObject>, MarshalError> {
+        let len = self.read_len()?;
+        self.read_items(len, offset)
+    }
+
+    /// Reads a tuple with a one byte length
+    fn read_small_tuple(&mut self, offset: usize) -> Result<Vec<PyObject>, MarshalError> {
+        let len = usize::from(self.read_u8()?);
+        self.read_items(len, offset)
+    }
+
+    fn read_items(&mut self, len: usize, offset: usize) -> Result<Vec<PyObject>, MarshalError> {
+        self.check_length(len, offset)?;
+        self.allocate(len.saturating_mul(core::mem::size_of::<PyObject>()), offset)?;
+        // Every item is at least one byte, so don't trust the length for preallocation beyond
+        // what the remaining data could hold
+        let mut items = Vec::with_capacity(core::cmp::min(len, self.remaining()));
+        for _ in 0..len {
+            items.push(self.read_object()?);
+        }
+
+        Ok(items)
+    }
+
+    /// Reads a float stored as a length-prefixed ASCII string
+    fn read_float_str(&mut self) -> Result<f64, MarshalError> {
+        let offset = self.offset;
+        let len = usize::from(self.read_u8()?);
+        let bytes = self.read_bytes(len)?;
+
+        core::str::from_utf8(bytes)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or(MarshalError::InvalidFloat { offset })
+    }
+
+    /// Reads a length which must not be negative
+    pub(super) fn read_len(&mut self) -> Result<usize, MarshalError> {
+        let offset = self.offset;
+        let length = self.read_i32()?;
+
+        usize::try_from(length).map_err(|_| MarshalError::InvalidLength { length, offset })
+    }
+
+    pub(super) fn check_length(&self, len: usize, offset: usize) -> Result<(), MarshalError> {
+        if len > self.limits.max_length {
+            return Err(MarshalError::LimitExceeded {
+                limit: Limit::Length,
+                offset,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Accounts for `bytes` more bytes being allocated for the object at `offset`
+    pub(super) fn allocate(&mut self, bytes: usize, offset: usize) -> Result<(), MarshalError> {
+        self.allocated = self
+            .allocated
+            .checked_add(bytes)
+            .filter(|&allocated| allocated <= self.limits.max_allocation)
+            .ok_or(MarshalError::LimitExceeded {
+                limit: Limit::Allocation,
+                offset,
+            })?;
+
+        Ok(())
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    pub(super) fn peek_u8(&self) -> Result<u8, MarshalError> {
+        self.data
+            .get(self.offset)
+            .copied()
+            .ok_or(MarshalError::UnexpectedEof {
+                offset: self.offset,
+            })
+    }
+
+    pub(super) fn read_u8(&mut self) -> Result<u8, MarshalError> {
+        let byte = self.peek_u8()?;
+        self.offset += 1;
+
+        Ok(byte)
+    }
+
+    pub(super) fn read_i32(&mut self) -> Result<i32, MarshalError> {
+        Ok(i32::from_le_bytes(self.read_array()?))
+    }
+
+    pub(super) fn read_u32(&mut self) -> Result<u32, MarshalError> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    pub(super) fn read_array<const N: usize>(&mut self) -> Result<[u8; N], MarshalError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+
+        Ok(array)
+    }
+
+    pub(super) fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], MarshalError> {
+        let bytes = self
+            .data
+            .get(self.offset..)
+            .and_then(|data| data.get(..len))
+            .ok_or(MarshalError::UnexpectedEof {
+                offset: self.offset,
+            })?;
+        self.offset += len;
+
+        Ok(bytes)
+    }
+}
+
+impl FieldReader for Reader<'_> {
+    type Bytes = Vec<u8>;
+    type Name = PyObject;
+    type Consts = Vec<PyObject>;
+
+    fn version(&self) -> PythonVersion {
+        self.version
+    }
+
+    fn u32_field(&mut self) -> Result<u32, MarshalError> {
+        self.read_u32()
+    }
+
+    fn bytes_field(&mut self) -> Result<Vec<u8>, MarshalError> {
+        self.read_bytes_object()
+    }
+
+    fn name_field(&mut self) -> Result<PyObject, MarshalError> {
+        self.read_object()
+    }
+
+    fn names_field(&mut self) -> Result<Vec<PyObject>, MarshalError> {
+        self.read_tuple()
+    }
+
+    fn consts_field(&mut self) -> Result<Vec<PyObject>, MarshalError> {
+        self.read_tuple()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    /// Reads `data` as Python 2.7 marshal data, which must be consumed completely
+    fn load27(data: &[u8]) -> PyObject {
+        let mut reader = Reader::new(data, PythonVersion::new(2, 7));
+        let obj = reader.read_object().unwrap();
+        assert_eq!(reader.offset(), data.len());
+
+        obj
+    }
+
+    fn interned(s: &str) -> PyObject {
+        PyObject::Interned(s.as_bytes().to_vec())
+    }
+
+    // The fixtures in these tests were written by Python 2.7's `marshal.dumps`
+
+    #[test]
+    fn py27_numbers() {
+        assert_eq!(load27(&[105, 251, 255, 255, 255]), PyObject::Int(-5));
+        assert_eq!(
+            load27(&[73, 0, 0, 0, 0, 0, 1, 0, 0]),
+            PyObject::Int64(1 << 40)
+        );
+
+        // -(2 ** 70)
+        let long = load27(&[108, 251, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4]);
+        let PyObject::Long(long) = long else {
+            panic!("expected a long");
+        };
+        assert_eq!(long.digits, [0, 0, 0, 0, 1024]);
+        assert_eq!(long.to_i128(), Some(-(1 << 70)));
+
+        // Marshal version 1 writes floats as text and version 2 in binary
+        assert_eq!(load27(&[102, 3, 49, 46, 53]), PyObject::Float(1.5));
+        assert_eq!(
+            load27(&[103, 0, 0, 0, 0, 0, 0, 248, 63]),
+            PyObject::Float(1.5)
+        );
+        let complex = PyObject::Complex {
+            real: 1.5,
+            imag: -2.0,
+        };
+        assert_eq!(load27(&[120, 3, 49, 46, 53, 2, 45, 50]), complex);
+        assert_eq!(
+            load27(&[121, 0, 0, 0, 0, 0, 0, 248, 63, 0, 0, 0, 0, 0, 0, 0, 192]),
+            complex
+        );
+    }
+
+    #[test]
+    fn py27_strings() {
+        assert_eq!(
+            load27(&[115, 3, 0, 0, 0, 97, 0, 98]),
+            PyObject::String(b"a\0b".to_vec())
+        );
+        assert_eq!(
+            load27(&[117, 3, 0, 0, 0, 104, 195, 169]),
+            PyObject::Unicode("h\u{e9}".as_bytes().to_vec())
+        );
+
+        // (intern('spam'), intern('spam'), intern('eggs')), where the second is a reference
+        let data = [
+            40, 3, 0, 0, 0, 116, 4, 0, 0, 0, 115, 112, 97, 109, 82, 0, 0, 0, 0, 116, 4, 0, 0, 0,
+            101, 103, 103, 115,
+        ];
+        assert_eq!(
+            load27(&data),
+            PyObject::Tuple(vec![interned("spam"), interned("spam"), interned("eggs")])
+        );
+
+        let mut reader = Reader::new(&[82, 1, 0, 0, 0], PythonVersion::new(2, 7));
+        assert_eq!(
+            reader.read_object(),
+            Err(MarshalError::InvalidStringRef {
+                index: 1,
+                offset: 0
+            })
+        );
+    }
+
+    #[test]
+    fn py27_containers() {
+        // [None, True, False, Ellipsis, StopIteration, ()]
+        assert_eq!(
+            load27(&[91, 6, 0, 0, 0, 78, 84, 70, 46, 83, 40, 0, 0, 0, 0]),
+            PyObject::List(vec![
+                PyObject::None,
+                PyObject::True,
+                PyObject::False,
+                PyObject::Ellipsis,
+                PyObject::StopIteration,
+                PyObject::Tuple(Vec::new()),
+            ])
+        );
+        // (set([1]), frozenset([2]))
+        assert_eq!(
+            load27(&[
+                40, 2, 0, 0, 0, 60, 1, 0, 0, 0, 105, 1, 0, 0, 0, 62, 1, 0, 0, 0, 105, 2, 0, 0, 0
+            ]),
+            PyObject::Tuple(vec![
+                PyObject::Set(vec![PyObject::Int(1)]),
+                PyObject::FrozenSet(vec![PyObject::Int(2)]),
+            ])
+        );
+        // {'a': 1}
+        assert_eq!(
+            load27(&[123, 116, 1, 0, 0, 0, 97, 105, 1, 0, 0, 0, 48]),
+            PyObject::Dict(vec![(interned("a"), PyObject::Int(1))])
+        );
+    }
+
+    /// `def f(a, b=2): return a + b` followed by `x = f(1)`, compiled by Python 2.7
+    pub(crate) const PY27_CODE: &[u8] = &[
+        99, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 64, 0, 0, 0, 115, 28, 0, 0, 0, 100, 0, 0, 100, 1,
+        0, 132, 1, 0, 90, 0, 0, 101, 0, 0, 100, 2, 0, 131, 1, 0, 90, 1, 0, 100, 3, 0, 83, 40, 4, 0,
+        0, 0, 105, 2, 0, 0, 0, 99, 2, 0, 0, 0, 2, 0, 0, 0, 2, 0, 0, 0, 67, 0, 0, 0, 115, 8, 0, 0,
+        0, 124, 0, 0, 124, 1, 0, 23, 83, 40, 1, 0, 0, 0, 78, 40, 0, 0, 0, 0, 40, 2, 0, 0, 0, 116,
+        1, 0, 0, 0, 97, 116, 1, 0, 0, 0, 98, 40, 0, 0, 0, 0, 40, 0, 0, 0, 0, 115, 4, 0, 0, 0, 109,
+        46, 112, 121, 116, 1, 0, 0, 0, 102, 1, 0, 0, 0, 115, 2, 0, 0, 0, 0, 1, 105, 1, 0, 0, 0, 78,
+        40, 2, 0, 0, 0, 82, 2, 0, 0, 0, 116, 1, 0, 0, 0, 120, 40, 0, 0, 0, 0, 40, 0, 0, 0, 0, 40,
+        0, 0, 0, 0, 115, 4, 0, 0, 0, 109, 46, 112, 121, 116, 8, 0, 0, 0, 60, 109, 111, 100, 117,
+        108, 101, 62, 1, 0, 0, 0, 115, 2, 0, 0, 0, 12, 2,
+    ];
+
+    #[test]
+    fn py27_code() {
+        let module = load27(PY27_CODE);
+        let module = module.as_code().unwrap();
+        assert_eq!(module.stacksize, 2);
+        assert_eq!(module.flags, 0x40);
+        assert_eq!(module.code.len(), 28);
+        // The name `f` is a string reference to the interned `co_name` of the function
+        assert_eq!(module.names, [interned("f"), interned("x")]);
+        assert_eq!(module.filename, PyObject::String(b"m.py".to_vec()));
+        assert_eq!(module.name, interned("<module>"));
+        assert_eq!(module.linetable, [12, 2]);
+        assert_eq!(module.qualname, None);
+
+        assert_eq!(module.consts[0], PyObject::Int(2));
+        assert_eq!(module.consts[2], PyObject::Int(1));
+        assert_eq!(module.consts[3], PyObject::None);
+        let function = module.consts[1].as_code().unwrap();
+        assert_eq!(function.argcount, 2);
+        assert_eq!(function.nlocals, 2);
+        assert_eq!(function.flags, 0x43);
+        assert_eq!(function.code, [124, 0, 0, 124, 1, 0, 23, 83]);
+        assert_eq!(function.consts, [PyObject::None]);
+        assert_eq!(function.varnames, [interned("a"), interned("b")]);
+        assert_eq!(function.name, interned("f"));
+        assert_eq!(function.firstlineno, 1);
+        assert_eq!(function.linetable, [0, 1]);
+    }
+
+    /// Marshals `((((),),),)` nested `depth` deep with every level flagged, followed by a tuple
+    /// of back-references to each level
+    fn nested_refs(depth: usize) -> Vec<u8> {
+        let mut data = vec![TYPE_SMALL_TUPLE, 2];
+        for _ in 0..depth {
+            data.extend([TYPE_SMALL_TUPLE | FLAG_REF, 1]);
+        }
+        data.extend([TYPE_SMALL_TUPLE | FLAG_REF, 0]);
+        data.extend([TYPE_SMALL_TUPLE, depth as u8 + 1]);
+        for index in 0..=depth as i32 {
+            data.push(TYPE_REF);
+            data.extend(index.to_le_bytes());
+        }
+
+        data
+    }
+
+    #[test]
+    fn nested_refs_count_once() {
+        let depth = 40;
+        let data = nested_refs(depth);
+        let mut reader = Reader::with_limits(&data, PythonVersion::new(3, 12), Limits::default());
+        let obj = reader.read_object().unwrap();
+        assert_eq!(reader.offset(), data.len());
+
+        let PyObject::Tuple(items) = obj else {
+            panic!("expected a tuple");
+        };
+        let PyObject::Tuple(refs) = &items[1] else {
+            panic!("expected a tuple");
+        };
+        assert_eq!(refs.len(), depth + 1);
+        assert_eq!(refs[0], items[0]);
+
+        let mut level = &refs[0];
+        for copy in refs {
+            assert_eq!(copy, level);
+            if let PyObject::Tuple(inner) = level {
+                level = inner.first().unwrap_or(level);
+            }
+        }
+        assert_eq!(*level, PyObject::Tuple(Vec::new()));
+    }
+
+    #[test]
+    fn refs_count_towards_allocation_limit() {
+        let data = nested_refs(40);
+        let limits = Limits {
+            max_allocation: 100 * core::mem::size_of::<PyObject>(),
+            ..Limits::default()
+        };
+        let mut reader = Reader::with_limits(&data, PythonVersion::new(3, 12), limits);
+        assert!(matches!(
+            reader.read_object(),
+            Err(MarshalError::LimitExceeded {
+                limit: Limit::Allocation,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn unfinished_ref_is_invalid() {
+        let mut data = vec![TYPE_SMALL_TUPLE | FLAG_REF, 1, TYPE_REF];
+        data.extend(0i32.to_le_bytes());
+        let mut reader = Reader::new(&data, PythonVersion::new(3, 12));
+        assert!(matches!(
+            reader.read_object(),
+            Err(MarshalError::InvalidRef { index: 0, .. })
+        ));
+    }
+
+    /// Checks that both `Reader` and the lazy scanner fail with the given limit
+    fn check_limit(
+        data: &[u8],
+        version: PythonVersion,
+        limits: Limits,
+        limit: Limit,
+        offset: usize,
+    ) {
+        let expected = MarshalError::LimitExceeded { limit, offset };
+        let mut reader = Reader::with_limits(data, version, limits);
+        assert_eq!(reader.read_object(), Err(expected.clone()));
+        let lazy = crate::marshal::lazy::code_objects(data, version, limits);
+        assert_eq!(lazy.err(), Some(expected));
+    }
+
+    #[test]
+    fn limits() {
+        let py27 = PythonVersion::new(2, 7);
+
+        // [[[]]]
+        let data = [91, 1, 0, 0, 0, 91, 1, 0, 0, 0, 91, 0, 0, 0, 0];
+        let limits = Limits {
+            max_depth: 3,
+            ..Limits::default()
+        };
+        assert!(Reader::with_limits(&data, py27, limits)
+            .read_object()
+            .is_ok());
+        let limits = Limits {
+            max_depth: 2,
+            ..Limits::default()
+        };
+        check_limit(&data, py27, limits, Limit::Depth, 10);
+
+        // (u'abcd', [None, None, None, None])
+        let data = [
+            40, 2, 0, 0, 0, 117, 4, 0, 0, 0, 97, 98, 99, 100, 91, 4, 0, 0, 0, 78, 78, 78, 78,
+        ];
+        let limits = Limits {
+            max_length: 4,
+            ..Limits::default()
+        };
+        assert!(Reader::with_limits(&data, py27, limits)
+            .read_object()
+            .is_ok());
+        let limits = Limits {
+            max_length: 3,
+            ..Limits::default()
+        };
+        check_limit(&data, py27, limits, Limit::Length, 5);
+        check_limit(&data[14..], py27, limits, Limit::Length, 0);
+
+        // Every string and container counts towards the allocation limit, so the list no longer
+        // fits after the tuple and the string
+        let limits = Limits {
+            max_allocation: 4 + 4 * core::mem::size_of::<PyObject>(),
+            ..Limits::default()
+        };
+        let mut reader = Reader::with_limits(&data, py27, limits);
+        assert_eq!(
+            reader.read_object(),
+            Err(MarshalError::LimitExceeded {
+                limit: Limit::Allocation,
+                offset: 14
+            })
+        );
+        let mut reader = Reader::with_limits(&data[14..], py27, limits);
+        assert!(reader.read_object().is_ok());
+
+        // The module and the function it defines
+        let limits = Limits {
+            max_code_objects: 2,
+            ..Limits::default()
+        };
+        assert!(Reader::with_limits(PY27_CODE, py27, limits)
+            .read_object()
+            .is_ok());
+        let limits = Limits {
+            max_code_objects: 1,
+            ..Limits::default()
+        };
+        check_limit(PY27_CODE, py27, limits, Limit::CodeObjects, 60);
+    }
+
+    #[test]
+    fn crafted_lengths_fail_without_allocating() {
+        let py27 = PythonVersion::new(2, 7);
+
+        // A string and a list which claim to be 2 GiB long
+        for type_code in [TYPE_STRING, TYPE_LIST] {
+            let mut data = vec![type_code];
+            data.extend(i32::MAX.to_le_bytes());
+            data.push(78);
+
+            check_limit(&data, py27, Limits::default(), Limit::Length, 0);
+            let mut reader = Reader::with_limits(&data, py27, Limits::unlimited());
+            assert!(matches!(
+                reader.read_object(),
+                Err(MarshalError::UnexpectedEof { .. })
+            ));
+        }
+
+        // Far more nesting than the stack could hold
+        let data = [TYPE_LIST, 1, 0, 0, 0].repeat(100_000);
+        check_limit(&data, py27, Limits::default(), Limit::Depth, 256 * 5);
+    }
+
+    fn load3(data: &[u8]) -> PyObject {
+        let mut reader = Reader::new(data, PythonVersion::new(3, 12));
+        let obj = reader.read_object().unwrap();
+        assert_eq!(reader.offset(), data.len());
+
+        obj
+    }
+
+    fn unicode(s: &str) -> PyObject {
+        PyObject::Unicode(s.as_bytes().to_vec())
+    }
+
+    // The fixtures in these tests were written by Python 3.12's `marshal.dumps`
+    #[test]
+    fn py3_type_codes() {
+        // (sys.intern('spam'), sys.intern('spam'), x, x) where x = [1]. The tuple, the string
+        // and the list have FLAG_REF set and are referred to with TYPE_REF.
+        let data = [
+            169, 4, 218, 4, 115, 112, 97, 109, 114, 1, 0, 0, 0, 219, 1, 0, 0, 0, 233, 1, 0, 0, 0,
+            114, 2, 0, 0, 0,
+        ];
+        let list = PyObject::List(vec![PyObject::Int(1)]);
+        assert_eq!(
+            load3(&data),
+            PyObject::Tuple(vec![interned("spam"), interned("spam"), list.clone(), list])
+        );
+
+        // Short ASCII strings, interned or not, and long ASCII strings
+        assert_eq!(load3(&[218, 3, 97, 98, 99]), interned("abc"));
+        assert_eq!(load3(&[250, 3, 97, 32, 98]), unicode("a b"));
+        let mut data = vec![TYPE_ASCII | FLAG_REF];
+        data.extend(300i32.to_le_bytes());
+        data.extend(b"a b".repeat(100));
+        assert_eq!(load3(&data), unicode(&"a b".repeat(100)));
+        data[0] = TYPE_ASCII_INTERNED | FLAG_REF;
+        assert_eq!(load3(&data), interned(&"a b".repeat(100)));
+
+        assert_eq!(load3(&[245, 3, 0, 0, 0, 104, 195, 169]), unicode("h\u{e9}"));
+        assert_eq!(
+            load3(&[243, 3, 0, 0, 0, 97, 0, 98]),
+            PyObject::String(b"a\0b".to_vec())
+        );
+        assert_eq!(
+            load3(&[169, 2, 78, 84]),
+            PyObject::Tuple(vec![PyObject::None, PyObject::True])
+        );
+        assert_eq!(
+            load3(&[190, 1, 0, 0, 0, 233, 2, 0, 0, 0]),
+            PyObject::FrozenSet(vec![PyObject::Int(2)])
+        );
+        assert_eq!(
+            load3(&[231, 0, 0, 0, 0, 0, 0, 248, 63]),
+            PyObject::Float(1.5)
+        );
+
+        // Python 3 has no string references
+        let mut reader = Reader::new(&[82, 0, 0, 0, 0], PythonVersion::new(3, 12));
+        assert_eq!(
+            reader.read_object(),
+            Err(MarshalError::InvalidStringRef {
+                index: 0,
+                offset: 0
+            })
+        );
+    }
+
+    fn hex(bytes: &[u8]) -> alloc::string::String {
+        bytes
+            .iter()
+            .map(|byte| alloc::format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn joined(names: &[PyObject]) -> alloc::string::String {
+        let names: Vec<&str> = names.iter().map(|name| name.as_str().unwrap()).collect();
+        match names.join(",") {
+            names if names.is_empty() => "-".into(),
+            names => names,
+        }
+    }
+
+    /// Checks every code object in a pyc file against the description written by
+    /// `testdata/py3/generate.py`
+    fn check_code_layout(pyc: &[u8], description: &str) {
+        let (_, module) = crate::pyc::load(pyc, Limits::default()).unwrap();
+        let module = module.as_code().unwrap();
+
+        let mut codes = module.walk();
+        let mut code = None;
+        for line in description.lines() {
+            let (field, expected) = line.split_once(' ').unwrap();
+            if field == "code" && code.is_none() {
+                let (path, next) = codes.next().unwrap();
+                assert_eq!(path, expected);
+                code = Some(next);
+                continue;
+            }
+
+            let current: &CodeObject = code.unwrap();
+            let actual = match field {
+                "argcount" => current.argcount.to_string(),
+                "posonlyargcount" => current.posonlyargcount.to_string(),
+                "kwonlyargcount" => current.kwonlyargcount.to_string(),
+                "nlocals" => current.nlocals.to_string(),
+                "stacksize" => current.stacksize.to_string(),
+                "flags" => current.flags.to_string(),
+                "firstlineno" => current.firstlineno.to_string(),
+                "names" => joined(&current.names),
+                "varnames" => joined(&current.varnames),
+                "freevars" => joined(&current.freevars),
+                "cellvars" => joined(&current.cellvars),
+                "filename" => current.filename.as_str().unwrap().into(),
+                "qualname" => current
+                    .qualname
+                    .as_ref()
+                    .map_or("-", |name| name.as_str().unwrap())
+                    .into(),
+                "consts" => current.consts.len().to_string(),
+                "code" => hex(&current.code),
+                "linetable" if current.linetable.is_empty() => "-".into(),
+                "linetable" => hex(&current.linetable),
+                "exceptiontable" if current.exceptiontable.is_empty() => "-".into(),
+                "exceptiontable" => hex(&current.exceptiontable),
+                _ => panic!("unknown field {}", field),
+            };
+            assert_eq!(
+                actual,
+                expected,
+                "{} of {}",
+                field,
+                current.name.as_str().unwrap()
+            );
+
+            // The last field of each code object
+            if field == "exceptiontable" {
+                code = None;
+            }
+        }
+        assert!(codes.next().is_none());
+    }
+
+    #[test]
+    fn py3_code_layouts() {
+        let files: [(&[u8], &str); 4] = [
+            (
+                include_bytes!("../../testdata/py3/py38.pyc"),
+                include_str!("../../testdata/py3/py38.txt"),
+            ),
+            (
+                include_bytes!("../../testdata/py3/py311.pyc"),
+                include_str!("../../testdata/py3/py311.txt"),
+            ),
+            (
+                include_bytes!("../../testdata/py3/py312.pyc"),
+                include_str!("../../testdata/py3/py312.txt"),
+            ),
+            (
+                include_bytes!("../../testdata/py3/py313.pyc"),
+                include_str!("../../testdata/py3/py313.txt"),
+            ),
+        ];
+        for (pyc, description) in files {
+            check_code_layout(pyc, description);
+
+            let (_, module) = crate::pyc::load(pyc, Limits::default()).unwrap();
+            let consts = &module.as_code().unwrap().consts;
+            let long = consts.iter().find_map(|obj| match obj {
+                PyObject::Long(long) => long.to_i128(),
+                _ => None,
+            });
+            assert_eq!(long, Some(1 << 100));
+            assert!(consts.contains(&PyObject::Float(0.1)));
+            assert!(consts.contains(&PyObject::Complex {
+                real: 1.5,
+                imag: -2.0
+            }));
+            assert!(consts.contains(&unicode("h\u{e9}llo")));
+            assert!(consts.contains(&PyObject::String(b"a\0b".to_vec())));
+            // The second 'spam' is a reference to the first
+            assert!(consts.contains(&PyObject::Tuple(vec![interned("spam"), interned("spam")])));
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use super::{
+    PyLong, PyObject, TYPE_BINARY_COMPLEX, TYPE_BINARY_FLOAT, TYPE_CODE, TYPE_DICT, TYPE_ELLIPSIS,
+    TYPE_FALSE, TYPE_FROZENSET, TYPE_INT, TYPE_INT64, TYPE_INTERNED, TYPE_LIST, TYPE_LONG,
+    TYPE_NONE, TYPE_NULL, TYPE_SET, TYPE_STOPITER, TYPE_STRING, TYPE_STRINGREF, TYPE_TRUE,
+    TYPE_TUPLE, TYPE_UNICODE,
+};
+use crate::code::CodeObject;
+use crate::error::MarshalError;
+use crate::pyc::PythonVersion;
+
+/// Serializes objects in the marshal format read by a given Python version. Only Python 2.5 to
+/// 2.7 (marshal version 2) are supported, and code objects are written with the Python 2 layout.
+///
+/// The first occurrence of each interned string is written in full and later occurrences are
+/// written as references to it, the same as CPython does. Objects read with
+/// [`Reader`](super::Reader) are therefore written back out byte-for-byte, except for floats and
+/// complex numbers in the text form of marshal version 1 (`marshal.dumps(x, 1)`), which are
+/// written in binary form.
+///
+/// Writing fails for other versions, for code objects with fields the Python 2 layout does not
+/// have, and for strings or containers with more than `i32::MAX` elements.
+#[derive(Debug)]
+pub struct Writer {
+    version: PythonVersion,
+    data: Vec<u8>,
+    /// Index of each interned string written so far
+    interned: BTreeMap<Vec<u8>, i32>,
+}
+
+impl Writer {
+    /// Creates a writer for data which will be read by the given Python version
+    pub fn new(version: PythonVersion) -> Self {
+        Writer {
+            version,
+            data: Vec::new(),
+            interned: BTreeMap::new(),
+        }
+    }
+
+    /// The data written so far
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consumes the writer and returns the data written
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Writes `obj` and everything it contains. Nothing is written if the version is not
+    /// supported, but `obj` may have been partially written if it contains an object which cannot
+    /// be written.
+    pub fn write_object(&mut self, obj: &PyObject) -> Result<(), MarshalError> {
+        if self.version < PythonVersion::new(2, 5) || self.version.major >= 3 {
+            return Err(MarshalError::UnsupportedVersion(self.version));
+        }
+
+        self.write(obj)
+    }
+
+    fn write(&mut self, obj: &PyObject) -> Result<(), MarshalError> {
+        match obj {
+            PyObject::None => self.data.push(TYPE_NONE),
+            PyObject::True => self.data.push(TYPE_TRUE),
+            PyObject::False => self.data.push(TYPE_FALSE),
+            PyObject::Ellipsis => self.data.push(TYPE_ELLIPSIS),
+            PyObject::StopIteration => self.data.push(TYPE_STOPITER),
+            PyObject::Int(value) => {
+                self.data.push(TYPE_INT);
+                self.data.extend_from_slice(&value.to_le_bytes());
+            }
+            PyObject::Int64(value) => {
+                self.data.push(TYPE_INT64);
+                self.data.extend_from_slice(&value.to_le_bytes());
+            }
+            PyObject::Long(long) => self.write_long(long)?,
+            PyObject::Float(value) => {
+                self.data.push(TYPE_BINARY_FLOAT);
+                self.data.extend_from_slice(&value.to_le_bytes());
+            }
+            PyObject::Complex { real, imag } => {
+                self.data.push(TYPE_BINARY_COMPLEX);
+                self.data.extend_from_slice(&real.to_le_bytes());
+                self.data.extend_from_slice(&imag.to_le_bytes());
+            }
+            PyObject::String(bytes) => self.write_bytes(TYPE_STRING, bytes)?,
+            PyObject::Interned(bytes) => match self.interned.get(bytes) {
+                Some(&index) => {
+                    self.data.push(TYPE_STRINGREF);
+                    self.data.extend_from_slice(&index.to_le_bytes());
+                }
+                None => {
+                    let index = to_len(self.interned.len())?;
+                    self.interned.insert(bytes.clone(), index);
+                    self.write_bytes(TYPE_INTERNED, bytes)?;
+                }
+            },
+            PyObject::Unicode(s) => self.write_bytes(TYPE_UNICODE, s)?,
+            PyObject::Tuple(items) => self.write_sequence(TYPE_TUPLE, items)?,
+            PyObject::List(items) => self.write_sequence(TYPE_LIST, items)?,
+            PyObject::Set(items) => self.write_sequence(TYPE_SET, items)?,
+            PyObject::FrozenSet(items) => self.write_sequence(TYPE_FROZENSET, items)?,
+            PyObject::Dict(entries) => {
+                self.data.push(TYPE_DICT);
+                for (key, value) in entries {
+                    self.write(key)?;
+                    self.write(value)?;
+                }
+                self.data.push(TYPE_NULL);
+            }
+            PyObject::Code(code) => self.write_code(code)?,
+        }
+
+        Ok(())
+    }
+
+    fn write_code(&mut self, code: &CodeObject) -> Result<(), MarshalError> {
+        let unsupported = [
+            ("co_posonlyargcount", code.posonlyargcount != 0),
+            ("co_kwonlyargcount", code.kwonlyargcount != 0),
+            ("co_localsplusnames", !code.localsplusnames.is_empty()),
+            ("co_localspluskinds", !code.localspluskinds.is_empty()),
+            ("co_qualname", code.qualname.is_some()),
+            ("co_exceptiontable", !code.exceptiontable.is_empty()),
+        ];
+        if let Some(&(field, _)) = unsupported.iter().find(|(_, present)| *present) {
+            return Err(MarshalError::UnsupportedField {
+                field,
+                version: self.version,
+            });
+        }
+
+        self.data.push(TYPE_CODE);
+        self.write_u32(code.argcount);
+        self.write_u32(code.nlocals);
+        self.write_u32(code.stacksize);
+        self.write_u32(code.flags);
+        self.write_code_string(&code.code)?;
+        self.write_sequence(TYPE_TUPLE, &code.consts)?;
+        self.write_sequence(TYPE_TUPLE, &code.names)?;
+        self.write_sequence(TYPE_TUPLE, &code.varnames)?;
+        self.write_sequence(TYPE_TUPLE, &code.freevars)?;
+        self.write_sequence(TYPE_TUPLE, &code.cellvars)?;
+        self.write(&code.filename)?;
+        self.write(&code.name)?;
+        self.write_u32(code.firstlineno);
+        self.write_code_string(&code.linetable)
+    }
+
+    /// Writes a byte string field of a code object. CPython shares empty and single character
+    /// strings as interned singletons, so these are written as interned strings to match.
+    fn write_code_string(&mut self, bytes: &[u8]) -> Result<(), MarshalError> {
+        if bytes.len() <= 1 {
+            self.write(&PyObject::Interned(bytes.to_vec()))
+        } else {
+            self.write_bytes(TYPE_STRING, bytes)
+        }
+    }
+
+    fn write_long(&mut self, long: &PyLong) -> Result<(), MarshalError> {
+        let size = to_len(long.digits.len())?;
+
+        self.data.push(TYPE_LONG);
+        self.data
+            .extend_from_slice(&(if long.negative { -size } else { size }).to_le_bytes());
+        for digit in &long.digits {
+            self.data.extend_from_slice(&digit.to_le_bytes());
+        }
+
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, type_code: u8, bytes: &[u8]) -> Result<(), MarshalError> {
+        self.data.push(type_code);
+        self.write_len(bytes.len())?;
+        self.data.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    fn write_sequence(&mut self, type_code: u8, items: &[PyObject]) -> Result<(), MarshalError> {
+        self.data.push(type_code);
+        self.write_len(items.len())?;
+        for item in items {
+            self.write(item)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_len(&mut self, len: usize) -> Result<(), MarshalError> {
+        self.data.extend_from_slice(&to_len(len)?.to_le_bytes());
+
+        Ok(())
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn to_len(length: usize) -> Result<i32, MarshalError> {
+    i32::try_from(length).map_err(|_| MarshalError::TooLarge { length })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::marshal::{dumps, loads};
+    use alloc::vec;
+
+    const PY27: PythonVersion = PythonVersion::new(2, 7);
+
+    #[test]
+    fn unsupported_versions() {
+        for version in [
+            PythonVersion::new(2, 4),
+            PythonVersion::new(3, 0),
+            PythonVersion::new(3, 7),
+            PythonVersion::new(3, 12),
+        ] {
+            assert_eq!(
+                dumps(&PyObject::None, version),
+                Err(MarshalError::UnsupportedVersion(version))
+            );
+        }
+        assert_eq!(
+            dumps(&PyObject::None, PythonVersion::new(2, 5)),
+            Ok(vec![TYPE_NONE])
+        );
+    }
+
+    #[test]
+    fn unsupported_code_fields() {
+        let module = loads(crate::marshal::reader::tests::PY27_CODE, PY27).unwrap();
+        let PyObject::Code(module) = module else {
+            panic!("expected a code object");
+        };
+
+        type Change = fn(&mut CodeObject);
+        let cases: [(&str, Change); 6] = [
+            ("co_posonlyargcount", |code| code.posonlyargcount = 1),
+            ("co_kwonlyargcount", |code| code.kwonlyargcount = 1),
+            ("co_localsplusnames", |code| {
+                code.localsplusnames = vec![PyObject::None]
+            }),
+            ("co_localspluskinds", |code| code.localspluskinds = vec![0]),
+            ("co_qualname", |code| code.qualname = Some(PyObject::None)),
+            ("co_exceptiontable", |code| code.exceptiontable = vec![0]),
+        ];
+        for (field, change) in cases {
+            // The field is also rejected in nested code objects
+            let mut code = module.clone();
+            let PyObject::Code(function) = &mut code.consts[1] else {
+                panic!("expected a code object");
+            };
+            change(function);
+            assert_eq!(
+                dumps(&PyObject::Code(code), PY27),
+                Err(MarshalError::UnsupportedField {
+                    field,
+                    version: PY27
+                })
+            );
+        }
+
+        let module = PyObject::Code(module);
+        assert_eq!(
+            dumps(&module, PY27).as_deref(),
+            Ok(crate::marshal::reader::tests::PY27_CODE)
+        );
+    }
+
+    #[test]
+    fn text_floats_are_written_in_binary() {
+        // marshal.dumps(1.5, 1) and marshal.dumps(1.5 - 2j, 1)
+        let float = loads(&[102, 3, 49, 46, 53], PY27).unwrap();
+        assert_eq!(
+            dumps(&float, PY27),
+            Ok(vec![103, 0, 0, 0, 0, 0, 0, 248, 63])
+        );
+        let complex = loads(&[120, 3, 49, 46, 53, 2, 45, 50], PY27).unwrap();
+        assert_eq!(
+            dumps(&complex, PY27),
+            Ok(vec![
+                121, 0, 0, 0, 0, 0, 0, 248, 63, 0, 0, 0, 0, 0, 0, 0, 192
+            ])
+        );
+    }
+}
@@ -1,11 +1,29 @@
-use std::fmt::{self, Debug};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt::{self, Debug};
+#[cfg(feature = "std")]
+use std::io::{self, Write};
 
 pub use num_traits::FromPrimitive;
 pub use num_traits::ToPrimitive;
 
-use self::py27::Mnemonic;
-
+pub mod py25;
+pub mod py26;
 pub mod py27;
+pub mod py30;
+pub mod py31;
+pub mod py32;
+pub mod py33;
+pub mod py34;
+pub mod py35;
+pub mod py36;
+pub mod py37;
+pub mod py38;
+pub mod py39;
+pub mod py310;
+pub mod py311;
+pub mod py312;
+pub mod py313;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Instruction<O: Opcode> {
@@ -41,172 +59,246 @@ impl<O: Opcode + Debug> fmt::Display for Instruction<O> {
     }
 }
 
+#[allow(clippy::len_without_is_empty)]
 impl<O: Opcode> Instruction<O> {
     /// The length of this instruction in bytes
     pub fn len(&self) -> usize {
-        std::mem::size_of::<u8>()
-            + if let Some(arg) = self.arg.as_ref() {
-                std::mem::size_of_val(arg)
-            } else {
-                0
+        O::encoding().instruction_len(self.arg.is_some())
+    }
+
+    /// The length in bytes of the inline cache entries which follow this instruction. These
+    /// are not included in [`Instruction::len`].
+    pub fn cache_len(&self) -> usize {
+        cache_len(&self.opcode)
+    }
+
+    /// The absolute byte offset this instruction jumps to, given the `offset` it was decoded
+    /// at. Returns `None` if this instruction is not a jump.
+    ///
+    /// This does not account for any `EXTENDED_ARG` prefix. Use
+    /// [`ExtendedInstruction::jump_target`] when the argument may have been extended.
+    pub fn jump_target(&self, offset: usize) -> Option<usize> {
+        jump_target(&self.opcode, self.arg.map(u32::from), offset, self.len())
+    }
+
+    /// Appends the encoded bytes of this instruction to `dest`. With [`Encoding::Bytecode`] the
+    /// argument, if any, is encoded as a little-endian `u16`. With [`Encoding::Wordcode`] it is
+    /// encoded as a single byte, which is 0 for instructions without an argument.
+    ///
+    /// Decoding an instruction and encoding it again produces the exact bytes that were
+    /// decoded, except for the ignored argument byte of a wordcode instruction without an
+    /// argument.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the opcode cannot be represented as a byte, or if the argument does not fit in
+    /// a wordcode instruction.
+    pub fn encode_into(&self, dest: &mut Vec<u8>) {
+        dest.push(self.opcode.to_u8().expect("opcode cannot be represented as a byte"));
+
+        match O::encoding() {
+            Encoding::Bytecode => {
+                if let Some(arg) = self.arg {
+                    dest.extend_from_slice(&arg.to_le_bytes());
+                }
+            }
+            Encoding::Wordcode => {
+                let arg = self.arg.unwrap_or(0);
+                dest.push(u8::try_from(arg).expect("argument does not fit in a wordcode instruction"));
             }
+        }
+    }
+
+    /// Encodes this instruction to a new buffer. See [`Instruction::encode_into`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the opcode cannot be represented as a byte.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(self.len());
+        self.encode_into(&mut encoded);
+
+        encoded
+    }
+
+    /// Writes the encoded bytes of this instruction to `dest`. See [`Instruction::encode_into`].
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, dest: &mut W) -> io::Result<()> {
+        check_encodable(&self.opcode)?;
+        dest.write_all(&self.encode())
+    }
+}
+
+/// A logical instruction whose `EXTENDED_ARG` prefixes have been folded into a single
+/// 32-bit argument. This is what the interpreter actually executes and is the form that
+/// jump resolution and stack analysis should operate on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtendedInstruction<O: Opcode> {
+    pub opcode: O,
+    pub arg: Option<u32>,
+    /// Number of `EXTENDED_ARG` prefixes that were consumed to build this instruction
+    pub extended_args: usize,
+}
+
+impl<O: Opcode + Debug> fmt::Display for ExtendedInstruction<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.opcode)?;
+
+        if let Some(arg) = self.arg {
+            write!(f, " {}", arg)?;
+        }
+
+        Ok(())
     }
 }
 
-impl<O: Opcode<Mnemonic = py27::Mnemonic>> Instruction<O> {
+#[allow(clippy::len_without_is_empty)]
+impl<O: Opcode> ExtendedInstruction<O> {
+    /// The length of this instruction in bytes, including the `EXTENDED_ARG` prefixes that
+    /// [`ExtendedInstruction::encode_into`] emits
+    pub fn len(&self) -> usize {
+        let encoding = O::encoding();
+
+        (self.prefixes() * encoding.instruction_len(true))
+            + encoding.instruction_len(self.arg.is_some())
+    }
+
+    /// The number of `EXTENDED_ARG` prefixes this instruction is encoded with
+    fn prefixes(&self) -> usize {
+        let required = O::encoding().prefixes_for(self.arg.unwrap_or(0));
+
+        core::cmp::max(required, self.extended_args)
+    }
+
+    /// The length in bytes of the inline cache entries which follow this instruction. These
+    /// are not included in [`ExtendedInstruction::len`].
+    pub fn cache_len(&self) -> usize {
+        cache_len(&self.opcode)
+    }
+
+    /// The absolute byte offset this instruction jumps to, given the `offset` of its first
+    /// `EXTENDED_ARG` prefix (or of the instruction itself if it has none). Returns `None` if
+    /// this instruction is not a jump.
+    pub fn jump_target(&self, offset: usize) -> Option<usize> {
+        jump_target(&self.opcode, self.arg, offset, self.len())
+    }
+
+    /// Appends the encoded bytes of this instruction to `dest`, emitting `EXTENDED_ARG` prefixes
+    /// for the upper bits of the argument when they are required.
+    ///
+    /// At least [`ExtendedInstruction::extended_args`] prefixes are always emitted so that
+    /// decoding an instruction and encoding it again produces the exact bytes that were
+    /// decoded, even if a prefix was redundant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the opcode cannot be represented as a byte.
+    pub fn encode_into(&self, dest: &mut Vec<u8>) {
+        let encoding = O::encoding();
+        let arg = self.arg.unwrap_or(0);
+
+        let extended_arg = O::extended_arg();
+        for i in (1..=self.prefixes()).rev() {
+            Instruction {
+                opcode: extended_arg,
+                arg: Some(encoding.arg_chunk(arg, i)),
+            }
+            .encode_into(dest);
+        }
+
+        Instruction {
+            opcode: self.opcode,
+            arg: self.arg.map(|arg| encoding.arg_chunk(arg, 0)),
+        }
+        .encode_into(dest)
+    }
+
+    /// Encodes this instruction to a new buffer. See [`ExtendedInstruction::encode_into`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the opcode cannot be represented as a byte.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(self.len());
+        self.encode_into(&mut encoded);
+
+        encoded
+    }
+
+    /// Writes the encoded bytes of this instruction to `dest`. See
+    /// [`ExtendedInstruction::encode_into`].
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, dest: &mut W) -> io::Result<()> {
+        check_encodable(&self.opcode)?;
+        check_encodable(&O::extended_arg())?;
+        dest.write_all(&self.encode())
+    }
+}
+
+impl<O: Opcode> From<Instruction<O>> for ExtendedInstruction<O> {
+    fn from(instr: Instruction<O>) -> Self {
+        ExtendedInstruction {
+            opcode: instr.opcode,
+            arg: instr.arg.map(u32::from),
+            extended_args: 0,
+        }
+    }
+}
+
+impl<O: Opcode> Instruction<O>
+where
+    O::Mnemonic: StackEffect,
+{
     /// How the stack adjusts after this instruction executes. A positive number indicates that N
     /// elements were pushed to the stack while a negative number indicates the number of elements
     /// removed from the stack
     pub fn stack_adjustment_after(&self) -> isize {
-        match self.opcode.mnemonic() {
-            // Meta instructions
-            Mnemonic::STOP_CODE
-            | Mnemonic::NOP
-            | Mnemonic::ROT_TWO
-            | Mnemonic::ROT_THREE
-            | Mnemonic::ROT_FOUR => 0,
-
-            Mnemonic::POP_TOP => -1,
-            Mnemonic::DUP_TOP => 1,
-            Mnemonic::DUP_TOP_TWO => 2,
-            // Unary ops
-            Mnemonic::UNARY_POSITIVE
-            | Mnemonic::UNARY_NEGATIVE
-            | Mnemonic::UNARY_NOT
-            | Mnemonic::UNARY_CONVERT
-            | Mnemonic::UNARY_INVERT
-            | Mnemonic::GET_ITER => 0,
-            // Binary ops
-            Mnemonic::BINARY_POWER
-            | Mnemonic::BINARY_MULTIPLY
-            | Mnemonic::BINARY_DIVIDE
-            | Mnemonic::BINARY_FLOOR_DIVIDE
-            | Mnemonic::BINARY_TRUE_DIVIDE
-            | Mnemonic::BINARY_MODULO
-            | Mnemonic::BINARY_ADD
-            | Mnemonic::BINARY_SUBTRACT
-            | Mnemonic::BINARY_SUBSC
-            | Mnemonic::BINARY_LSHIFT
-            | Mnemonic::BINARY_RSHIFT
-            | Mnemonic::BINARY_AND
-            | Mnemonic::BINARY_XOR
-            | Mnemonic::BINARY_OR => -1,
-            // In-place operations
-            Mnemonic::INPLACE_POWER
-            | Mnemonic::INPLACE_MULTIPLY
-            | Mnemonic::INPLACE_DIVIDE
-            | Mnemonic::INPLACE_FLOOR_DIVIDE
-            | Mnemonic::INPLACE_TRUE_DIVIDE
-            | Mnemonic::INPLACE_MODULO
-            | Mnemonic::INPLACE_ADD
-            | Mnemonic::INPLACE_SUBTRACT
-            | Mnemonic::INPLACE_LSHIFT
-            | Mnemonic::INPLACE_RSHIFT
-            | Mnemonic::INPLACE_AND
-            | Mnemonic::INPLACE_XOR
-            | Mnemonic::INPLACE_OR => -1,
-            // Slice operations
-            Mnemonic::SLICE_0 => 0,
-            Mnemonic::SLICE_1 => -1,
-            Mnemonic::SLICE_2 => -2,
-            Mnemonic::SLICE_3 => -3,
-            Mnemonic::STORE_SLICE_0 => -1,
-            Mnemonic::STORE_SLICE_1 => -2,
-            Mnemonic::STORE_SLICE_2 => -3,
-            Mnemonic::STORE_SLICE_3 => -4,
-            Mnemonic::DELETE_SLICE_0 => -1,
-            Mnemonic::DELETE_SLICE_1 => -2,
-            Mnemonic::DELETE_SLICE_2 => -3,
-            Mnemonic::DELETE_SLICE_3 => -4,
-            Mnemonic::STORE_SUBSCR => -3,
-            Mnemonic::DELETE_SUBSCR => -2,
-            // Misc
-            Mnemonic::PRINT_EXPR => -1,
-            Mnemonic::PRINT_ITEM => -1,
-            Mnemonic::PRINT_ITEM_TO => -2,
-            Mnemonic::PRINT_NEWLINE => 0,
-            Mnemonic::PRINT_NEWLINE_TO => -1,
-            Mnemonic::BREAK_LOOP => 0,
-            Mnemonic::CONTINUE_LOOP => 0,
-            Mnemonic::LIST_APPEND => -1,
-            Mnemonic::LOAD_LOCALS => 1,
-            Mnemonic::RETURN_VALUE => 0,
-            Mnemonic::YIELD_VALUE => 0,
-            Mnemonic::IMPORT_STAR => -1,
-            Mnemonic::EXEC_STMT => -3,
-            Mnemonic::POP_BLOCK => 0,
-            Mnemonic::END_FINALLY => 0,
-            Mnemonic::BUILD_CLASS => -3,
-            // TODO: maybe not right?
-            Mnemonic::SETUP_WITH => 1,
-            Mnemonic::WITH_CLEANUP => {
-                panic!("with_cleanup may require runtime info");
-            }
-            Mnemonic::STORE_NAME => -1,
-            Mnemonic::STORE_FAST => -1,
-            Mnemonic::STORE_DEREF => -1,
-            Mnemonic::SET_ADD => -1,
-            Mnemonic::MAP_ADD => -1,
-            Mnemonic::DELETE_NAME => 0,
-            Mnemonic::UNPACK_SEQUENCE => (self.arg.unwrap() as isize) - 1,
-            Mnemonic::DUP_TOPX => self.arg.unwrap() as isize,
-            Mnemonic::STORE_ATTR => -2,
-            Mnemonic::DELETE_ATTR => -1,
-            Mnemonic::STORE_GLOBAL => -1,
-            Mnemonic::DELETE_GLOBAL => 0,
-            Mnemonic::LOAD_CONST => 1,
-            Mnemonic::LOAD_NAME => 1,
-            Mnemonic::BUILD_TUPLE | Mnemonic::BUILD_LIST | Mnemonic::BUILD_SET => {
-                (self.arg.unwrap() as isize) - 1
-            }
-            Mnemonic::BUILD_MAP => 1,
-            Mnemonic::LOAD_ATTR => 0,
-            Mnemonic::COMPARE_OP => -1,
-            Mnemonic::IMPORT_NAME => -1,
-            Mnemonic::IMPORT_FROM => 1,
-            Mnemonic::JUMP_FORWARD | Mnemonic::JUMP_ABSOLUTE => 0,
-            Mnemonic::POP_JUMP_IF_FALSE | Mnemonic::POP_JUMP_IF_TRUE => -1,
-            Mnemonic::JUMP_IF_FALSE_OR_POP | Mnemonic::JUMP_IF_TRUE_OR_POP => {
-                panic!("JUMP_IF_*_OR_POP requires runtime info");
-            }
-            Mnemonic::FOR_ITER => 1,
-            Mnemonic::LOAD_GLOBAL => 1,
-            Mnemonic::SETUP_LOOP => 0,
-            Mnemonic::SETUP_EXCEPT | Mnemonic::SETUP_FINALLY => {
-                panic!("SETUP_EXCEPT requires runtime info");
-            }
-            Mnemonic::STORE_MAP => -2,
-            Mnemonic::LOAD_FAST => 1,
-            Mnemonic::DELETE_FAST => 0,
-            Mnemonic::LOAD_CLOSURE => 0,
-            Mnemonic::LOAD_DEREF => 1,
-            Mnemonic::RAISE_VARARGS => 0,
-            Mnemonic::CALL_FUNCTION => {
-                let pos_args = self.arg.unwrap() & 0xFF;
-                let kwargs = (self.arg.unwrap() >> 8) & 0xFF;
-                // 1 arg is removed for the callable, 1 is added for the return value
-                -(pos_args as isize + kwargs as isize + 1) + 1
-            }
-            Mnemonic::MAKE_FUNCTION => -1,
-            Mnemonic::MAKE_CLOSURE => 2 + self.arg.unwrap() as isize,
-            Mnemonic::BUILD_SLICE => 1 - (self.arg.unwrap() as isize),
-            Mnemonic::EXTENDED_ARG => panic!("not supported yet"),
-            Mnemonic::CALL_FUNCTION_VAR | Mnemonic::CALL_FUNCTION_KW => {
-                let pos_args = self.arg.unwrap() & 0xFF;
-                let kwargs = (self.arg.unwrap() >> 8) & 0xFF;
-                // 1 arg is removed for the callable and additional positional args, 1 is added for the return value
-                -(pos_args as isize + kwargs as isize + 2) + 1
-            }
-            Mnemonic::CALL_FUNCTION_VAR_KW => {
-                let pos_args = self.arg.unwrap() & 0xFF;
-                let kwargs = (self.arg.unwrap() >> 8) & 0xFF;
-                // 1 arg is removed for the callable and additional positional+kw args, 1 is added for the return value
-                -(pos_args as isize + kwargs as isize + 3) + 1
-            }
-        }
+        self.opcode.mnemonic().stack_adjustment(self.arg.map(u32::from))
+    }
+}
+
+impl<O: Opcode> ExtendedInstruction<O>
+where
+    O::Mnemonic: StackEffect,
+{
+    /// How the stack adjusts after this instruction executes. See
+    /// [`Instruction::stack_adjustment_after`] for details.
+    pub fn stack_adjustment_after(&self) -> isize {
+        self.opcode.mnemonic().stack_adjustment(self.arg)
+    }
+}
+
+#[cfg(feature = "std")]
+fn check_encodable<O: Opcode>(opcode: &O) -> io::Result<()> {
+    match opcode.to_u8() {
+        Some(_) => Ok(()),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("opcode {:?} cannot be represented as a byte", opcode),
+        )),
     }
 }
 
+fn jump_target<O: Opcode>(opcode: &O, arg: Option<u32>, offset: usize, len: usize) -> Option<usize> {
+    let arg = arg? as usize * O::jump_unit();
+    // Relative jumps are taken from the start of the next instruction, after any inline caches
+    let next = offset + len + cache_len(opcode);
+
+    if opcode.is_backward_jump() {
+        next.checked_sub(arg)
+    } else if opcode.is_relative_jump() {
+        Some(next + arg)
+    } else if opcode.is_absolute_jump() {
+        Some(arg)
+    } else {
+        None
+    }
+}
+
+fn cache_len<O: Opcode>(opcode: &O) -> usize {
+    opcode.cache_entries() * O::encoding().code_unit()
+}
+
 /// Trait that provides convenience routines for opcode properties such as whether
 /// or not it has an argument, is a jump, etc.
 pub trait Opcode: From<Self::Mnemonic> + Send + Sync + FromPrimitive + ToPrimitive + Copy + Clone + Debug {
@@ -218,6 +310,12 @@ pub trait Opcode: From<Self::Mnemonic> + Send + Sync + FromPrimitive + ToPrimiti
     /// Whether or not this opcode has an extended argument
     fn has_extended_arg(&self) -> bool;
 
+    /// Whether or not this opcode is the `EXTENDED_ARG` prefix
+    fn is_extended_arg(&self) -> bool;
+
+    /// The `EXTENDED_ARG` prefix opcode of this opcode table
+    fn extended_arg() -> Self;
+
     /// Whether or not this opcode has a constant parameter
     fn has_const(&self) -> bool;
 
@@ -250,4 +348,433 @@ pub trait Opcode: From<Self::Mnemonic> + Send + Sync + FromPrimitive + ToPrimiti
     fn has_free(&self) -> bool;
 
     fn mnemonic(&self) -> Self::Mnemonic;
+
+    /// How instructions of this opcode table are laid out in bytecode
+    fn encoding() -> Encoding {
+        Encoding::Bytecode
+    }
+
+    /// The number of bytes each unit of a jump argument stands for. Jump arguments count
+    /// 2-byte code units instead of bytes since Python 3.10.
+    fn jump_unit() -> usize {
+        1
+    }
+
+    /// The number of inline cache code units which follow instructions with this opcode. Used
+    /// to specialize instructions at runtime since Python 3.11.
+    fn cache_entries(&self) -> usize {
+        0
+    }
+
+    /// The `CACHE` opcode which fills inline cache entries, if this opcode table has one
+    fn cache() -> Option<Self> {
+        None
+    }
+
+    /// Whether or not this opcode is a relative jump whose target precedes it. Relative jumps
+    /// only go backwards since Python 3.11.
+    fn is_backward_jump(&self) -> bool {
+        false
+    }
+
+    /// The index into the table of consts, names or variables which `arg` refers to. Some
+    /// opcodes keep flags in the low bits of their argument since Python 3.11.
+    fn argument_index(&self, arg: u32) -> u32 {
+        arg
+    }
+
+    /// The indexes of the two locals which `arg` refers to, for the superinstructions added in
+    /// Python 3.13 that each access a pair of locals. `None` for every other opcode.
+    fn local_pair(&self, _arg: u32) -> Option<(u32, u32)> {
+        None
+    }
+
+    /// The base opcode of a specialized or instrumented opcode, which the interpreter rewrites
+    /// instructions to at runtime since Python 3.11. Use this to analyze bytecode which was read
+    /// from the memory of a running interpreter. Other opcodes are returned unchanged.
+    fn deoptimize(&self) -> Self {
+        *self
+    }
+
+    /// Every opcode defined by this opcode table, in ascending order of byte value
+    fn all() -> impl Iterator<Item = Self> {
+        (0..=u8::MAX).filter_map(Self::from_u8)
+    }
+}
+
+/// Stack effects of the mnemonics of an opcode table
+pub trait StackEffect {
+    /// How the stack adjusts after an instruction with this mnemonic and argument executes. A
+    /// positive number indicates that N elements were pushed to the stack while a negative
+    /// number indicates the number of elements removed from the stack
+    fn stack_adjustment(&self, arg: Option<u32>) -> isize;
+}
+
+/// How the instructions of an opcode table are laid out in bytecode
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Encoding {
+    /// A 1-byte opcode followed by a 2-byte little-endian argument if the opcode has one. Used
+    /// before Python 3.6.
+    Bytecode,
+    /// 2-byte code units of a 1-byte opcode and a 1-byte argument, which is ignored if the opcode
+    /// has no argument. Used since Python 3.6.
+    Wordcode,
+}
+
+impl Encoding {
+    /// The size of a code unit in bytes. Instructions always start on a code unit boundary.
+    pub fn code_unit(self) -> usize {
+        match self {
+            Encoding::Bytecode => 1,
+            Encoding::Wordcode => 2,
+        }
+    }
+
+    /// The length in bytes of a single instruction, not counting `EXTENDED_ARG` prefixes
+    pub fn instruction_len(self, has_arg: bool) -> usize {
+        match self {
+            Encoding::Bytecode if has_arg => 3,
+            Encoding::Bytecode => 1,
+            Encoding::Wordcode => 2,
+        }
+    }
+
+    /// The number of argument bits a single instruction holds. Each `EXTENDED_ARG` prefix
+    /// contributes this many high bits to the argument of the instruction it precedes.
+    pub fn arg_bits(self) -> u32 {
+        match self {
+            Encoding::Bytecode => 16,
+            Encoding::Wordcode => 8,
+        }
+    }
+
+    /// The number of `EXTENDED_ARG` prefixes required to encode `arg`
+    pub fn prefixes_for(self, arg: u32) -> usize {
+        let bits = 32 - arg.leading_zeros();
+        let arg_bits = self.arg_bits();
+
+        (bits.saturating_sub(1) / arg_bits) as usize
+    }
+
+    /// The `index`th chunk of `arg`, counting from the least significant bits, which is
+    /// carried by the instruction `index` places before the end of an `EXTENDED_ARG` chain
+    pub(crate) fn arg_chunk(self, arg: u32, index: usize) -> u16 {
+        let arg_bits = self.arg_bits();
+        let mask = (1u32 << arg_bits) - 1;
+
+        (arg.checked_shr(arg_bits * index as u32).unwrap_or(0) & mask) as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode_extended_slice;
+    use crate::disassembler::ExtendedDisassembler;
+
+    /// Checks that every mnemonic in `all` round trips through its name and its opcode, and that
+    /// `Opcode::all` yields one opcode for each of them in ascending order
+    pub(crate) fn check_mnemonics<O>(all: &[O::Mnemonic], name: fn(&O::Mnemonic) -> &'static str)
+    where
+        O: Opcode,
+        O::Mnemonic: Copy + Debug + PartialEq + fmt::Display + core::str::FromStr,
+    {
+        for mnemonic in all {
+            let name = name(mnemonic);
+            assert_eq!(alloc::format!("{}", mnemonic), name);
+            assert_eq!(name.parse::<O::Mnemonic>().ok(), Some(*mnemonic), "{}", name);
+            assert_eq!(O::from(*mnemonic).mnemonic(), *mnemonic);
+        }
+        assert!("NOT_AN_OPCODE".parse::<O::Mnemonic>().is_err());
+
+        let opcodes: Vec<O> = O::all().collect();
+        let bytes: Vec<u8> = opcodes.iter().map(|op| op.to_u8().unwrap()).collect();
+        assert!(bytes.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(opcodes.len(), all.len());
+        for mnemonic in all {
+            assert_eq!(
+                opcodes.iter().filter(|op| op.mnemonic() == *mnemonic).count(),
+                1,
+                "{:?}",
+                mnemonic
+            );
+        }
+    }
+
+    /// The space separated fields of each line of a fixture, skipping comments
+    fn fixture_lines(fixture: &str) -> impl Iterator<Item = Vec<&str>> {
+        fixture
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| line.split(' ').collect())
+    }
+
+    fn parse_mnemonic<M: core::str::FromStr>(name: &str) -> M {
+        name.parse()
+            .unwrap_or_else(|_| panic!("unknown mnemonic {}", name))
+    }
+
+    /// Checks an opcode table against a fixture of `testdata/opcode`, which lists every opcode of
+    /// a Python version as `opcode NAME BYTE`, the sizes of its code and jump units, the base
+    /// opcode of each specialized or instrumented opcode as `base NAME BASE` and the opcodes
+    /// followed by inline caches as `cache NAME ENTRIES`, the superinstructions which refer to two
+    /// locals as `pair NAME ARG FIRST SECOND`, along with the lines read by `check_stack_effects`
+    fn check_table<O>(fixture: &str)
+    where
+        O: Opcode,
+        O::Mnemonic: Debug + core::str::FromStr + StackEffect,
+    {
+        let mut names = Vec::new();
+        let mut bases = Vec::new();
+        let mut caches = Vec::new();
+        let mut pairs = Vec::new();
+        for fields in fixture_lines(fixture) {
+            match fields[..] {
+                ["code_unit", unit] => {
+                    assert_eq!(Some(O::encoding().code_unit()), unit.parse().ok())
+                }
+                ["jump_unit", unit] => assert_eq!(Some(O::jump_unit()), unit.parse().ok()),
+                ["opcode", name, byte] => {
+                    let opcode = O::from(parse_mnemonic(name));
+                    assert_eq!(opcode.to_u8(), byte.parse().ok(), "{}", name);
+                    names.push(name);
+                }
+                ["base", name, base] => bases.push((name, base)),
+                ["cache", name, entries] => caches.push((name, entries.parse::<usize>().unwrap())),
+                ["pair", name, arg, first, second] => {
+                    let parse = |field: &str| field.parse::<u32>().unwrap();
+                    pairs.push((name, parse(arg), (parse(first), parse(second))));
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(O::all().count(), names.len());
+
+        for name in names {
+            let opcode = O::from(parse_mnemonic(name));
+            let base = bases
+                .iter()
+                .find(|(specialized, _)| *specialized == name)
+                .map_or(name, |(_, base)| *base);
+            let base = O::from(parse_mnemonic(base));
+            assert_eq!(opcode.deoptimize().to_u8(), base.to_u8(), "{}", name);
+
+            let entries = caches
+                .iter()
+                .find(|(cached, _)| *cached == name)
+                .map_or(0, |(_, entries)| *entries);
+            assert_eq!(opcode.cache_entries(), entries, "{}", name);
+
+            match pairs.iter().find(|(paired, _, _)| *paired == name) {
+                Some(&(_, arg, pair)) => {
+                    assert_eq!(opcode.local_pair(arg), Some(pair), "{}", name)
+                }
+                None => assert_eq!(opcode.local_pair(0x12), None, "{}", name),
+            }
+        }
+
+        check_stack_effects::<O::Mnemonic>(fixture);
+    }
+
+    /// Checks the `effect NAME ARG EFFECT` lines of a fixture, where `ARG` is `-` for opcodes
+    /// without an argument
+    fn check_stack_effects<M>(fixture: &str)
+    where
+        M: Debug + core::str::FromStr + StackEffect,
+    {
+        for fields in fixture_lines(fixture) {
+            if let ["effect", name, arg, effect] = fields[..] {
+                let arg = arg.parse().ok();
+                assert_eq!(
+                    parse_mnemonic::<M>(name).stack_adjustment(arg),
+                    effect.parse::<isize>().unwrap(),
+                    "{} {:?}",
+                    name,
+                    arg
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn py25_table() {
+        check_mnemonics::<py25::Standard>(py25::Mnemonic::ALL, py25::Mnemonic::name);
+        check_table::<py25::Standard>(include_str!("../../testdata/opcode/py25.txt"));
+    }
+
+    #[test]
+    fn py26_table() {
+        check_mnemonics::<py26::Standard>(py26::Mnemonic::ALL, py26::Mnemonic::name);
+        check_table::<py26::Standard>(include_str!("../../testdata/opcode/py26.txt"));
+    }
+
+    #[test]
+    fn py27_stack_effects() {
+        // The numbering of the Python 2.7 table differs from CPython's for ROT_FOUR and
+        // STORE_DEREF, and it has an extra DUP_TOP_TWO opcode, so only stack effects are checked
+        check_stack_effects::<py27::Mnemonic>(include_str!("../../testdata/opcode/py27.txt"));
+    }
+
+    #[test]
+    fn py27_mnemonics() {
+        check_mnemonics::<py27::Standard>(py27::Mnemonic::ALL, py27::Mnemonic::name);
+
+        for (alias, mnemonic) in [
+            ("SLICE+0", py27::Mnemonic::SLICE_0),
+            ("SLICE+3", py27::Mnemonic::SLICE_3),
+            ("STORE_SLICE+1", py27::Mnemonic::STORE_SLICE_1),
+            ("DELETE_SLICE+2", py27::Mnemonic::DELETE_SLICE_2),
+        ] {
+            assert_eq!(alias.parse(), Ok(mnemonic));
+        }
+        assert_eq!(
+            "load_const".parse::<py27::Mnemonic>(),
+            Err(crate::error::ParseMnemonicError("load_const".into()))
+        );
+        assert_eq!(
+            py27::Standard::all().next(),
+            Some(py27::Standard::STOP_CODE)
+        );
+    }
+
+    #[test]
+    fn py30_table() {
+        check_mnemonics::<py30::Standard>(py30::Mnemonic::ALL, py30::Mnemonic::name);
+        check_table::<py30::Standard>(include_str!("../../testdata/opcode/py30.txt"));
+    }
+
+    #[test]
+    fn py31_table() {
+        check_mnemonics::<py31::Standard>(py31::Mnemonic::ALL, py31::Mnemonic::name);
+        check_table::<py31::Standard>(include_str!("../../testdata/opcode/py31.txt"));
+    }
+
+    #[test]
+    fn py32_table() {
+        check_mnemonics::<py32::Standard>(py32::Mnemonic::ALL, py32::Mnemonic::name);
+        check_table::<py32::Standard>(include_str!("../../testdata/opcode/py32.txt"));
+    }
+
+    #[test]
+    fn py33_table() {
+        check_mnemonics::<py33::Standard>(py33::Mnemonic::ALL, py33::Mnemonic::name);
+        check_table::<py33::Standard>(include_str!("../../testdata/opcode/py33.txt"));
+    }
+
+    #[test]
+    fn py34_table() {
+        check_mnemonics::<py34::Standard>(py34::Mnemonic::ALL, py34::Mnemonic::name);
+        check_table::<py34::Standard>(include_str!("../../testdata/opcode/py34.txt"));
+    }
+
+    #[test]
+    fn py35_table() {
+        check_mnemonics::<py35::Standard>(py35::Mnemonic::ALL, py35::Mnemonic::name);
+        check_table::<py35::Standard>(include_str!("../../testdata/opcode/py35.txt"));
+    }
+
+    #[test]
+    fn py36_table() {
+        check_mnemonics::<py36::Standard>(py36::Mnemonic::ALL, py36::Mnemonic::name);
+        check_table::<py36::Standard>(include_str!("../../testdata/opcode/py36.txt"));
+    }
+
+    #[test]
+    fn py37_table() {
+        check_mnemonics::<py37::Standard>(py37::Mnemonic::ALL, py37::Mnemonic::name);
+        check_table::<py37::Standard>(include_str!("../../testdata/opcode/py37.txt"));
+    }
+
+    #[test]
+    fn py38_table() {
+        check_mnemonics::<py38::Standard>(py38::Mnemonic::ALL, py38::Mnemonic::name);
+        check_table::<py38::Standard>(include_str!("../../testdata/opcode/py38.txt"));
+    }
+
+    #[test]
+    fn py39_table() {
+        check_mnemonics::<py39::Standard>(py39::Mnemonic::ALL, py39::Mnemonic::name);
+        check_table::<py39::Standard>(include_str!("../../testdata/opcode/py39.txt"));
+    }
+
+    #[test]
+    fn py310_table() {
+        check_mnemonics::<py310::Standard>(py310::Mnemonic::ALL, py310::Mnemonic::name);
+        check_table::<py310::Standard>(include_str!("../../testdata/opcode/py310.txt"));
+    }
+
+    #[test]
+    fn py311_table() {
+        check_mnemonics::<py311::Standard>(py311::Mnemonic::ALL, py311::Mnemonic::name);
+        check_table::<py311::Standard>(include_str!("../../testdata/opcode/py311.txt"));
+    }
+
+    #[test]
+    fn py312_table() {
+        check_mnemonics::<py312::Standard>(py312::Mnemonic::ALL, py312::Mnemonic::name);
+        check_table::<py312::Standard>(include_str!("../../testdata/opcode/py312.txt"));
+    }
+
+    #[test]
+    fn py313_table() {
+        check_mnemonics::<py313::Standard>(py313::Mnemonic::ALL, py313::Mnemonic::name);
+        check_table::<py313::Standard>(include_str!("../../testdata/opcode/py313.txt"));
+    }
+
+    const ARGS: [u32; 8] = [0, 1, 0xFF, 0x100, 0xFFFF, 0x10000, 70000, u32::MAX];
+
+    fn check_len<O: Opcode>(opcode: O) {
+        for arg in ARGS {
+            for extended_args in 0..3 {
+                let instr = ExtendedInstruction {
+                    opcode,
+                    arg: Some(arg),
+                    extended_args,
+                };
+                assert_eq!(instr.encode().len(), instr.len(), "{:?} {}", opcode, arg);
+            }
+        }
+    }
+
+    fn check_round_trip<O: Opcode + PartialEq>(opcode: O) {
+        for arg in ARGS {
+            let instr = ExtendedInstruction {
+                opcode,
+                arg: Some(arg),
+                extended_args: 0,
+            };
+            let encoded = instr.encode();
+            let decoded = decode_extended_slice::<O>(&encoded).unwrap();
+            assert_eq!(decoded.arg, Some(arg));
+            assert_eq!(decoded.len(), encoded.len());
+            assert_eq!(decoded.encode(), encoded);
+        }
+    }
+
+    #[test]
+    fn len_matches_encoding() {
+        check_len(py27::Standard::LOAD_CONST);
+        check_len(py36::Standard::LOAD_CONST);
+        check_len(py311::Standard::LOAD_GLOBAL);
+    }
+
+    #[test]
+    fn encode_round_trips() {
+        check_round_trip(py27::Standard::LOAD_CONST);
+        check_round_trip(py36::Standard::LOAD_CONST);
+        check_round_trip(py311::Standard::LOAD_GLOBAL);
+    }
+
+    #[test]
+    fn redundant_prefixes_round_trip() {
+        // EXTENDED_ARG 0; LOAD_CONST 1; EXTENDED_ARG 1; JUMP_FORWARD 2; RETURN_VALUE 0
+        let code = [144, 0, 100, 1, 144, 1, 110, 2, 83, 0];
+        let mut encoded = Vec::new();
+        for result in ExtendedDisassembler::<py36::Standard>::new(&code) {
+            let (offset, instr) = result.unwrap();
+            assert_eq!(offset, encoded.len());
+            instr.encode_into(&mut encoded);
+        }
+        assert_eq!(encoded, code);
+    }
 }
@@ -1,4 +1,9 @@
 pub use enum_primitive_derive::Primitive;
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::error::ParseMnemonicError;
 
 /// Standard set of instruction mnemoics
 #[allow(non_camel_case_types)]
@@ -140,6 +145,409 @@ pub enum Mnemonic {
     MAP_ADD,
 }
 
+impl Mnemonic {
+    /// Every mnemonic, in declaration order
+    pub const ALL: &'static [Mnemonic] = &[
+        Self::STOP_CODE,
+        Self::POP_TOP,
+        Self::ROT_TWO,
+        Self::ROT_THREE,
+        Self::DUP_TOP,
+        Self::DUP_TOP_TWO,
+        Self::ROT_FOUR,
+        Self::NOP,
+        Self::UNARY_POSITIVE,
+        Self::UNARY_NEGATIVE,
+        Self::UNARY_NOT,
+        Self::UNARY_CONVERT,
+        Self::UNARY_INVERT,
+        Self::BINARY_POWER,
+        Self::BINARY_MULTIPLY,
+        Self::BINARY_DIVIDE,
+        Self::BINARY_MODULO,
+        Self::BINARY_ADD,
+        Self::BINARY_SUBTRACT,
+        Self::BINARY_SUBSC,
+        Self::BINARY_FLOOR_DIVIDE,
+        Self::BINARY_TRUE_DIVIDE,
+        Self::INPLACE_FLOOR_DIVIDE,
+        Self::INPLACE_TRUE_DIVIDE,
+        Self::SLICE_0,
+        Self::SLICE_1,
+        Self::SLICE_2,
+        Self::SLICE_3,
+        Self::STORE_SLICE_0,
+        Self::STORE_SLICE_1,
+        Self::STORE_SLICE_2,
+        Self::STORE_SLICE_3,
+        Self::DELETE_SLICE_0,
+        Self::DELETE_SLICE_1,
+        Self::DELETE_SLICE_2,
+        Self::DELETE_SLICE_3,
+        Self::STORE_MAP,
+        Self::INPLACE_ADD,
+        Self::INPLACE_SUBTRACT,
+        Self::INPLACE_MULTIPLY,
+        Self::INPLACE_DIVIDE,
+        Self::INPLACE_MODULO,
+        Self::STORE_SUBSCR,
+        Self::DELETE_SUBSCR,
+        Self::BINARY_LSHIFT,
+        Self::BINARY_RSHIFT,
+        Self::BINARY_AND,
+        Self::BINARY_XOR,
+        Self::BINARY_OR,
+        Self::INPLACE_POWER,
+        Self::GET_ITER,
+        Self::PRINT_EXPR,
+        Self::PRINT_ITEM,
+        Self::PRINT_NEWLINE,
+        Self::PRINT_ITEM_TO,
+        Self::PRINT_NEWLINE_TO,
+        Self::INPLACE_LSHIFT,
+        Self::INPLACE_RSHIFT,
+        Self::INPLACE_AND,
+        Self::INPLACE_XOR,
+        Self::INPLACE_OR,
+        Self::BREAK_LOOP,
+        Self::WITH_CLEANUP,
+        Self::LOAD_LOCALS,
+        Self::RETURN_VALUE,
+        Self::IMPORT_STAR,
+        Self::EXEC_STMT,
+        Self::YIELD_VALUE,
+        Self::POP_BLOCK,
+        Self::END_FINALLY,
+        Self::BUILD_CLASS,
+        Self::STORE_NAME,
+        Self::DELETE_NAME,
+        Self::UNPACK_SEQUENCE,
+        Self::FOR_ITER,
+        Self::LIST_APPEND,
+        Self::STORE_ATTR,
+        Self::DELETE_ATTR,
+        Self::STORE_GLOBAL,
+        Self::DELETE_GLOBAL,
+        Self::DUP_TOPX,
+        Self::LOAD_CONST,
+        Self::LOAD_NAME,
+        Self::BUILD_TUPLE,
+        Self::BUILD_LIST,
+        Self::BUILD_SET,
+        Self::BUILD_MAP,
+        Self::LOAD_ATTR,
+        Self::COMPARE_OP,
+        Self::IMPORT_NAME,
+        Self::IMPORT_FROM,
+        Self::JUMP_FORWARD,
+        Self::JUMP_IF_FALSE_OR_POP,
+        Self::JUMP_IF_TRUE_OR_POP,
+        Self::JUMP_ABSOLUTE,
+        Self::POP_JUMP_IF_FALSE,
+        Self::POP_JUMP_IF_TRUE,
+        Self::LOAD_GLOBAL,
+        Self::CONTINUE_LOOP,
+        Self::SETUP_LOOP,
+        Self::SETUP_EXCEPT,
+        Self::SETUP_FINALLY,
+        Self::LOAD_FAST,
+        Self::STORE_FAST,
+        Self::DELETE_FAST,
+        Self::RAISE_VARARGS,
+        Self::CALL_FUNCTION,
+        Self::MAKE_FUNCTION,
+        Self::BUILD_SLICE,
+        Self::MAKE_CLOSURE,
+        Self::LOAD_CLOSURE,
+        Self::LOAD_DEREF,
+        Self::STORE_DEREF,
+        Self::CALL_FUNCTION_VAR,
+        Self::CALL_FUNCTION_KW,
+        Self::CALL_FUNCTION_VAR_KW,
+        Self::SETUP_WITH,
+        Self::EXTENDED_ARG,
+        Self::SET_ADD,
+        Self::MAP_ADD,
+    ];
+
+    /// The canonical name of this mnemonic. This is the same string which
+    /// [`Mnemonic::from_str`] accepts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::STOP_CODE => "STOP_CODE",
+            Self::POP_TOP => "POP_TOP",
+            Self::ROT_TWO => "ROT_TWO",
+            Self::ROT_THREE => "ROT_THREE",
+            Self::DUP_TOP => "DUP_TOP",
+            Self::DUP_TOP_TWO => "DUP_TOP_TWO",
+            Self::ROT_FOUR => "ROT_FOUR",
+            Self::NOP => "NOP",
+            Self::UNARY_POSITIVE => "UNARY_POSITIVE",
+            Self::UNARY_NEGATIVE => "UNARY_NEGATIVE",
+            Self::UNARY_NOT => "UNARY_NOT",
+            Self::UNARY_CONVERT => "UNARY_CONVERT",
+            Self::UNARY_INVERT => "UNARY_INVERT",
+            Self::BINARY_POWER => "BINARY_POWER",
+            Self::BINARY_MULTIPLY => "BINARY_MULTIPLY",
+            Self::BINARY_DIVIDE => "BINARY_DIVIDE",
+            Self::BINARY_MODULO => "BINARY_MODULO",
+            Self::BINARY_ADD => "BINARY_ADD",
+            Self::BINARY_SUBTRACT => "BINARY_SUBTRACT",
+            Self::BINARY_SUBSC => "BINARY_SUBSC",
+            Self::BINARY_FLOOR_DIVIDE => "BINARY_FLOOR_DIVIDE",
+            Self::BINARY_TRUE_DIVIDE => "BINARY_TRUE_DIVIDE",
+            Self::INPLACE_FLOOR_DIVIDE => "INPLACE_FLOOR_DIVIDE",
+            Self::INPLACE_TRUE_DIVIDE => "INPLACE_TRUE_DIVIDE",
+            Self::SLICE_0 => "SLICE_0",
+            Self::SLICE_1 => "SLICE_1",
+            Self::SLICE_2 => "SLICE_2",
+            Self::SLICE_3 => "SLICE_3",
+            Self::STORE_SLICE_0 => "STORE_SLICE_0",
+            Self::STORE_SLICE_1 => "STORE_SLICE_1",
+            Self::STORE_SLICE_2 => "STORE_SLICE_2",
+            Self::STORE_SLICE_3 => "STORE_SLICE_3",
+            Self::DELETE_SLICE_0 => "DELETE_SLICE_0",
+            Self::DELETE_SLICE_1 => "DELETE_SLICE_1",
+            Self::DELETE_SLICE_2 => "DELETE_SLICE_2",
+            Self::DELETE_SLICE_3 => "DELETE_SLICE_3",
+            Self::STORE_MAP => "STORE_MAP",
+            Self::INPLACE_ADD => "INPLACE_ADD",
+            Self::INPLACE_SUBTRACT => "INPLACE_SUBTRACT",
+            Self::INPLACE_MULTIPLY => "INPLACE_MULTIPLY",
+            Self::INPLACE_DIVIDE => "INPLACE_DIVIDE",
+            Self::INPLACE_MODULO => "INPLACE_MODULO",
+            Self::STORE_SUBSCR => "STORE_SUBSCR",
+            Self::DELETE_SUBSCR => "DELETE_SUBSCR",
+            Self::BINARY_LSHIFT => "BINARY_LSHIFT",
+            Self::BINARY_RSHIFT => "BINARY_RSHIFT",
+            Self::BINARY_AND => "BINARY_AND",
+            Self::BINARY_XOR => "BINARY_XOR",
+            Self::BINARY_OR => "BINARY_OR",
+            Self::INPLACE_POWER => "INPLACE_POWER",
+            Self::GET_ITER => "GET_ITER",
+            Self::PRINT_EXPR => "PRINT_EXPR",
+            Self::PRINT_ITEM => "PRINT_ITEM",
+            Self::PRINT_NEWLINE => "PRINT_NEWLINE",
+            Self::PRINT_ITEM_TO => "PRINT_ITEM_TO",
+            Self::PRINT_NEWLINE_TO => "PRINT_NEWLINE_TO",
+            Self::INPLACE_LSHIFT => "INPLACE_LSHIFT",
+            Self::INPLACE_RSHIFT => "INPLACE_RSHIFT",
+            Self::INPLACE_AND => "INPLACE_AND",
+            Self::INPLACE_XOR => "INPLACE_XOR",
+            Self::INPLACE_OR => "INPLACE_OR",
+            Self::BREAK_LOOP => "BREAK_LOOP",
+            Self::WITH_CLEANUP => "WITH_CLEANUP",
+            Self::LOAD_LOCALS => "LOAD_LOCALS",
+            Self::RETURN_VALUE => "RETURN_VALUE",
+            Self::IMPORT_STAR => "IMPORT_STAR",
+            Self::EXEC_STMT => "EXEC_STMT",
+            Self::YIELD_VALUE => "YIELD_VALUE",
+            Self::POP_BLOCK => "POP_BLOCK",
+            Self::END_FINALLY => "END_FINALLY",
+            Self::BUILD_CLASS => "BUILD_CLASS",
+            Self::STORE_NAME => "STORE_NAME",
+            Self::DELETE_NAME => "DELETE_NAME",
+            Self::UNPACK_SEQUENCE => "UNPACK_SEQUENCE",
+            Self::FOR_ITER => "FOR_ITER",
+            Self::LIST_APPEND => "LIST_APPEND",
+            Self::STORE_ATTR => "STORE_ATTR",
+            Self::DELETE_ATTR => "DELETE_ATTR",
+            Self::STORE_GLOBAL => "STORE_GLOBAL",
+            Self::DELETE_GLOBAL => "DELETE_GLOBAL",
+            Self::DUP_TOPX => "DUP_TOPX",
+            Self::LOAD_CONST => "LOAD_CONST",
+            Self::LOAD_NAME => "LOAD_NAME",
+            Self::BUILD_TUPLE => "BUILD_TUPLE",
+            Self::BUILD_LIST => "BUILD_LIST",
+            Self::BUILD_SET => "BUILD_SET",
+            Self::BUILD_MAP => "BUILD_MAP",
+            Self::LOAD_ATTR => "LOAD_ATTR",
+            Self::COMPARE_OP => "COMPARE_OP",
+            Self::IMPORT_NAME => "IMPORT_NAME",
+            Self::IMPORT_FROM => "IMPORT_FROM",
+            Self::JUMP_FORWARD => "JUMP_FORWARD",
+            Self::JUMP_IF_FALSE_OR_POP => "JUMP_IF_FALSE_OR_POP",
+            Self::JUMP_IF_TRUE_OR_POP => "JUMP_IF_TRUE_OR_POP",
+            Self::JUMP_ABSOLUTE => "JUMP_ABSOLUTE",
+            Self::POP_JUMP_IF_FALSE => "POP_JUMP_IF_FALSE",
+            Self::POP_JUMP_IF_TRUE => "POP_JUMP_IF_TRUE",
+            Self::LOAD_GLOBAL => "LOAD_GLOBAL",
+            Self::CONTINUE_LOOP => "CONTINUE_LOOP",
+            Self::SETUP_LOOP => "SETUP_LOOP",
+            Self::SETUP_EXCEPT => "SETUP_EXCEPT",
+            Self::SETUP_FINALLY => "SETUP_FINALLY",
+            Self::LOAD_FAST => "LOAD_FAST",
+            Self::STORE_FAST => "STORE_FAST",
+            Self::DELETE_FAST => "DELETE_FAST",
+            Self::RAISE_VARARGS => "RAISE_VARARGS",
+            Self::CALL_FUNCTION => "CALL_FUNCTION",
+            Self::MAKE_FUNCTION => "MAKE_FUNCTION",
+            Self::BUILD_SLICE => "BUILD_SLICE",
+            Self::MAKE_CLOSURE => "MAKE_CLOSURE",
+            Self::LOAD_CLOSURE => "LOAD_CLOSURE",
+            Self::LOAD_DEREF => "LOAD_DEREF",
+            Self::STORE_DEREF => "STORE_DEREF",
+            Self::CALL_FUNCTION_VAR => "CALL_FUNCTION_VAR",
+            Self::CALL_FUNCTION_KW => "CALL_FUNCTION_KW",
+            Self::CALL_FUNCTION_VAR_KW => "CALL_FUNCTION_VAR_KW",
+            Self::SETUP_WITH => "SETUP_WITH",
+            Self::EXTENDED_ARG => "EXTENDED_ARG",
+            Self::SET_ADD => "SET_ADD",
+            Self::MAP_ADD => "MAP_ADD",
+        }
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = ParseMnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "STOP_CODE" => Ok(Self::STOP_CODE),
+            "POP_TOP" => Ok(Self::POP_TOP),
+            "ROT_TWO" => Ok(Self::ROT_TWO),
+            "ROT_THREE" => Ok(Self::ROT_THREE),
+            "DUP_TOP" => Ok(Self::DUP_TOP),
+            "DUP_TOP_TWO" => Ok(Self::DUP_TOP_TWO),
+            "ROT_FOUR" => Ok(Self::ROT_FOUR),
+            "NOP" => Ok(Self::NOP),
+            "UNARY_POSITIVE" => Ok(Self::UNARY_POSITIVE),
+            "UNARY_NEGATIVE" => Ok(Self::UNARY_NEGATIVE),
+            "UNARY_NOT" => Ok(Self::UNARY_NOT),
+            "UNARY_CONVERT" => Ok(Self::UNARY_CONVERT),
+            "UNARY_INVERT" => Ok(Self::UNARY_INVERT),
+            "BINARY_POWER" => Ok(Self::BINARY_POWER),
+            "BINARY_MULTIPLY" => Ok(Self::BINARY_MULTIPLY),
+            "BINARY_DIVIDE" => Ok(Self::BINARY_DIVIDE),
+            "BINARY_MODULO" => Ok(Self::BINARY_MODULO),
+            "BINARY_ADD" => Ok(Self::BINARY_ADD),
+            "BINARY_SUBTRACT" => Ok(Self::BINARY_SUBTRACT),
+            "BINARY_SUBSC" => Ok(Self::BINARY_SUBSC),
+            "BINARY_FLOOR_DIVIDE" => Ok(Self::BINARY_FLOOR_DIVIDE),
+            "BINARY_TRUE_DIVIDE" => Ok(Self::BINARY_TRUE_DIVIDE),
+            "INPLACE_FLOOR_DIVIDE" => Ok(Self::INPLACE_FLOOR_DIVIDE),
+            "INPLACE_TRUE_DIVIDE" => Ok(Self::INPLACE_TRUE_DIVIDE),
+            "SLICE_0" => Ok(Self::SLICE_0),
+            "SLICE_1" => Ok(Self::SLICE_1),
+            "SLICE_2" => Ok(Self::SLICE_2),
+            "SLICE_3" => Ok(Self::SLICE_3),
+            "STORE_SLICE_0" => Ok(Self::STORE_SLICE_0),
+            "STORE_SLICE_1" => Ok(Self::STORE_SLICE_1),
+            "STORE_SLICE_2" => Ok(Self::STORE_SLICE_2),
+            "STORE_SLICE_3" => Ok(Self::STORE_SLICE_3),
+            "DELETE_SLICE_0" => Ok(Self::DELETE_SLICE_0),
+            "DELETE_SLICE_1" => Ok(Self::DELETE_SLICE_1),
+            "DELETE_SLICE_2" => Ok(Self::DELETE_SLICE_2),
+            "DELETE_SLICE_3" => Ok(Self::DELETE_SLICE_3),
+            "STORE_MAP" => Ok(Self::STORE_MAP),
+            "INPLACE_ADD" => Ok(Self::INPLACE_ADD),
+            "INPLACE_SUBTRACT" => Ok(Self::INPLACE_SUBTRACT),
+            "INPLACE_MULTIPLY" => Ok(Self::INPLACE_MULTIPLY),
+            "INPLACE_DIVIDE" => Ok(Self::INPLACE_DIVIDE),
+            "INPLACE_MODULO" => Ok(Self::INPLACE_MODULO),
+            "STORE_SUBSCR" => Ok(Self::STORE_SUBSCR),
+            "DELETE_SUBSCR" => Ok(Self::DELETE_SUBSCR),
+            "BINARY_LSHIFT" => Ok(Self::BINARY_LSHIFT),
+            "BINARY_RSHIFT" => Ok(Self::BINARY_RSHIFT),
+            "BINARY_AND" => Ok(Self::BINARY_AND),
+            "BINARY_XOR" => Ok(Self::BINARY_XOR),
+            "BINARY_OR" => Ok(Self::BINARY_OR),
+            "INPLACE_POWER" => Ok(Self::INPLACE_POWER),
+            "GET_ITER" => Ok(Self::GET_ITER),
+            "PRINT_EXPR" => Ok(Self::PRINT_EXPR),
+            "PRINT_ITEM" => Ok(Self::PRINT_ITEM),
+            "PRINT_NEWLINE" => Ok(Self::PRINT_NEWLINE),
+            "PRINT_ITEM_TO" => Ok(Self::PRINT_ITEM_TO),
+            "PRINT_NEWLINE_TO" => Ok(Self::PRINT_NEWLINE_TO),
+            "INPLACE_LSHIFT" => Ok(Self::INPLACE_LSHIFT),
+            "INPLACE_RSHIFT" => Ok(Self::INPLACE_RSHIFT),
+            "INPLACE_AND" => Ok(Self::INPLACE_AND),
+            "INPLACE_XOR" => Ok(Self::INPLACE_XOR),
+            "INPLACE_OR" => Ok(Self::INPLACE_OR),
+            "BREAK_LOOP" => Ok(Self::BREAK_LOOP),
+            "WITH_CLEANUP" => Ok(Self::WITH_CLEANUP),
+            "LOAD_LOCALS" => Ok(Self::LOAD_LOCALS),
+            "RETURN_VALUE" => Ok(Self::RETURN_VALUE),
+            "IMPORT_STAR" => Ok(Self::IMPORT_STAR),
+            "EXEC_STMT" => Ok(Self::EXEC_STMT),
+            "YIELD_VALUE" => Ok(Self::YIELD_VALUE),
+            "POP_BLOCK" => Ok(Self::POP_BLOCK),
+            "END_FINALLY" => Ok(Self::END_FINALLY),
+            "BUILD_CLASS" => Ok(Self::BUILD_CLASS),
+            "STORE_NAME" => Ok(Self::STORE_NAME),
+            "DELETE_NAME" => Ok(Self::DELETE_NAME),
+            "UNPACK_SEQUENCE" => Ok(Self::UNPACK_SEQUENCE),
+            "FOR_ITER" => Ok(Self::FOR_ITER),
+            "LIST_APPEND" => Ok(Self::LIST_APPEND),
+            "STORE_ATTR" => Ok(Self::STORE_ATTR),
+            "DELETE_ATTR" => Ok(Self::DELETE_ATTR),
+            "STORE_GLOBAL" => Ok(Self::STORE_GLOBAL),
+            "DELETE_GLOBAL" => Ok(Self::DELETE_GLOBAL),
+            "DUP_TOPX" => Ok(Self::DUP_TOPX),
+            "LOAD_CONST" => Ok(Self::LOAD_CONST),
+            "LOAD_NAME" => Ok(Self::LOAD_NAME),
+            "BUILD_TUPLE" => Ok(Self::BUILD_TUPLE),
+            "BUILD_LIST" => Ok(Self::BUILD_LIST),
+            "BUILD_SET" => Ok(Self::BUILD_SET),
+            "BUILD_MAP" => Ok(Self::BUILD_MAP),
+            "LOAD_ATTR" => Ok(Self::LOAD_ATTR),
+            "COMPARE_OP" => Ok(Self::COMPARE_OP),
+            "IMPORT_NAME" => Ok(Self::IMPORT_NAME),
+            "IMPORT_FROM" => Ok(Self::IMPORT_FROM),
+            "JUMP_FORWARD" => Ok(Self::JUMP_FORWARD),
+            "JUMP_IF_FALSE_OR_POP" => Ok(Self::JUMP_IF_FALSE_OR_POP),
+            "JUMP_IF_TRUE_OR_POP" => Ok(Self::JUMP_IF_TRUE_OR_POP),
+            "JUMP_ABSOLUTE" => Ok(Self::JUMP_ABSOLUTE),
+            "POP_JUMP_IF_FALSE" => Ok(Self::POP_JUMP_IF_FALSE),
+            "POP_JUMP_IF_TRUE" => Ok(Self::POP_JUMP_IF_TRUE),
+            "LOAD_GLOBAL" => Ok(Self::LOAD_GLOBAL),
+            "CONTINUE_LOOP" => Ok(Self::CONTINUE_LOOP),
+            "SETUP_LOOP" => Ok(Self::SETUP_LOOP),
+            "SETUP_EXCEPT" => Ok(Self::SETUP_EXCEPT),
+            "SETUP_FINALLY" => Ok(Self::SETUP_FINALLY),
+            "LOAD_FAST" => Ok(Self::LOAD_FAST),
+            "STORE_FAST" => Ok(Self::STORE_FAST),
+            "DELETE_FAST" => Ok(Self::DELETE_FAST),
+            "RAISE_VARARGS" => Ok(Self::RAISE_VARARGS),
+            "CALL_FUNCTION" => Ok(Self::CALL_FUNCTION),
+            "MAKE_FUNCTION" => Ok(Self::MAKE_FUNCTION),
+            "BUILD_SLICE" => Ok(Self::BUILD_SLICE),
+            "MAKE_CLOSURE" => Ok(Self::MAKE_CLOSURE),
+            "LOAD_CLOSURE" => Ok(Self::LOAD_CLOSURE),
+            "LOAD_DEREF" => Ok(Self::LOAD_DEREF),
+            "STORE_DEREF" => Ok(Self::STORE_DEREF),
+            "CALL_FUNCTION_VAR" => Ok(Self::CALL_FUNCTION_VAR),
+            "CALL_FUNCTION_KW" => Ok(Self::CALL_FUNCTION_KW),
+            "CALL_FUNCTION_VAR_KW" => Ok(Self::CALL_FUNCTION_VAR_KW),
+            "SETUP_WITH" => Ok(Self::SETUP_WITH),
+            "EXTENDED_ARG" => Ok(Self::EXTENDED_ARG),
+            "SET_ADD" => Ok(Self::SET_ADD),
+            "MAP_ADD" => Ok(Self::MAP_ADD),
+            // Spellings used by CPython's `opcode` module
+            "BINARY_SUBSCR" => Ok(Self::BINARY_SUBSC),
+            "SLICE+0" => Ok(Self::SLICE_0),
+            "SLICE+1" => Ok(Self::SLICE_1),
+            "SLICE+2" => Ok(Self::SLICE_2),
+            "SLICE+3" => Ok(Self::SLICE_3),
+            "STORE_SLICE+0" => Ok(Self::STORE_SLICE_0),
+            "STORE_SLICE+1" => Ok(Self::STORE_SLICE_1),
+            "STORE_SLICE+2" => Ok(Self::STORE_SLICE_2),
+            "STORE_SLICE+3" => Ok(Self::STORE_SLICE_3),
+            "DELETE_SLICE+0" => Ok(Self::DELETE_SLICE_0),
+            "DELETE_SLICE+1" => Ok(Self::DELETE_SLICE_1),
+            "DELETE_SLICE+2" => Ok(Self::DELETE_SLICE_2),
+            "DELETE_SLICE+3" => Ok(Self::DELETE_SLICE_3),
+            _ => Err(ParseMnemonicError(s.to_string())),
+        }
+    }
+}
+
 /// Opcodes taken from https://github.com/python/cpython/blob/2.7/Lib/opcode.py.
 /// This is the standard VM opcode set.
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Primitive)]
@@ -436,6 +844,16 @@ impl super::Opcode for Standard {
         *self as u8 >= 144
     }
 
+    /// Whether or not this opcode is the `EXTENDED_ARG` prefix
+    fn is_extended_arg(&self) -> bool {
+        *self == Self::EXTENDED_ARG
+    }
+
+    /// The `EXTENDED_ARG` prefix opcode of this opcode table
+    fn extended_arg() -> Self {
+        Self::EXTENDED_ARG
+    }
+
     /// Whether or not this opcode has a constant parameter
     fn has_const(&self) -> bool {
         *self == Self::LOAD_CONST
@@ -666,3 +1084,151 @@ impl super::Opcode for Standard {
         }
     }
 }
+
+impl super::StackEffect for Mnemonic {
+    fn stack_adjustment(&self, arg: Option<u32>) -> isize {
+        match self {
+            // Meta instructions
+            Self::STOP_CODE
+            | Self::NOP
+            | Self::ROT_TWO
+            | Self::ROT_THREE
+            | Self::ROT_FOUR => 0,
+
+            Self::POP_TOP => -1,
+            Self::DUP_TOP => 1,
+            Self::DUP_TOP_TWO => 2,
+            // Unary ops
+            Self::UNARY_POSITIVE
+            | Self::UNARY_NEGATIVE
+            | Self::UNARY_NOT
+            | Self::UNARY_CONVERT
+            | Self::UNARY_INVERT
+            | Self::GET_ITER => 0,
+            // Binary ops
+            Self::BINARY_POWER
+            | Self::BINARY_MULTIPLY
+            | Self::BINARY_DIVIDE
+            | Self::BINARY_FLOOR_DIVIDE
+            | Self::BINARY_TRUE_DIVIDE
+            | Self::BINARY_MODULO
+            | Self::BINARY_ADD
+            | Self::BINARY_SUBTRACT
+            | Self::BINARY_SUBSC
+            | Self::BINARY_LSHIFT
+            | Self::BINARY_RSHIFT
+            | Self::BINARY_AND
+            | Self::BINARY_XOR
+            | Self::BINARY_OR => -1,
+            // In-place operations
+            Self::INPLACE_POWER
+            | Self::INPLACE_MULTIPLY
+            | Self::INPLACE_DIVIDE
+            | Self::INPLACE_FLOOR_DIVIDE
+            | Self::INPLACE_TRUE_DIVIDE
+            | Self::INPLACE_MODULO
+            | Self::INPLACE_ADD
+            | Self::INPLACE_SUBTRACT
+            | Self::INPLACE_LSHIFT
+            | Self::INPLACE_RSHIFT
+            | Self::INPLACE_AND
+            | Self::INPLACE_XOR
+            | Self::INPLACE_OR => -1,
+            // Slice operations
+            Self::SLICE_0 => 0,
+            Self::SLICE_1 | Self::SLICE_2 => -1,
+            Self::SLICE_3 => -2,
+            Self::STORE_SLICE_0 => -2,
+            Self::STORE_SLICE_1 | Self::STORE_SLICE_2 => -3,
+            Self::STORE_SLICE_3 => -4,
+            Self::DELETE_SLICE_0 => -1,
+            Self::DELETE_SLICE_1 | Self::DELETE_SLICE_2 => -2,
+            Self::DELETE_SLICE_3 => -3,
+            Self::STORE_SUBSCR => -3,
+            Self::DELETE_SUBSCR => -2,
+            // Misc
+            Self::PRINT_EXPR => -1,
+            Self::PRINT_ITEM => -1,
+            Self::PRINT_ITEM_TO => -2,
+            Self::PRINT_NEWLINE => 0,
+            Self::PRINT_NEWLINE_TO => -1,
+            Self::BREAK_LOOP => 0,
+            Self::CONTINUE_LOOP => 0,
+            // The list, set or dict stays on the stack below the loop's iterator
+            Self::LIST_APPEND | Self::SET_ADD => -1,
+            Self::MAP_ADD => -2,
+            Self::LOAD_LOCALS => 1,
+            Self::RETURN_VALUE => -1,
+            Self::YIELD_VALUE => 0,
+            Self::IMPORT_STAR => -1,
+            Self::EXEC_STMT => -3,
+            Self::POP_BLOCK => 0,
+            // Fewer values are popped if no exception is being re-raised
+            Self::END_FINALLY => -3,
+            Self::BUILD_CLASS => -2,
+            // Pushes the bound __exit__ method and the result of __enter__, and leaves room for
+            // an exception
+            Self::SETUP_WITH => 4,
+            // More values are popped if the block was exited by an exception
+            Self::WITH_CLEANUP => -1,
+            Self::STORE_NAME => -1,
+            Self::STORE_FAST => -1,
+            Self::STORE_DEREF => -1,
+            Self::DELETE_NAME => 0,
+            Self::UNPACK_SEQUENCE => (arg.unwrap() as isize) - 1,
+            Self::DUP_TOPX => arg.unwrap() as isize,
+            Self::STORE_ATTR => -2,
+            Self::DELETE_ATTR => -1,
+            Self::STORE_GLOBAL => -1,
+            Self::DELETE_GLOBAL => 0,
+            Self::LOAD_CONST => 1,
+            Self::LOAD_NAME => 1,
+            Self::BUILD_TUPLE | Self::BUILD_LIST | Self::BUILD_SET => {
+                1 - (arg.unwrap() as isize)
+            }
+            Self::BUILD_MAP => 1,
+            Self::LOAD_ATTR => 0,
+            Self::COMPARE_OP => -1,
+            Self::IMPORT_NAME => -1,
+            Self::IMPORT_FROM => 1,
+            Self::JUMP_FORWARD | Self::JUMP_ABSOLUTE => 0,
+            Self::POP_JUMP_IF_FALSE | Self::POP_JUMP_IF_TRUE => -1,
+            // The condition is only popped if the jump is not taken
+            Self::JUMP_IF_FALSE_OR_POP | Self::JUMP_IF_TRUE_OR_POP => 0,
+            Self::FOR_ITER => 1,
+            Self::LOAD_GLOBAL => 1,
+            Self::SETUP_LOOP => 0,
+            // Values are only pushed when an exception unwinds to the handler
+            Self::SETUP_EXCEPT | Self::SETUP_FINALLY => 3,
+            Self::STORE_MAP => -2,
+            Self::LOAD_FAST => 1,
+            Self::DELETE_FAST => 0,
+            Self::LOAD_CLOSURE => 1,
+            Self::LOAD_DEREF => 1,
+            Self::RAISE_VARARGS => -(arg.unwrap() as isize),
+            Self::CALL_FUNCTION => {
+                let pos_args = arg.unwrap() & 0xFF;
+                let kwargs = (arg.unwrap() >> 8) & 0xFF;
+                // 1 arg is removed for the callable, 1 is added for the return value
+                -(pos_args as isize + 2 * kwargs as isize + 1) + 1
+            }
+            Self::MAKE_FUNCTION => -(arg.unwrap() as isize),
+            Self::MAKE_CLOSURE => -(arg.unwrap() as isize) - 1,
+            Self::BUILD_SLICE => 1 - (arg.unwrap() as isize),
+            // The prefix only widens the argument of the following instruction
+            Self::EXTENDED_ARG => 0,
+            Self::CALL_FUNCTION_VAR | Self::CALL_FUNCTION_KW => {
+                let pos_args = arg.unwrap() & 0xFF;
+                let kwargs = (arg.unwrap() >> 8) & 0xFF;
+                // 1 arg is removed for the callable and additional positional args, 1 is added for the return value
+                -(pos_args as isize + 2 * kwargs as isize + 2) + 1
+            }
+            Self::CALL_FUNCTION_VAR_KW => {
+                let pos_args = arg.unwrap() & 0xFF;
+                let kwargs = (arg.unwrap() >> 8) & 0xFF;
+                // 1 arg is removed for the callable and additional positional+kw args, 1 is added for the return value
+                -(pos_args as isize + 2 * kwargs as isize + 3) + 1
+            }
+        }
+    }
+}
@@ -0,0 +1,1902 @@
+pub use enum_primitive_derive::Primitive;
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
+
+use super::Encoding;
+use crate::error::ParseMnemonicError;
+
+/// Standard set of instruction mnemonics
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Mnemonic {
+    CACHE,
+    POP_TOP,
+    PUSH_NULL,
+
+    NOP,
+    UNARY_POSITIVE,
+    UNARY_NEGATIVE,
+    UNARY_NOT,
+
+    UNARY_INVERT,
+
+    BINARY_SUBSCR,
+
+    GET_LEN,
+    MATCH_MAPPING,
+    MATCH_SEQUENCE,
+    MATCH_KEYS,
+
+    PUSH_EXC_INFO,
+    CHECK_EXC_MATCH,
+    CHECK_EG_MATCH,
+
+    WITH_EXCEPT_START,
+    GET_AITER,
+    GET_ANEXT,
+    BEFORE_ASYNC_WITH,
+    BEFORE_WITH,
+    END_ASYNC_FOR,
+
+    STORE_SUBSCR,
+    DELETE_SUBSCR,
+
+    GET_ITER,
+    GET_YIELD_FROM_ITER,
+    PRINT_EXPR,
+    LOAD_BUILD_CLASS,
+
+    LOAD_ASSERTION_ERROR,
+    RETURN_GENERATOR,
+
+    LIST_TO_TUPLE,
+    RETURN_VALUE,
+    IMPORT_STAR,
+    SETUP_ANNOTATIONS,
+    YIELD_VALUE,
+    ASYNC_GEN_WRAP,
+    PREP_RERAISE_STAR,
+    POP_EXCEPT,
+
+    // Opcodes with arguments
+    STORE_NAME,
+    DELETE_NAME,
+    UNPACK_SEQUENCE,
+    FOR_ITER,
+    UNPACK_EX,
+    STORE_ATTR,
+    DELETE_ATTR,
+    STORE_GLOBAL,
+    DELETE_GLOBAL,
+    SWAP,
+    LOAD_CONST,
+    LOAD_NAME,
+    BUILD_TUPLE,
+    BUILD_LIST,
+    BUILD_SET,
+    BUILD_MAP,
+    LOAD_ATTR,
+    COMPARE_OP,
+    IMPORT_NAME,
+    IMPORT_FROM,
+    JUMP_FORWARD,
+    JUMP_IF_FALSE_OR_POP,
+    JUMP_IF_TRUE_OR_POP,
+
+    POP_JUMP_FORWARD_IF_FALSE,
+    POP_JUMP_FORWARD_IF_TRUE,
+    LOAD_GLOBAL,
+    IS_OP,
+    CONTAINS_OP,
+    RERAISE,
+    COPY,
+
+    BINARY_OP,
+    SEND,
+    LOAD_FAST,
+    STORE_FAST,
+    DELETE_FAST,
+
+    POP_JUMP_FORWARD_IF_NOT_NONE,
+    POP_JUMP_FORWARD_IF_NONE,
+    RAISE_VARARGS,
+    GET_AWAITABLE,
+    MAKE_FUNCTION,
+    BUILD_SLICE,
+    JUMP_BACKWARD_NO_INTERRUPT,
+    MAKE_CELL,
+    LOAD_CLOSURE,
+    LOAD_DEREF,
+    STORE_DEREF,
+    DELETE_DEREF,
+    JUMP_BACKWARD,
+
+    CALL_FUNCTION_EX,
+
+    EXTENDED_ARG,
+    LIST_APPEND,
+    SET_ADD,
+    MAP_ADD,
+    LOAD_CLASSDEREF,
+    COPY_FREE_VARS,
+
+    RESUME,
+    MATCH_CLASS,
+
+    FORMAT_VALUE,
+    BUILD_CONST_KEY_MAP,
+    BUILD_STRING,
+
+    LOAD_METHOD,
+
+    LIST_EXTEND,
+    SET_UPDATE,
+    DICT_MERGE,
+    DICT_UPDATE,
+    PRECALL,
+
+    CALL,
+    KW_NAMES,
+    POP_JUMP_BACKWARD_IF_NOT_NONE,
+    POP_JUMP_BACKWARD_IF_NONE,
+    POP_JUMP_BACKWARD_IF_FALSE,
+    POP_JUMP_BACKWARD_IF_TRUE,
+
+    // Specialized opcodes, which only appear in code that has been executed
+    BINARY_OP_ADAPTIVE,
+    BINARY_OP_ADD_FLOAT,
+    BINARY_OP_ADD_INT,
+    BINARY_OP_ADD_UNICODE,
+    BINARY_OP_INPLACE_ADD_UNICODE,
+    BINARY_OP_MULTIPLY_FLOAT,
+    BINARY_OP_MULTIPLY_INT,
+    BINARY_OP_SUBTRACT_FLOAT,
+    BINARY_OP_SUBTRACT_INT,
+    BINARY_SUBSCR_ADAPTIVE,
+    BINARY_SUBSCR_DICT,
+    BINARY_SUBSCR_GETITEM,
+    BINARY_SUBSCR_LIST_INT,
+    BINARY_SUBSCR_TUPLE_INT,
+    CALL_ADAPTIVE,
+    CALL_PY_EXACT_ARGS,
+    CALL_PY_WITH_DEFAULTS,
+    COMPARE_OP_ADAPTIVE,
+    COMPARE_OP_FLOAT_JUMP,
+    COMPARE_OP_INT_JUMP,
+    COMPARE_OP_STR_JUMP,
+    EXTENDED_ARG_QUICK,
+    JUMP_BACKWARD_QUICK,
+    LOAD_ATTR_ADAPTIVE,
+    LOAD_ATTR_INSTANCE_VALUE,
+    LOAD_ATTR_MODULE,
+    LOAD_ATTR_SLOT,
+    LOAD_ATTR_WITH_HINT,
+    LOAD_CONST__LOAD_FAST,
+    LOAD_FAST__LOAD_CONST,
+    LOAD_FAST__LOAD_FAST,
+    LOAD_GLOBAL_ADAPTIVE,
+    LOAD_GLOBAL_BUILTIN,
+    LOAD_GLOBAL_MODULE,
+    LOAD_METHOD_ADAPTIVE,
+    LOAD_METHOD_CLASS,
+    LOAD_METHOD_MODULE,
+    LOAD_METHOD_NO_DICT,
+    LOAD_METHOD_WITH_DICT,
+    LOAD_METHOD_WITH_VALUES,
+    PRECALL_ADAPTIVE,
+    PRECALL_BOUND_METHOD,
+    PRECALL_BUILTIN_CLASS,
+    PRECALL_BUILTIN_FAST_WITH_KEYWORDS,
+    PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS,
+    PRECALL_NO_KW_BUILTIN_FAST,
+    PRECALL_NO_KW_BUILTIN_O,
+    PRECALL_NO_KW_ISINSTANCE,
+    PRECALL_NO_KW_LEN,
+    PRECALL_NO_KW_LIST_APPEND,
+    PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST,
+    PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS,
+    PRECALL_NO_KW_METHOD_DESCRIPTOR_O,
+    PRECALL_NO_KW_STR_1,
+    PRECALL_NO_KW_TUPLE_1,
+    PRECALL_NO_KW_TYPE_1,
+    PRECALL_PYFUNC,
+    RESUME_QUICK,
+    STORE_ATTR_ADAPTIVE,
+    STORE_ATTR_INSTANCE_VALUE,
+    STORE_ATTR_SLOT,
+    STORE_ATTR_WITH_HINT,
+    STORE_FAST__LOAD_FAST,
+    STORE_FAST__STORE_FAST,
+    STORE_SUBSCR_ADAPTIVE,
+    STORE_SUBSCR_DICT,
+    STORE_SUBSCR_LIST_INT,
+    UNPACK_SEQUENCE_ADAPTIVE,
+    UNPACK_SEQUENCE_LIST,
+    UNPACK_SEQUENCE_TUPLE,
+    UNPACK_SEQUENCE_TWO_TUPLE,
+}
+
+impl Mnemonic {
+    /// Every mnemonic, in declaration order
+    pub const ALL: &'static [Mnemonic] = &[
+        Self::CACHE,
+        Self::POP_TOP,
+        Self::PUSH_NULL,
+        Self::NOP,
+        Self::UNARY_POSITIVE,
+        Self::UNARY_NEGATIVE,
+        Self::UNARY_NOT,
+        Self::UNARY_INVERT,
+        Self::BINARY_SUBSCR,
+        Self::GET_LEN,
+        Self::MATCH_MAPPING,
+        Self::MATCH_SEQUENCE,
+        Self::MATCH_KEYS,
+        Self::PUSH_EXC_INFO,
+        Self::CHECK_EXC_MATCH,
+        Self::CHECK_EG_MATCH,
+        Self::WITH_EXCEPT_START,
+        Self::GET_AITER,
+        Self::GET_ANEXT,
+        Self::BEFORE_ASYNC_WITH,
+        Self::BEFORE_WITH,
+        Self::END_ASYNC_FOR,
+        Self::STORE_SUBSCR,
+        Self::DELETE_SUBSCR,
+        Self::GET_ITER,
+        Self::GET_YIELD_FROM_ITER,
+        Self::PRINT_EXPR,
+        Self::LOAD_BUILD_CLASS,
+        Self::LOAD_ASSERTION_ERROR,
+        Self::RETURN_GENERATOR,
+        Self::LIST_TO_TUPLE,
+        Self::RETURN_VALUE,
+        Self::IMPORT_STAR,
+        Self::SETUP_ANNOTATIONS,
+        Self::YIELD_VALUE,
+        Self::ASYNC_GEN_WRAP,
+        Self::PREP_RERAISE_STAR,
+        Self::POP_EXCEPT,
+        Self::STORE_NAME,
+        Self::DELETE_NAME,
+        Self::UNPACK_SEQUENCE,
+        Self::FOR_ITER,
+        Self::UNPACK_EX,
+        Self::STORE_ATTR,
+        Self::DELETE_ATTR,
+        Self::STORE_GLOBAL,
+        Self::DELETE_GLOBAL,
+        Self::SWAP,
+        Self::LOAD_CONST,
+        Self::LOAD_NAME,
+        Self::BUILD_TUPLE,
+        Self::BUILD_LIST,
+        Self::BUILD_SET,
+        Self::BUILD_MAP,
+        Self::LOAD_ATTR,
+        Self::COMPARE_OP,
+        Self::IMPORT_NAME,
+        Self::IMPORT_FROM,
+        Self::JUMP_FORWARD,
+        Self::JUMP_IF_FALSE_OR_POP,
+        Self::JUMP_IF_TRUE_OR_POP,
+        Self::POP_JUMP_FORWARD_IF_FALSE,
+        Self::POP_JUMP_FORWARD_IF_TRUE,
+        Self::LOAD_GLOBAL,
+        Self::IS_OP,
+        Self::CONTAINS_OP,
+        Self::RERAISE,
+        Self::COPY,
+        Self::BINARY_OP,
+        Self::SEND,
+        Self::LOAD_FAST,
+        Self::STORE_FAST,
+        Self::DELETE_FAST,
+        Self::POP_JUMP_FORWARD_IF_NOT_NONE,
+        Self::POP_JUMP_FORWARD_IF_NONE,
+        Self::RAISE_VARARGS,
+        Self::GET_AWAITABLE,
+        Self::MAKE_FUNCTION,
+        Self::BUILD_SLICE,
+        Self::JUMP_BACKWARD_NO_INTERRUPT,
+        Self::MAKE_CELL,
+        Self::LOAD_CLOSURE,
+        Self::LOAD_DEREF,
+        Self::STORE_DEREF,
+        Self::DELETE_DEREF,
+        Self::JUMP_BACKWARD,
+        Self::CALL_FUNCTION_EX,
+        Self::EXTENDED_ARG,
+        Self::LIST_APPEND,
+        Self::SET_ADD,
+        Self::MAP_ADD,
+        Self::LOAD_CLASSDEREF,
+        Self::COPY_FREE_VARS,
+        Self::RESUME,
+        Self::MATCH_CLASS,
+        Self::FORMAT_VALUE,
+        Self::BUILD_CONST_KEY_MAP,
+        Self::BUILD_STRING,
+        Self::LOAD_METHOD,
+        Self::LIST_EXTEND,
+        Self::SET_UPDATE,
+        Self::DICT_MERGE,
+        Self::DICT_UPDATE,
+        Self::PRECALL,
+        Self::CALL,
+        Self::KW_NAMES,
+        Self::POP_JUMP_BACKWARD_IF_NOT_NONE,
+        Self::POP_JUMP_BACKWARD_IF_NONE,
+        Self::POP_JUMP_BACKWARD_IF_FALSE,
+        Self::POP_JUMP_BACKWARD_IF_TRUE,
+        Self::BINARY_OP_ADAPTIVE,
+        Self::BINARY_OP_ADD_FLOAT,
+        Self::BINARY_OP_ADD_INT,
+        Self::BINARY_OP_ADD_UNICODE,
+        Self::BINARY_OP_INPLACE_ADD_UNICODE,
+        Self::BINARY_OP_MULTIPLY_FLOAT,
+        Self::BINARY_OP_MULTIPLY_INT,
+        Self::BINARY_OP_SUBTRACT_FLOAT,
+        Self::BINARY_OP_SUBTRACT_INT,
+        Self::BINARY_SUBSCR_ADAPTIVE,
+        Self::BINARY_SUBSCR_DICT,
+        Self::BINARY_SUBSCR_GETITEM,
+        Self::BINARY_SUBSCR_LIST_INT,
+        Self::BINARY_SUBSCR_TUPLE_INT,
+        Self::CALL_ADAPTIVE,
+        Self::CALL_PY_EXACT_ARGS,
+        Self::CALL_PY_WITH_DEFAULTS,
+        Self::COMPARE_OP_ADAPTIVE,
+        Self::COMPARE_OP_FLOAT_JUMP,
+        Self::COMPARE_OP_INT_JUMP,
+        Self::COMPARE_OP_STR_JUMP,
+        Self::EXTENDED_ARG_QUICK,
+        Self::JUMP_BACKWARD_QUICK,
+        Self::LOAD_ATTR_ADAPTIVE,
+        Self::LOAD_ATTR_INSTANCE_VALUE,
+        Self::LOAD_ATTR_MODULE,
+        Self::LOAD_ATTR_SLOT,
+        Self::LOAD_ATTR_WITH_HINT,
+        Self::LOAD_CONST__LOAD_FAST,
+        Self::LOAD_FAST__LOAD_CONST,
+        Self::LOAD_FAST__LOAD_FAST,
+        Self::LOAD_GLOBAL_ADAPTIVE,
+        Self::LOAD_GLOBAL_BUILTIN,
+        Self::LOAD_GLOBAL_MODULE,
+        Self::LOAD_METHOD_ADAPTIVE,
+        Self::LOAD_METHOD_CLASS,
+        Self::LOAD_METHOD_MODULE,
+        Self::LOAD_METHOD_NO_DICT,
+        Self::LOAD_METHOD_WITH_DICT,
+        Self::LOAD_METHOD_WITH_VALUES,
+        Self::PRECALL_ADAPTIVE,
+        Self::PRECALL_BOUND_METHOD,
+        Self::PRECALL_BUILTIN_CLASS,
+        Self::PRECALL_BUILTIN_FAST_WITH_KEYWORDS,
+        Self::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS,
+        Self::PRECALL_NO_KW_BUILTIN_FAST,
+        Self::PRECALL_NO_KW_BUILTIN_O,
+        Self::PRECALL_NO_KW_ISINSTANCE,
+        Self::PRECALL_NO_KW_LEN,
+        Self::PRECALL_NO_KW_LIST_APPEND,
+        Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST,
+        Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS,
+        Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_O,
+        Self::PRECALL_NO_KW_STR_1,
+        Self::PRECALL_NO_KW_TUPLE_1,
+        Self::PRECALL_NO_KW_TYPE_1,
+        Self::PRECALL_PYFUNC,
+        Self::RESUME_QUICK,
+        Self::STORE_ATTR_ADAPTIVE,
+        Self::STORE_ATTR_INSTANCE_VALUE,
+        Self::STORE_ATTR_SLOT,
+        Self::STORE_ATTR_WITH_HINT,
+        Self::STORE_FAST__LOAD_FAST,
+        Self::STORE_FAST__STORE_FAST,
+        Self::STORE_SUBSCR_ADAPTIVE,
+        Self::STORE_SUBSCR_DICT,
+        Self::STORE_SUBSCR_LIST_INT,
+        Self::UNPACK_SEQUENCE_ADAPTIVE,
+        Self::UNPACK_SEQUENCE_LIST,
+        Self::UNPACK_SEQUENCE_TUPLE,
+        Self::UNPACK_SEQUENCE_TWO_TUPLE,
+    ];
+
+    /// The canonical name of this mnemonic. This is the same string which
+    /// [`Mnemonic::from_str`] accepts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CACHE => "CACHE",
+            Self::POP_TOP => "POP_TOP",
+            Self::PUSH_NULL => "PUSH_NULL",
+            Self::NOP => "NOP",
+            Self::UNARY_POSITIVE => "UNARY_POSITIVE",
+            Self::UNARY_NEGATIVE => "UNARY_NEGATIVE",
+            Self::UNARY_NOT => "UNARY_NOT",
+            Self::UNARY_INVERT => "UNARY_INVERT",
+            Self::BINARY_SUBSCR => "BINARY_SUBSCR",
+            Self::GET_LEN => "GET_LEN",
+            Self::MATCH_MAPPING => "MATCH_MAPPING",
+            Self::MATCH_SEQUENCE => "MATCH_SEQUENCE",
+            Self::MATCH_KEYS => "MATCH_KEYS",
+            Self::PUSH_EXC_INFO => "PUSH_EXC_INFO",
+            Self::CHECK_EXC_MATCH => "CHECK_EXC_MATCH",
+            Self::CHECK_EG_MATCH => "CHECK_EG_MATCH",
+            Self::WITH_EXCEPT_START => "WITH_EXCEPT_START",
+            Self::GET_AITER => "GET_AITER",
+            Self::GET_ANEXT => "GET_ANEXT",
+            Self::BEFORE_ASYNC_WITH => "BEFORE_ASYNC_WITH",
+            Self::BEFORE_WITH => "BEFORE_WITH",
+            Self::END_ASYNC_FOR => "END_ASYNC_FOR",
+            Self::STORE_SUBSCR => "STORE_SUBSCR",
+            Self::DELETE_SUBSCR => "DELETE_SUBSCR",
+            Self::GET_ITER => "GET_ITER",
+            Self::GET_YIELD_FROM_ITER => "GET_YIELD_FROM_ITER",
+            Self::PRINT_EXPR => "PRINT_EXPR",
+            Self::LOAD_BUILD_CLASS => "LOAD_BUILD_CLASS",
+            Self::LOAD_ASSERTION_ERROR => "LOAD_ASSERTION_ERROR",
+            Self::RETURN_GENERATOR => "RETURN_GENERATOR",
+            Self::LIST_TO_TUPLE => "LIST_TO_TUPLE",
+            Self::RETURN_VALUE => "RETURN_VALUE",
+            Self::IMPORT_STAR => "IMPORT_STAR",
+            Self::SETUP_ANNOTATIONS => "SETUP_ANNOTATIONS",
+            Self::YIELD_VALUE => "YIELD_VALUE",
+            Self::ASYNC_GEN_WRAP => "ASYNC_GEN_WRAP",
+            Self::PREP_RERAISE_STAR => "PREP_RERAISE_STAR",
+            Self::POP_EXCEPT => "POP_EXCEPT",
+            Self::STORE_NAME => "STORE_NAME",
+            Self::DELETE_NAME => "DELETE_NAME",
+            Self::UNPACK_SEQUENCE => "UNPACK_SEQUENCE",
+            Self::FOR_ITER => "FOR_ITER",
+            Self::UNPACK_EX => "UNPACK_EX",
+            Self::STORE_ATTR => "STORE_ATTR",
+            Self::DELETE_ATTR => "DELETE_ATTR",
+            Self::STORE_GLOBAL => "STORE_GLOBAL",
+            Self::DELETE_GLOBAL => "DELETE_GLOBAL",
+            Self::SWAP => "SWAP",
+            Self::LOAD_CONST => "LOAD_CONST",
+            Self::LOAD_NAME => "LOAD_NAME",
+            Self::BUILD_TUPLE => "BUILD_TUPLE",
+            Self::BUILD_LIST => "BUILD_LIST",
+            Self::BUILD_SET => "BUILD_SET",
+            Self::BUILD_MAP => "BUILD_MAP",
+            Self::LOAD_ATTR => "LOAD_ATTR",
+            Self::COMPARE_OP => "COMPARE_OP",
+            Self::IMPORT_NAME => "IMPORT_NAME",
+            Self::IMPORT_FROM => "IMPORT_FROM",
+            Self::JUMP_FORWARD => "JUMP_FORWARD",
+            Self::JUMP_IF_FALSE_OR_POP => "JUMP_IF_FALSE_OR_POP",
+            Self::JUMP_IF_TRUE_OR_POP => "JUMP_IF_TRUE_OR_POP",
+            Self::POP_JUMP_FORWARD_IF_FALSE => "POP_JUMP_FORWARD_IF_FALSE",
+            Self::POP_JUMP_FORWARD_IF_TRUE => "POP_JUMP_FORWARD_IF_TRUE",
+            Self::LOAD_GLOBAL => "LOAD_GLOBAL",
+            Self::IS_OP => "IS_OP",
+            Self::CONTAINS_OP => "CONTAINS_OP",
+            Self::RERAISE => "RERAISE",
+            Self::COPY => "COPY",
+            Self::BINARY_OP => "BINARY_OP",
+            Self::SEND => "SEND",
+            Self::LOAD_FAST => "LOAD_FAST",
+            Self::STORE_FAST => "STORE_FAST",
+            Self::DELETE_FAST => "DELETE_FAST",
+            Self::POP_JUMP_FORWARD_IF_NOT_NONE => "POP_JUMP_FORWARD_IF_NOT_NONE",
+            Self::POP_JUMP_FORWARD_IF_NONE => "POP_JUMP_FORWARD_IF_NONE",
+            Self::RAISE_VARARGS => "RAISE_VARARGS",
+            Self::GET_AWAITABLE => "GET_AWAITABLE",
+            Self::MAKE_FUNCTION => "MAKE_FUNCTION",
+            Self::BUILD_SLICE => "BUILD_SLICE",
+            Self::JUMP_BACKWARD_NO_INTERRUPT => "JUMP_BACKWARD_NO_INTERRUPT",
+            Self::MAKE_CELL => "MAKE_CELL",
+            Self::LOAD_CLOSURE => "LOAD_CLOSURE",
+            Self::LOAD_DEREF => "LOAD_DEREF",
+            Self::STORE_DEREF => "STORE_DEREF",
+            Self::DELETE_DEREF => "DELETE_DEREF",
+            Self::JUMP_BACKWARD => "JUMP_BACKWARD",
+            Self::CALL_FUNCTION_EX => "CALL_FUNCTION_EX",
+            Self::EXTENDED_ARG => "EXTENDED_ARG",
+            Self::LIST_APPEND => "LIST_APPEND",
+            Self::SET_ADD => "SET_ADD",
+            Self::MAP_ADD => "MAP_ADD",
+            Self::LOAD_CLASSDEREF => "LOAD_CLASSDEREF",
+            Self::COPY_FREE_VARS => "COPY_FREE_VARS",
+            Self::RESUME => "RESUME",
+            Self::MATCH_CLASS => "MATCH_CLASS",
+            Self::FORMAT_VALUE => "FORMAT_VALUE",
+            Self::BUILD_CONST_KEY_MAP => "BUILD_CONST_KEY_MAP",
+            Self::BUILD_STRING => "BUILD_STRING",
+            Self::LOAD_METHOD => "LOAD_METHOD",
+            Self::LIST_EXTEND => "LIST_EXTEND",
+            Self::SET_UPDATE => "SET_UPDATE",
+            Self::DICT_MERGE => "DICT_MERGE",
+            Self::DICT_UPDATE => "DICT_UPDATE",
+            Self::PRECALL => "PRECALL",
+            Self::CALL => "CALL",
+            Self::KW_NAMES => "KW_NAMES",
+            Self::POP_JUMP_BACKWARD_IF_NOT_NONE => "POP_JUMP_BACKWARD_IF_NOT_NONE",
+            Self::POP_JUMP_BACKWARD_IF_NONE => "POP_JUMP_BACKWARD_IF_NONE",
+            Self::POP_JUMP_BACKWARD_IF_FALSE => "POP_JUMP_BACKWARD_IF_FALSE",
+            Self::POP_JUMP_BACKWARD_IF_TRUE => "POP_JUMP_BACKWARD_IF_TRUE",
+            Self::BINARY_OP_ADAPTIVE => "BINARY_OP_ADAPTIVE",
+            Self::BINARY_OP_ADD_FLOAT => "BINARY_OP_ADD_FLOAT",
+            Self::BINARY_OP_ADD_INT => "BINARY_OP_ADD_INT",
+            Self::BINARY_OP_ADD_UNICODE => "BINARY_OP_ADD_UNICODE",
+            Self::BINARY_OP_INPLACE_ADD_UNICODE => "BINARY_OP_INPLACE_ADD_UNICODE",
+            Self::BINARY_OP_MULTIPLY_FLOAT => "BINARY_OP_MULTIPLY_FLOAT",
+            Self::BINARY_OP_MULTIPLY_INT => "BINARY_OP_MULTIPLY_INT",
+            Self::BINARY_OP_SUBTRACT_FLOAT => "BINARY_OP_SUBTRACT_FLOAT",
+            Self::BINARY_OP_SUBTRACT_INT => "BINARY_OP_SUBTRACT_INT",
+            Self::BINARY_SUBSCR_ADAPTIVE => "BINARY_SUBSCR_ADAPTIVE",
+            Self::BINARY_SUBSCR_DICT => "BINARY_SUBSCR_DICT",
+            Self::BINARY_SUBSCR_GETITEM => "BINARY_SUBSCR_GETITEM",
+            Self::BINARY_SUBSCR_LIST_INT => "BINARY_SUBSCR_LIST_INT",
+            Self::BINARY_SUBSCR_TUPLE_INT => "BINARY_SUBSCR_TUPLE_INT",
+            Self::CALL_ADAPTIVE => "CALL_ADAPTIVE",
+            Self::CALL_PY_EXACT_ARGS => "CALL_PY_EXACT_ARGS",
+            Self::CALL_PY_WITH_DEFAULTS => "CALL_PY_WITH_DEFAULTS",
+            Self::COMPARE_OP_ADAPTIVE => "COMPARE_OP_ADAPTIVE",
+            Self::COMPARE_OP_FLOAT_JUMP => "COMPARE_OP_FLOAT_JUMP",
+            Self::COMPARE_OP_INT_JUMP => "COMPARE_OP_INT_JUMP",
+            Self::COMPARE_OP_STR_JUMP => "COMPARE_OP_STR_JUMP",
+            Self::EXTENDED_ARG_QUICK => "EXTENDED_ARG_QUICK",
+            Self::JUMP_BACKWARD_QUICK => "JUMP_BACKWARD_QUICK",
+            Self::LOAD_ATTR_ADAPTIVE => "LOAD_ATTR_ADAPTIVE",
+            Self::LOAD_ATTR_INSTANCE_VALUE => "LOAD_ATTR_INSTANCE_VALUE",
+            Self::LOAD_ATTR_MODULE => "LOAD_ATTR_MODULE",
+            Self::LOAD_ATTR_SLOT => "LOAD_ATTR_SLOT",
+            Self::LOAD_ATTR_WITH_HINT => "LOAD_ATTR_WITH_HINT",
+            Self::LOAD_CONST__LOAD_FAST => "LOAD_CONST__LOAD_FAST",
+            Self::LOAD_FAST__LOAD_CONST => "LOAD_FAST__LOAD_CONST",
+            Self::LOAD_FAST__LOAD_FAST => "LOAD_FAST__LOAD_FAST",
+            Self::LOAD_GLOBAL_ADAPTIVE => "LOAD_GLOBAL_ADAPTIVE",
+            Self::LOAD_GLOBAL_BUILTIN => "LOAD_GLOBAL_BUILTIN",
+            Self::LOAD_GLOBAL_MODULE => "LOAD_GLOBAL_MODULE",
+            Self::LOAD_METHOD_ADAPTIVE => "LOAD_METHOD_ADAPTIVE",
+            Self::LOAD_METHOD_CLASS => "LOAD_METHOD_CLASS",
+            Self::LOAD_METHOD_MODULE => "LOAD_METHOD_MODULE",
+            Self::LOAD_METHOD_NO_DICT => "LOAD_METHOD_NO_DICT",
+            Self::LOAD_METHOD_WITH_DICT => "LOAD_METHOD_WITH_DICT",
+            Self::LOAD_METHOD_WITH_VALUES => "LOAD_METHOD_WITH_VALUES",
+            Self::PRECALL_ADAPTIVE => "PRECALL_ADAPTIVE",
+            Self::PRECALL_BOUND_METHOD => "PRECALL_BOUND_METHOD",
+            Self::PRECALL_BUILTIN_CLASS => "PRECALL_BUILTIN_CLASS",
+            Self::PRECALL_BUILTIN_FAST_WITH_KEYWORDS => "PRECALL_BUILTIN_FAST_WITH_KEYWORDS",
+            Self::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                "PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS"
+            }
+            Self::PRECALL_NO_KW_BUILTIN_FAST => "PRECALL_NO_KW_BUILTIN_FAST",
+            Self::PRECALL_NO_KW_BUILTIN_O => "PRECALL_NO_KW_BUILTIN_O",
+            Self::PRECALL_NO_KW_ISINSTANCE => "PRECALL_NO_KW_ISINSTANCE",
+            Self::PRECALL_NO_KW_LEN => "PRECALL_NO_KW_LEN",
+            Self::PRECALL_NO_KW_LIST_APPEND => "PRECALL_NO_KW_LIST_APPEND",
+            Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST => "PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST",
+            Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS => {
+                "PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS"
+            }
+            Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_O => "PRECALL_NO_KW_METHOD_DESCRIPTOR_O",
+            Self::PRECALL_NO_KW_STR_1 => "PRECALL_NO_KW_STR_1",
+            Self::PRECALL_NO_KW_TUPLE_1 => "PRECALL_NO_KW_TUPLE_1",
+            Self::PRECALL_NO_KW_TYPE_1 => "PRECALL_NO_KW_TYPE_1",
+            Self::PRECALL_PYFUNC => "PRECALL_PYFUNC",
+            Self::RESUME_QUICK => "RESUME_QUICK",
+            Self::STORE_ATTR_ADAPTIVE => "STORE_ATTR_ADAPTIVE",
+            Self::STORE_ATTR_INSTANCE_VALUE => "STORE_ATTR_INSTANCE_VALUE",
+            Self::STORE_ATTR_SLOT => "STORE_ATTR_SLOT",
+            Self::STORE_ATTR_WITH_HINT => "STORE_ATTR_WITH_HINT",
+            Self::STORE_FAST__LOAD_FAST => "STORE_FAST__LOAD_FAST",
+            Self::STORE_FAST__STORE_FAST => "STORE_FAST__STORE_FAST",
+            Self::STORE_SUBSCR_ADAPTIVE => "STORE_SUBSCR_ADAPTIVE",
+            Self::STORE_SUBSCR_DICT => "STORE_SUBSCR_DICT",
+            Self::STORE_SUBSCR_LIST_INT => "STORE_SUBSCR_LIST_INT",
+            Self::UNPACK_SEQUENCE_ADAPTIVE => "UNPACK_SEQUENCE_ADAPTIVE",
+            Self::UNPACK_SEQUENCE_LIST => "UNPACK_SEQUENCE_LIST",
+            Self::UNPACK_SEQUENCE_TUPLE => "UNPACK_SEQUENCE_TUPLE",
+            Self::UNPACK_SEQUENCE_TWO_TUPLE => "UNPACK_SEQUENCE_TWO_TUPLE",
+        }
+    }
+
+    /// The base mnemonic of a specialized mnemonic. Other mnemonics are returned unchanged.
+    pub fn deoptimize(&self) -> Mnemonic {
+        match self {
+            Self::BINARY_SUBSCR_ADAPTIVE
+            | Self::BINARY_SUBSCR_DICT
+            | Self::BINARY_SUBSCR_GETITEM
+            | Self::BINARY_SUBSCR_LIST_INT
+            | Self::BINARY_SUBSCR_TUPLE_INT => Self::BINARY_SUBSCR,
+            Self::STORE_SUBSCR_ADAPTIVE | Self::STORE_SUBSCR_DICT | Self::STORE_SUBSCR_LIST_INT => {
+                Self::STORE_SUBSCR
+            }
+            Self::UNPACK_SEQUENCE_ADAPTIVE
+            | Self::UNPACK_SEQUENCE_LIST
+            | Self::UNPACK_SEQUENCE_TUPLE
+            | Self::UNPACK_SEQUENCE_TWO_TUPLE => Self::UNPACK_SEQUENCE,
+            Self::STORE_ATTR_ADAPTIVE
+            | Self::STORE_ATTR_INSTANCE_VALUE
+            | Self::STORE_ATTR_SLOT
+            | Self::STORE_ATTR_WITH_HINT => Self::STORE_ATTR,
+            Self::LOAD_CONST__LOAD_FAST => Self::LOAD_CONST,
+            Self::LOAD_ATTR_ADAPTIVE
+            | Self::LOAD_ATTR_INSTANCE_VALUE
+            | Self::LOAD_ATTR_MODULE
+            | Self::LOAD_ATTR_SLOT
+            | Self::LOAD_ATTR_WITH_HINT => Self::LOAD_ATTR,
+            Self::COMPARE_OP_ADAPTIVE
+            | Self::COMPARE_OP_FLOAT_JUMP
+            | Self::COMPARE_OP_INT_JUMP
+            | Self::COMPARE_OP_STR_JUMP => Self::COMPARE_OP,
+            Self::LOAD_GLOBAL_ADAPTIVE | Self::LOAD_GLOBAL_BUILTIN | Self::LOAD_GLOBAL_MODULE => {
+                Self::LOAD_GLOBAL
+            }
+            Self::BINARY_OP_ADAPTIVE
+            | Self::BINARY_OP_ADD_FLOAT
+            | Self::BINARY_OP_ADD_INT
+            | Self::BINARY_OP_ADD_UNICODE
+            | Self::BINARY_OP_INPLACE_ADD_UNICODE
+            | Self::BINARY_OP_MULTIPLY_FLOAT
+            | Self::BINARY_OP_MULTIPLY_INT
+            | Self::BINARY_OP_SUBTRACT_FLOAT
+            | Self::BINARY_OP_SUBTRACT_INT => Self::BINARY_OP,
+            Self::LOAD_FAST__LOAD_CONST | Self::LOAD_FAST__LOAD_FAST => Self::LOAD_FAST,
+            Self::STORE_FAST__LOAD_FAST | Self::STORE_FAST__STORE_FAST => Self::STORE_FAST,
+            Self::JUMP_BACKWARD_QUICK => Self::JUMP_BACKWARD,
+            Self::EXTENDED_ARG_QUICK => Self::EXTENDED_ARG,
+            Self::RESUME_QUICK => Self::RESUME,
+            Self::LOAD_METHOD_ADAPTIVE
+            | Self::LOAD_METHOD_CLASS
+            | Self::LOAD_METHOD_MODULE
+            | Self::LOAD_METHOD_NO_DICT
+            | Self::LOAD_METHOD_WITH_DICT
+            | Self::LOAD_METHOD_WITH_VALUES => Self::LOAD_METHOD,
+            Self::PRECALL_ADAPTIVE
+            | Self::PRECALL_BOUND_METHOD
+            | Self::PRECALL_BUILTIN_CLASS
+            | Self::PRECALL_BUILTIN_FAST_WITH_KEYWORDS
+            | Self::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            | Self::PRECALL_NO_KW_BUILTIN_FAST
+            | Self::PRECALL_NO_KW_BUILTIN_O
+            | Self::PRECALL_NO_KW_ISINSTANCE
+            | Self::PRECALL_NO_KW_LEN
+            | Self::PRECALL_NO_KW_LIST_APPEND
+            | Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST
+            | Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            | Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_O
+            | Self::PRECALL_NO_KW_STR_1
+            | Self::PRECALL_NO_KW_TUPLE_1
+            | Self::PRECALL_NO_KW_TYPE_1
+            | Self::PRECALL_PYFUNC => Self::PRECALL,
+            Self::CALL_ADAPTIVE | Self::CALL_PY_EXACT_ARGS | Self::CALL_PY_WITH_DEFAULTS => {
+                Self::CALL
+            }
+            _ => *self,
+        }
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = ParseMnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CACHE" => Ok(Self::CACHE),
+            "POP_TOP" => Ok(Self::POP_TOP),
+            "PUSH_NULL" => Ok(Self::PUSH_NULL),
+            "NOP" => Ok(Self::NOP),
+            "UNARY_POSITIVE" => Ok(Self::UNARY_POSITIVE),
+            "UNARY_NEGATIVE" => Ok(Self::UNARY_NEGATIVE),
+            "UNARY_NOT" => Ok(Self::UNARY_NOT),
+            "UNARY_INVERT" => Ok(Self::UNARY_INVERT),
+            "BINARY_SUBSCR" => Ok(Self::BINARY_SUBSCR),
+            "GET_LEN" => Ok(Self::GET_LEN),
+            "MATCH_MAPPING" => Ok(Self::MATCH_MAPPING),
+            "MATCH_SEQUENCE" => Ok(Self::MATCH_SEQUENCE),
+            "MATCH_KEYS" => Ok(Self::MATCH_KEYS),
+            "PUSH_EXC_INFO" => Ok(Self::PUSH_EXC_INFO),
+            "CHECK_EXC_MATCH" => Ok(Self::CHECK_EXC_MATCH),
+            "CHECK_EG_MATCH" => Ok(Self::CHECK_EG_MATCH),
+            "WITH_EXCEPT_START" => Ok(Self::WITH_EXCEPT_START),
+            "GET_AITER" => Ok(Self::GET_AITER),
+            "GET_ANEXT" => Ok(Self::GET_ANEXT),
+            "BEFORE_ASYNC_WITH" => Ok(Self::BEFORE_ASYNC_WITH),
+            "BEFORE_WITH" => Ok(Self::BEFORE_WITH),
+            "END_ASYNC_FOR" => Ok(Self::END_ASYNC_FOR),
+            "STORE_SUBSCR" => Ok(Self::STORE_SUBSCR),
+            "DELETE_SUBSCR" => Ok(Self::DELETE_SUBSCR),
+            "GET_ITER" => Ok(Self::GET_ITER),
+            "GET_YIELD_FROM_ITER" => Ok(Self::GET_YIELD_FROM_ITER),
+            "PRINT_EXPR" => Ok(Self::PRINT_EXPR),
+            "LOAD_BUILD_CLASS" => Ok(Self::LOAD_BUILD_CLASS),
+            "LOAD_ASSERTION_ERROR" => Ok(Self::LOAD_ASSERTION_ERROR),
+            "RETURN_GENERATOR" => Ok(Self::RETURN_GENERATOR),
+            "LIST_TO_TUPLE" => Ok(Self::LIST_TO_TUPLE),
+            "RETURN_VALUE" => Ok(Self::RETURN_VALUE),
+            "IMPORT_STAR" => Ok(Self::IMPORT_STAR),
+            "SETUP_ANNOTATIONS" => Ok(Self::SETUP_ANNOTATIONS),
+            "YIELD_VALUE" => Ok(Self::YIELD_VALUE),
+            "ASYNC_GEN_WRAP" => Ok(Self::ASYNC_GEN_WRAP),
+            "PREP_RERAISE_STAR" => Ok(Self::PREP_RERAISE_STAR),
+            "POP_EXCEPT" => Ok(Self::POP_EXCEPT),
+            "STORE_NAME" => Ok(Self::STORE_NAME),
+            "DELETE_NAME" => Ok(Self::DELETE_NAME),
+            "UNPACK_SEQUENCE" => Ok(Self::UNPACK_SEQUENCE),
+            "FOR_ITER" => Ok(Self::FOR_ITER),
+            "UNPACK_EX" => Ok(Self::UNPACK_EX),
+            "STORE_ATTR" => Ok(Self::STORE_ATTR),
+            "DELETE_ATTR" => Ok(Self::DELETE_ATTR),
+            "STORE_GLOBAL" => Ok(Self::STORE_GLOBAL),
+            "DELETE_GLOBAL" => Ok(Self::DELETE_GLOBAL),
+            "SWAP" => Ok(Self::SWAP),
+            "LOAD_CONST" => Ok(Self::LOAD_CONST),
+            "LOAD_NAME" => Ok(Self::LOAD_NAME),
+            "BUILD_TUPLE" => Ok(Self::BUILD_TUPLE),
+            "BUILD_LIST" => Ok(Self::BUILD_LIST),
+            "BUILD_SET" => Ok(Self::BUILD_SET),
+            "BUILD_MAP" => Ok(Self::BUILD_MAP),
+            "LOAD_ATTR" => Ok(Self::LOAD_ATTR),
+            "COMPARE_OP" => Ok(Self::COMPARE_OP),
+            "IMPORT_NAME" => Ok(Self::IMPORT_NAME),
+            "IMPORT_FROM" => Ok(Self::IMPORT_FROM),
+            "JUMP_FORWARD" => Ok(Self::JUMP_FORWARD),
+            "JUMP_IF_FALSE_OR_POP" => Ok(Self::JUMP_IF_FALSE_OR_POP),
+            "JUMP_IF_TRUE_OR_POP" => Ok(Self::JUMP_IF_TRUE_OR_POP),
+            "POP_JUMP_FORWARD_IF_FALSE" => Ok(Self::POP_JUMP_FORWARD_IF_FALSE),
+            "POP_JUMP_FORWARD_IF_TRUE" => Ok(Self::POP_JUMP_FORWARD_IF_TRUE),
+            "LOAD_GLOBAL" => Ok(Self::LOAD_GLOBAL),
+            "IS_OP" => Ok(Self::IS_OP),
+            "CONTAINS_OP" => Ok(Self::CONTAINS_OP),
+            "RERAISE" => Ok(Self::RERAISE),
+            "COPY" => Ok(Self::COPY),
+            "BINARY_OP" => Ok(Self::BINARY_OP),
+            "SEND" => Ok(Self::SEND),
+            "LOAD_FAST" => Ok(Self::LOAD_FAST),
+            "STORE_FAST" => Ok(Self::STORE_FAST),
+            "DELETE_FAST" => Ok(Self::DELETE_FAST),
+            "POP_JUMP_FORWARD_IF_NOT_NONE" => Ok(Self::POP_JUMP_FORWARD_IF_NOT_NONE),
+            "POP_JUMP_FORWARD_IF_NONE" => Ok(Self::POP_JUMP_FORWARD_IF_NONE),
+            "RAISE_VARARGS" => Ok(Self::RAISE_VARARGS),
+            "GET_AWAITABLE" => Ok(Self::GET_AWAITABLE),
+            "MAKE_FUNCTION" => Ok(Self::MAKE_FUNCTION),
+            "BUILD_SLICE" => Ok(Self::BUILD_SLICE),
+            "JUMP_BACKWARD_NO_INTERRUPT" => Ok(Self::JUMP_BACKWARD_NO_INTERRUPT),
+            "MAKE_CELL" => Ok(Self::MAKE_CELL),
+            "LOAD_CLOSURE" => Ok(Self::LOAD_CLOSURE),
+            "LOAD_DEREF" => Ok(Self::LOAD_DEREF),
+            "STORE_DEREF" => Ok(Self::STORE_DEREF),
+            "DELETE_DEREF" => Ok(Self::DELETE_DEREF),
+            "JUMP_BACKWARD" => Ok(Self::JUMP_BACKWARD),
+            "CALL_FUNCTION_EX" => Ok(Self::CALL_FUNCTION_EX),
+            "EXTENDED_ARG" => Ok(Self::EXTENDED_ARG),
+            "LIST_APPEND" => Ok(Self::LIST_APPEND),
+            "SET_ADD" => Ok(Self::SET_ADD),
+            "MAP_ADD" => Ok(Self::MAP_ADD),
+            "LOAD_CLASSDEREF" => Ok(Self::LOAD_CLASSDEREF),
+            "COPY_FREE_VARS" => Ok(Self::COPY_FREE_VARS),
+            "RESUME" => Ok(Self::RESUME),
+            "MATCH_CLASS" => Ok(Self::MATCH_CLASS),
+            "FORMAT_VALUE" => Ok(Self::FORMAT_VALUE),
+            "BUILD_CONST_KEY_MAP" => Ok(Self::BUILD_CONST_KEY_MAP),
+            "BUILD_STRING" => Ok(Self::BUILD_STRING),
+            "LOAD_METHOD" => Ok(Self::LOAD_METHOD),
+            "LIST_EXTEND" => Ok(Self::LIST_EXTEND),
+            "SET_UPDATE" => Ok(Self::SET_UPDATE),
+            "DICT_MERGE" => Ok(Self::DICT_MERGE),
+            "DICT_UPDATE" => Ok(Self::DICT_UPDATE),
+            "PRECALL" => Ok(Self::PRECALL),
+            "CALL" => Ok(Self::CALL),
+            "KW_NAMES" => Ok(Self::KW_NAMES),
+            "POP_JUMP_BACKWARD_IF_NOT_NONE" => Ok(Self::POP_JUMP_BACKWARD_IF_NOT_NONE),
+            "POP_JUMP_BACKWARD_IF_NONE" => Ok(Self::POP_JUMP_BACKWARD_IF_NONE),
+            "POP_JUMP_BACKWARD_IF_FALSE" => Ok(Self::POP_JUMP_BACKWARD_IF_FALSE),
+            "POP_JUMP_BACKWARD_IF_TRUE" => Ok(Self::POP_JUMP_BACKWARD_IF_TRUE),
+            "BINARY_OP_ADAPTIVE" => Ok(Self::BINARY_OP_ADAPTIVE),
+            "BINARY_OP_ADD_FLOAT" => Ok(Self::BINARY_OP_ADD_FLOAT),
+            "BINARY_OP_ADD_INT" => Ok(Self::BINARY_OP_ADD_INT),
+            "BINARY_OP_ADD_UNICODE" => Ok(Self::BINARY_OP_ADD_UNICODE),
+            "BINARY_OP_INPLACE_ADD_UNICODE" => Ok(Self::BINARY_OP_INPLACE_ADD_UNICODE),
+            "BINARY_OP_MULTIPLY_FLOAT" => Ok(Self::BINARY_OP_MULTIPLY_FLOAT),
+            "BINARY_OP_MULTIPLY_INT" => Ok(Self::BINARY_OP_MULTIPLY_INT),
+            "BINARY_OP_SUBTRACT_FLOAT" => Ok(Self::BINARY_OP_SUBTRACT_FLOAT),
+            "BINARY_OP_SUBTRACT_INT" => Ok(Self::BINARY_OP_SUBTRACT_INT),
+            "BINARY_SUBSCR_ADAPTIVE" => Ok(Self::BINARY_SUBSCR_ADAPTIVE),
+            "BINARY_SUBSCR_DICT" => Ok(Self::BINARY_SUBSCR_DICT),
+            "BINARY_SUBSCR_GETITEM" => Ok(Self::BINARY_SUBSCR_GETITEM),
+            "BINARY_SUBSCR_LIST_INT" => Ok(Self::BINARY_SUBSCR_LIST_INT),
+            "BINARY_SUBSCR_TUPLE_INT" => Ok(Self::BINARY_SUBSCR_TUPLE_INT),
+            "CALL_ADAPTIVE" => Ok(Self::CALL_ADAPTIVE),
+            "CALL_PY_EXACT_ARGS" => Ok(Self::CALL_PY_EXACT_ARGS),
+            "CALL_PY_WITH_DEFAULTS" => Ok(Self::CALL_PY_WITH_DEFAULTS),
+            "COMPARE_OP_ADAPTIVE" => Ok(Self::COMPARE_OP_ADAPTIVE),
+            "COMPARE_OP_FLOAT_JUMP" => Ok(Self::COMPARE_OP_FLOAT_JUMP),
+            "COMPARE_OP_INT_JUMP" => Ok(Self::COMPARE_OP_INT_JUMP),
+            "COMPARE_OP_STR_JUMP" => Ok(Self::COMPARE_OP_STR_JUMP),
+            "EXTENDED_ARG_QUICK" => Ok(Self::EXTENDED_ARG_QUICK),
+            "JUMP_BACKWARD_QUICK" => Ok(Self::JUMP_BACKWARD_QUICK),
+            "LOAD_ATTR_ADAPTIVE" => Ok(Self::LOAD_ATTR_ADAPTIVE),
+            "LOAD_ATTR_INSTANCE_VALUE" => Ok(Self::LOAD_ATTR_INSTANCE_VALUE),
+            "LOAD_ATTR_MODULE" => Ok(Self::LOAD_ATTR_MODULE),
+            "LOAD_ATTR_SLOT" => Ok(Self::LOAD_ATTR_SLOT),
+            "LOAD_ATTR_WITH_HINT" => Ok(Self::LOAD_ATTR_WITH_HINT),
+            "LOAD_CONST__LOAD_FAST" => Ok(Self::LOAD_CONST__LOAD_FAST),
+            "LOAD_FAST__LOAD_CONST" => Ok(Self::LOAD_FAST__LOAD_CONST),
+            "LOAD_FAST__LOAD_FAST" => Ok(Self::LOAD_FAST__LOAD_FAST),
+            "LOAD_GLOBAL_ADAPTIVE" => Ok(Self::LOAD_GLOBAL_ADAPTIVE),
+            "LOAD_GLOBAL_BUILTIN" => Ok(Self::LOAD_GLOBAL_BUILTIN),
+            "LOAD_GLOBAL_MODULE" => Ok(Self::LOAD_GLOBAL_MODULE),
+            "LOAD_METHOD_ADAPTIVE" => Ok(Self::LOAD_METHOD_ADAPTIVE),
+            "LOAD_METHOD_CLASS" => Ok(Self::LOAD_METHOD_CLASS),
+            "LOAD_METHOD_MODULE" => Ok(Self::LOAD_METHOD_MODULE),
+            "LOAD_METHOD_NO_DICT" => Ok(Self::LOAD_METHOD_NO_DICT),
+            "LOAD_METHOD_WITH_DICT" => Ok(Self::LOAD_METHOD_WITH_DICT),
+            "LOAD_METHOD_WITH_VALUES" => Ok(Self::LOAD_METHOD_WITH_VALUES),
+            "PRECALL_ADAPTIVE" => Ok(Self::PRECALL_ADAPTIVE),
+            "PRECALL_BOUND_METHOD" => Ok(Self::PRECALL_BOUND_METHOD),
+            "PRECALL_BUILTIN_CLASS" => Ok(Self::PRECALL_BUILTIN_CLASS),
+            "PRECALL_BUILTIN_FAST_WITH_KEYWORDS" => Ok(Self::PRECALL_BUILTIN_FAST_WITH_KEYWORDS),
+            "PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS" => {
+                Ok(Self::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS)
+            }
+            "PRECALL_NO_KW_BUILTIN_FAST" => Ok(Self::PRECALL_NO_KW_BUILTIN_FAST),
+            "PRECALL_NO_KW_BUILTIN_O" => Ok(Self::PRECALL_NO_KW_BUILTIN_O),
+            "PRECALL_NO_KW_ISINSTANCE" => Ok(Self::PRECALL_NO_KW_ISINSTANCE),
+            "PRECALL_NO_KW_LEN" => Ok(Self::PRECALL_NO_KW_LEN),
+            "PRECALL_NO_KW_LIST_APPEND" => Ok(Self::PRECALL_NO_KW_LIST_APPEND),
+            "PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST" => {
+                Ok(Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST)
+            }
+            "PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS" => {
+                Ok(Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS)
+            }
+            "PRECALL_NO_KW_METHOD_DESCRIPTOR_O" => Ok(Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_O),
+            "PRECALL_NO_KW_STR_1" => Ok(Self::PRECALL_NO_KW_STR_1),
+            "PRECALL_NO_KW_TUPLE_1" => Ok(Self::PRECALL_NO_KW_TUPLE_1),
+            "PRECALL_NO_KW_TYPE_1" => Ok(Self::PRECALL_NO_KW_TYPE_1),
+            "PRECALL_PYFUNC" => Ok(Self::PRECALL_PYFUNC),
+            "RESUME_QUICK" => Ok(Self::RESUME_QUICK),
+            "STORE_ATTR_ADAPTIVE" => Ok(Self::STORE_ATTR_ADAPTIVE),
+            "STORE_ATTR_INSTANCE_VALUE" => Ok(Self::STORE_ATTR_INSTANCE_VALUE),
+            "STORE_ATTR_SLOT" => Ok(Self::STORE_ATTR_SLOT),
+            "STORE_ATTR_WITH_HINT" => Ok(Self::STORE_ATTR_WITH_HINT),
+            "STORE_FAST__LOAD_FAST" => Ok(Self::STORE_FAST__LOAD_FAST),
+            "STORE_FAST__STORE_FAST" => Ok(Self::STORE_FAST__STORE_FAST),
+            "STORE_SUBSCR_ADAPTIVE" => Ok(Self::STORE_SUBSCR_ADAPTIVE),
+            "STORE_SUBSCR_DICT" => Ok(Self::STORE_SUBSCR_DICT),
+            "STORE_SUBSCR_LIST_INT" => Ok(Self::STORE_SUBSCR_LIST_INT),
+            "UNPACK_SEQUENCE_ADAPTIVE" => Ok(Self::UNPACK_SEQUENCE_ADAPTIVE),
+            "UNPACK_SEQUENCE_LIST" => Ok(Self::UNPACK_SEQUENCE_LIST),
+            "UNPACK_SEQUENCE_TUPLE" => Ok(Self::UNPACK_SEQUENCE_TUPLE),
+            "UNPACK_SEQUENCE_TWO_TUPLE" => Ok(Self::UNPACK_SEQUENCE_TWO_TUPLE),
+            _ => Err(ParseMnemonicError(s.to_string())),
+        }
+    }
+}
+
+/// Opcodes taken from https://github.com/python/cpython/blob/3.11/Include/opcode.h.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Primitive)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum Standard {
+    CACHE = 0,
+    POP_TOP = 1,
+    PUSH_NULL = 2,
+
+    NOP = 9,
+    UNARY_POSITIVE = 10,
+    UNARY_NEGATIVE = 11,
+    UNARY_NOT = 12,
+
+    UNARY_INVERT = 15,
+
+    BINARY_SUBSCR = 25,
+
+    GET_LEN = 30,
+    MATCH_MAPPING = 31,
+    MATCH_SEQUENCE = 32,
+    MATCH_KEYS = 33,
+
+    PUSH_EXC_INFO = 35,
+    CHECK_EXC_MATCH = 36,
+    CHECK_EG_MATCH = 37,
+
+    WITH_EXCEPT_START = 49,
+    GET_AITER = 50,
+    GET_ANEXT = 51,
+    BEFORE_ASYNC_WITH = 52,
+    BEFORE_WITH = 53,
+    END_ASYNC_FOR = 54,
+
+    STORE_SUBSCR = 60,
+    DELETE_SUBSCR = 61,
+
+    GET_ITER = 68,
+    GET_YIELD_FROM_ITER = 69,
+    PRINT_EXPR = 70,
+    LOAD_BUILD_CLASS = 71,
+
+    LOAD_ASSERTION_ERROR = 74,
+    RETURN_GENERATOR = 75,
+
+    LIST_TO_TUPLE = 82,
+    RETURN_VALUE = 83,
+    IMPORT_STAR = 84,
+    SETUP_ANNOTATIONS = 85,
+    YIELD_VALUE = 86,
+    ASYNC_GEN_WRAP = 87,
+    PREP_RERAISE_STAR = 88,
+    POP_EXCEPT = 89,
+
+    // Opcodes with arguments
+    STORE_NAME = 90,
+    DELETE_NAME = 91,
+    UNPACK_SEQUENCE = 92,
+    FOR_ITER = 93,
+    UNPACK_EX = 94,
+    STORE_ATTR = 95,
+    DELETE_ATTR = 96,
+    STORE_GLOBAL = 97,
+    DELETE_GLOBAL = 98,
+    SWAP = 99,
+    LOAD_CONST = 100,
+    LOAD_NAME = 101,
+    BUILD_TUPLE = 102,
+    BUILD_LIST = 103,
+    BUILD_SET = 104,
+    BUILD_MAP = 105,
+    LOAD_ATTR = 106,
+    COMPARE_OP = 107,
+    IMPORT_NAME = 108,
+    IMPORT_FROM = 109,
+    JUMP_FORWARD = 110,
+    JUMP_IF_FALSE_OR_POP = 111,
+    JUMP_IF_TRUE_OR_POP = 112,
+
+    POP_JUMP_FORWARD_IF_FALSE = 114,
+    POP_JUMP_FORWARD_IF_TRUE = 115,
+    LOAD_GLOBAL = 116,
+    IS_OP = 117,
+    CONTAINS_OP = 118,
+    RERAISE = 119,
+    COPY = 120,
+
+    BINARY_OP = 122,
+    SEND = 123,
+    LOAD_FAST = 124,
+    STORE_FAST = 125,
+    DELETE_FAST = 126,
+
+    POP_JUMP_FORWARD_IF_NOT_NONE = 128,
+    POP_JUMP_FORWARD_IF_NONE = 129,
+    RAISE_VARARGS = 130,
+    GET_AWAITABLE = 131,
+    MAKE_FUNCTION = 132,
+    BUILD_SLICE = 133,
+    JUMP_BACKWARD_NO_INTERRUPT = 134,
+    MAKE_CELL = 135,
+    LOAD_CLOSURE = 136,
+    LOAD_DEREF = 137,
+    STORE_DEREF = 138,
+    DELETE_DEREF = 139,
+    JUMP_BACKWARD = 140,
+
+    CALL_FUNCTION_EX = 142,
+
+    EXTENDED_ARG = 144,
+    LIST_APPEND = 145,
+    SET_ADD = 146,
+    MAP_ADD = 147,
+    LOAD_CLASSDEREF = 148,
+    COPY_FREE_VARS = 149,
+
+    RESUME = 151,
+    MATCH_CLASS = 152,
+
+    FORMAT_VALUE = 155,
+    BUILD_CONST_KEY_MAP = 156,
+    BUILD_STRING = 157,
+
+    LOAD_METHOD = 160,
+
+    LIST_EXTEND = 162,
+    SET_UPDATE = 163,
+    DICT_MERGE = 164,
+    DICT_UPDATE = 165,
+    PRECALL = 166,
+
+    CALL = 171,
+    KW_NAMES = 172,
+    POP_JUMP_BACKWARD_IF_NOT_NONE = 173,
+    POP_JUMP_BACKWARD_IF_NONE = 174,
+    POP_JUMP_BACKWARD_IF_FALSE = 175,
+    POP_JUMP_BACKWARD_IF_TRUE = 176,
+
+    // Specialized opcodes, which only appear in code that has been executed
+    BINARY_OP_ADAPTIVE = 3,
+    BINARY_OP_ADD_FLOAT = 4,
+    BINARY_OP_ADD_INT = 5,
+    BINARY_OP_ADD_UNICODE = 6,
+    BINARY_OP_INPLACE_ADD_UNICODE = 7,
+    BINARY_OP_MULTIPLY_FLOAT = 8,
+    BINARY_OP_MULTIPLY_INT = 13,
+    BINARY_OP_SUBTRACT_FLOAT = 14,
+    BINARY_OP_SUBTRACT_INT = 16,
+    BINARY_SUBSCR_ADAPTIVE = 17,
+    BINARY_SUBSCR_DICT = 18,
+    BINARY_SUBSCR_GETITEM = 19,
+    BINARY_SUBSCR_LIST_INT = 20,
+    BINARY_SUBSCR_TUPLE_INT = 21,
+    CALL_ADAPTIVE = 22,
+    CALL_PY_EXACT_ARGS = 23,
+    CALL_PY_WITH_DEFAULTS = 24,
+    COMPARE_OP_ADAPTIVE = 26,
+    COMPARE_OP_FLOAT_JUMP = 27,
+    COMPARE_OP_INT_JUMP = 28,
+    COMPARE_OP_STR_JUMP = 29,
+    EXTENDED_ARG_QUICK = 34,
+    JUMP_BACKWARD_QUICK = 38,
+    LOAD_ATTR_ADAPTIVE = 39,
+    LOAD_ATTR_INSTANCE_VALUE = 40,
+    LOAD_ATTR_MODULE = 41,
+    LOAD_ATTR_SLOT = 42,
+    LOAD_ATTR_WITH_HINT = 43,
+    LOAD_CONST__LOAD_FAST = 44,
+    LOAD_FAST__LOAD_CONST = 45,
+    LOAD_FAST__LOAD_FAST = 46,
+    LOAD_GLOBAL_ADAPTIVE = 47,
+    LOAD_GLOBAL_BUILTIN = 48,
+    LOAD_GLOBAL_MODULE = 55,
+    LOAD_METHOD_ADAPTIVE = 56,
+    LOAD_METHOD_CLASS = 57,
+    LOAD_METHOD_MODULE = 58,
+    LOAD_METHOD_NO_DICT = 59,
+    LOAD_METHOD_WITH_DICT = 62,
+    LOAD_METHOD_WITH_VALUES = 63,
+    PRECALL_ADAPTIVE = 64,
+    PRECALL_BOUND_METHOD = 65,
+    PRECALL_BUILTIN_CLASS = 66,
+    PRECALL_BUILTIN_FAST_WITH_KEYWORDS = 67,
+    PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS = 72,
+    PRECALL_NO_KW_BUILTIN_FAST = 73,
+    PRECALL_NO_KW_BUILTIN_O = 76,
+    PRECALL_NO_KW_ISINSTANCE = 77,
+    PRECALL_NO_KW_LEN = 78,
+    PRECALL_NO_KW_LIST_APPEND = 79,
+    PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST = 80,
+    PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS = 81,
+    PRECALL_NO_KW_METHOD_DESCRIPTOR_O = 113,
+    PRECALL_NO_KW_STR_1 = 121,
+    PRECALL_NO_KW_TUPLE_1 = 127,
+    PRECALL_NO_KW_TYPE_1 = 141,
+    PRECALL_PYFUNC = 143,
+    RESUME_QUICK = 150,
+    STORE_ATTR_ADAPTIVE = 153,
+    STORE_ATTR_INSTANCE_VALUE = 154,
+    STORE_ATTR_SLOT = 158,
+    STORE_ATTR_WITH_HINT = 159,
+    STORE_FAST__LOAD_FAST = 161,
+    STORE_FAST__STORE_FAST = 167,
+    STORE_SUBSCR_ADAPTIVE = 168,
+    STORE_SUBSCR_DICT = 169,
+    STORE_SUBSCR_LIST_INT = 170,
+    UNPACK_SEQUENCE_ADAPTIVE = 177,
+    UNPACK_SEQUENCE_LIST = 178,
+    UNPACK_SEQUENCE_TUPLE = 179,
+    UNPACK_SEQUENCE_TWO_TUPLE = 180,
+}
+
+impl From<Mnemonic> for Standard {
+    fn from(mnemonic: Mnemonic) -> Self {
+        match mnemonic {
+            Mnemonic::CACHE => Self::CACHE,
+            Mnemonic::POP_TOP => Self::POP_TOP,
+            Mnemonic::PUSH_NULL => Self::PUSH_NULL,
+
+            Mnemonic::NOP => Self::NOP,
+            Mnemonic::UNARY_POSITIVE => Self::UNARY_POSITIVE,
+            Mnemonic::UNARY_NEGATIVE => Self::UNARY_NEGATIVE,
+            Mnemonic::UNARY_NOT => Self::UNARY_NOT,
+
+            Mnemonic::UNARY_INVERT => Self::UNARY_INVERT,
+
+            Mnemonic::BINARY_SUBSCR => Self::BINARY_SUBSCR,
+
+            Mnemonic::GET_LEN => Self::GET_LEN,
+            Mnemonic::MATCH_MAPPING => Self::MATCH_MAPPING,
+            Mnemonic::MATCH_SEQUENCE => Self::MATCH_SEQUENCE,
+            Mnemonic::MATCH_KEYS => Self::MATCH_KEYS,
+
+            Mnemonic::PUSH_EXC_INFO => Self::PUSH_EXC_INFO,
+            Mnemonic::CHECK_EXC_MATCH => Self::CHECK_EXC_MATCH,
+            Mnemonic::CHECK_EG_MATCH => Self::CHECK_EG_MATCH,
+
+            Mnemonic::WITH_EXCEPT_START => Self::WITH_EXCEPT_START,
+            Mnemonic::GET_AITER => Self::GET_AITER,
+            Mnemonic::GET_ANEXT => Self::GET_ANEXT,
+            Mnemonic::BEFORE_ASYNC_WITH => Self::BEFORE_ASYNC_WITH,
+            Mnemonic::BEFORE_WITH => Self::BEFORE_WITH,
+            Mnemonic::END_ASYNC_FOR => Self::END_ASYNC_FOR,
+
+            Mnemonic::STORE_SUBSCR => Self::STORE_SUBSCR,
+            Mnemonic::DELETE_SUBSCR => Self::DELETE_SUBSCR,
+
+            Mnemonic::GET_ITER => Self::GET_ITER,
+            Mnemonic::GET_YIELD_FROM_ITER => Self::GET_YIELD_FROM_ITER,
+            Mnemonic::PRINT_EXPR => Self::PRINT_EXPR,
+            Mnemonic::LOAD_BUILD_CLASS => Self::LOAD_BUILD_CLASS,
+
+            Mnemonic::LOAD_ASSERTION_ERROR => Self::LOAD_ASSERTION_ERROR,
+            Mnemonic::RETURN_GENERATOR => Self::RETURN_GENERATOR,
+
+            Mnemonic::LIST_TO_TUPLE => Self::LIST_TO_TUPLE,
+            Mnemonic::RETURN_VALUE => Self::RETURN_VALUE,
+            Mnemonic::IMPORT_STAR => Self::IMPORT_STAR,
+            Mnemonic::SETUP_ANNOTATIONS => Self::SETUP_ANNOTATIONS,
+            Mnemonic::YIELD_VALUE => Self::YIELD_VALUE,
+            Mnemonic::ASYNC_GEN_WRAP => Self::ASYNC_GEN_WRAP,
+            Mnemonic::PREP_RERAISE_STAR => Self::PREP_RERAISE_STAR,
+            Mnemonic::POP_EXCEPT => Self::POP_EXCEPT,
+
+            // Opcodes with arguments
+            Mnemonic::STORE_NAME => Self::STORE_NAME,
+            Mnemonic::DELETE_NAME => Self::DELETE_NAME,
+            Mnemonic::UNPACK_SEQUENCE => Self::UNPACK_SEQUENCE,
+            Mnemonic::FOR_ITER => Self::FOR_ITER,
+            Mnemonic::UNPACK_EX => Self::UNPACK_EX,
+            Mnemonic::STORE_ATTR => Self::STORE_ATTR,
+            Mnemonic::DELETE_ATTR => Self::DELETE_ATTR,
+            Mnemonic::STORE_GLOBAL => Self::STORE_GLOBAL,
+            Mnemonic::DELETE_GLOBAL => Self::DELETE_GLOBAL,
+            Mnemonic::SWAP => Self::SWAP,
+            Mnemonic::LOAD_CONST => Self::LOAD_CONST,
+            Mnemonic::LOAD_NAME => Self::LOAD_NAME,
+            Mnemonic::BUILD_TUPLE => Self::BUILD_TUPLE,
+            Mnemonic::BUILD_LIST => Self::BUILD_LIST,
+            Mnemonic::BUILD_SET => Self::BUILD_SET,
+            Mnemonic::BUILD_MAP => Self::BUILD_MAP,
+            Mnemonic::LOAD_ATTR => Self::LOAD_ATTR,
+            Mnemonic::COMPARE_OP => Self::COMPARE_OP,
+            Mnemonic::IMPORT_NAME => Self::IMPORT_NAME,
+            Mnemonic::IMPORT_FROM => Self::IMPORT_FROM,
+            Mnemonic::JUMP_FORWARD => Self::JUMP_FORWARD,
+            Mnemonic::JUMP_IF_FALSE_OR_POP => Self::JUMP_IF_FALSE_OR_POP,
+            Mnemonic::JUMP_IF_TRUE_OR_POP => Self::JUMP_IF_TRUE_OR_POP,
+
+            Mnemonic::POP_JUMP_FORWARD_IF_FALSE => Self::POP_JUMP_FORWARD_IF_FALSE,
+            Mnemonic::POP_JUMP_FORWARD_IF_TRUE => Self::POP_JUMP_FORWARD_IF_TRUE,
+            Mnemonic::LOAD_GLOBAL => Self::LOAD_GLOBAL,
+            Mnemonic::IS_OP => Self::IS_OP,
+            Mnemonic::CONTAINS_OP => Self::CONTAINS_OP,
+            Mnemonic::RERAISE => Self::RERAISE,
+            Mnemonic::COPY => Self::COPY,
+
+            Mnemonic::BINARY_OP => Self::BINARY_OP,
+            Mnemonic::SEND => Self::SEND,
+            Mnemonic::LOAD_FAST => Self::LOAD_FAST,
+            Mnemonic::STORE_FAST => Self::STORE_FAST,
+            Mnemonic::DELETE_FAST => Self::DELETE_FAST,
+
+            Mnemonic::POP_JUMP_FORWARD_IF_NOT_NONE => Self::POP_JUMP_FORWARD_IF_NOT_NONE,
+            Mnemonic::POP_JUMP_FORWARD_IF_NONE => Self::POP_JUMP_FORWARD_IF_NONE,
+            Mnemonic::RAISE_VARARGS => Self::RAISE_VARARGS,
+            Mnemonic::GET_AWAITABLE => Self::GET_AWAITABLE,
+            Mnemonic::MAKE_FUNCTION => Self::MAKE_FUNCTION,
+            Mnemonic::BUILD_SLICE => Self::BUILD_SLICE,
+            Mnemonic::JUMP_BACKWARD_NO_INTERRUPT => Self::JUMP_BACKWARD_NO_INTERRUPT,
+            Mnemonic::MAKE_CELL => Self::MAKE_CELL,
+            Mnemonic::LOAD_CLOSURE => Self::LOAD_CLOSURE,
+            Mnemonic::LOAD_DEREF => Self::LOAD_DEREF,
+            Mnemonic::STORE_DEREF => Self::STORE_DEREF,
+            Mnemonic::DELETE_DEREF => Self::DELETE_DEREF,
+            Mnemonic::JUMP_BACKWARD => Self::JUMP_BACKWARD,
+
+            Mnemonic::CALL_FUNCTION_EX => Self::CALL_FUNCTION_EX,
+
+            Mnemonic::EXTENDED_ARG => Self::EXTENDED_ARG,
+            Mnemonic::LIST_APPEND => Self::LIST_APPEND,
+            Mnemonic::SET_ADD => Self::SET_ADD,
+            Mnemonic::MAP_ADD => Self::MAP_ADD,
+            Mnemonic::LOAD_CLASSDEREF => Self::LOAD_CLASSDEREF,
+            Mnemonic::COPY_FREE_VARS => Self::COPY_FREE_VARS,
+
+            Mnemonic::RESUME => Self::RESUME,
+            Mnemonic::MATCH_CLASS => Self::MATCH_CLASS,
+
+            Mnemonic::FORMAT_VALUE => Self::FORMAT_VALUE,
+            Mnemonic::BUILD_CONST_KEY_MAP => Self::BUILD_CONST_KEY_MAP,
+            Mnemonic::BUILD_STRING => Self::BUILD_STRING,
+
+            Mnemonic::LOAD_METHOD => Self::LOAD_METHOD,
+
+            Mnemonic::LIST_EXTEND => Self::LIST_EXTEND,
+            Mnemonic::SET_UPDATE => Self::SET_UPDATE,
+            Mnemonic::DICT_MERGE => Self::DICT_MERGE,
+            Mnemonic::DICT_UPDATE => Self::DICT_UPDATE,
+            Mnemonic::PRECALL => Self::PRECALL,
+
+            Mnemonic::CALL => Self::CALL,
+            Mnemonic::KW_NAMES => Self::KW_NAMES,
+            Mnemonic::POP_JUMP_BACKWARD_IF_NOT_NONE => Self::POP_JUMP_BACKWARD_IF_NOT_NONE,
+            Mnemonic::POP_JUMP_BACKWARD_IF_NONE => Self::POP_JUMP_BACKWARD_IF_NONE,
+            Mnemonic::POP_JUMP_BACKWARD_IF_FALSE => Self::POP_JUMP_BACKWARD_IF_FALSE,
+            Mnemonic::POP_JUMP_BACKWARD_IF_TRUE => Self::POP_JUMP_BACKWARD_IF_TRUE,
+
+            // Specialized opcodes, which only appear in code that has been executed
+            Mnemonic::BINARY_OP_ADAPTIVE => Self::BINARY_OP_ADAPTIVE,
+            Mnemonic::BINARY_OP_ADD_FLOAT => Self::BINARY_OP_ADD_FLOAT,
+            Mnemonic::BINARY_OP_ADD_INT => Self::BINARY_OP_ADD_INT,
+            Mnemonic::BINARY_OP_ADD_UNICODE => Self::BINARY_OP_ADD_UNICODE,
+            Mnemonic::BINARY_OP_INPLACE_ADD_UNICODE => Self::BINARY_OP_INPLACE_ADD_UNICODE,
+            Mnemonic::BINARY_OP_MULTIPLY_FLOAT => Self::BINARY_OP_MULTIPLY_FLOAT,
+            Mnemonic::BINARY_OP_MULTIPLY_INT => Self::BINARY_OP_MULTIPLY_INT,
+            Mnemonic::BINARY_OP_SUBTRACT_FLOAT => Self::BINARY_OP_SUBTRACT_FLOAT,
+            Mnemonic::BINARY_OP_SUBTRACT_INT => Self::BINARY_OP_SUBTRACT_INT,
+            Mnemonic::BINARY_SUBSCR_ADAPTIVE => Self::BINARY_SUBSCR_ADAPTIVE,
+            Mnemonic::BINARY_SUBSCR_DICT => Self::BINARY_SUBSCR_DICT,
+            Mnemonic::BINARY_SUBSCR_GETITEM => Self::BINARY_SUBSCR_GETITEM,
+            Mnemonic::BINARY_SUBSCR_LIST_INT => Self::BINARY_SUBSCR_LIST_INT,
+            Mnemonic::BINARY_SUBSCR_TUPLE_INT => Self::BINARY_SUBSCR_TUPLE_INT,
+            Mnemonic::CALL_ADAPTIVE => Self::CALL_ADAPTIVE,
+            Mnemonic::CALL_PY_EXACT_ARGS => Self::CALL_PY_EXACT_ARGS,
+            Mnemonic::CALL_PY_WITH_DEFAULTS => Self::CALL_PY_WITH_DEFAULTS,
+            Mnemonic::COMPARE_OP_ADAPTIVE => Self::COMPARE_OP_ADAPTIVE,
+            Mnemonic::COMPARE_OP_FLOAT_JUMP => Self::COMPARE_OP_FLOAT_JUMP,
+            Mnemonic::COMPARE_OP_INT_JUMP => Self::COMPARE_OP_INT_JUMP,
+            Mnemonic::COMPARE_OP_STR_JUMP => Self::COMPARE_OP_STR_JUMP,
+            Mnemonic::EXTENDED_ARG_QUICK => Self::EXTENDED_ARG_QUICK,
+            Mnemonic::JUMP_BACKWARD_QUICK => Self::JUMP_BACKWARD_QUICK,
+            Mnemonic::LOAD_ATTR_ADAPTIVE => Self::LOAD_ATTR_ADAPTIVE,
+            Mnemonic::LOAD_ATTR_INSTANCE_VALUE => Self::LOAD_ATTR_INSTANCE_VALUE,
+            Mnemonic::LOAD_ATTR_MODULE => Self::LOAD_ATTR_MODULE,
+            Mnemonic::LOAD_ATTR_SLOT => Self::LOAD_ATTR_SLOT,
+            Mnemonic::LOAD_ATTR_WITH_HINT => Self::LOAD_ATTR_WITH_HINT,
+            Mnemonic::LOAD_CONST__LOAD_FAST => Self::LOAD_CONST__LOAD_FAST,
+            Mnemonic::LOAD_FAST__LOAD_CONST => Self::LOAD_FAST__LOAD_CONST,
+            Mnemonic::LOAD_FAST__LOAD_FAST => Self::LOAD_FAST__LOAD_FAST,
+            Mnemonic::LOAD_GLOBAL_ADAPTIVE => Self::LOAD_GLOBAL_ADAPTIVE,
+            Mnemonic::LOAD_GLOBAL_BUILTIN => Self::LOAD_GLOBAL_BUILTIN,
+            Mnemonic::LOAD_GLOBAL_MODULE => Self::LOAD_GLOBAL_MODULE,
+            Mnemonic::LOAD_METHOD_ADAPTIVE => Self::LOAD_METHOD_ADAPTIVE,
+            Mnemonic::LOAD_METHOD_CLASS => Self::LOAD_METHOD_CLASS,
+            Mnemonic::LOAD_METHOD_MODULE => Self::LOAD_METHOD_MODULE,
+            Mnemonic::LOAD_METHOD_NO_DICT => Self::LOAD_METHOD_NO_DICT,
+            Mnemonic::LOAD_METHOD_WITH_DICT => Self::LOAD_METHOD_WITH_DICT,
+            Mnemonic::LOAD_METHOD_WITH_VALUES => Self::LOAD_METHOD_WITH_VALUES,
+            Mnemonic::PRECALL_ADAPTIVE => Self::PRECALL_ADAPTIVE,
+            Mnemonic::PRECALL_BOUND_METHOD => Self::PRECALL_BOUND_METHOD,
+            Mnemonic::PRECALL_BUILTIN_CLASS => Self::PRECALL_BUILTIN_CLASS,
+            Mnemonic::PRECALL_BUILTIN_FAST_WITH_KEYWORDS => {
+                Self::PRECALL_BUILTIN_FAST_WITH_KEYWORDS
+            }
+            Mnemonic::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                Self::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            }
+            Mnemonic::PRECALL_NO_KW_BUILTIN_FAST => Self::PRECALL_NO_KW_BUILTIN_FAST,
+            Mnemonic::PRECALL_NO_KW_BUILTIN_O => Self::PRECALL_NO_KW_BUILTIN_O,
+            Mnemonic::PRECALL_NO_KW_ISINSTANCE => Self::PRECALL_NO_KW_ISINSTANCE,
+            Mnemonic::PRECALL_NO_KW_LEN => Self::PRECALL_NO_KW_LEN,
+            Mnemonic::PRECALL_NO_KW_LIST_APPEND => Self::PRECALL_NO_KW_LIST_APPEND,
+            Mnemonic::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST => {
+                Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST
+            }
+            Mnemonic::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS => {
+                Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            }
+            Mnemonic::PRECALL_NO_KW_METHOD_DESCRIPTOR_O => Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_O,
+            Mnemonic::PRECALL_NO_KW_STR_1 => Self::PRECALL_NO_KW_STR_1,
+            Mnemonic::PRECALL_NO_KW_TUPLE_1 => Self::PRECALL_NO_KW_TUPLE_1,
+            Mnemonic::PRECALL_NO_KW_TYPE_1 => Self::PRECALL_NO_KW_TYPE_1,
+            Mnemonic::PRECALL_PYFUNC => Self::PRECALL_PYFUNC,
+            Mnemonic::RESUME_QUICK => Self::RESUME_QUICK,
+            Mnemonic::STORE_ATTR_ADAPTIVE => Self::STORE_ATTR_ADAPTIVE,
+            Mnemonic::STORE_ATTR_INSTANCE_VALUE => Self::STORE_ATTR_INSTANCE_VALUE,
+            Mnemonic::STORE_ATTR_SLOT => Self::STORE_ATTR_SLOT,
+            Mnemonic::STORE_ATTR_WITH_HINT => Self::STORE_ATTR_WITH_HINT,
+            Mnemonic::STORE_FAST__LOAD_FAST => Self::STORE_FAST__LOAD_FAST,
+            Mnemonic::STORE_FAST__STORE_FAST => Self::STORE_FAST__STORE_FAST,
+            Mnemonic::STORE_SUBSCR_ADAPTIVE => Self::STORE_SUBSCR_ADAPTIVE,
+            Mnemonic::STORE_SUBSCR_DICT => Self::STORE_SUBSCR_DICT,
+            Mnemonic::STORE_SUBSCR_LIST_INT => Self::STORE_SUBSCR_LIST_INT,
+            Mnemonic::UNPACK_SEQUENCE_ADAPTIVE => Self::UNPACK_SEQUENCE_ADAPTIVE,
+            Mnemonic::UNPACK_SEQUENCE_LIST => Self::UNPACK_SEQUENCE_LIST,
+            Mnemonic::UNPACK_SEQUENCE_TUPLE => Self::UNPACK_SEQUENCE_TUPLE,
+            Mnemonic::UNPACK_SEQUENCE_TWO_TUPLE => Self::UNPACK_SEQUENCE_TWO_TUPLE,
+        }
+    }
+}
+
+impl super::Opcode for Standard {
+    type Mnemonic = Mnemonic;
+
+    /// Whether or not this opcode has an argument
+    fn has_arg(&self) -> bool {
+        self.deoptimize() as u8 >= 90
+    }
+
+    /// Whether or not this opcode has an extended argument
+    fn has_extended_arg(&self) -> bool {
+        self.deoptimize() == Self::EXTENDED_ARG
+    }
+
+    /// Whether or not this opcode is the `EXTENDED_ARG` prefix
+    fn is_extended_arg(&self) -> bool {
+        self.deoptimize() == Self::EXTENDED_ARG
+    }
+
+    /// The `EXTENDED_ARG` prefix opcode of this opcode table
+    fn extended_arg() -> Self {
+        Self::EXTENDED_ARG
+    }
+
+    /// Whether or not this opcode has a constant parameter
+    fn has_const(&self) -> bool {
+        matches!(self.deoptimize(), Self::LOAD_CONST | Self::KW_NAMES)
+    }
+
+    /// Whether or not this opcode is a boolean operation
+    fn has_comp(&self) -> bool {
+        matches!(self.deoptimize(), Self::COMPARE_OP)
+    }
+
+    /// Whether or not this opcode has a relative jump target
+    fn is_relative_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::FOR_ITER
+                | Self::JUMP_FORWARD
+                | Self::JUMP_IF_FALSE_OR_POP
+                | Self::JUMP_IF_TRUE_OR_POP
+                | Self::POP_JUMP_FORWARD_IF_FALSE
+                | Self::POP_JUMP_FORWARD_IF_TRUE
+                | Self::SEND
+                | Self::POP_JUMP_FORWARD_IF_NOT_NONE
+                | Self::POP_JUMP_FORWARD_IF_NONE
+                | Self::JUMP_BACKWARD_NO_INTERRUPT
+                | Self::JUMP_BACKWARD
+                | Self::POP_JUMP_BACKWARD_IF_NOT_NONE
+                | Self::POP_JUMP_BACKWARD_IF_NONE
+                | Self::POP_JUMP_BACKWARD_IF_FALSE
+                | Self::POP_JUMP_BACKWARD_IF_TRUE
+        )
+    }
+
+    /// Whether or not this opcode has an absolute jump target
+    fn is_absolute_jump(&self) -> bool {
+        false
+    }
+
+    /// Whether or not this opcode is another type of "special" jumping instruction
+    /// e.g. FOR_ITER, SETUP_LOOP, etc.
+    fn is_other_conditional_jump(&self) -> bool {
+        matches!(self.deoptimize(), Self::FOR_ITER | Self::SEND)
+    }
+
+    /// Whether or not this opcode is a conditional jump
+    fn is_conditional_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::JUMP_IF_FALSE_OR_POP
+                | Self::JUMP_IF_TRUE_OR_POP
+                | Self::POP_JUMP_FORWARD_IF_FALSE
+                | Self::POP_JUMP_FORWARD_IF_TRUE
+                | Self::POP_JUMP_FORWARD_IF_NOT_NONE
+                | Self::POP_JUMP_FORWARD_IF_NONE
+                | Self::POP_JUMP_BACKWARD_IF_NOT_NONE
+                | Self::POP_JUMP_BACKWARD_IF_NONE
+                | Self::POP_JUMP_BACKWARD_IF_FALSE
+                | Self::POP_JUMP_BACKWARD_IF_TRUE
+        )
+    }
+
+    /// Whether or not this opcode accesses an attribute by name
+    fn has_name(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::STORE_NAME
+                | Self::DELETE_NAME
+                | Self::STORE_ATTR
+                | Self::DELETE_ATTR
+                | Self::STORE_GLOBAL
+                | Self::DELETE_GLOBAL
+                | Self::LOAD_NAME
+                | Self::LOAD_ATTR
+                | Self::IMPORT_NAME
+                | Self::IMPORT_FROM
+                | Self::LOAD_GLOBAL
+                | Self::LOAD_METHOD
+        )
+    }
+
+    /// Whether or not this opcode accesses a local variable
+    fn has_local(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::LOAD_FAST | Self::STORE_FAST | Self::DELETE_FAST
+        )
+    }
+
+    /// Whether or not this opcode accesses a free variable
+    fn has_free(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::MAKE_CELL
+                | Self::LOAD_CLOSURE
+                | Self::LOAD_DEREF
+                | Self::STORE_DEREF
+                | Self::DELETE_DEREF
+                | Self::LOAD_CLASSDEREF
+        )
+    }
+
+    /// How instructions of this opcode table are laid out in bytecode
+    fn encoding() -> Encoding {
+        Encoding::Wordcode
+    }
+
+    /// The number of bytes each unit of a jump argument stands for
+    fn jump_unit() -> usize {
+        2
+    }
+
+    /// The number of inline cache code units which follow instructions with this opcode
+    fn cache_entries(&self) -> usize {
+        match self.deoptimize() {
+            Self::STORE_SUBSCR | Self::UNPACK_SEQUENCE | Self::BINARY_OP | Self::PRECALL => 1,
+            Self::COMPARE_OP => 2,
+            Self::BINARY_SUBSCR | Self::STORE_ATTR | Self::LOAD_ATTR | Self::CALL => 4,
+            Self::LOAD_GLOBAL => 5,
+            Self::LOAD_METHOD => 10,
+            _ => 0,
+        }
+    }
+
+    /// The `CACHE` opcode which fills inline cache entries
+    fn cache() -> Option<Self> {
+        Some(Self::CACHE)
+    }
+
+    /// Whether or not this opcode is a relative jump whose target precedes it
+    fn is_backward_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::JUMP_BACKWARD_NO_INTERRUPT
+                | Self::JUMP_BACKWARD
+                | Self::POP_JUMP_BACKWARD_IF_NOT_NONE
+                | Self::POP_JUMP_BACKWARD_IF_NONE
+                | Self::POP_JUMP_BACKWARD_IF_FALSE
+                | Self::POP_JUMP_BACKWARD_IF_TRUE
+        )
+    }
+
+    /// The index which an argument of this opcode refers to
+    fn argument_index(&self, arg: u32) -> u32 {
+        match self.deoptimize() {
+            // The low bit of the argument pushes a NULL or `self` as well
+            Self::LOAD_GLOBAL => arg >> 1,
+            _ => arg,
+        }
+    }
+
+    /// The base opcode of a specialized or instrumented opcode
+    fn deoptimize(&self) -> Self {
+        Self::from(self.mnemonic().deoptimize())
+    }
+
+    fn mnemonic(&self) -> Mnemonic {
+        match self {
+            Self::CACHE => Mnemonic::CACHE,
+            Self::POP_TOP => Mnemonic::POP_TOP,
+            Self::PUSH_NULL => Mnemonic::PUSH_NULL,
+
+            Self::NOP => Mnemonic::NOP,
+            Self::UNARY_POSITIVE => Mnemonic::UNARY_POSITIVE,
+            Self::UNARY_NEGATIVE => Mnemonic::UNARY_NEGATIVE,
+            Self::UNARY_NOT => Mnemonic::UNARY_NOT,
+
+            Self::UNARY_INVERT => Mnemonic::UNARY_INVERT,
+
+            Self::BINARY_SUBSCR => Mnemonic::BINARY_SUBSCR,
+
+            Self::GET_LEN => Mnemonic::GET_LEN,
+            Self::MATCH_MAPPING => Mnemonic::MATCH_MAPPING,
+            Self::MATCH_SEQUENCE => Mnemonic::MATCH_SEQUENCE,
+            Self::MATCH_KEYS => Mnemonic::MATCH_KEYS,
+
+            Self::PUSH_EXC_INFO => Mnemonic::PUSH_EXC_INFO,
+            Self::CHECK_EXC_MATCH => Mnemonic::CHECK_EXC_MATCH,
+            Self::CHECK_EG_MATCH => Mnemonic::CHECK_EG_MATCH,
+
+            Self::WITH_EXCEPT_START => Mnemonic::WITH_EXCEPT_START,
+            Self::GET_AITER => Mnemonic::GET_AITER,
+            Self::GET_ANEXT => Mnemonic::GET_ANEXT,
+            Self::BEFORE_ASYNC_WITH => Mnemonic::BEFORE_ASYNC_WITH,
+            Self::BEFORE_WITH => Mnemonic::BEFORE_WITH,
+            Self::END_ASYNC_FOR => Mnemonic::END_ASYNC_FOR,
+
+            Self::STORE_SUBSCR => Mnemonic::STORE_SUBSCR,
+            Self::DELETE_SUBSCR => Mnemonic::DELETE_SUBSCR,
+
+            Self::GET_ITER => Mnemonic::GET_ITER,
+            Self::GET_YIELD_FROM_ITER => Mnemonic::GET_YIELD_FROM_ITER,
+            Self::PRINT_EXPR => Mnemonic::PRINT_EXPR,
+            Self::LOAD_BUILD_CLASS => Mnemonic::LOAD_BUILD_CLASS,
+
+            Self::LOAD_ASSERTION_ERROR => Mnemonic::LOAD_ASSERTION_ERROR,
+            Self::RETURN_GENERATOR => Mnemonic::RETURN_GENERATOR,
+
+            Self::LIST_TO_TUPLE => Mnemonic::LIST_TO_TUPLE,
+            Self::RETURN_VALUE => Mnemonic::RETURN_VALUE,
+            Self::IMPORT_STAR => Mnemonic::IMPORT_STAR,
+            Self::SETUP_ANNOTATIONS => Mnemonic::SETUP_ANNOTATIONS,
+            Self::YIELD_VALUE => Mnemonic::YIELD_VALUE,
+            Self::ASYNC_GEN_WRAP => Mnemonic::ASYNC_GEN_WRAP,
+            Self::PREP_RERAISE_STAR => Mnemonic::PREP_RERAISE_STAR,
+            Self::POP_EXCEPT => Mnemonic::POP_EXCEPT,
+
+            // Opcodes with arguments
+            Self::STORE_NAME => Mnemonic::STORE_NAME,
+            Self::DELETE_NAME => Mnemonic::DELETE_NAME,
+            Self::UNPACK_SEQUENCE => Mnemonic::UNPACK_SEQUENCE,
+            Self::FOR_ITER => Mnemonic::FOR_ITER,
+            Self::UNPACK_EX => Mnemonic::UNPACK_EX,
+            Self::STORE_ATTR => Mnemonic::STORE_ATTR,
+            Self::DELETE_ATTR => Mnemonic::DELETE_ATTR,
+            Self::STORE_GLOBAL => Mnemonic::STORE_GLOBAL,
+            Self::DELETE_GLOBAL => Mnemonic::DELETE_GLOBAL,
+            Self::SWAP => Mnemonic::SWAP,
+            Self::LOAD_CONST => Mnemonic::LOAD_CONST,
+            Self::LOAD_NAME => Mnemonic::LOAD_NAME,
+            Self::BUILD_TUPLE => Mnemonic::BUILD_TUPLE,
+            Self::BUILD_LIST => Mnemonic::BUILD_LIST,
+            Self::BUILD_SET => Mnemonic::BUILD_SET,
+            Self::BUILD_MAP => Mnemonic::BUILD_MAP,
+            Self::LOAD_ATTR => Mnemonic::LOAD_ATTR,
+            Self::COMPARE_OP => Mnemonic::COMPARE_OP,
+            Self::IMPORT_NAME => Mnemonic::IMPORT_NAME,
+            Self::IMPORT_FROM => Mnemonic::IMPORT_FROM,
+            Self::JUMP_FORWARD => Mnemonic::JUMP_FORWARD,
+            Self::JUMP_IF_FALSE_OR_POP => Mnemonic::JUMP_IF_FALSE_OR_POP,
+            Self::JUMP_IF_TRUE_OR_POP => Mnemonic::JUMP_IF_TRUE_OR_POP,
+
+            Self::POP_JUMP_FORWARD_IF_FALSE => Mnemonic::POP_JUMP_FORWARD_IF_FALSE,
+            Self::POP_JUMP_FORWARD_IF_TRUE => Mnemonic::POP_JUMP_FORWARD_IF_TRUE,
+            Self::LOAD_GLOBAL => Mnemonic::LOAD_GLOBAL,
+            Self::IS_OP => Mnemonic::IS_OP,
+            Self::CONTAINS_OP => Mnemonic::CONTAINS_OP,
+            Self::RERAISE => Mnemonic::RERAISE,
+            Self::COPY => Mnemonic::COPY,
+
+            Self::BINARY_OP => Mnemonic::BINARY_OP,
+            Self::SEND => Mnemonic::SEND,
+            Self::LOAD_FAST => Mnemonic::LOAD_FAST,
+            Self::STORE_FAST => Mnemonic::STORE_FAST,
+            Self::DELETE_FAST => Mnemonic::DELETE_FAST,
+
+            Self::POP_JUMP_FORWARD_IF_NOT_NONE => Mnemonic::POP_JUMP_FORWARD_IF_NOT_NONE,
+            Self::POP_JUMP_FORWARD_IF_NONE => Mnemonic::POP_JUMP_FORWARD_IF_NONE,
+            Self::RAISE_VARARGS => Mnemonic::RAISE_VARARGS,
+            Self::GET_AWAITABLE => Mnemonic::GET_AWAITABLE,
+            Self::MAKE_FUNCTION => Mnemonic::MAKE_FUNCTION,
+            Self::BUILD_SLICE => Mnemonic::BUILD_SLICE,
+            Self::JUMP_BACKWARD_NO_INTERRUPT => Mnemonic::JUMP_BACKWARD_NO_INTERRUPT,
+            Self::MAKE_CELL => Mnemonic::MAKE_CELL,
+            Self::LOAD_CLOSURE => Mnemonic::LOAD_CLOSURE,
+            Self::LOAD_DEREF => Mnemonic::LOAD_DEREF,
+            Self::STORE_DEREF => Mnemonic::STORE_DEREF,
+            Self::DELETE_DEREF => Mnemonic::DELETE_DEREF,
+            Self::JUMP_BACKWARD => Mnemonic::JUMP_BACKWARD,
+
+            Self::CALL_FUNCTION_EX => Mnemonic::CALL_FUNCTION_EX,
+
+            Self::EXTENDED_ARG => Mnemonic::EXTENDED_ARG,
+            Self::LIST_APPEND => Mnemonic::LIST_APPEND,
+            Self::SET_ADD => Mnemonic::SET_ADD,
+            Self::MAP_ADD => Mnemonic::MAP_ADD,
+            Self::LOAD_CLASSDEREF => Mnemonic::LOAD_CLASSDEREF,
+            Self::COPY_FREE_VARS => Mnemonic::COPY_FREE_VARS,
+
+            Self::RESUME => Mnemonic::RESUME,
+            Self::MATCH_CLASS => Mnemonic::MATCH_CLASS,
+
+            Self::FORMAT_VALUE => Mnemonic::FORMAT_VALUE,
+            Self::BUILD_CONST_KEY_MAP => Mnemonic::BUILD_CONST_KEY_MAP,
+            Self::BUILD_STRING => Mnemonic::BUILD_STRING,
+
+            Self::LOAD_METHOD => Mnemonic::LOAD_METHOD,
+
+            Self::LIST_EXTEND => Mnemonic::LIST_EXTEND,
+            Self::SET_UPDATE => Mnemonic::SET_UPDATE,
+            Self::DICT_MERGE => Mnemonic::DICT_MERGE,
+            Self::DICT_UPDATE => Mnemonic::DICT_UPDATE,
+            Self::PRECALL => Mnemonic::PRECALL,
+
+            Self::CALL => Mnemonic::CALL,
+            Self::KW_NAMES => Mnemonic::KW_NAMES,
+            Self::POP_JUMP_BACKWARD_IF_NOT_NONE => Mnemonic::POP_JUMP_BACKWARD_IF_NOT_NONE,
+            Self::POP_JUMP_BACKWARD_IF_NONE => Mnemonic::POP_JUMP_BACKWARD_IF_NONE,
+            Self::POP_JUMP_BACKWARD_IF_FALSE => Mnemonic::POP_JUMP_BACKWARD_IF_FALSE,
+            Self::POP_JUMP_BACKWARD_IF_TRUE => Mnemonic::POP_JUMP_BACKWARD_IF_TRUE,
+
+            // Specialized opcodes, which only appear in code that has been executed
+            Self::BINARY_OP_ADAPTIVE => Mnemonic::BINARY_OP_ADAPTIVE,
+            Self::BINARY_OP_ADD_FLOAT => Mnemonic::BINARY_OP_ADD_FLOAT,
+            Self::BINARY_OP_ADD_INT => Mnemonic::BINARY_OP_ADD_INT,
+            Self::BINARY_OP_ADD_UNICODE => Mnemonic::BINARY_OP_ADD_UNICODE,
+            Self::BINARY_OP_INPLACE_ADD_UNICODE => Mnemonic::BINARY_OP_INPLACE_ADD_UNICODE,
+            Self::BINARY_OP_MULTIPLY_FLOAT => Mnemonic::BINARY_OP_MULTIPLY_FLOAT,
+            Self::BINARY_OP_MULTIPLY_INT => Mnemonic::BINARY_OP_MULTIPLY_INT,
+            Self::BINARY_OP_SUBTRACT_FLOAT => Mnemonic::BINARY_OP_SUBTRACT_FLOAT,
+            Self::BINARY_OP_SUBTRACT_INT => Mnemonic::BINARY_OP_SUBTRACT_INT,
+            Self::BINARY_SUBSCR_ADAPTIVE => Mnemonic::BINARY_SUBSCR_ADAPTIVE,
+            Self::BINARY_SUBSCR_DICT => Mnemonic::BINARY_SUBSCR_DICT,
+            Self::BINARY_SUBSCR_GETITEM => Mnemonic::BINARY_SUBSCR_GETITEM,
+            Self::BINARY_SUBSCR_LIST_INT => Mnemonic::BINARY_SUBSCR_LIST_INT,
+            Self::BINARY_SUBSCR_TUPLE_INT => Mnemonic::BINARY_SUBSCR_TUPLE_INT,
+            Self::CALL_ADAPTIVE => Mnemonic::CALL_ADAPTIVE,
+            Self::CALL_PY_EXACT_ARGS => Mnemonic::CALL_PY_EXACT_ARGS,
+            Self::CALL_PY_WITH_DEFAULTS => Mnemonic::CALL_PY_WITH_DEFAULTS,
+            Self::COMPARE_OP_ADAPTIVE => Mnemonic::COMPARE_OP_ADAPTIVE,
+            Self::COMPARE_OP_FLOAT_JUMP => Mnemonic::COMPARE_OP_FLOAT_JUMP,
+            Self::COMPARE_OP_INT_JUMP => Mnemonic::COMPARE_OP_INT_JUMP,
+            Self::COMPARE_OP_STR_JUMP => Mnemonic::COMPARE_OP_STR_JUMP,
+            Self::EXTENDED_ARG_QUICK => Mnemonic::EXTENDED_ARG_QUICK,
+            Self::JUMP_BACKWARD_QUICK => Mnemonic::JUMP_BACKWARD_QUICK,
+            Self::LOAD_ATTR_ADAPTIVE => Mnemonic::LOAD_ATTR_ADAPTIVE,
+            Self::LOAD_ATTR_INSTANCE_VALUE => Mnemonic::LOAD_ATTR_INSTANCE_VALUE,
+            Self::LOAD_ATTR_MODULE => Mnemonic::LOAD_ATTR_MODULE,
+            Self::LOAD_ATTR_SLOT => Mnemonic::LOAD_ATTR_SLOT,
+            Self::LOAD_ATTR_WITH_HINT => Mnemonic::LOAD_ATTR_WITH_HINT,
+            Self::LOAD_CONST__LOAD_FAST => Mnemonic::LOAD_CONST__LOAD_FAST,
+            Self::LOAD_FAST__LOAD_CONST => Mnemonic::LOAD_FAST__LOAD_CONST,
+            Self::LOAD_FAST__LOAD_FAST => Mnemonic::LOAD_FAST__LOAD_FAST,
+            Self::LOAD_GLOBAL_ADAPTIVE => Mnemonic::LOAD_GLOBAL_ADAPTIVE,
+            Self::LOAD_GLOBAL_BUILTIN => Mnemonic::LOAD_GLOBAL_BUILTIN,
+            Self::LOAD_GLOBAL_MODULE => Mnemonic::LOAD_GLOBAL_MODULE,
+            Self::LOAD_METHOD_ADAPTIVE => Mnemonic::LOAD_METHOD_ADAPTIVE,
+            Self::LOAD_METHOD_CLASS => Mnemonic::LOAD_METHOD_CLASS,
+            Self::LOAD_METHOD_MODULE => Mnemonic::LOAD_METHOD_MODULE,
+            Self::LOAD_METHOD_NO_DICT => Mnemonic::LOAD_METHOD_NO_DICT,
+            Self::LOAD_METHOD_WITH_DICT => Mnemonic::LOAD_METHOD_WITH_DICT,
+            Self::LOAD_METHOD_WITH_VALUES => Mnemonic::LOAD_METHOD_WITH_VALUES,
+            Self::PRECALL_ADAPTIVE => Mnemonic::PRECALL_ADAPTIVE,
+            Self::PRECALL_BOUND_METHOD => Mnemonic::PRECALL_BOUND_METHOD,
+            Self::PRECALL_BUILTIN_CLASS => Mnemonic::PRECALL_BUILTIN_CLASS,
+            Self::PRECALL_BUILTIN_FAST_WITH_KEYWORDS => {
+                Mnemonic::PRECALL_BUILTIN_FAST_WITH_KEYWORDS
+            }
+            Self::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                Mnemonic::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            }
+            Self::PRECALL_NO_KW_BUILTIN_FAST => Mnemonic::PRECALL_NO_KW_BUILTIN_FAST,
+            Self::PRECALL_NO_KW_BUILTIN_O => Mnemonic::PRECALL_NO_KW_BUILTIN_O,
+            Self::PRECALL_NO_KW_ISINSTANCE => Mnemonic::PRECALL_NO_KW_ISINSTANCE,
+            Self::PRECALL_NO_KW_LEN => Mnemonic::PRECALL_NO_KW_LEN,
+            Self::PRECALL_NO_KW_LIST_APPEND => Mnemonic::PRECALL_NO_KW_LIST_APPEND,
+            Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST => {
+                Mnemonic::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST
+            }
+            Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS => {
+                Mnemonic::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            }
+            Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_O => Mnemonic::PRECALL_NO_KW_METHOD_DESCRIPTOR_O,
+            Self::PRECALL_NO_KW_STR_1 => Mnemonic::PRECALL_NO_KW_STR_1,
+            Self::PRECALL_NO_KW_TUPLE_1 => Mnemonic::PRECALL_NO_KW_TUPLE_1,
+            Self::PRECALL_NO_KW_TYPE_1 => Mnemonic::PRECALL_NO_KW_TYPE_1,
+            Self::PRECALL_PYFUNC => Mnemonic::PRECALL_PYFUNC,
+            Self::RESUME_QUICK => Mnemonic::RESUME_QUICK,
+            Self::STORE_ATTR_ADAPTIVE => Mnemonic::STORE_ATTR_ADAPTIVE,
+            Self::STORE_ATTR_INSTANCE_VALUE => Mnemonic::STORE_ATTR_INSTANCE_VALUE,
+            Self::STORE_ATTR_SLOT => Mnemonic::STORE_ATTR_SLOT,
+            Self::STORE_ATTR_WITH_HINT => Mnemonic::STORE_ATTR_WITH_HINT,
+            Self::STORE_FAST__LOAD_FAST => Mnemonic::STORE_FAST__LOAD_FAST,
+            Self::STORE_FAST__STORE_FAST => Mnemonic::STORE_FAST__STORE_FAST,
+            Self::STORE_SUBSCR_ADAPTIVE => Mnemonic::STORE_SUBSCR_ADAPTIVE,
+            Self::STORE_SUBSCR_DICT => Mnemonic::STORE_SUBSCR_DICT,
+            Self::STORE_SUBSCR_LIST_INT => Mnemonic::STORE_SUBSCR_LIST_INT,
+            Self::UNPACK_SEQUENCE_ADAPTIVE => Mnemonic::UNPACK_SEQUENCE_ADAPTIVE,
+            Self::UNPACK_SEQUENCE_LIST => Mnemonic::UNPACK_SEQUENCE_LIST,
+            Self::UNPACK_SEQUENCE_TUPLE => Mnemonic::UNPACK_SEQUENCE_TUPLE,
+            Self::UNPACK_SEQUENCE_TWO_TUPLE => Mnemonic::UNPACK_SEQUENCE_TWO_TUPLE,
+        }
+    }
+}
+
+impl super::StackEffect for Mnemonic {
+    fn stack_adjustment(&self, arg: Option<u32>) -> isize {
+        match self {
+            // Meta instructions
+            Self::NOP => 0,
+            Self::POP_TOP => -1,
+            Self::PUSH_NULL => 1,
+            Self::SWAP => 0,
+            Self::COPY => 1,
+            // Unary ops
+            Self::UNARY_POSITIVE
+            | Self::UNARY_NEGATIVE
+            | Self::UNARY_NOT
+            | Self::UNARY_INVERT
+            | Self::GET_ITER
+            | Self::GET_YIELD_FROM_ITER
+            | Self::GET_AITER
+            | Self::GET_AWAITABLE => 0,
+            // Binary ops
+            Self::BINARY_OP => -1,
+            Self::BINARY_SUBSCR => -1,
+            Self::STORE_SUBSCR => -3,
+            Self::DELETE_SUBSCR => -2,
+            // Misc
+            Self::PRINT_EXPR => -1,
+            Self::LIST_APPEND | Self::SET_ADD => -1,
+            Self::MAP_ADD => -2,
+            Self::LIST_EXTEND | Self::SET_UPDATE | Self::DICT_MERGE | Self::DICT_UPDATE => -1,
+            Self::LOAD_BUILD_CLASS => 1,
+            Self::LOAD_ASSERTION_ERROR => 1,
+            Self::LIST_TO_TUPLE => 0,
+            Self::SETUP_ANNOTATIONS => 0,
+            Self::RETURN_VALUE => -1,
+            Self::YIELD_VALUE => 0,
+            Self::RETURN_GENERATOR | Self::ASYNC_GEN_WRAP => 0,
+            Self::IMPORT_STAR => -1,
+            // The previous exception is pushed below the one being handled
+            Self::PUSH_EXC_INFO => 1,
+            // Restores the previous exception
+            Self::POP_EXCEPT => -1,
+            // The exception type is replaced by the result of the match
+            Self::CHECK_EXC_MATCH | Self::CHECK_EG_MATCH => 0,
+            Self::PREP_RERAISE_STAR => -1,
+            // The exception and the iterator
+            Self::END_ASYNC_FOR => -2,
+            // The result of `__enter__` is pushed above the exit method
+            Self::BEFORE_WITH | Self::BEFORE_ASYNC_WITH | Self::GET_ANEXT => 1,
+            Self::STORE_NAME | Self::STORE_FAST | Self::STORE_DEREF | Self::STORE_GLOBAL => -1,
+            Self::DELETE_NAME | Self::DELETE_FAST | Self::DELETE_DEREF | Self::DELETE_GLOBAL => 0,
+            Self::UNPACK_SEQUENCE => (arg.unwrap() as isize) - 1,
+            // The counts of values before and after the starred target
+            Self::UNPACK_EX => (arg.unwrap() & 0xFF) as isize + (arg.unwrap() >> 8) as isize,
+            Self::STORE_ATTR => -2,
+            Self::DELETE_ATTR => -1,
+            Self::LOAD_CONST
+            | Self::LOAD_NAME
+            | Self::LOAD_FAST
+            | Self::LOAD_CLOSURE
+            | Self::LOAD_DEREF
+            | Self::LOAD_CLASSDEREF => 1,
+            Self::BUILD_TUPLE | Self::BUILD_LIST | Self::BUILD_SET => 1 - (arg.unwrap() as isize),
+            // The argument is the number of key/value pairs
+            Self::BUILD_MAP => 1 - 2 * (arg.unwrap() as isize),
+            Self::LOAD_ATTR => 0,
+            // The low bit of the argument pushes a NULL before the global
+            Self::LOAD_GLOBAL => 1 + (arg.unwrap() & 0x01) as isize,
+            Self::LOAD_METHOD => 1,
+            Self::COMPARE_OP => -1,
+            Self::IS_OP | Self::CONTAINS_OP => -1,
+            Self::IMPORT_NAME => -1,
+            Self::IMPORT_FROM => 1,
+            Self::JUMP_FORWARD => 0,
+            Self::JUMP_BACKWARD | Self::JUMP_BACKWARD_NO_INTERRUPT => 0,
+            Self::POP_JUMP_FORWARD_IF_FALSE
+            | Self::POP_JUMP_FORWARD_IF_TRUE
+            | Self::POP_JUMP_FORWARD_IF_NOT_NONE
+            | Self::POP_JUMP_FORWARD_IF_NONE
+            | Self::POP_JUMP_BACKWARD_IF_NOT_NONE
+            | Self::POP_JUMP_BACKWARD_IF_NONE
+            | Self::POP_JUMP_BACKWARD_IF_FALSE
+            | Self::POP_JUMP_BACKWARD_IF_TRUE => -1,
+            // The condition is popped if the jump is not taken
+            Self::JUMP_IF_FALSE_OR_POP | Self::JUMP_IF_TRUE_OR_POP => 0,
+            // The iterator is popped instead once it is exhausted
+            Self::FOR_ITER => 1,
+            // The result replaces the value sent, the iterator is popped instead if it returns
+            Self::SEND => 0,
+            Self::RAISE_VARARGS => -(arg.unwrap() as isize),
+            // The NULL, callable, arguments and optional keyword arguments are replaced by the result
+            Self::CALL_FUNCTION_EX => -2 - (arg.unwrap() & 0x01) as isize,
+            // The arguments are popped, leaving the callable and `self` or NULL for CALL
+            Self::PRECALL => -(arg.unwrap() as isize),
+            // The callable and `self` or NULL are replaced by the return value
+            Self::CALL => -1,
+            // Stores the keyword names for the next CALL
+            Self::KW_NAMES => 0,
+            // Each flag bit adds a value to be popped, the code object is replaced by the function
+            Self::MAKE_FUNCTION => -((arg.unwrap() & 0x0F).count_ones() as isize),
+            Self::BUILD_SLICE => 1 - (arg.unwrap() as isize),
+            // Bit 2 of the argument means a format spec is on the stack
+            Self::FORMAT_VALUE => {
+                if arg.unwrap() & 0x04 != 0 {
+                    -1
+                } else {
+                    0
+                }
+            }
+            // The values and the tuple of keys are replaced by the dict
+            Self::BUILD_CONST_KEY_MAP => -(arg.unwrap() as isize),
+            Self::BUILD_STRING => 1 - (arg.unwrap() as isize),
+            Self::RERAISE => -1,
+            Self::WITH_EXCEPT_START => 1,
+            // Pattern matching
+            Self::GET_LEN | Self::MATCH_MAPPING | Self::MATCH_SEQUENCE => 1,
+            Self::MATCH_KEYS => 1,
+            Self::MATCH_CLASS => -2,
+            // Frame setup
+            Self::MAKE_CELL | Self::COPY_FREE_VARS | Self::RESUME => 0,
+            // The prefix only widens the argument of the following instruction
+            Self::EXTENDED_ARG => 0,
+            // Inline cache entries are never executed
+            Self::CACHE => 0,
+            // Specialized and instrumented opcodes have the stack effect of their base opcode
+            Self::BINARY_OP_ADAPTIVE
+            | Self::BINARY_OP_ADD_FLOAT
+            | Self::BINARY_OP_ADD_INT
+            | Self::BINARY_OP_ADD_UNICODE
+            | Self::BINARY_OP_INPLACE_ADD_UNICODE
+            | Self::BINARY_OP_MULTIPLY_FLOAT
+            | Self::BINARY_OP_MULTIPLY_INT
+            | Self::BINARY_OP_SUBTRACT_FLOAT
+            | Self::BINARY_OP_SUBTRACT_INT
+            | Self::BINARY_SUBSCR_ADAPTIVE
+            | Self::BINARY_SUBSCR_DICT
+            | Self::BINARY_SUBSCR_GETITEM
+            | Self::BINARY_SUBSCR_LIST_INT
+            | Self::BINARY_SUBSCR_TUPLE_INT
+            | Self::CALL_ADAPTIVE
+            | Self::CALL_PY_EXACT_ARGS
+            | Self::CALL_PY_WITH_DEFAULTS
+            | Self::COMPARE_OP_ADAPTIVE
+            | Self::COMPARE_OP_FLOAT_JUMP
+            | Self::COMPARE_OP_INT_JUMP
+            | Self::COMPARE_OP_STR_JUMP
+            | Self::EXTENDED_ARG_QUICK
+            | Self::JUMP_BACKWARD_QUICK
+            | Self::LOAD_ATTR_ADAPTIVE
+            | Self::LOAD_ATTR_INSTANCE_VALUE
+            | Self::LOAD_ATTR_MODULE
+            | Self::LOAD_ATTR_SLOT
+            | Self::LOAD_ATTR_WITH_HINT
+            | Self::LOAD_CONST__LOAD_FAST
+            | Self::LOAD_FAST__LOAD_CONST
+            | Self::LOAD_FAST__LOAD_FAST
+            | Self::LOAD_GLOBAL_ADAPTIVE
+            | Self::LOAD_GLOBAL_BUILTIN
+            | Self::LOAD_GLOBAL_MODULE
+            | Self::LOAD_METHOD_ADAPTIVE
+            | Self::LOAD_METHOD_CLASS
+            | Self::LOAD_METHOD_MODULE
+            | Self::LOAD_METHOD_NO_DICT
+            | Self::LOAD_METHOD_WITH_DICT
+            | Self::LOAD_METHOD_WITH_VALUES
+            | Self::PRECALL_ADAPTIVE
+            | Self::PRECALL_BOUND_METHOD
+            | Self::PRECALL_BUILTIN_CLASS
+            | Self::PRECALL_BUILTIN_FAST_WITH_KEYWORDS
+            | Self::PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            | Self::PRECALL_NO_KW_BUILTIN_FAST
+            | Self::PRECALL_NO_KW_BUILTIN_O
+            | Self::PRECALL_NO_KW_ISINSTANCE
+            | Self::PRECALL_NO_KW_LEN
+            | Self::PRECALL_NO_KW_LIST_APPEND
+            | Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST
+            | Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            | Self::PRECALL_NO_KW_METHOD_DESCRIPTOR_O
+            | Self::PRECALL_NO_KW_STR_1
+            | Self::PRECALL_NO_KW_TUPLE_1
+            | Self::PRECALL_NO_KW_TYPE_1
+            | Self::PRECALL_PYFUNC
+            | Self::RESUME_QUICK
+            | Self::STORE_ATTR_ADAPTIVE
+            | Self::STORE_ATTR_INSTANCE_VALUE
+            | Self::STORE_ATTR_SLOT
+            | Self::STORE_ATTR_WITH_HINT
+            | Self::STORE_FAST__LOAD_FAST
+            | Self::STORE_FAST__STORE_FAST
+            | Self::STORE_SUBSCR_ADAPTIVE
+            | Self::STORE_SUBSCR_DICT
+            | Self::STORE_SUBSCR_LIST_INT
+            | Self::UNPACK_SEQUENCE_ADAPTIVE
+            | Self::UNPACK_SEQUENCE_LIST
+            | Self::UNPACK_SEQUENCE_TUPLE
+            | Self::UNPACK_SEQUENCE_TWO_TUPLE => self.deoptimize().stack_adjustment(arg),
+        }
+    }
+}
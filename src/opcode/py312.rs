@@ -0,0 +1,2107 @@
+pub use enum_primitive_derive::Primitive;
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
+
+use super::Encoding;
+use crate::error::ParseMnemonicError;
+
+/// Standard set of instruction mnemonics
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Mnemonic {
+    CACHE,
+    POP_TOP,
+    PUSH_NULL,
+    INTERPRETER_EXIT,
+    END_FOR,
+    END_SEND,
+
+    NOP,
+
+    UNARY_NEGATIVE,
+    UNARY_NOT,
+
+    UNARY_INVERT,
+
+    RESERVED,
+
+    BINARY_SUBSCR,
+    BINARY_SLICE,
+    STORE_SLICE,
+
+    GET_LEN,
+    MATCH_MAPPING,
+    MATCH_SEQUENCE,
+    MATCH_KEYS,
+
+    PUSH_EXC_INFO,
+    CHECK_EXC_MATCH,
+    CHECK_EG_MATCH,
+
+    WITH_EXCEPT_START,
+    GET_AITER,
+    GET_ANEXT,
+    BEFORE_ASYNC_WITH,
+    BEFORE_WITH,
+    END_ASYNC_FOR,
+    CLEANUP_THROW,
+
+    STORE_SUBSCR,
+    DELETE_SUBSCR,
+
+    GET_ITER,
+    GET_YIELD_FROM_ITER,
+
+    LOAD_BUILD_CLASS,
+
+    LOAD_ASSERTION_ERROR,
+    RETURN_GENERATOR,
+
+    RETURN_VALUE,
+
+    SETUP_ANNOTATIONS,
+
+    LOAD_LOCALS,
+
+    POP_EXCEPT,
+
+    // Opcodes with arguments
+    STORE_NAME,
+    DELETE_NAME,
+    UNPACK_SEQUENCE,
+    FOR_ITER,
+    UNPACK_EX,
+    STORE_ATTR,
+    DELETE_ATTR,
+    STORE_GLOBAL,
+    DELETE_GLOBAL,
+    SWAP,
+    LOAD_CONST,
+    LOAD_NAME,
+    BUILD_TUPLE,
+    BUILD_LIST,
+    BUILD_SET,
+    BUILD_MAP,
+    LOAD_ATTR,
+    COMPARE_OP,
+    IMPORT_NAME,
+    IMPORT_FROM,
+    JUMP_FORWARD,
+
+    POP_JUMP_IF_FALSE,
+    POP_JUMP_IF_TRUE,
+    LOAD_GLOBAL,
+    IS_OP,
+    CONTAINS_OP,
+    RERAISE,
+    COPY,
+    RETURN_CONST,
+    BINARY_OP,
+    SEND,
+    LOAD_FAST,
+    STORE_FAST,
+    DELETE_FAST,
+    LOAD_FAST_CHECK,
+    POP_JUMP_IF_NOT_NONE,
+    POP_JUMP_IF_NONE,
+    RAISE_VARARGS,
+    GET_AWAITABLE,
+    MAKE_FUNCTION,
+    BUILD_SLICE,
+    JUMP_BACKWARD_NO_INTERRUPT,
+    MAKE_CELL,
+    LOAD_CLOSURE,
+    LOAD_DEREF,
+    STORE_DEREF,
+    DELETE_DEREF,
+    JUMP_BACKWARD,
+    LOAD_SUPER_ATTR,
+    CALL_FUNCTION_EX,
+    LOAD_FAST_AND_CLEAR,
+    EXTENDED_ARG,
+    LIST_APPEND,
+    SET_ADD,
+    MAP_ADD,
+
+    COPY_FREE_VARS,
+    YIELD_VALUE,
+    RESUME,
+    MATCH_CLASS,
+
+    FORMAT_VALUE,
+    BUILD_CONST_KEY_MAP,
+    BUILD_STRING,
+
+    LIST_EXTEND,
+    SET_UPDATE,
+    DICT_MERGE,
+    DICT_UPDATE,
+
+    CALL,
+    KW_NAMES,
+    CALL_INTRINSIC_1,
+    CALL_INTRINSIC_2,
+    LOAD_FROM_DICT_OR_GLOBALS,
+    LOAD_FROM_DICT_OR_DEREF,
+
+    // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+    INSTRUMENTED_LOAD_SUPER_ATTR,
+    INSTRUMENTED_POP_JUMP_IF_NONE,
+    INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+    INSTRUMENTED_RESUME,
+    INSTRUMENTED_CALL,
+    INSTRUMENTED_RETURN_VALUE,
+    INSTRUMENTED_YIELD_VALUE,
+    INSTRUMENTED_CALL_FUNCTION_EX,
+    INSTRUMENTED_JUMP_FORWARD,
+    INSTRUMENTED_JUMP_BACKWARD,
+    INSTRUMENTED_RETURN_CONST,
+    INSTRUMENTED_FOR_ITER,
+    INSTRUMENTED_POP_JUMP_IF_FALSE,
+    INSTRUMENTED_POP_JUMP_IF_TRUE,
+    INSTRUMENTED_END_FOR,
+    INSTRUMENTED_END_SEND,
+    INSTRUMENTED_INSTRUCTION,
+    INSTRUMENTED_LINE,
+
+    // Specialized opcodes, which only appear in code that has been executed
+    BINARY_OP_ADD_FLOAT,
+    BINARY_OP_ADD_INT,
+    BINARY_OP_ADD_UNICODE,
+    BINARY_OP_INPLACE_ADD_UNICODE,
+    BINARY_OP_MULTIPLY_FLOAT,
+    BINARY_OP_MULTIPLY_INT,
+    BINARY_OP_SUBTRACT_FLOAT,
+    BINARY_OP_SUBTRACT_INT,
+    BINARY_SUBSCR_DICT,
+    BINARY_SUBSCR_GETITEM,
+    BINARY_SUBSCR_LIST_INT,
+    BINARY_SUBSCR_TUPLE_INT,
+    CALL_PY_EXACT_ARGS,
+    CALL_PY_WITH_DEFAULTS,
+    CALL_BOUND_METHOD_EXACT_ARGS,
+    CALL_BUILTIN_CLASS,
+    CALL_BUILTIN_FAST_WITH_KEYWORDS,
+    CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS,
+    CALL_NO_KW_BUILTIN_FAST,
+    CALL_NO_KW_BUILTIN_O,
+    CALL_NO_KW_ISINSTANCE,
+    CALL_NO_KW_LEN,
+    CALL_NO_KW_LIST_APPEND,
+    CALL_NO_KW_METHOD_DESCRIPTOR_FAST,
+    CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS,
+    CALL_NO_KW_METHOD_DESCRIPTOR_O,
+    CALL_NO_KW_STR_1,
+    CALL_NO_KW_TUPLE_1,
+    CALL_NO_KW_TYPE_1,
+    COMPARE_OP_FLOAT,
+    COMPARE_OP_INT,
+    COMPARE_OP_STR,
+    FOR_ITER_LIST,
+    FOR_ITER_TUPLE,
+    FOR_ITER_RANGE,
+    FOR_ITER_GEN,
+    LOAD_SUPER_ATTR_ATTR,
+    LOAD_SUPER_ATTR_METHOD,
+    LOAD_ATTR_CLASS,
+    LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+    LOAD_ATTR_INSTANCE_VALUE,
+    LOAD_ATTR_MODULE,
+    LOAD_ATTR_PROPERTY,
+    LOAD_ATTR_SLOT,
+    LOAD_ATTR_WITH_HINT,
+    LOAD_ATTR_METHOD_LAZY_DICT,
+    LOAD_ATTR_METHOD_NO_DICT,
+    LOAD_ATTR_METHOD_WITH_VALUES,
+    LOAD_CONST__LOAD_FAST,
+    LOAD_FAST__LOAD_CONST,
+    LOAD_FAST__LOAD_FAST,
+    LOAD_GLOBAL_BUILTIN,
+    LOAD_GLOBAL_MODULE,
+    STORE_ATTR_INSTANCE_VALUE,
+    STORE_ATTR_SLOT,
+    STORE_ATTR_WITH_HINT,
+    STORE_FAST__LOAD_FAST,
+    STORE_FAST__STORE_FAST,
+    STORE_SUBSCR_DICT,
+    STORE_SUBSCR_LIST_INT,
+    UNPACK_SEQUENCE_LIST,
+    UNPACK_SEQUENCE_TUPLE,
+    UNPACK_SEQUENCE_TWO_TUPLE,
+    SEND_GEN,
+}
+
+impl Mnemonic {
+    /// Every mnemonic, in declaration order
+    pub const ALL: &'static [Mnemonic] = &[
+        Self::CACHE,
+        Self::POP_TOP,
+        Self::PUSH_NULL,
+        Self::INTERPRETER_EXIT,
+        Self::END_FOR,
+        Self::END_SEND,
+        Self::NOP,
+        Self::UNARY_NEGATIVE,
+        Self::UNARY_NOT,
+        Self::UNARY_INVERT,
+        Self::RESERVED,
+        Self::BINARY_SUBSCR,
+        Self::BINARY_SLICE,
+        Self::STORE_SLICE,
+        Self::GET_LEN,
+        Self::MATCH_MAPPING,
+        Self::MATCH_SEQUENCE,
+        Self::MATCH_KEYS,
+        Self::PUSH_EXC_INFO,
+        Self::CHECK_EXC_MATCH,
+        Self::CHECK_EG_MATCH,
+        Self::WITH_EXCEPT_START,
+        Self::GET_AITER,
+        Self::GET_ANEXT,
+        Self::BEFORE_ASYNC_WITH,
+        Self::BEFORE_WITH,
+        Self::END_ASYNC_FOR,
+        Self::CLEANUP_THROW,
+        Self::STORE_SUBSCR,
+        Self::DELETE_SUBSCR,
+        Self::GET_ITER,
+        Self::GET_YIELD_FROM_ITER,
+        Self::LOAD_BUILD_CLASS,
+        Self::LOAD_ASSERTION_ERROR,
+        Self::RETURN_GENERATOR,
+        Self::RETURN_VALUE,
+        Self::SETUP_ANNOTATIONS,
+        Self::LOAD_LOCALS,
+        Self::POP_EXCEPT,
+        Self::STORE_NAME,
+        Self::DELETE_NAME,
+        Self::UNPACK_SEQUENCE,
+        Self::FOR_ITER,
+        Self::UNPACK_EX,
+        Self::STORE_ATTR,
+        Self::DELETE_ATTR,
+        Self::STORE_GLOBAL,
+        Self::DELETE_GLOBAL,
+        Self::SWAP,
+        Self::LOAD_CONST,
+        Self::LOAD_NAME,
+        Self::BUILD_TUPLE,
+        Self::BUILD_LIST,
+        Self::BUILD_SET,
+        Self::BUILD_MAP,
+        Self::LOAD_ATTR,
+        Self::COMPARE_OP,
+        Self::IMPORT_NAME,
+        Self::IMPORT_FROM,
+        Self::JUMP_FORWARD,
+        Self::POP_JUMP_IF_FALSE,
+        Self::POP_JUMP_IF_TRUE,
+        Self::LOAD_GLOBAL,
+        Self::IS_OP,
+        Self::CONTAINS_OP,
+        Self::RERAISE,
+        Self::COPY,
+        Self::RETURN_CONST,
+        Self::BINARY_OP,
+        Self::SEND,
+        Self::LOAD_FAST,
+        Self::STORE_FAST,
+        Self::DELETE_FAST,
+        Self::LOAD_FAST_CHECK,
+        Self::POP_JUMP_IF_NOT_NONE,
+        Self::POP_JUMP_IF_NONE,
+        Self::RAISE_VARARGS,
+        Self::GET_AWAITABLE,
+        Self::MAKE_FUNCTION,
+        Self::BUILD_SLICE,
+        Self::JUMP_BACKWARD_NO_INTERRUPT,
+        Self::MAKE_CELL,
+        Self::LOAD_CLOSURE,
+        Self::LOAD_DEREF,
+        Self::STORE_DEREF,
+        Self::DELETE_DEREF,
+        Self::JUMP_BACKWARD,
+        Self::LOAD_SUPER_ATTR,
+        Self::CALL_FUNCTION_EX,
+        Self::LOAD_FAST_AND_CLEAR,
+        Self::EXTENDED_ARG,
+        Self::LIST_APPEND,
+        Self::SET_ADD,
+        Self::MAP_ADD,
+        Self::COPY_FREE_VARS,
+        Self::YIELD_VALUE,
+        Self::RESUME,
+        Self::MATCH_CLASS,
+        Self::FORMAT_VALUE,
+        Self::BUILD_CONST_KEY_MAP,
+        Self::BUILD_STRING,
+        Self::LIST_EXTEND,
+        Self::SET_UPDATE,
+        Self::DICT_MERGE,
+        Self::DICT_UPDATE,
+        Self::CALL,
+        Self::KW_NAMES,
+        Self::CALL_INTRINSIC_1,
+        Self::CALL_INTRINSIC_2,
+        Self::LOAD_FROM_DICT_OR_GLOBALS,
+        Self::LOAD_FROM_DICT_OR_DEREF,
+        Self::INSTRUMENTED_LOAD_SUPER_ATTR,
+        Self::INSTRUMENTED_POP_JUMP_IF_NONE,
+        Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+        Self::INSTRUMENTED_RESUME,
+        Self::INSTRUMENTED_CALL,
+        Self::INSTRUMENTED_RETURN_VALUE,
+        Self::INSTRUMENTED_YIELD_VALUE,
+        Self::INSTRUMENTED_CALL_FUNCTION_EX,
+        Self::INSTRUMENTED_JUMP_FORWARD,
+        Self::INSTRUMENTED_JUMP_BACKWARD,
+        Self::INSTRUMENTED_RETURN_CONST,
+        Self::INSTRUMENTED_FOR_ITER,
+        Self::INSTRUMENTED_POP_JUMP_IF_FALSE,
+        Self::INSTRUMENTED_POP_JUMP_IF_TRUE,
+        Self::INSTRUMENTED_END_FOR,
+        Self::INSTRUMENTED_END_SEND,
+        Self::INSTRUMENTED_INSTRUCTION,
+        Self::INSTRUMENTED_LINE,
+        Self::BINARY_OP_ADD_FLOAT,
+        Self::BINARY_OP_ADD_INT,
+        Self::BINARY_OP_ADD_UNICODE,
+        Self::BINARY_OP_INPLACE_ADD_UNICODE,
+        Self::BINARY_OP_MULTIPLY_FLOAT,
+        Self::BINARY_OP_MULTIPLY_INT,
+        Self::BINARY_OP_SUBTRACT_FLOAT,
+        Self::BINARY_OP_SUBTRACT_INT,
+        Self::BINARY_SUBSCR_DICT,
+        Self::BINARY_SUBSCR_GETITEM,
+        Self::BINARY_SUBSCR_LIST_INT,
+        Self::BINARY_SUBSCR_TUPLE_INT,
+        Self::CALL_PY_EXACT_ARGS,
+        Self::CALL_PY_WITH_DEFAULTS,
+        Self::CALL_BOUND_METHOD_EXACT_ARGS,
+        Self::CALL_BUILTIN_CLASS,
+        Self::CALL_BUILTIN_FAST_WITH_KEYWORDS,
+        Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS,
+        Self::CALL_NO_KW_BUILTIN_FAST,
+        Self::CALL_NO_KW_BUILTIN_O,
+        Self::CALL_NO_KW_ISINSTANCE,
+        Self::CALL_NO_KW_LEN,
+        Self::CALL_NO_KW_LIST_APPEND,
+        Self::CALL_NO_KW_METHOD_DESCRIPTOR_FAST,
+        Self::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS,
+        Self::CALL_NO_KW_METHOD_DESCRIPTOR_O,
+        Self::CALL_NO_KW_STR_1,
+        Self::CALL_NO_KW_TUPLE_1,
+        Self::CALL_NO_KW_TYPE_1,
+        Self::COMPARE_OP_FLOAT,
+        Self::COMPARE_OP_INT,
+        Self::COMPARE_OP_STR,
+        Self::FOR_ITER_LIST,
+        Self::FOR_ITER_TUPLE,
+        Self::FOR_ITER_RANGE,
+        Self::FOR_ITER_GEN,
+        Self::LOAD_SUPER_ATTR_ATTR,
+        Self::LOAD_SUPER_ATTR_METHOD,
+        Self::LOAD_ATTR_CLASS,
+        Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+        Self::LOAD_ATTR_INSTANCE_VALUE,
+        Self::LOAD_ATTR_MODULE,
+        Self::LOAD_ATTR_PROPERTY,
+        Self::LOAD_ATTR_SLOT,
+        Self::LOAD_ATTR_WITH_HINT,
+        Self::LOAD_ATTR_METHOD_LAZY_DICT,
+        Self::LOAD_ATTR_METHOD_NO_DICT,
+        Self::LOAD_ATTR_METHOD_WITH_VALUES,
+        Self::LOAD_CONST__LOAD_FAST,
+        Self::LOAD_FAST__LOAD_CONST,
+        Self::LOAD_FAST__LOAD_FAST,
+        Self::LOAD_GLOBAL_BUILTIN,
+        Self::LOAD_GLOBAL_MODULE,
+        Self::STORE_ATTR_INSTANCE_VALUE,
+        Self::STORE_ATTR_SLOT,
+        Self::STORE_ATTR_WITH_HINT,
+        Self::STORE_FAST__LOAD_FAST,
+        Self::STORE_FAST__STORE_FAST,
+        Self::STORE_SUBSCR_DICT,
+        Self::STORE_SUBSCR_LIST_INT,
+        Self::UNPACK_SEQUENCE_LIST,
+        Self::UNPACK_SEQUENCE_TUPLE,
+        Self::UNPACK_SEQUENCE_TWO_TUPLE,
+        Self::SEND_GEN,
+    ];
+
+    /// The canonical name of this mnemonic. This is the same string which
+    /// [`Mnemonic::from_str`] accepts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CACHE => "CACHE",
+            Self::POP_TOP => "POP_TOP",
+            Self::PUSH_NULL => "PUSH_NULL",
+            Self::INTERPRETER_EXIT => "INTERPRETER_EXIT",
+            Self::END_FOR => "END_FOR",
+            Self::END_SEND => "END_SEND",
+            Self::NOP => "NOP",
+            Self::UNARY_NEGATIVE => "UNARY_NEGATIVE",
+            Self::UNARY_NOT => "UNARY_NOT",
+            Self::UNARY_INVERT => "UNARY_INVERT",
+            Self::RESERVED => "RESERVED",
+            Self::BINARY_SUBSCR => "BINARY_SUBSCR",
+            Self::BINARY_SLICE => "BINARY_SLICE",
+            Self::STORE_SLICE => "STORE_SLICE",
+            Self::GET_LEN => "GET_LEN",
+            Self::MATCH_MAPPING => "MATCH_MAPPING",
+            Self::MATCH_SEQUENCE => "MATCH_SEQUENCE",
+            Self::MATCH_KEYS => "MATCH_KEYS",
+            Self::PUSH_EXC_INFO => "PUSH_EXC_INFO",
+            Self::CHECK_EXC_MATCH => "CHECK_EXC_MATCH",
+            Self::CHECK_EG_MATCH => "CHECK_EG_MATCH",
+            Self::WITH_EXCEPT_START => "WITH_EXCEPT_START",
+            Self::GET_AITER => "GET_AITER",
+            Self::GET_ANEXT => "GET_ANEXT",
+            Self::BEFORE_ASYNC_WITH => "BEFORE_ASYNC_WITH",
+            Self::BEFORE_WITH => "BEFORE_WITH",
+            Self::END_ASYNC_FOR => "END_ASYNC_FOR",
+            Self::CLEANUP_THROW => "CLEANUP_THROW",
+            Self::STORE_SUBSCR => "STORE_SUBSCR",
+            Self::DELETE_SUBSCR => "DELETE_SUBSCR",
+            Self::GET_ITER => "GET_ITER",
+            Self::GET_YIELD_FROM_ITER => "GET_YIELD_FROM_ITER",
+            Self::LOAD_BUILD_CLASS => "LOAD_BUILD_CLASS",
+            Self::LOAD_ASSERTION_ERROR => "LOAD_ASSERTION_ERROR",
+            Self::RETURN_GENERATOR => "RETURN_GENERATOR",
+            Self::RETURN_VALUE => "RETURN_VALUE",
+            Self::SETUP_ANNOTATIONS => "SETUP_ANNOTATIONS",
+            Self::LOAD_LOCALS => "LOAD_LOCALS",
+            Self::POP_EXCEPT => "POP_EXCEPT",
+            Self::STORE_NAME => "STORE_NAME",
+            Self::DELETE_NAME => "DELETE_NAME",
+            Self::UNPACK_SEQUENCE => "UNPACK_SEQUENCE",
+            Self::FOR_ITER => "FOR_ITER",
+            Self::UNPACK_EX => "UNPACK_EX",
+            Self::STORE_ATTR => "STORE_ATTR",
+            Self::DELETE_ATTR => "DELETE_ATTR",
+            Self::STORE_GLOBAL => "STORE_GLOBAL",
+            Self::DELETE_GLOBAL => "DELETE_GLOBAL",
+            Self::SWAP => "SWAP",
+            Self::LOAD_CONST => "LOAD_CONST",
+            Self::LOAD_NAME => "LOAD_NAME",
+            Self::BUILD_TUPLE => "BUILD_TUPLE",
+            Self::BUILD_LIST => "BUILD_LIST",
+            Self::BUILD_SET => "BUILD_SET",
+            Self::BUILD_MAP => "BUILD_MAP",
+            Self::LOAD_ATTR => "LOAD_ATTR",
+            Self::COMPARE_OP => "COMPARE_OP",
+            Self::IMPORT_NAME => "IMPORT_NAME",
+            Self::IMPORT_FROM => "IMPORT_FROM",
+            Self::JUMP_FORWARD => "JUMP_FORWARD",
+            Self::POP_JUMP_IF_FALSE => "POP_JUMP_IF_FALSE",
+            Self::POP_JUMP_IF_TRUE => "POP_JUMP_IF_TRUE",
+            Self::LOAD_GLOBAL => "LOAD_GLOBAL",
+            Self::IS_OP => "IS_OP",
+            Self::CONTAINS_OP => "CONTAINS_OP",
+            Self::RERAISE => "RERAISE",
+            Self::COPY => "COPY",
+            Self::RETURN_CONST => "RETURN_CONST",
+            Self::BINARY_OP => "BINARY_OP",
+            Self::SEND => "SEND",
+            Self::LOAD_FAST => "LOAD_FAST",
+            Self::STORE_FAST => "STORE_FAST",
+            Self::DELETE_FAST => "DELETE_FAST",
+            Self::LOAD_FAST_CHECK => "LOAD_FAST_CHECK",
+            Self::POP_JUMP_IF_NOT_NONE => "POP_JUMP_IF_NOT_NONE",
+            Self::POP_JUMP_IF_NONE => "POP_JUMP_IF_NONE",
+            Self::RAISE_VARARGS => "RAISE_VARARGS",
+            Self::GET_AWAITABLE => "GET_AWAITABLE",
+            Self::MAKE_FUNCTION => "MAKE_FUNCTION",
+            Self::BUILD_SLICE => "BUILD_SLICE",
+            Self::JUMP_BACKWARD_NO_INTERRUPT => "JUMP_BACKWARD_NO_INTERRUPT",
+            Self::MAKE_CELL => "MAKE_CELL",
+            Self::LOAD_CLOSURE => "LOAD_CLOSURE",
+            Self::LOAD_DEREF => "LOAD_DEREF",
+            Self::STORE_DEREF => "STORE_DEREF",
+            Self::DELETE_DEREF => "DELETE_DEREF",
+            Self::JUMP_BACKWARD => "JUMP_BACKWARD",
+            Self::LOAD_SUPER_ATTR => "LOAD_SUPER_ATTR",
+            Self::CALL_FUNCTION_EX => "CALL_FUNCTION_EX",
+            Self::LOAD_FAST_AND_CLEAR => "LOAD_FAST_AND_CLEAR",
+            Self::EXTENDED_ARG => "EXTENDED_ARG",
+            Self::LIST_APPEND => "LIST_APPEND",
+            Self::SET_ADD => "SET_ADD",
+            Self::MAP_ADD => "MAP_ADD",
+            Self::COPY_FREE_VARS => "COPY_FREE_VARS",
+            Self::YIELD_VALUE => "YIELD_VALUE",
+            Self::RESUME => "RESUME",
+            Self::MATCH_CLASS => "MATCH_CLASS",
+            Self::FORMAT_VALUE => "FORMAT_VALUE",
+            Self::BUILD_CONST_KEY_MAP => "BUILD_CONST_KEY_MAP",
+            Self::BUILD_STRING => "BUILD_STRING",
+            Self::LIST_EXTEND => "LIST_EXTEND",
+            Self::SET_UPDATE => "SET_UPDATE",
+            Self::DICT_MERGE => "DICT_MERGE",
+            Self::DICT_UPDATE => "DICT_UPDATE",
+            Self::CALL => "CALL",
+            Self::KW_NAMES => "KW_NAMES",
+            Self::CALL_INTRINSIC_1 => "CALL_INTRINSIC_1",
+            Self::CALL_INTRINSIC_2 => "CALL_INTRINSIC_2",
+            Self::LOAD_FROM_DICT_OR_GLOBALS => "LOAD_FROM_DICT_OR_GLOBALS",
+            Self::LOAD_FROM_DICT_OR_DEREF => "LOAD_FROM_DICT_OR_DEREF",
+            Self::INSTRUMENTED_LOAD_SUPER_ATTR => "INSTRUMENTED_LOAD_SUPER_ATTR",
+            Self::INSTRUMENTED_POP_JUMP_IF_NONE => "INSTRUMENTED_POP_JUMP_IF_NONE",
+            Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => "INSTRUMENTED_POP_JUMP_IF_NOT_NONE",
+            Self::INSTRUMENTED_RESUME => "INSTRUMENTED_RESUME",
+            Self::INSTRUMENTED_CALL => "INSTRUMENTED_CALL",
+            Self::INSTRUMENTED_RETURN_VALUE => "INSTRUMENTED_RETURN_VALUE",
+            Self::INSTRUMENTED_YIELD_VALUE => "INSTRUMENTED_YIELD_VALUE",
+            Self::INSTRUMENTED_CALL_FUNCTION_EX => "INSTRUMENTED_CALL_FUNCTION_EX",
+            Self::INSTRUMENTED_JUMP_FORWARD => "INSTRUMENTED_JUMP_FORWARD",
+            Self::INSTRUMENTED_JUMP_BACKWARD => "INSTRUMENTED_JUMP_BACKWARD",
+            Self::INSTRUMENTED_RETURN_CONST => "INSTRUMENTED_RETURN_CONST",
+            Self::INSTRUMENTED_FOR_ITER => "INSTRUMENTED_FOR_ITER",
+            Self::INSTRUMENTED_POP_JUMP_IF_FALSE => "INSTRUMENTED_POP_JUMP_IF_FALSE",
+            Self::INSTRUMENTED_POP_JUMP_IF_TRUE => "INSTRUMENTED_POP_JUMP_IF_TRUE",
+            Self::INSTRUMENTED_END_FOR => "INSTRUMENTED_END_FOR",
+            Self::INSTRUMENTED_END_SEND => "INSTRUMENTED_END_SEND",
+            Self::INSTRUMENTED_INSTRUCTION => "INSTRUMENTED_INSTRUCTION",
+            Self::INSTRUMENTED_LINE => "INSTRUMENTED_LINE",
+            Self::BINARY_OP_ADD_FLOAT => "BINARY_OP_ADD_FLOAT",
+            Self::BINARY_OP_ADD_INT => "BINARY_OP_ADD_INT",
+            Self::BINARY_OP_ADD_UNICODE => "BINARY_OP_ADD_UNICODE",
+            Self::BINARY_OP_INPLACE_ADD_UNICODE => "BINARY_OP_INPLACE_ADD_UNICODE",
+            Self::BINARY_OP_MULTIPLY_FLOAT => "BINARY_OP_MULTIPLY_FLOAT",
+            Self::BINARY_OP_MULTIPLY_INT => "BINARY_OP_MULTIPLY_INT",
+            Self::BINARY_OP_SUBTRACT_FLOAT => "BINARY_OP_SUBTRACT_FLOAT",
+            Self::BINARY_OP_SUBTRACT_INT => "BINARY_OP_SUBTRACT_INT",
+            Self::BINARY_SUBSCR_DICT => "BINARY_SUBSCR_DICT",
+            Self::BINARY_SUBSCR_GETITEM => "BINARY_SUBSCR_GETITEM",
+            Self::BINARY_SUBSCR_LIST_INT => "BINARY_SUBSCR_LIST_INT",
+            Self::BINARY_SUBSCR_TUPLE_INT => "BINARY_SUBSCR_TUPLE_INT",
+            Self::CALL_PY_EXACT_ARGS => "CALL_PY_EXACT_ARGS",
+            Self::CALL_PY_WITH_DEFAULTS => "CALL_PY_WITH_DEFAULTS",
+            Self::CALL_BOUND_METHOD_EXACT_ARGS => "CALL_BOUND_METHOD_EXACT_ARGS",
+            Self::CALL_BUILTIN_CLASS => "CALL_BUILTIN_CLASS",
+            Self::CALL_BUILTIN_FAST_WITH_KEYWORDS => "CALL_BUILTIN_FAST_WITH_KEYWORDS",
+            Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                "CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS"
+            }
+            Self::CALL_NO_KW_BUILTIN_FAST => "CALL_NO_KW_BUILTIN_FAST",
+            Self::CALL_NO_KW_BUILTIN_O => "CALL_NO_KW_BUILTIN_O",
+            Self::CALL_NO_KW_ISINSTANCE => "CALL_NO_KW_ISINSTANCE",
+            Self::CALL_NO_KW_LEN => "CALL_NO_KW_LEN",
+            Self::CALL_NO_KW_LIST_APPEND => "CALL_NO_KW_LIST_APPEND",
+            Self::CALL_NO_KW_METHOD_DESCRIPTOR_FAST => "CALL_NO_KW_METHOD_DESCRIPTOR_FAST",
+            Self::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS => "CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS",
+            Self::CALL_NO_KW_METHOD_DESCRIPTOR_O => "CALL_NO_KW_METHOD_DESCRIPTOR_O",
+            Self::CALL_NO_KW_STR_1 => "CALL_NO_KW_STR_1",
+            Self::CALL_NO_KW_TUPLE_1 => "CALL_NO_KW_TUPLE_1",
+            Self::CALL_NO_KW_TYPE_1 => "CALL_NO_KW_TYPE_1",
+            Self::COMPARE_OP_FLOAT => "COMPARE_OP_FLOAT",
+            Self::COMPARE_OP_INT => "COMPARE_OP_INT",
+            Self::COMPARE_OP_STR => "COMPARE_OP_STR",
+            Self::FOR_ITER_LIST => "FOR_ITER_LIST",
+            Self::FOR_ITER_TUPLE => "FOR_ITER_TUPLE",
+            Self::FOR_ITER_RANGE => "FOR_ITER_RANGE",
+            Self::FOR_ITER_GEN => "FOR_ITER_GEN",
+            Self::LOAD_SUPER_ATTR_ATTR => "LOAD_SUPER_ATTR_ATTR",
+            Self::LOAD_SUPER_ATTR_METHOD => "LOAD_SUPER_ATTR_METHOD",
+            Self::LOAD_ATTR_CLASS => "LOAD_ATTR_CLASS",
+            Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN => "LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN",
+            Self::LOAD_ATTR_INSTANCE_VALUE => "LOAD_ATTR_INSTANCE_VALUE",
+            Self::LOAD_ATTR_MODULE => "LOAD_ATTR_MODULE",
+            Self::LOAD_ATTR_PROPERTY => "LOAD_ATTR_PROPERTY",
+            Self::LOAD_ATTR_SLOT => "LOAD_ATTR_SLOT",
+            Self::LOAD_ATTR_WITH_HINT => "LOAD_ATTR_WITH_HINT",
+            Self::LOAD_ATTR_METHOD_LAZY_DICT => "LOAD_ATTR_METHOD_LAZY_DICT",
+            Self::LOAD_ATTR_METHOD_NO_DICT => "LOAD_ATTR_METHOD_NO_DICT",
+            Self::LOAD_ATTR_METHOD_WITH_VALUES => "LOAD_ATTR_METHOD_WITH_VALUES",
+            Self::LOAD_CONST__LOAD_FAST => "LOAD_CONST__LOAD_FAST",
+            Self::LOAD_FAST__LOAD_CONST => "LOAD_FAST__LOAD_CONST",
+            Self::LOAD_FAST__LOAD_FAST => "LOAD_FAST__LOAD_FAST",
+            Self::LOAD_GLOBAL_BUILTIN => "LOAD_GLOBAL_BUILTIN",
+            Self::LOAD_GLOBAL_MODULE => "LOAD_GLOBAL_MODULE",
+            Self::STORE_ATTR_INSTANCE_VALUE => "STORE_ATTR_INSTANCE_VALUE",
+            Self::STORE_ATTR_SLOT => "STORE_ATTR_SLOT",
+            Self::STORE_ATTR_WITH_HINT => "STORE_ATTR_WITH_HINT",
+            Self::STORE_FAST__LOAD_FAST => "STORE_FAST__LOAD_FAST",
+            Self::STORE_FAST__STORE_FAST => "STORE_FAST__STORE_FAST",
+            Self::STORE_SUBSCR_DICT => "STORE_SUBSCR_DICT",
+            Self::STORE_SUBSCR_LIST_INT => "STORE_SUBSCR_LIST_INT",
+            Self::UNPACK_SEQUENCE_LIST => "UNPACK_SEQUENCE_LIST",
+            Self::UNPACK_SEQUENCE_TUPLE => "UNPACK_SEQUENCE_TUPLE",
+            Self::UNPACK_SEQUENCE_TWO_TUPLE => "UNPACK_SEQUENCE_TWO_TUPLE",
+            Self::SEND_GEN => "SEND_GEN",
+        }
+    }
+
+    /// The base mnemonic of a specialized or instrumented mnemonic. Other mnemonics are returned
+    /// unchanged, including `INSTRUMENTED_LINE` and `INSTRUMENTED_INSTRUCTION`, which replace an
+    /// opcode that is stored outside of the bytecode.
+    pub fn deoptimize(&self) -> Mnemonic {
+        match self {
+            Self::INSTRUMENTED_END_FOR => Self::END_FOR,
+            Self::INSTRUMENTED_END_SEND => Self::END_SEND,
+            Self::BINARY_SUBSCR_DICT
+            | Self::BINARY_SUBSCR_GETITEM
+            | Self::BINARY_SUBSCR_LIST_INT
+            | Self::BINARY_SUBSCR_TUPLE_INT => Self::BINARY_SUBSCR,
+            Self::STORE_SUBSCR_DICT | Self::STORE_SUBSCR_LIST_INT => Self::STORE_SUBSCR,
+            Self::INSTRUMENTED_RETURN_VALUE => Self::RETURN_VALUE,
+            Self::UNPACK_SEQUENCE_LIST
+            | Self::UNPACK_SEQUENCE_TUPLE
+            | Self::UNPACK_SEQUENCE_TWO_TUPLE => Self::UNPACK_SEQUENCE,
+            Self::FOR_ITER_LIST
+            | Self::FOR_ITER_TUPLE
+            | Self::FOR_ITER_RANGE
+            | Self::FOR_ITER_GEN
+            | Self::INSTRUMENTED_FOR_ITER => Self::FOR_ITER,
+            Self::STORE_ATTR_INSTANCE_VALUE
+            | Self::STORE_ATTR_SLOT
+            | Self::STORE_ATTR_WITH_HINT => Self::STORE_ATTR,
+            Self::LOAD_CONST__LOAD_FAST => Self::LOAD_CONST,
+            Self::LOAD_ATTR_CLASS
+            | Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN
+            | Self::LOAD_ATTR_INSTANCE_VALUE
+            | Self::LOAD_ATTR_MODULE
+            | Self::LOAD_ATTR_PROPERTY
+            | Self::LOAD_ATTR_SLOT
+            | Self::LOAD_ATTR_WITH_HINT
+            | Self::LOAD_ATTR_METHOD_LAZY_DICT
+            | Self::LOAD_ATTR_METHOD_NO_DICT
+            | Self::LOAD_ATTR_METHOD_WITH_VALUES => Self::LOAD_ATTR,
+            Self::COMPARE_OP_FLOAT | Self::COMPARE_OP_INT | Self::COMPARE_OP_STR => {
+                Self::COMPARE_OP
+            }
+            Self::INSTRUMENTED_JUMP_FORWARD => Self::JUMP_FORWARD,
+            Self::INSTRUMENTED_POP_JUMP_IF_FALSE => Self::POP_JUMP_IF_FALSE,
+            Self::INSTRUMENTED_POP_JUMP_IF_TRUE => Self::POP_JUMP_IF_TRUE,
+            Self::LOAD_GLOBAL_BUILTIN | Self::LOAD_GLOBAL_MODULE => Self::LOAD_GLOBAL,
+            Self::INSTRUMENTED_RETURN_CONST => Self::RETURN_CONST,
+            Self::BINARY_OP_ADD_FLOAT
+            | Self::BINARY_OP_ADD_INT
+            | Self::BINARY_OP_ADD_UNICODE
+            | Self::BINARY_OP_INPLACE_ADD_UNICODE
+            | Self::BINARY_OP_MULTIPLY_FLOAT
+            | Self::BINARY_OP_MULTIPLY_INT
+            | Self::BINARY_OP_SUBTRACT_FLOAT
+            | Self::BINARY_OP_SUBTRACT_INT => Self::BINARY_OP,
+            Self::SEND_GEN => Self::SEND,
+            Self::LOAD_FAST__LOAD_CONST | Self::LOAD_FAST__LOAD_FAST => Self::LOAD_FAST,
+            Self::STORE_FAST__LOAD_FAST | Self::STORE_FAST__STORE_FAST => Self::STORE_FAST,
+            Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => Self::POP_JUMP_IF_NOT_NONE,
+            Self::INSTRUMENTED_POP_JUMP_IF_NONE => Self::POP_JUMP_IF_NONE,
+            Self::INSTRUMENTED_JUMP_BACKWARD => Self::JUMP_BACKWARD,
+            Self::LOAD_SUPER_ATTR_ATTR
+            | Self::LOAD_SUPER_ATTR_METHOD
+            | Self::INSTRUMENTED_LOAD_SUPER_ATTR => Self::LOAD_SUPER_ATTR,
+            Self::INSTRUMENTED_CALL_FUNCTION_EX => Self::CALL_FUNCTION_EX,
+            Self::INSTRUMENTED_YIELD_VALUE => Self::YIELD_VALUE,
+            Self::INSTRUMENTED_RESUME => Self::RESUME,
+            Self::CALL_PY_EXACT_ARGS
+            | Self::CALL_PY_WITH_DEFAULTS
+            | Self::CALL_BOUND_METHOD_EXACT_ARGS
+            | Self::CALL_BUILTIN_CLASS
+            | Self::CALL_BUILTIN_FAST_WITH_KEYWORDS
+            | Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            | Self::CALL_NO_KW_BUILTIN_FAST
+            | Self::CALL_NO_KW_BUILTIN_O
+            | Self::CALL_NO_KW_ISINSTANCE
+            | Self::CALL_NO_KW_LEN
+            | Self::CALL_NO_KW_LIST_APPEND
+            | Self::CALL_NO_KW_METHOD_DESCRIPTOR_FAST
+            | Self::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            | Self::CALL_NO_KW_METHOD_DESCRIPTOR_O
+            | Self::CALL_NO_KW_STR_1
+            | Self::CALL_NO_KW_TUPLE_1
+            | Self::CALL_NO_KW_TYPE_1
+            | Self::INSTRUMENTED_CALL => Self::CALL,
+            _ => *self,
+        }
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = ParseMnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CACHE" => Ok(Self::CACHE),
+            "POP_TOP" => Ok(Self::POP_TOP),
+            "PUSH_NULL" => Ok(Self::PUSH_NULL),
+            "INTERPRETER_EXIT" => Ok(Self::INTERPRETER_EXIT),
+            "END_FOR" => Ok(Self::END_FOR),
+            "END_SEND" => Ok(Self::END_SEND),
+            "NOP" => Ok(Self::NOP),
+            "UNARY_NEGATIVE" => Ok(Self::UNARY_NEGATIVE),
+            "UNARY_NOT" => Ok(Self::UNARY_NOT),
+            "UNARY_INVERT" => Ok(Self::UNARY_INVERT),
+            "RESERVED" => Ok(Self::RESERVED),
+            "BINARY_SUBSCR" => Ok(Self::BINARY_SUBSCR),
+            "BINARY_SLICE" => Ok(Self::BINARY_SLICE),
+            "STORE_SLICE" => Ok(Self::STORE_SLICE),
+            "GET_LEN" => Ok(Self::GET_LEN),
+            "MATCH_MAPPING" => Ok(Self::MATCH_MAPPING),
+            "MATCH_SEQUENCE" => Ok(Self::MATCH_SEQUENCE),
+            "MATCH_KEYS" => Ok(Self::MATCH_KEYS),
+            "PUSH_EXC_INFO" => Ok(Self::PUSH_EXC_INFO),
+            "CHECK_EXC_MATCH" => Ok(Self::CHECK_EXC_MATCH),
+            "CHECK_EG_MATCH" => Ok(Self::CHECK_EG_MATCH),
+            "WITH_EXCEPT_START" => Ok(Self::WITH_EXCEPT_START),
+            "GET_AITER" => Ok(Self::GET_AITER),
+            "GET_ANEXT" => Ok(Self::GET_ANEXT),
+            "BEFORE_ASYNC_WITH" => Ok(Self::BEFORE_ASYNC_WITH),
+            "BEFORE_WITH" => Ok(Self::BEFORE_WITH),
+            "END_ASYNC_FOR" => Ok(Self::END_ASYNC_FOR),
+            "CLEANUP_THROW" => Ok(Self::CLEANUP_THROW),
+            "STORE_SUBSCR" => Ok(Self::STORE_SUBSCR),
+            "DELETE_SUBSCR" => Ok(Self::DELETE_SUBSCR),
+            "GET_ITER" => Ok(Self::GET_ITER),
+            "GET_YIELD_FROM_ITER" => Ok(Self::GET_YIELD_FROM_ITER),
+            "LOAD_BUILD_CLASS" => Ok(Self::LOAD_BUILD_CLASS),
+            "LOAD_ASSERTION_ERROR" => Ok(Self::LOAD_ASSERTION_ERROR),
+            "RETURN_GENERATOR" => Ok(Self::RETURN_GENERATOR),
+            "RETURN_VALUE" => Ok(Self::RETURN_VALUE),
+            "SETUP_ANNOTATIONS" => Ok(Self::SETUP_ANNOTATIONS),
+            "LOAD_LOCALS" => Ok(Self::LOAD_LOCALS),
+            "POP_EXCEPT" => Ok(Self::POP_EXCEPT),
+            "STORE_NAME" => Ok(Self::STORE_NAME),
+            "DELETE_NAME" => Ok(Self::DELETE_NAME),
+            "UNPACK_SEQUENCE" => Ok(Self::UNPACK_SEQUENCE),
+            "FOR_ITER" => Ok(Self::FOR_ITER),
+            "UNPACK_EX" => Ok(Self::UNPACK_EX),
+            "STORE_ATTR" => Ok(Self::STORE_ATTR),
+            "DELETE_ATTR" => Ok(Self::DELETE_ATTR),
+            "STORE_GLOBAL" => Ok(Self::STORE_GLOBAL),
+            "DELETE_GLOBAL" => Ok(Self::DELETE_GLOBAL),
+            "SWAP" => Ok(Self::SWAP),
+            "LOAD_CONST" => Ok(Self::LOAD_CONST),
+            "LOAD_NAME" => Ok(Self::LOAD_NAME),
+            "BUILD_TUPLE" => Ok(Self::BUILD_TUPLE),
+            "BUILD_LIST" => Ok(Self::BUILD_LIST),
+            "BUILD_SET" => Ok(Self::BUILD_SET),
+            "BUILD_MAP" => Ok(Self::BUILD_MAP),
+            "LOAD_ATTR" => Ok(Self::LOAD_ATTR),
+            "COMPARE_OP" => Ok(Self::COMPARE_OP),
+            "IMPORT_NAME" => Ok(Self::IMPORT_NAME),
+            "IMPORT_FROM" => Ok(Self::IMPORT_FROM),
+            "JUMP_FORWARD" => Ok(Self::JUMP_FORWARD),
+            "POP_JUMP_IF_FALSE" => Ok(Self::POP_JUMP_IF_FALSE),
+            "POP_JUMP_IF_TRUE" => Ok(Self::POP_JUMP_IF_TRUE),
+            "LOAD_GLOBAL" => Ok(Self::LOAD_GLOBAL),
+            "IS_OP" => Ok(Self::IS_OP),
+            "CONTAINS_OP" => Ok(Self::CONTAINS_OP),
+            "RERAISE" => Ok(Self::RERAISE),
+            "COPY" => Ok(Self::COPY),
+            "RETURN_CONST" => Ok(Self::RETURN_CONST),
+            "BINARY_OP" => Ok(Self::BINARY_OP),
+            "SEND" => Ok(Self::SEND),
+            "LOAD_FAST" => Ok(Self::LOAD_FAST),
+            "STORE_FAST" => Ok(Self::STORE_FAST),
+            "DELETE_FAST" => Ok(Self::DELETE_FAST),
+            "LOAD_FAST_CHECK" => Ok(Self::LOAD_FAST_CHECK),
+            "POP_JUMP_IF_NOT_NONE" => Ok(Self::POP_JUMP_IF_NOT_NONE),
+            "POP_JUMP_IF_NONE" => Ok(Self::POP_JUMP_IF_NONE),
+            "RAISE_VARARGS" => Ok(Self::RAISE_VARARGS),
+            "GET_AWAITABLE" => Ok(Self::GET_AWAITABLE),
+            "MAKE_FUNCTION" => Ok(Self::MAKE_FUNCTION),
+            "BUILD_SLICE" => Ok(Self::BUILD_SLICE),
+            "JUMP_BACKWARD_NO_INTERRUPT" => Ok(Self::JUMP_BACKWARD_NO_INTERRUPT),
+            "MAKE_CELL" => Ok(Self::MAKE_CELL),
+            "LOAD_CLOSURE" => Ok(Self::LOAD_CLOSURE),
+            "LOAD_DEREF" => Ok(Self::LOAD_DEREF),
+            "STORE_DEREF" => Ok(Self::STORE_DEREF),
+            "DELETE_DEREF" => Ok(Self::DELETE_DEREF),
+            "JUMP_BACKWARD" => Ok(Self::JUMP_BACKWARD),
+            "LOAD_SUPER_ATTR" => Ok(Self::LOAD_SUPER_ATTR),
+            "CALL_FUNCTION_EX" => Ok(Self::CALL_FUNCTION_EX),
+            "LOAD_FAST_AND_CLEAR" => Ok(Self::LOAD_FAST_AND_CLEAR),
+            "EXTENDED_ARG" => Ok(Self::EXTENDED_ARG),
+            "LIST_APPEND" => Ok(Self::LIST_APPEND),
+            "SET_ADD" => Ok(Self::SET_ADD),
+            "MAP_ADD" => Ok(Self::MAP_ADD),
+            "COPY_FREE_VARS" => Ok(Self::COPY_FREE_VARS),
+            "YIELD_VALUE" => Ok(Self::YIELD_VALUE),
+            "RESUME" => Ok(Self::RESUME),
+            "MATCH_CLASS" => Ok(Self::MATCH_CLASS),
+            "FORMAT_VALUE" => Ok(Self::FORMAT_VALUE),
+            "BUILD_CONST_KEY_MAP" => Ok(Self::BUILD_CONST_KEY_MAP),
+            "BUILD_STRING" => Ok(Self::BUILD_STRING),
+            "LIST_EXTEND" => Ok(Self::LIST_EXTEND),
+            "SET_UPDATE" => Ok(Self::SET_UPDATE),
+            "DICT_MERGE" => Ok(Self::DICT_MERGE),
+            "DICT_UPDATE" => Ok(Self::DICT_UPDATE),
+            "CALL" => Ok(Self::CALL),
+            "KW_NAMES" => Ok(Self::KW_NAMES),
+            "CALL_INTRINSIC_1" => Ok(Self::CALL_INTRINSIC_1),
+            "CALL_INTRINSIC_2" => Ok(Self::CALL_INTRINSIC_2),
+            "LOAD_FROM_DICT_OR_GLOBALS" => Ok(Self::LOAD_FROM_DICT_OR_GLOBALS),
+            "LOAD_FROM_DICT_OR_DEREF" => Ok(Self::LOAD_FROM_DICT_OR_DEREF),
+            "INSTRUMENTED_LOAD_SUPER_ATTR" => Ok(Self::INSTRUMENTED_LOAD_SUPER_ATTR),
+            "INSTRUMENTED_POP_JUMP_IF_NONE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_NONE),
+            "INSTRUMENTED_POP_JUMP_IF_NOT_NONE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE),
+            "INSTRUMENTED_RESUME" => Ok(Self::INSTRUMENTED_RESUME),
+            "INSTRUMENTED_CALL" => Ok(Self::INSTRUMENTED_CALL),
+            "INSTRUMENTED_RETURN_VALUE" => Ok(Self::INSTRUMENTED_RETURN_VALUE),
+            "INSTRUMENTED_YIELD_VALUE" => Ok(Self::INSTRUMENTED_YIELD_VALUE),
+            "INSTRUMENTED_CALL_FUNCTION_EX" => Ok(Self::INSTRUMENTED_CALL_FUNCTION_EX),
+            "INSTRUMENTED_JUMP_FORWARD" => Ok(Self::INSTRUMENTED_JUMP_FORWARD),
+            "INSTRUMENTED_JUMP_BACKWARD" => Ok(Self::INSTRUMENTED_JUMP_BACKWARD),
+            "INSTRUMENTED_RETURN_CONST" => Ok(Self::INSTRUMENTED_RETURN_CONST),
+            "INSTRUMENTED_FOR_ITER" => Ok(Self::INSTRUMENTED_FOR_ITER),
+            "INSTRUMENTED_POP_JUMP_IF_FALSE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_FALSE),
+            "INSTRUMENTED_POP_JUMP_IF_TRUE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_TRUE),
+            "INSTRUMENTED_END_FOR" => Ok(Self::INSTRUMENTED_END_FOR),
+            "INSTRUMENTED_END_SEND" => Ok(Self::INSTRUMENTED_END_SEND),
+            "INSTRUMENTED_INSTRUCTION" => Ok(Self::INSTRUMENTED_INSTRUCTION),
+            "INSTRUMENTED_LINE" => Ok(Self::INSTRUMENTED_LINE),
+            "BINARY_OP_ADD_FLOAT" => Ok(Self::BINARY_OP_ADD_FLOAT),
+            "BINARY_OP_ADD_INT" => Ok(Self::BINARY_OP_ADD_INT),
+            "BINARY_OP_ADD_UNICODE" => Ok(Self::BINARY_OP_ADD_UNICODE),
+            "BINARY_OP_INPLACE_ADD_UNICODE" => Ok(Self::BINARY_OP_INPLACE_ADD_UNICODE),
+            "BINARY_OP_MULTIPLY_FLOAT" => Ok(Self::BINARY_OP_MULTIPLY_FLOAT),
+            "BINARY_OP_MULTIPLY_INT" => Ok(Self::BINARY_OP_MULTIPLY_INT),
+            "BINARY_OP_SUBTRACT_FLOAT" => Ok(Self::BINARY_OP_SUBTRACT_FLOAT),
+            "BINARY_OP_SUBTRACT_INT" => Ok(Self::BINARY_OP_SUBTRACT_INT),
+            "BINARY_SUBSCR_DICT" => Ok(Self::BINARY_SUBSCR_DICT),
+            "BINARY_SUBSCR_GETITEM" => Ok(Self::BINARY_SUBSCR_GETITEM),
+            "BINARY_SUBSCR_LIST_INT" => Ok(Self::BINARY_SUBSCR_LIST_INT),
+            "BINARY_SUBSCR_TUPLE_INT" => Ok(Self::BINARY_SUBSCR_TUPLE_INT),
+            "CALL_PY_EXACT_ARGS" => Ok(Self::CALL_PY_EXACT_ARGS),
+            "CALL_PY_WITH_DEFAULTS" => Ok(Self::CALL_PY_WITH_DEFAULTS),
+            "CALL_BOUND_METHOD_EXACT_ARGS" => Ok(Self::CALL_BOUND_METHOD_EXACT_ARGS),
+            "CALL_BUILTIN_CLASS" => Ok(Self::CALL_BUILTIN_CLASS),
+            "CALL_BUILTIN_FAST_WITH_KEYWORDS" => Ok(Self::CALL_BUILTIN_FAST_WITH_KEYWORDS),
+            "CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS" => {
+                Ok(Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS)
+            }
+            "CALL_NO_KW_BUILTIN_FAST" => Ok(Self::CALL_NO_KW_BUILTIN_FAST),
+            "CALL_NO_KW_BUILTIN_O" => Ok(Self::CALL_NO_KW_BUILTIN_O),
+            "CALL_NO_KW_ISINSTANCE" => Ok(Self::CALL_NO_KW_ISINSTANCE),
+            "CALL_NO_KW_LEN" => Ok(Self::CALL_NO_KW_LEN),
+            "CALL_NO_KW_LIST_APPEND" => Ok(Self::CALL_NO_KW_LIST_APPEND),
+            "CALL_NO_KW_METHOD_DESCRIPTOR_FAST" => Ok(Self::CALL_NO_KW_METHOD_DESCRIPTOR_FAST),
+            "CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS" => Ok(Self::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS),
+            "CALL_NO_KW_METHOD_DESCRIPTOR_O" => Ok(Self::CALL_NO_KW_METHOD_DESCRIPTOR_O),
+            "CALL_NO_KW_STR_1" => Ok(Self::CALL_NO_KW_STR_1),
+            "CALL_NO_KW_TUPLE_1" => Ok(Self::CALL_NO_KW_TUPLE_1),
+            "CALL_NO_KW_TYPE_1" => Ok(Self::CALL_NO_KW_TYPE_1),
+            "COMPARE_OP_FLOAT" => Ok(Self::COMPARE_OP_FLOAT),
+            "COMPARE_OP_INT" => Ok(Self::COMPARE_OP_INT),
+            "COMPARE_OP_STR" => Ok(Self::COMPARE_OP_STR),
+            "FOR_ITER_LIST" => Ok(Self::FOR_ITER_LIST),
+            "FOR_ITER_TUPLE" => Ok(Self::FOR_ITER_TUPLE),
+            "FOR_ITER_RANGE" => Ok(Self::FOR_ITER_RANGE),
+            "FOR_ITER_GEN" => Ok(Self::FOR_ITER_GEN),
+            "LOAD_SUPER_ATTR_ATTR" => Ok(Self::LOAD_SUPER_ATTR_ATTR),
+            "LOAD_SUPER_ATTR_METHOD" => Ok(Self::LOAD_SUPER_ATTR_METHOD),
+            "LOAD_ATTR_CLASS" => Ok(Self::LOAD_ATTR_CLASS),
+            "LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN" => Ok(Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN),
+            "LOAD_ATTR_INSTANCE_VALUE" => Ok(Self::LOAD_ATTR_INSTANCE_VALUE),
+            "LOAD_ATTR_MODULE" => Ok(Self::LOAD_ATTR_MODULE),
+            "LOAD_ATTR_PROPERTY" => Ok(Self::LOAD_ATTR_PROPERTY),
+            "LOAD_ATTR_SLOT" => Ok(Self::LOAD_ATTR_SLOT),
+            "LOAD_ATTR_WITH_HINT" => Ok(Self::LOAD_ATTR_WITH_HINT),
+            "LOAD_ATTR_METHOD_LAZY_DICT" => Ok(Self::LOAD_ATTR_METHOD_LAZY_DICT),
+            "LOAD_ATTR_METHOD_NO_DICT" => Ok(Self::LOAD_ATTR_METHOD_NO_DICT),
+            "LOAD_ATTR_METHOD_WITH_VALUES" => Ok(Self::LOAD_ATTR_METHOD_WITH_VALUES),
+            "LOAD_CONST__LOAD_FAST" => Ok(Self::LOAD_CONST__LOAD_FAST),
+            "LOAD_FAST__LOAD_CONST" => Ok(Self::LOAD_FAST__LOAD_CONST),
+            "LOAD_FAST__LOAD_FAST" => Ok(Self::LOAD_FAST__LOAD_FAST),
+            "LOAD_GLOBAL_BUILTIN" => Ok(Self::LOAD_GLOBAL_BUILTIN),
+            "LOAD_GLOBAL_MODULE" => Ok(Self::LOAD_GLOBAL_MODULE),
+            "STORE_ATTR_INSTANCE_VALUE" => Ok(Self::STORE_ATTR_INSTANCE_VALUE),
+            "STORE_ATTR_SLOT" => Ok(Self::STORE_ATTR_SLOT),
+            "STORE_ATTR_WITH_HINT" => Ok(Self::STORE_ATTR_WITH_HINT),
+            "STORE_FAST__LOAD_FAST" => Ok(Self::STORE_FAST__LOAD_FAST),
+            "STORE_FAST__STORE_FAST" => Ok(Self::STORE_FAST__STORE_FAST),
+            "STORE_SUBSCR_DICT" => Ok(Self::STORE_SUBSCR_DICT),
+            "STORE_SUBSCR_LIST_INT" => Ok(Self::STORE_SUBSCR_LIST_INT),
+            "UNPACK_SEQUENCE_LIST" => Ok(Self::UNPACK_SEQUENCE_LIST),
+            "UNPACK_SEQUENCE_TUPLE" => Ok(Self::UNPACK_SEQUENCE_TUPLE),
+            "UNPACK_SEQUENCE_TWO_TUPLE" => Ok(Self::UNPACK_SEQUENCE_TWO_TUPLE),
+            "SEND_GEN" => Ok(Self::SEND_GEN),
+            _ => Err(ParseMnemonicError(s.to_string())),
+        }
+    }
+}
+
+/// Opcodes taken from https://github.com/python/cpython/blob/3.12/Include/opcode.h.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Primitive)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum Standard {
+    CACHE = 0,
+    POP_TOP = 1,
+    PUSH_NULL = 2,
+    INTERPRETER_EXIT = 3,
+    END_FOR = 4,
+    END_SEND = 5,
+
+    NOP = 9,
+
+    UNARY_NEGATIVE = 11,
+    UNARY_NOT = 12,
+
+    UNARY_INVERT = 15,
+
+    RESERVED = 17,
+
+    BINARY_SUBSCR = 25,
+    BINARY_SLICE = 26,
+    STORE_SLICE = 27,
+
+    GET_LEN = 30,
+    MATCH_MAPPING = 31,
+    MATCH_SEQUENCE = 32,
+    MATCH_KEYS = 33,
+
+    PUSH_EXC_INFO = 35,
+    CHECK_EXC_MATCH = 36,
+    CHECK_EG_MATCH = 37,
+
+    WITH_EXCEPT_START = 49,
+    GET_AITER = 50,
+    GET_ANEXT = 51,
+    BEFORE_ASYNC_WITH = 52,
+    BEFORE_WITH = 53,
+    END_ASYNC_FOR = 54,
+    CLEANUP_THROW = 55,
+
+    STORE_SUBSCR = 60,
+    DELETE_SUBSCR = 61,
+
+    GET_ITER = 68,
+    GET_YIELD_FROM_ITER = 69,
+
+    LOAD_BUILD_CLASS = 71,
+
+    LOAD_ASSERTION_ERROR = 74,
+    RETURN_GENERATOR = 75,
+
+    RETURN_VALUE = 83,
+
+    SETUP_ANNOTATIONS = 85,
+
+    LOAD_LOCALS = 87,
+
+    POP_EXCEPT = 89,
+
+    // Opcodes with arguments
+    STORE_NAME = 90,
+    DELETE_NAME = 91,
+    UNPACK_SEQUENCE = 92,
+    FOR_ITER = 93,
+    UNPACK_EX = 94,
+    STORE_ATTR = 95,
+    DELETE_ATTR = 96,
+    STORE_GLOBAL = 97,
+    DELETE_GLOBAL = 98,
+    SWAP = 99,
+    LOAD_CONST = 100,
+    LOAD_NAME = 101,
+    BUILD_TUPLE = 102,
+    BUILD_LIST = 103,
+    BUILD_SET = 104,
+    BUILD_MAP = 105,
+    LOAD_ATTR = 106,
+    COMPARE_OP = 107,
+    IMPORT_NAME = 108,
+    IMPORT_FROM = 109,
+    JUMP_FORWARD = 110,
+
+    POP_JUMP_IF_FALSE = 114,
+    POP_JUMP_IF_TRUE = 115,
+    LOAD_GLOBAL = 116,
+    IS_OP = 117,
+    CONTAINS_OP = 118,
+    RERAISE = 119,
+    COPY = 120,
+    RETURN_CONST = 121,
+    BINARY_OP = 122,
+    SEND = 123,
+    LOAD_FAST = 124,
+    STORE_FAST = 125,
+    DELETE_FAST = 126,
+    LOAD_FAST_CHECK = 127,
+    POP_JUMP_IF_NOT_NONE = 128,
+    POP_JUMP_IF_NONE = 129,
+    RAISE_VARARGS = 130,
+    GET_AWAITABLE = 131,
+    MAKE_FUNCTION = 132,
+    BUILD_SLICE = 133,
+    JUMP_BACKWARD_NO_INTERRUPT = 134,
+    MAKE_CELL = 135,
+    LOAD_CLOSURE = 136,
+    LOAD_DEREF = 137,
+    STORE_DEREF = 138,
+    DELETE_DEREF = 139,
+    JUMP_BACKWARD = 140,
+    LOAD_SUPER_ATTR = 141,
+    CALL_FUNCTION_EX = 142,
+    LOAD_FAST_AND_CLEAR = 143,
+    EXTENDED_ARG = 144,
+    LIST_APPEND = 145,
+    SET_ADD = 146,
+    MAP_ADD = 147,
+
+    COPY_FREE_VARS = 149,
+    YIELD_VALUE = 150,
+    RESUME = 151,
+    MATCH_CLASS = 152,
+
+    FORMAT_VALUE = 155,
+    BUILD_CONST_KEY_MAP = 156,
+    BUILD_STRING = 157,
+
+    LIST_EXTEND = 162,
+    SET_UPDATE = 163,
+    DICT_MERGE = 164,
+    DICT_UPDATE = 165,
+
+    CALL = 171,
+    KW_NAMES = 172,
+    CALL_INTRINSIC_1 = 173,
+    CALL_INTRINSIC_2 = 174,
+    LOAD_FROM_DICT_OR_GLOBALS = 175,
+    LOAD_FROM_DICT_OR_DEREF = 176,
+
+    // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+    INSTRUMENTED_LOAD_SUPER_ATTR = 237,
+    INSTRUMENTED_POP_JUMP_IF_NONE = 238,
+    INSTRUMENTED_POP_JUMP_IF_NOT_NONE = 239,
+    INSTRUMENTED_RESUME = 240,
+    INSTRUMENTED_CALL = 241,
+    INSTRUMENTED_RETURN_VALUE = 242,
+    INSTRUMENTED_YIELD_VALUE = 243,
+    INSTRUMENTED_CALL_FUNCTION_EX = 244,
+    INSTRUMENTED_JUMP_FORWARD = 245,
+    INSTRUMENTED_JUMP_BACKWARD = 246,
+    INSTRUMENTED_RETURN_CONST = 247,
+    INSTRUMENTED_FOR_ITER = 248,
+    INSTRUMENTED_POP_JUMP_IF_FALSE = 249,
+    INSTRUMENTED_POP_JUMP_IF_TRUE = 250,
+    INSTRUMENTED_END_FOR = 251,
+    INSTRUMENTED_END_SEND = 252,
+    INSTRUMENTED_INSTRUCTION = 253,
+    INSTRUMENTED_LINE = 254,
+
+    // Specialized opcodes, which only appear in code that has been executed
+    BINARY_OP_ADD_FLOAT = 6,
+    BINARY_OP_ADD_INT = 7,
+    BINARY_OP_ADD_UNICODE = 8,
+    BINARY_OP_INPLACE_ADD_UNICODE = 10,
+    BINARY_OP_MULTIPLY_FLOAT = 13,
+    BINARY_OP_MULTIPLY_INT = 14,
+    BINARY_OP_SUBTRACT_FLOAT = 16,
+    BINARY_OP_SUBTRACT_INT = 18,
+    BINARY_SUBSCR_DICT = 19,
+    BINARY_SUBSCR_GETITEM = 20,
+    BINARY_SUBSCR_LIST_INT = 21,
+    BINARY_SUBSCR_TUPLE_INT = 22,
+    CALL_PY_EXACT_ARGS = 23,
+    CALL_PY_WITH_DEFAULTS = 24,
+    CALL_BOUND_METHOD_EXACT_ARGS = 28,
+    CALL_BUILTIN_CLASS = 29,
+    CALL_BUILTIN_FAST_WITH_KEYWORDS = 34,
+    CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS = 38,
+    CALL_NO_KW_BUILTIN_FAST = 39,
+    CALL_NO_KW_BUILTIN_O = 40,
+    CALL_NO_KW_ISINSTANCE = 41,
+    CALL_NO_KW_LEN = 42,
+    CALL_NO_KW_LIST_APPEND = 43,
+    CALL_NO_KW_METHOD_DESCRIPTOR_FAST = 44,
+    CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS = 45,
+    CALL_NO_KW_METHOD_DESCRIPTOR_O = 46,
+    CALL_NO_KW_STR_1 = 47,
+    CALL_NO_KW_TUPLE_1 = 48,
+    CALL_NO_KW_TYPE_1 = 56,
+    COMPARE_OP_FLOAT = 57,
+    COMPARE_OP_INT = 58,
+    COMPARE_OP_STR = 59,
+    FOR_ITER_LIST = 62,
+    FOR_ITER_TUPLE = 63,
+    FOR_ITER_RANGE = 64,
+    FOR_ITER_GEN = 65,
+    LOAD_SUPER_ATTR_ATTR = 66,
+    LOAD_SUPER_ATTR_METHOD = 67,
+    LOAD_ATTR_CLASS = 70,
+    LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN = 72,
+    LOAD_ATTR_INSTANCE_VALUE = 73,
+    LOAD_ATTR_MODULE = 76,
+    LOAD_ATTR_PROPERTY = 77,
+    LOAD_ATTR_SLOT = 78,
+    LOAD_ATTR_WITH_HINT = 79,
+    LOAD_ATTR_METHOD_LAZY_DICT = 80,
+    LOAD_ATTR_METHOD_NO_DICT = 81,
+    LOAD_ATTR_METHOD_WITH_VALUES = 82,
+    LOAD_CONST__LOAD_FAST = 84,
+    LOAD_FAST__LOAD_CONST = 86,
+    LOAD_FAST__LOAD_FAST = 88,
+    LOAD_GLOBAL_BUILTIN = 111,
+    LOAD_GLOBAL_MODULE = 112,
+    STORE_ATTR_INSTANCE_VALUE = 113,
+    STORE_ATTR_SLOT = 148,
+    STORE_ATTR_WITH_HINT = 153,
+    STORE_FAST__LOAD_FAST = 154,
+    STORE_FAST__STORE_FAST = 158,
+    STORE_SUBSCR_DICT = 159,
+    STORE_SUBSCR_LIST_INT = 160,
+    UNPACK_SEQUENCE_LIST = 161,
+    UNPACK_SEQUENCE_TUPLE = 166,
+    UNPACK_SEQUENCE_TWO_TUPLE = 167,
+    SEND_GEN = 168,
+}
+
+impl From<Mnemonic> for Standard {
+    fn from(mnemonic: Mnemonic) -> Self {
+        match mnemonic {
+            Mnemonic::CACHE => Self::CACHE,
+            Mnemonic::POP_TOP => Self::POP_TOP,
+            Mnemonic::PUSH_NULL => Self::PUSH_NULL,
+            Mnemonic::INTERPRETER_EXIT => Self::INTERPRETER_EXIT,
+            Mnemonic::END_FOR => Self::END_FOR,
+            Mnemonic::END_SEND => Self::END_SEND,
+
+            Mnemonic::NOP => Self::NOP,
+
+            Mnemonic::UNARY_NEGATIVE => Self::UNARY_NEGATIVE,
+            Mnemonic::UNARY_NOT => Self::UNARY_NOT,
+
+            Mnemonic::UNARY_INVERT => Self::UNARY_INVERT,
+
+            Mnemonic::RESERVED => Self::RESERVED,
+
+            Mnemonic::BINARY_SUBSCR => Self::BINARY_SUBSCR,
+            Mnemonic::BINARY_SLICE => Self::BINARY_SLICE,
+            Mnemonic::STORE_SLICE => Self::STORE_SLICE,
+
+            Mnemonic::GET_LEN => Self::GET_LEN,
+            Mnemonic::MATCH_MAPPING => Self::MATCH_MAPPING,
+            Mnemonic::MATCH_SEQUENCE => Self::MATCH_SEQUENCE,
+            Mnemonic::MATCH_KEYS => Self::MATCH_KEYS,
+
+            Mnemonic::PUSH_EXC_INFO => Self::PUSH_EXC_INFO,
+            Mnemonic::CHECK_EXC_MATCH => Self::CHECK_EXC_MATCH,
+            Mnemonic::CHECK_EG_MATCH => Self::CHECK_EG_MATCH,
+
+            Mnemonic::WITH_EXCEPT_START => Self::WITH_EXCEPT_START,
+            Mnemonic::GET_AITER => Self::GET_AITER,
+            Mnemonic::GET_ANEXT => Self::GET_ANEXT,
+            Mnemonic::BEFORE_ASYNC_WITH => Self::BEFORE_ASYNC_WITH,
+            Mnemonic::BEFORE_WITH => Self::BEFORE_WITH,
+            Mnemonic::END_ASYNC_FOR => Self::END_ASYNC_FOR,
+            Mnemonic::CLEANUP_THROW => Self::CLEANUP_THROW,
+
+            Mnemonic::STORE_SUBSCR => Self::STORE_SUBSCR,
+            Mnemonic::DELETE_SUBSCR => Self::DELETE_SUBSCR,
+
+            Mnemonic::GET_ITER => Self::GET_ITER,
+            Mnemonic::GET_YIELD_FROM_ITER => Self::GET_YIELD_FROM_ITER,
+
+            Mnemonic::LOAD_BUILD_CLASS => Self::LOAD_BUILD_CLASS,
+
+            Mnemonic::LOAD_ASSERTION_ERROR => Self::LOAD_ASSERTION_ERROR,
+            Mnemonic::RETURN_GENERATOR => Self::RETURN_GENERATOR,
+
+            Mnemonic::RETURN_VALUE => Self::RETURN_VALUE,
+
+            Mnemonic::SETUP_ANNOTATIONS => Self::SETUP_ANNOTATIONS,
+
+            Mnemonic::LOAD_LOCALS => Self::LOAD_LOCALS,
+
+            Mnemonic::POP_EXCEPT => Self::POP_EXCEPT,
+
+            // Opcodes with arguments
+            Mnemonic::STORE_NAME => Self::STORE_NAME,
+            Mnemonic::DELETE_NAME => Self::DELETE_NAME,
+            Mnemonic::UNPACK_SEQUENCE => Self::UNPACK_SEQUENCE,
+            Mnemonic::FOR_ITER => Self::FOR_ITER,
+            Mnemonic::UNPACK_EX => Self::UNPACK_EX,
+            Mnemonic::STORE_ATTR => Self::STORE_ATTR,
+            Mnemonic::DELETE_ATTR => Self::DELETE_ATTR,
+            Mnemonic::STORE_GLOBAL => Self::STORE_GLOBAL,
+            Mnemonic::DELETE_GLOBAL => Self::DELETE_GLOBAL,
+            Mnemonic::SWAP => Self::SWAP,
+            Mnemonic::LOAD_CONST => Self::LOAD_CONST,
+            Mnemonic::LOAD_NAME => Self::LOAD_NAME,
+            Mnemonic::BUILD_TUPLE => Self::BUILD_TUPLE,
+            Mnemonic::BUILD_LIST => Self::BUILD_LIST,
+            Mnemonic::BUILD_SET => Self::BUILD_SET,
+            Mnemonic::BUILD_MAP => Self::BUILD_MAP,
+            Mnemonic::LOAD_ATTR => Self::LOAD_ATTR,
+            Mnemonic::COMPARE_OP => Self::COMPARE_OP,
+            Mnemonic::IMPORT_NAME => Self::IMPORT_NAME,
+            Mnemonic::IMPORT_FROM => Self::IMPORT_FROM,
+            Mnemonic::JUMP_FORWARD => Self::JUMP_FORWARD,
+
+            Mnemonic::POP_JUMP_IF_FALSE => Self::POP_JUMP_IF_FALSE,
+            Mnemonic::POP_JUMP_IF_TRUE => Self::POP_JUMP_IF_TRUE,
+            Mnemonic::LOAD_GLOBAL => Self::LOAD_GLOBAL,
+            Mnemonic::IS_OP => Self::IS_OP,
+            Mnemonic::CONTAINS_OP => Self::CONTAINS_OP,
+            Mnemonic::RERAISE => Self::RERAISE,
+            Mnemonic::COPY => Self::COPY,
+            Mnemonic::RETURN_CONST => Self::RETURN_CONST,
+            Mnemonic::BINARY_OP => Self::BINARY_OP,
+            Mnemonic::SEND => Self::SEND,
+            Mnemonic::LOAD_FAST => Self::LOAD_FAST,
+            Mnemonic::STORE_FAST => Self::STORE_FAST,
+            Mnemonic::DELETE_FAST => Self::DELETE_FAST,
+            Mnemonic::LOAD_FAST_CHECK => Self::LOAD_FAST_CHECK,
+            Mnemonic::POP_JUMP_IF_NOT_NONE => Self::POP_JUMP_IF_NOT_NONE,
+            Mnemonic::POP_JUMP_IF_NONE => Self::POP_JUMP_IF_NONE,
+            Mnemonic::RAISE_VARARGS => Self::RAISE_VARARGS,
+            Mnemonic::GET_AWAITABLE => Self::GET_AWAITABLE,
+            Mnemonic::MAKE_FUNCTION => Self::MAKE_FUNCTION,
+            Mnemonic::BUILD_SLICE => Self::BUILD_SLICE,
+            Mnemonic::JUMP_BACKWARD_NO_INTERRUPT => Self::JUMP_BACKWARD_NO_INTERRUPT,
+            Mnemonic::MAKE_CELL => Self::MAKE_CELL,
+            Mnemonic::LOAD_CLOSURE => Self::LOAD_CLOSURE,
+            Mnemonic::LOAD_DEREF => Self::LOAD_DEREF,
+            Mnemonic::STORE_DEREF => Self::STORE_DEREF,
+            Mnemonic::DELETE_DEREF => Self::DELETE_DEREF,
+            Mnemonic::JUMP_BACKWARD => Self::JUMP_BACKWARD,
+            Mnemonic::LOAD_SUPER_ATTR => Self::LOAD_SUPER_ATTR,
+            Mnemonic::CALL_FUNCTION_EX => Self::CALL_FUNCTION_EX,
+            Mnemonic::LOAD_FAST_AND_CLEAR => Self::LOAD_FAST_AND_CLEAR,
+            Mnemonic::EXTENDED_ARG => Self::EXTENDED_ARG,
+            Mnemonic::LIST_APPEND => Self::LIST_APPEND,
+            Mnemonic::SET_ADD => Self::SET_ADD,
+            Mnemonic::MAP_ADD => Self::MAP_ADD,
+
+            Mnemonic::COPY_FREE_VARS => Self::COPY_FREE_VARS,
+            Mnemonic::YIELD_VALUE => Self::YIELD_VALUE,
+            Mnemonic::RESUME => Self::RESUME,
+            Mnemonic::MATCH_CLASS => Self::MATCH_CLASS,
+
+            Mnemonic::FORMAT_VALUE => Self::FORMAT_VALUE,
+            Mnemonic::BUILD_CONST_KEY_MAP => Self::BUILD_CONST_KEY_MAP,
+            Mnemonic::BUILD_STRING => Self::BUILD_STRING,
+
+            Mnemonic::LIST_EXTEND => Self::LIST_EXTEND,
+            Mnemonic::SET_UPDATE => Self::SET_UPDATE,
+            Mnemonic::DICT_MERGE => Self::DICT_MERGE,
+            Mnemonic::DICT_UPDATE => Self::DICT_UPDATE,
+
+            Mnemonic::CALL => Self::CALL,
+            Mnemonic::KW_NAMES => Self::KW_NAMES,
+            Mnemonic::CALL_INTRINSIC_1 => Self::CALL_INTRINSIC_1,
+            Mnemonic::CALL_INTRINSIC_2 => Self::CALL_INTRINSIC_2,
+            Mnemonic::LOAD_FROM_DICT_OR_GLOBALS => Self::LOAD_FROM_DICT_OR_GLOBALS,
+            Mnemonic::LOAD_FROM_DICT_OR_DEREF => Self::LOAD_FROM_DICT_OR_DEREF,
+
+            // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+            Mnemonic::INSTRUMENTED_LOAD_SUPER_ATTR => Self::INSTRUMENTED_LOAD_SUPER_ATTR,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_NONE => Self::INSTRUMENTED_POP_JUMP_IF_NONE,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+            Mnemonic::INSTRUMENTED_RESUME => Self::INSTRUMENTED_RESUME,
+            Mnemonic::INSTRUMENTED_CALL => Self::INSTRUMENTED_CALL,
+            Mnemonic::INSTRUMENTED_RETURN_VALUE => Self::INSTRUMENTED_RETURN_VALUE,
+            Mnemonic::INSTRUMENTED_YIELD_VALUE => Self::INSTRUMENTED_YIELD_VALUE,
+            Mnemonic::INSTRUMENTED_CALL_FUNCTION_EX => Self::INSTRUMENTED_CALL_FUNCTION_EX,
+            Mnemonic::INSTRUMENTED_JUMP_FORWARD => Self::INSTRUMENTED_JUMP_FORWARD,
+            Mnemonic::INSTRUMENTED_JUMP_BACKWARD => Self::INSTRUMENTED_JUMP_BACKWARD,
+            Mnemonic::INSTRUMENTED_RETURN_CONST => Self::INSTRUMENTED_RETURN_CONST,
+            Mnemonic::INSTRUMENTED_FOR_ITER => Self::INSTRUMENTED_FOR_ITER,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_FALSE => Self::INSTRUMENTED_POP_JUMP_IF_FALSE,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_TRUE => Self::INSTRUMENTED_POP_JUMP_IF_TRUE,
+            Mnemonic::INSTRUMENTED_END_FOR => Self::INSTRUMENTED_END_FOR,
+            Mnemonic::INSTRUMENTED_END_SEND => Self::INSTRUMENTED_END_SEND,
+            Mnemonic::INSTRUMENTED_INSTRUCTION => Self::INSTRUMENTED_INSTRUCTION,
+            Mnemonic::INSTRUMENTED_LINE => Self::INSTRUMENTED_LINE,
+
+            // Specialized opcodes, which only appear in code that has been executed
+            Mnemonic::BINARY_OP_ADD_FLOAT => Self::BINARY_OP_ADD_FLOAT,
+            Mnemonic::BINARY_OP_ADD_INT => Self::BINARY_OP_ADD_INT,
+            Mnemonic::BINARY_OP_ADD_UNICODE => Self::BINARY_OP_ADD_UNICODE,
+            Mnemonic::BINARY_OP_INPLACE_ADD_UNICODE => Self::BINARY_OP_INPLACE_ADD_UNICODE,
+            Mnemonic::BINARY_OP_MULTIPLY_FLOAT => Self::BINARY_OP_MULTIPLY_FLOAT,
+            Mnemonic::BINARY_OP_MULTIPLY_INT => Self::BINARY_OP_MULTIPLY_INT,
+            Mnemonic::BINARY_OP_SUBTRACT_FLOAT => Self::BINARY_OP_SUBTRACT_FLOAT,
+            Mnemonic::BINARY_OP_SUBTRACT_INT => Self::BINARY_OP_SUBTRACT_INT,
+            Mnemonic::BINARY_SUBSCR_DICT => Self::BINARY_SUBSCR_DICT,
+            Mnemonic::BINARY_SUBSCR_GETITEM => Self::BINARY_SUBSCR_GETITEM,
+            Mnemonic::BINARY_SUBSCR_LIST_INT => Self::BINARY_SUBSCR_LIST_INT,
+            Mnemonic::BINARY_SUBSCR_TUPLE_INT => Self::BINARY_SUBSCR_TUPLE_INT,
+            Mnemonic::CALL_PY_EXACT_ARGS => Self::CALL_PY_EXACT_ARGS,
+            Mnemonic::CALL_PY_WITH_DEFAULTS => Self::CALL_PY_WITH_DEFAULTS,
+            Mnemonic::CALL_BOUND_METHOD_EXACT_ARGS => Self::CALL_BOUND_METHOD_EXACT_ARGS,
+            Mnemonic::CALL_BUILTIN_CLASS => Self::CALL_BUILTIN_CLASS,
+            Mnemonic::CALL_BUILTIN_FAST_WITH_KEYWORDS => Self::CALL_BUILTIN_FAST_WITH_KEYWORDS,
+            Mnemonic::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            }
+            Mnemonic::CALL_NO_KW_BUILTIN_FAST => Self::CALL_NO_KW_BUILTIN_FAST,
+            Mnemonic::CALL_NO_KW_BUILTIN_O => Self::CALL_NO_KW_BUILTIN_O,
+            Mnemonic::CALL_NO_KW_ISINSTANCE => Self::CALL_NO_KW_ISINSTANCE,
+            Mnemonic::CALL_NO_KW_LEN => Self::CALL_NO_KW_LEN,
+            Mnemonic::CALL_NO_KW_LIST_APPEND => Self::CALL_NO_KW_LIST_APPEND,
+            Mnemonic::CALL_NO_KW_METHOD_DESCRIPTOR_FAST => Self::CALL_NO_KW_METHOD_DESCRIPTOR_FAST,
+            Mnemonic::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS => {
+                Self::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            }
+            Mnemonic::CALL_NO_KW_METHOD_DESCRIPTOR_O => Self::CALL_NO_KW_METHOD_DESCRIPTOR_O,
+            Mnemonic::CALL_NO_KW_STR_1 => Self::CALL_NO_KW_STR_1,
+            Mnemonic::CALL_NO_KW_TUPLE_1 => Self::CALL_NO_KW_TUPLE_1,
+            Mnemonic::CALL_NO_KW_TYPE_1 => Self::CALL_NO_KW_TYPE_1,
+            Mnemonic::COMPARE_OP_FLOAT => Self::COMPARE_OP_FLOAT,
+            Mnemonic::COMPARE_OP_INT => Self::COMPARE_OP_INT,
+            Mnemonic::COMPARE_OP_STR => Self::COMPARE_OP_STR,
+            Mnemonic::FOR_ITER_LIST => Self::FOR_ITER_LIST,
+            Mnemonic::FOR_ITER_TUPLE => Self::FOR_ITER_TUPLE,
+            Mnemonic::FOR_ITER_RANGE => Self::FOR_ITER_RANGE,
+            Mnemonic::FOR_ITER_GEN => Self::FOR_ITER_GEN,
+            Mnemonic::LOAD_SUPER_ATTR_ATTR => Self::LOAD_SUPER_ATTR_ATTR,
+            Mnemonic::LOAD_SUPER_ATTR_METHOD => Self::LOAD_SUPER_ATTR_METHOD,
+            Mnemonic::LOAD_ATTR_CLASS => Self::LOAD_ATTR_CLASS,
+            Mnemonic::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN => Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+            Mnemonic::LOAD_ATTR_INSTANCE_VALUE => Self::LOAD_ATTR_INSTANCE_VALUE,
+            Mnemonic::LOAD_ATTR_MODULE => Self::LOAD_ATTR_MODULE,
+            Mnemonic::LOAD_ATTR_PROPERTY => Self::LOAD_ATTR_PROPERTY,
+            Mnemonic::LOAD_ATTR_SLOT => Self::LOAD_ATTR_SLOT,
+            Mnemonic::LOAD_ATTR_WITH_HINT => Self::LOAD_ATTR_WITH_HINT,
+            Mnemonic::LOAD_ATTR_METHOD_LAZY_DICT => Self::LOAD_ATTR_METHOD_LAZY_DICT,
+            Mnemonic::LOAD_ATTR_METHOD_NO_DICT => Self::LOAD_ATTR_METHOD_NO_DICT,
+            Mnemonic::LOAD_ATTR_METHOD_WITH_VALUES => Self::LOAD_ATTR_METHOD_WITH_VALUES,
+            Mnemonic::LOAD_CONST__LOAD_FAST => Self::LOAD_CONST__LOAD_FAST,
+            Mnemonic::LOAD_FAST__LOAD_CONST => Self::LOAD_FAST__LOAD_CONST,
+            Mnemonic::LOAD_FAST__LOAD_FAST => Self::LOAD_FAST__LOAD_FAST,
+            Mnemonic::LOAD_GLOBAL_BUILTIN => Self::LOAD_GLOBAL_BUILTIN,
+            Mnemonic::LOAD_GLOBAL_MODULE => Self::LOAD_GLOBAL_MODULE,
+            Mnemonic::STORE_ATTR_INSTANCE_VALUE => Self::STORE_ATTR_INSTANCE_VALUE,
+            Mnemonic::STORE_ATTR_SLOT => Self::STORE_ATTR_SLOT,
+            Mnemonic::STORE_ATTR_WITH_HINT => Self::STORE_ATTR_WITH_HINT,
+            Mnemonic::STORE_FAST__LOAD_FAST => Self::STORE_FAST__LOAD_FAST,
+            Mnemonic::STORE_FAST__STORE_FAST => Self::STORE_FAST__STORE_FAST,
+            Mnemonic::STORE_SUBSCR_DICT => Self::STORE_SUBSCR_DICT,
+            Mnemonic::STORE_SUBSCR_LIST_INT => Self::STORE_SUBSCR_LIST_INT,
+            Mnemonic::UNPACK_SEQUENCE_LIST => Self::UNPACK_SEQUENCE_LIST,
+            Mnemonic::UNPACK_SEQUENCE_TUPLE => Self::UNPACK_SEQUENCE_TUPLE,
+            Mnemonic::UNPACK_SEQUENCE_TWO_TUPLE => Self::UNPACK_SEQUENCE_TWO_TUPLE,
+            Mnemonic::SEND_GEN => Self::SEND_GEN,
+        }
+    }
+}
+
+impl super::Opcode for Standard {
+    type Mnemonic = Mnemonic;
+
+    /// Whether or not this opcode has an argument
+    fn has_arg(&self) -> bool {
+        self.deoptimize() as u8 >= 90
+    }
+
+    /// Whether or not this opcode has an extended argument
+    fn has_extended_arg(&self) -> bool {
+        self.deoptimize() == Self::EXTENDED_ARG
+    }
+
+    /// Whether or not this opcode is the `EXTENDED_ARG` prefix
+    fn is_extended_arg(&self) -> bool {
+        self.deoptimize() == Self::EXTENDED_ARG
+    }
+
+    /// The `EXTENDED_ARG` prefix opcode of this opcode table
+    fn extended_arg() -> Self {
+        Self::EXTENDED_ARG
+    }
+
+    /// Whether or not this opcode has a constant parameter
+    fn has_const(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::LOAD_CONST | Self::RETURN_CONST | Self::KW_NAMES
+        )
+    }
+
+    /// Whether or not this opcode is a boolean operation
+    fn has_comp(&self) -> bool {
+        matches!(self.deoptimize(), Self::COMPARE_OP)
+    }
+
+    /// Whether or not this opcode has a relative jump target
+    fn is_relative_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::FOR_ITER
+                | Self::JUMP_FORWARD
+                | Self::POP_JUMP_IF_FALSE
+                | Self::POP_JUMP_IF_TRUE
+                | Self::SEND
+                | Self::POP_JUMP_IF_NOT_NONE
+                | Self::POP_JUMP_IF_NONE
+                | Self::JUMP_BACKWARD_NO_INTERRUPT
+                | Self::JUMP_BACKWARD
+        )
+    }
+
+    /// Whether or not this opcode has an absolute jump target
+    fn is_absolute_jump(&self) -> bool {
+        false
+    }
+
+    /// Whether or not this opcode is another type of "special" jumping instruction
+    /// e.g. FOR_ITER, SETUP_LOOP, etc.
+    fn is_other_conditional_jump(&self) -> bool {
+        matches!(self.deoptimize(), Self::FOR_ITER | Self::SEND)
+    }
+
+    /// Whether or not this opcode is a conditional jump
+    fn is_conditional_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::POP_JUMP_IF_FALSE
+                | Self::POP_JUMP_IF_TRUE
+                | Self::POP_JUMP_IF_NOT_NONE
+                | Self::POP_JUMP_IF_NONE
+        )
+    }
+
+    /// Whether or not this opcode accesses an attribute by name
+    fn has_name(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::STORE_NAME
+                | Self::DELETE_NAME
+                | Self::STORE_ATTR
+                | Self::DELETE_ATTR
+                | Self::STORE_GLOBAL
+                | Self::DELETE_GLOBAL
+                | Self::LOAD_NAME
+                | Self::LOAD_ATTR
+                | Self::IMPORT_NAME
+                | Self::IMPORT_FROM
+                | Self::LOAD_GLOBAL
+                | Self::LOAD_SUPER_ATTR
+                | Self::LOAD_FROM_DICT_OR_GLOBALS
+        )
+    }
+
+    /// Whether or not this opcode accesses a local variable
+    fn has_local(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::LOAD_FAST
+                | Self::STORE_FAST
+                | Self::DELETE_FAST
+                | Self::LOAD_FAST_CHECK
+                | Self::LOAD_FAST_AND_CLEAR
+        )
+    }
+
+    /// Whether or not this opcode accesses a free variable
+    fn has_free(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::MAKE_CELL
+                | Self::LOAD_CLOSURE
+                | Self::LOAD_DEREF
+                | Self::STORE_DEREF
+                | Self::DELETE_DEREF
+                | Self::LOAD_FROM_DICT_OR_DEREF
+        )
+    }
+
+    /// How instructions of this opcode table are laid out in bytecode
+    fn encoding() -> Encoding {
+        Encoding::Wordcode
+    }
+
+    /// The number of bytes each unit of a jump argument stands for
+    fn jump_unit() -> usize {
+        2
+    }
+
+    /// The number of inline cache code units which follow instructions with this opcode
+    fn cache_entries(&self) -> usize {
+        match self.deoptimize() {
+            Self::BINARY_SUBSCR
+            | Self::STORE_SUBSCR
+            | Self::UNPACK_SEQUENCE
+            | Self::FOR_ITER
+            | Self::COMPARE_OP
+            | Self::BINARY_OP
+            | Self::SEND
+            | Self::LOAD_SUPER_ATTR => 1,
+            Self::CALL => 3,
+            Self::STORE_ATTR | Self::LOAD_GLOBAL => 4,
+            Self::LOAD_ATTR => 9,
+            _ => 0,
+        }
+    }
+
+    /// The `CACHE` opcode which fills inline cache entries
+    fn cache() -> Option<Self> {
+        Some(Self::CACHE)
+    }
+
+    /// Whether or not this opcode is a relative jump whose target precedes it
+    fn is_backward_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::JUMP_BACKWARD_NO_INTERRUPT | Self::JUMP_BACKWARD
+        )
+    }
+
+    /// The index which an argument of this opcode refers to
+    fn argument_index(&self, arg: u32) -> u32 {
+        match self.deoptimize() {
+            // The low bit of the argument pushes a NULL or `self` as well
+            Self::LOAD_GLOBAL | Self::LOAD_ATTR => arg >> 1,
+            // The low bits of the argument are the method and two-argument `super()` flags
+            Self::LOAD_SUPER_ATTR => arg >> 2,
+            _ => arg,
+        }
+    }
+
+    /// The base opcode of a specialized or instrumented opcode
+    fn deoptimize(&self) -> Self {
+        Self::from(self.mnemonic().deoptimize())
+    }
+
+    fn mnemonic(&self) -> Mnemonic {
+        match self {
+            Self::CACHE => Mnemonic::CACHE,
+            Self::POP_TOP => Mnemonic::POP_TOP,
+            Self::PUSH_NULL => Mnemonic::PUSH_NULL,
+            Self::INTERPRETER_EXIT => Mnemonic::INTERPRETER_EXIT,
+            Self::END_FOR => Mnemonic::END_FOR,
+            Self::END_SEND => Mnemonic::END_SEND,
+
+            Self::NOP => Mnemonic::NOP,
+
+            Self::UNARY_NEGATIVE => Mnemonic::UNARY_NEGATIVE,
+            Self::UNARY_NOT => Mnemonic::UNARY_NOT,
+
+            Self::UNARY_INVERT => Mnemonic::UNARY_INVERT,
+
+            Self::RESERVED => Mnemonic::RESERVED,
+
+            Self::BINARY_SUBSCR => Mnemonic::BINARY_SUBSCR,
+            Self::BINARY_SLICE => Mnemonic::BINARY_SLICE,
+            Self::STORE_SLICE => Mnemonic::STORE_SLICE,
+
+            Self::GET_LEN => Mnemonic::GET_LEN,
+            Self::MATCH_MAPPING => Mnemonic::MATCH_MAPPING,
+            Self::MATCH_SEQUENCE => Mnemonic::MATCH_SEQUENCE,
+            Self::MATCH_KEYS => Mnemonic::MATCH_KEYS,
+
+            Self::PUSH_EXC_INFO => Mnemonic::PUSH_EXC_INFO,
+            Self::CHECK_EXC_MATCH => Mnemonic::CHECK_EXC_MATCH,
+            Self::CHECK_EG_MATCH => Mnemonic::CHECK_EG_MATCH,
+
+            Self::WITH_EXCEPT_START => Mnemonic::WITH_EXCEPT_START,
+            Self::GET_AITER => Mnemonic::GET_AITER,
+            Self::GET_ANEXT => Mnemonic::GET_ANEXT,
+            Self::BEFORE_ASYNC_WITH => Mnemonic::BEFORE_ASYNC_WITH,
+            Self::BEFORE_WITH => Mnemonic::BEFORE_WITH,
+            Self::END_ASYNC_FOR => Mnemonic::END_ASYNC_FOR,
+            Self::CLEANUP_THROW => Mnemonic::CLEANUP_THROW,
+
+            Self::STORE_SUBSCR => Mnemonic::STORE_SUBSCR,
+            Self::DELETE_SUBSCR => Mnemonic::DELETE_SUBSCR,
+
+            Self::GET_ITER => Mnemonic::GET_ITER,
+            Self::GET_YIELD_FROM_ITER => Mnemonic::GET_YIELD_FROM_ITER,
+
+            Self::LOAD_BUILD_CLASS => Mnemonic::LOAD_BUILD_CLASS,
+
+            Self::LOAD_ASSERTION_ERROR => Mnemonic::LOAD_ASSERTION_ERROR,
+            Self::RETURN_GENERATOR => Mnemonic::RETURN_GENERATOR,
+
+            Self::RETURN_VALUE => Mnemonic::RETURN_VALUE,
+
+            Self::SETUP_ANNOTATIONS => Mnemonic::SETUP_ANNOTATIONS,
+
+            Self::LOAD_LOCALS => Mnemonic::LOAD_LOCALS,
+
+            Self::POP_EXCEPT => Mnemonic::POP_EXCEPT,
+
+            // Opcodes with arguments
+            Self::STORE_NAME => Mnemonic::STORE_NAME,
+            Self::DELETE_NAME => Mnemonic::DELETE_NAME,
+            Self::UNPACK_SEQUENCE => Mnemonic::UNPACK_SEQUENCE,
+            Self::FOR_ITER => Mnemonic::FOR_ITER,
+            Self::UNPACK_EX => Mnemonic::UNPACK_EX,
+            Self::STORE_ATTR => Mnemonic::STORE_ATTR,
+            Self::DELETE_ATTR => Mnemonic::DELETE_ATTR,
+            Self::STORE_GLOBAL => Mnemonic::STORE_GLOBAL,
+            Self::DELETE_GLOBAL => Mnemonic::DELETE_GLOBAL,
+            Self::SWAP => Mnemonic::SWAP,
+            Self::LOAD_CONST => Mnemonic::LOAD_CONST,
+            Self::LOAD_NAME => Mnemonic::LOAD_NAME,
+            Self::BUILD_TUPLE => Mnemonic::BUILD_TUPLE,
+            Self::BUILD_LIST => Mnemonic::BUILD_LIST,
+            Self::BUILD_SET => Mnemonic::BUILD_SET,
+            Self::BUILD_MAP => Mnemonic::BUILD_MAP,
+            Self::LOAD_ATTR => Mnemonic::LOAD_ATTR,
+            Self::COMPARE_OP => Mnemonic::COMPARE_OP,
+            Self::IMPORT_NAME => Mnemonic::IMPORT_NAME,
+            Self::IMPORT_FROM => Mnemonic::IMPORT_FROM,
+            Self::JUMP_FORWARD => Mnemonic::JUMP_FORWARD,
+
+            Self::POP_JUMP_IF_FALSE => Mnemonic::POP_JUMP_IF_FALSE,
+            Self::POP_JUMP_IF_TRUE => Mnemonic::POP_JUMP_IF_TRUE,
+            Self::LOAD_GLOBAL => Mnemonic::LOAD_GLOBAL,
+            Self::IS_OP => Mnemonic::IS_OP,
+            Self::CONTAINS_OP => Mnemonic::CONTAINS_OP,
+            Self::RERAISE => Mnemonic::RERAISE,
+            Self::COPY => Mnemonic::COPY,
+            Self::RETURN_CONST => Mnemonic::RETURN_CONST,
+            Self::BINARY_OP => Mnemonic::BINARY_OP,
+            Self::SEND => Mnemonic::SEND,
+            Self::LOAD_FAST => Mnemonic::LOAD_FAST,
+            Self::STORE_FAST => Mnemonic::STORE_FAST,
+            Self::DELETE_FAST => Mnemonic::DELETE_FAST,
+            Self::LOAD_FAST_CHECK => Mnemonic::LOAD_FAST_CHECK,
+            Self::POP_JUMP_IF_NOT_NONE => Mnemonic::POP_JUMP_IF_NOT_NONE,
+            Self::POP_JUMP_IF_NONE => Mnemonic::POP_JUMP_IF_NONE,
+            Self::RAISE_VARARGS => Mnemonic::RAISE_VARARGS,
+            Self::GET_AWAITABLE => Mnemonic::GET_AWAITABLE,
+            Self::MAKE_FUNCTION => Mnemonic::MAKE_FUNCTION,
+            Self::BUILD_SLICE => Mnemonic::BUILD_SLICE,
+            Self::JUMP_BACKWARD_NO_INTERRUPT => Mnemonic::JUMP_BACKWARD_NO_INTERRUPT,
+            Self::MAKE_CELL => Mnemonic::MAKE_CELL,
+            Self::LOAD_CLOSURE => Mnemonic::LOAD_CLOSURE,
+            Self::LOAD_DEREF => Mnemonic::LOAD_DEREF,
+            Self::STORE_DEREF => Mnemonic::STORE_DEREF,
+            Self::DELETE_DEREF => Mnemonic::DELETE_DEREF,
+            Self::JUMP_BACKWARD => Mnemonic::JUMP_BACKWARD,
+            Self::LOAD_SUPER_ATTR => Mnemonic::LOAD_SUPER_ATTR,
+            Self::CALL_FUNCTION_EX => Mnemonic::CALL_FUNCTION_EX,
+            Self::LOAD_FAST_AND_CLEAR => Mnemonic::LOAD_FAST_AND_CLEAR,
+            Self::EXTENDED_ARG => Mnemonic::EXTENDED_ARG,
+            Self::LIST_APPEND => Mnemonic::LIST_APPEND,
+            Self::SET_ADD => Mnemonic::SET_ADD,
+            Self::MAP_ADD => Mnemonic::MAP_ADD,
+
+            Self::COPY_FREE_VARS => Mnemonic::COPY_FREE_VARS,
+            Self::YIELD_VALUE => Mnemonic::YIELD_VALUE,
+            Self::RESUME => Mnemonic::RESUME,
+            Self::MATCH_CLASS => Mnemonic::MATCH_CLASS,
+
+            Self::FORMAT_VALUE => Mnemonic::FORMAT_VALUE,
+            Self::BUILD_CONST_KEY_MAP => Mnemonic::BUILD_CONST_KEY_MAP,
+            Self::BUILD_STRING => Mnemonic::BUILD_STRING,
+
+            Self::LIST_EXTEND => Mnemonic::LIST_EXTEND,
+            Self::SET_UPDATE => Mnemonic::SET_UPDATE,
+            Self::DICT_MERGE => Mnemonic::DICT_MERGE,
+            Self::DICT_UPDATE => Mnemonic::DICT_UPDATE,
+
+            Self::CALL => Mnemonic::CALL,
+            Self::KW_NAMES => Mnemonic::KW_NAMES,
+            Self::CALL_INTRINSIC_1 => Mnemonic::CALL_INTRINSIC_1,
+            Self::CALL_INTRINSIC_2 => Mnemonic::CALL_INTRINSIC_2,
+            Self::LOAD_FROM_DICT_OR_GLOBALS => Mnemonic::LOAD_FROM_DICT_OR_GLOBALS,
+            Self::LOAD_FROM_DICT_OR_DEREF => Mnemonic::LOAD_FROM_DICT_OR_DEREF,
+
+            // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+            Self::INSTRUMENTED_LOAD_SUPER_ATTR => Mnemonic::INSTRUMENTED_LOAD_SUPER_ATTR,
+            Self::INSTRUMENTED_POP_JUMP_IF_NONE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_NONE,
+            Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+            Self::INSTRUMENTED_RESUME => Mnemonic::INSTRUMENTED_RESUME,
+            Self::INSTRUMENTED_CALL => Mnemonic::INSTRUMENTED_CALL,
+            Self::INSTRUMENTED_RETURN_VALUE => Mnemonic::INSTRUMENTED_RETURN_VALUE,
+            Self::INSTRUMENTED_YIELD_VALUE => Mnemonic::INSTRUMENTED_YIELD_VALUE,
+            Self::INSTRUMENTED_CALL_FUNCTION_EX => Mnemonic::INSTRUMENTED_CALL_FUNCTION_EX,
+            Self::INSTRUMENTED_JUMP_FORWARD => Mnemonic::INSTRUMENTED_JUMP_FORWARD,
+            Self::INSTRUMENTED_JUMP_BACKWARD => Mnemonic::INSTRUMENTED_JUMP_BACKWARD,
+            Self::INSTRUMENTED_RETURN_CONST => Mnemonic::INSTRUMENTED_RETURN_CONST,
+            Self::INSTRUMENTED_FOR_ITER => Mnemonic::INSTRUMENTED_FOR_ITER,
+            Self::INSTRUMENTED_POP_JUMP_IF_FALSE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_FALSE,
+            Self::INSTRUMENTED_POP_JUMP_IF_TRUE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_TRUE,
+            Self::INSTRUMENTED_END_FOR => Mnemonic::INSTRUMENTED_END_FOR,
+            Self::INSTRUMENTED_END_SEND => Mnemonic::INSTRUMENTED_END_SEND,
+            Self::INSTRUMENTED_INSTRUCTION => Mnemonic::INSTRUMENTED_INSTRUCTION,
+            Self::INSTRUMENTED_LINE => Mnemonic::INSTRUMENTED_LINE,
+
+            // Specialized opcodes, which only appear in code that has been executed
+            Self::BINARY_OP_ADD_FLOAT => Mnemonic::BINARY_OP_ADD_FLOAT,
+            Self::BINARY_OP_ADD_INT => Mnemonic::BINARY_OP_ADD_INT,
+            Self::BINARY_OP_ADD_UNICODE => Mnemonic::BINARY_OP_ADD_UNICODE,
+            Self::BINARY_OP_INPLACE_ADD_UNICODE => Mnemonic::BINARY_OP_INPLACE_ADD_UNICODE,
+            Self::BINARY_OP_MULTIPLY_FLOAT => Mnemonic::BINARY_OP_MULTIPLY_FLOAT,
+            Self::BINARY_OP_MULTIPLY_INT => Mnemonic::BINARY_OP_MULTIPLY_INT,
+            Self::BINARY_OP_SUBTRACT_FLOAT => Mnemonic::BINARY_OP_SUBTRACT_FLOAT,
+            Self::BINARY_OP_SUBTRACT_INT => Mnemonic::BINARY_OP_SUBTRACT_INT,
+            Self::BINARY_SUBSCR_DICT => Mnemonic::BINARY_SUBSCR_DICT,
+            Self::BINARY_SUBSCR_GETITEM => Mnemonic::BINARY_SUBSCR_GETITEM,
+            Self::BINARY_SUBSCR_LIST_INT => Mnemonic::BINARY_SUBSCR_LIST_INT,
+            Self::BINARY_SUBSCR_TUPLE_INT => Mnemonic::BINARY_SUBSCR_TUPLE_INT,
+            Self::CALL_PY_EXACT_ARGS => Mnemonic::CALL_PY_EXACT_ARGS,
+            Self::CALL_PY_WITH_DEFAULTS => Mnemonic::CALL_PY_WITH_DEFAULTS,
+            Self::CALL_BOUND_METHOD_EXACT_ARGS => Mnemonic::CALL_BOUND_METHOD_EXACT_ARGS,
+            Self::CALL_BUILTIN_CLASS => Mnemonic::CALL_BUILTIN_CLASS,
+            Self::CALL_BUILTIN_FAST_WITH_KEYWORDS => Mnemonic::CALL_BUILTIN_FAST_WITH_KEYWORDS,
+            Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                Mnemonic::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            }
+            Self::CALL_NO_KW_BUILTIN_FAST => Mnemonic::CALL_NO_KW_BUILTIN_FAST,
+            Self::CALL_NO_KW_BUILTIN_O => Mnemonic::CALL_NO_KW_BUILTIN_O,
+            Self::CALL_NO_KW_ISINSTANCE => Mnemonic::CALL_NO_KW_ISINSTANCE,
+            Self::CALL_NO_KW_LEN => Mnemonic::CALL_NO_KW_LEN,
+            Self::CALL_NO_KW_LIST_APPEND => Mnemonic::CALL_NO_KW_LIST_APPEND,
+            Self::CALL_NO_KW_METHOD_DESCRIPTOR_FAST => Mnemonic::CALL_NO_KW_METHOD_DESCRIPTOR_FAST,
+            Self::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS => {
+                Mnemonic::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            }
+            Self::CALL_NO_KW_METHOD_DESCRIPTOR_O => Mnemonic::CALL_NO_KW_METHOD_DESCRIPTOR_O,
+            Self::CALL_NO_KW_STR_1 => Mnemonic::CALL_NO_KW_STR_1,
+            Self::CALL_NO_KW_TUPLE_1 => Mnemonic::CALL_NO_KW_TUPLE_1,
+            Self::CALL_NO_KW_TYPE_1 => Mnemonic::CALL_NO_KW_TYPE_1,
+            Self::COMPARE_OP_FLOAT => Mnemonic::COMPARE_OP_FLOAT,
+            Self::COMPARE_OP_INT => Mnemonic::COMPARE_OP_INT,
+            Self::COMPARE_OP_STR => Mnemonic::COMPARE_OP_STR,
+            Self::FOR_ITER_LIST => Mnemonic::FOR_ITER_LIST,
+            Self::FOR_ITER_TUPLE => Mnemonic::FOR_ITER_TUPLE,
+            Self::FOR_ITER_RANGE => Mnemonic::FOR_ITER_RANGE,
+            Self::FOR_ITER_GEN => Mnemonic::FOR_ITER_GEN,
+            Self::LOAD_SUPER_ATTR_ATTR => Mnemonic::LOAD_SUPER_ATTR_ATTR,
+            Self::LOAD_SUPER_ATTR_METHOD => Mnemonic::LOAD_SUPER_ATTR_METHOD,
+            Self::LOAD_ATTR_CLASS => Mnemonic::LOAD_ATTR_CLASS,
+            Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN => Mnemonic::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+            Self::LOAD_ATTR_INSTANCE_VALUE => Mnemonic::LOAD_ATTR_INSTANCE_VALUE,
+            Self::LOAD_ATTR_MODULE => Mnemonic::LOAD_ATTR_MODULE,
+            Self::LOAD_ATTR_PROPERTY => Mnemonic::LOAD_ATTR_PROPERTY,
+            Self::LOAD_ATTR_SLOT => Mnemonic::LOAD_ATTR_SLOT,
+            Self::LOAD_ATTR_WITH_HINT => Mnemonic::LOAD_ATTR_WITH_HINT,
+            Self::LOAD_ATTR_METHOD_LAZY_DICT => Mnemonic::LOAD_ATTR_METHOD_LAZY_DICT,
+            Self::LOAD_ATTR_METHOD_NO_DICT => Mnemonic::LOAD_ATTR_METHOD_NO_DICT,
+            Self::LOAD_ATTR_METHOD_WITH_VALUES => Mnemonic::LOAD_ATTR_METHOD_WITH_VALUES,
+            Self::LOAD_CONST__LOAD_FAST => Mnemonic::LOAD_CONST__LOAD_FAST,
+            Self::LOAD_FAST__LOAD_CONST => Mnemonic::LOAD_FAST__LOAD_CONST,
+            Self::LOAD_FAST__LOAD_FAST => Mnemonic::LOAD_FAST__LOAD_FAST,
+            Self::LOAD_GLOBAL_BUILTIN => Mnemonic::LOAD_GLOBAL_BUILTIN,
+            Self::LOAD_GLOBAL_MODULE => Mnemonic::LOAD_GLOBAL_MODULE,
+            Self::STORE_ATTR_INSTANCE_VALUE => Mnemonic::STORE_ATTR_INSTANCE_VALUE,
+            Self::STORE_ATTR_SLOT => Mnemonic::STORE_ATTR_SLOT,
+            Self::STORE_ATTR_WITH_HINT => Mnemonic::STORE_ATTR_WITH_HINT,
+            Self::STORE_FAST__LOAD_FAST => Mnemonic::STORE_FAST__LOAD_FAST,
+            Self::STORE_FAST__STORE_FAST => Mnemonic::STORE_FAST__STORE_FAST,
+            Self::STORE_SUBSCR_DICT => Mnemonic::STORE_SUBSCR_DICT,
+            Self::STORE_SUBSCR_LIST_INT => Mnemonic::STORE_SUBSCR_LIST_INT,
+            Self::UNPACK_SEQUENCE_LIST => Mnemonic::UNPACK_SEQUENCE_LIST,
+            Self::UNPACK_SEQUENCE_TUPLE => Mnemonic::UNPACK_SEQUENCE_TUPLE,
+            Self::UNPACK_SEQUENCE_TWO_TUPLE => Mnemonic::UNPACK_SEQUENCE_TWO_TUPLE,
+            Self::SEND_GEN => Mnemonic::SEND_GEN,
+        }
+    }
+}
+
+/// Pseudo-instructions used by the compiler. These are replaced with real instructions before
+/// bytecode is emitted, so they never appear in code objects.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u16)]
+pub enum Pseudo {
+    SETUP_FINALLY = 256,
+    SETUP_CLEANUP = 257,
+    SETUP_WITH = 258,
+    POP_BLOCK = 259,
+    JUMP = 260,
+    JUMP_NO_INTERRUPT = 261,
+    LOAD_METHOD = 262,
+    LOAD_SUPER_METHOD = 263,
+    LOAD_ZERO_SUPER_METHOD = 264,
+    LOAD_ZERO_SUPER_ATTR = 265,
+    STORE_FAST_MAYBE_NULL = 266,
+}
+
+impl Pseudo {
+    /// Every pseudo-instruction, in declaration order
+    pub const ALL: &'static [Pseudo] = &[
+        Self::SETUP_FINALLY,
+        Self::SETUP_CLEANUP,
+        Self::SETUP_WITH,
+        Self::POP_BLOCK,
+        Self::JUMP,
+        Self::JUMP_NO_INTERRUPT,
+        Self::LOAD_METHOD,
+        Self::LOAD_SUPER_METHOD,
+        Self::LOAD_ZERO_SUPER_METHOD,
+        Self::LOAD_ZERO_SUPER_ATTR,
+        Self::STORE_FAST_MAYBE_NULL,
+    ];
+
+    /// The canonical name of this pseudo-instruction
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SETUP_FINALLY => "SETUP_FINALLY",
+            Self::SETUP_CLEANUP => "SETUP_CLEANUP",
+            Self::SETUP_WITH => "SETUP_WITH",
+            Self::POP_BLOCK => "POP_BLOCK",
+            Self::JUMP => "JUMP",
+            Self::JUMP_NO_INTERRUPT => "JUMP_NO_INTERRUPT",
+            Self::LOAD_METHOD => "LOAD_METHOD",
+            Self::LOAD_SUPER_METHOD => "LOAD_SUPER_METHOD",
+            Self::LOAD_ZERO_SUPER_METHOD => "LOAD_ZERO_SUPER_METHOD",
+            Self::LOAD_ZERO_SUPER_ATTR => "LOAD_ZERO_SUPER_ATTR",
+            Self::STORE_FAST_MAYBE_NULL => "STORE_FAST_MAYBE_NULL",
+        }
+    }
+
+    /// The real instructions which this pseudo-instruction may be replaced with
+    pub fn targets(&self) -> &'static [Mnemonic] {
+        match self {
+            Self::SETUP_FINALLY | Self::SETUP_CLEANUP | Self::SETUP_WITH | Self::POP_BLOCK => {
+                &[Mnemonic::NOP]
+            }
+            Self::JUMP => &[Mnemonic::JUMP_FORWARD, Mnemonic::JUMP_BACKWARD],
+            Self::JUMP_NO_INTERRUPT => {
+                &[Mnemonic::JUMP_FORWARD, Mnemonic::JUMP_BACKWARD_NO_INTERRUPT]
+            }
+            Self::LOAD_METHOD => &[Mnemonic::LOAD_ATTR],
+            Self::LOAD_SUPER_METHOD | Self::LOAD_ZERO_SUPER_METHOD | Self::LOAD_ZERO_SUPER_ATTR => {
+                &[Mnemonic::LOAD_SUPER_ATTR]
+            }
+            Self::STORE_FAST_MAYBE_NULL => &[Mnemonic::STORE_FAST],
+        }
+    }
+}
+
+impl fmt::Display for Pseudo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Pseudo {
+    type Err = ParseMnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SETUP_FINALLY" => Ok(Self::SETUP_FINALLY),
+            "SETUP_CLEANUP" => Ok(Self::SETUP_CLEANUP),
+            "SETUP_WITH" => Ok(Self::SETUP_WITH),
+            "POP_BLOCK" => Ok(Self::POP_BLOCK),
+            "JUMP" => Ok(Self::JUMP),
+            "JUMP_NO_INTERRUPT" => Ok(Self::JUMP_NO_INTERRUPT),
+            "LOAD_METHOD" => Ok(Self::LOAD_METHOD),
+            "LOAD_SUPER_METHOD" => Ok(Self::LOAD_SUPER_METHOD),
+            "LOAD_ZERO_SUPER_METHOD" => Ok(Self::LOAD_ZERO_SUPER_METHOD),
+            "LOAD_ZERO_SUPER_ATTR" => Ok(Self::LOAD_ZERO_SUPER_ATTR),
+            "STORE_FAST_MAYBE_NULL" => Ok(Self::STORE_FAST_MAYBE_NULL),
+            _ => Err(ParseMnemonicError(s.to_string())),
+        }
+    }
+}
+
+impl super::StackEffect for Mnemonic {
+    fn stack_adjustment(&self, arg: Option<u32>) -> isize {
+        match self {
+            // Meta instructions
+            Self::NOP => 0,
+            Self::RESERVED => 0,
+            Self::POP_TOP => -1,
+            Self::PUSH_NULL => 1,
+            Self::SWAP => 0,
+            Self::COPY => 1,
+            // Unary ops
+            Self::UNARY_NEGATIVE
+            | Self::UNARY_NOT
+            | Self::UNARY_INVERT
+            | Self::GET_ITER
+            | Self::GET_YIELD_FROM_ITER
+            | Self::GET_AITER
+            | Self::GET_AWAITABLE => 0,
+            // Binary ops
+            Self::BINARY_OP => -1,
+            Self::BINARY_SUBSCR => -1,
+            // The container, start and stop are replaced by the slice
+            Self::BINARY_SLICE => -2,
+            Self::STORE_SLICE => -4,
+            Self::STORE_SUBSCR => -3,
+            Self::DELETE_SUBSCR => -2,
+            Self::LIST_APPEND | Self::SET_ADD => -1,
+            Self::MAP_ADD => -2,
+            Self::LIST_EXTEND | Self::SET_UPDATE | Self::DICT_MERGE | Self::DICT_UPDATE => -1,
+            Self::LOAD_BUILD_CLASS => 1,
+            Self::LOAD_LOCALS => 1,
+            Self::LOAD_ASSERTION_ERROR => 1,
+            Self::SETUP_ANNOTATIONS => 0,
+            Self::RETURN_VALUE => -1,
+            Self::RETURN_CONST => 0,
+            // Returns the value to the caller of the interpreter
+            Self::INTERPRETER_EXIT => -1,
+            Self::YIELD_VALUE => 0,
+            Self::RETURN_GENERATOR => 0,
+            // The previous exception is pushed below the one being handled
+            Self::PUSH_EXC_INFO => 1,
+            // Restores the previous exception
+            Self::POP_EXCEPT => -1,
+            // The exception type is replaced by the result of the match
+            Self::CHECK_EXC_MATCH | Self::CHECK_EG_MATCH => 0,
+            // The exception and the iterator
+            Self::END_ASYNC_FOR => -2,
+            // The result of `__enter__` is pushed above the exit method
+            Self::BEFORE_WITH | Self::BEFORE_ASYNC_WITH | Self::GET_ANEXT => 1,
+            Self::STORE_NAME | Self::STORE_FAST | Self::STORE_DEREF | Self::STORE_GLOBAL => -1,
+            Self::DELETE_NAME | Self::DELETE_FAST | Self::DELETE_DEREF | Self::DELETE_GLOBAL => 0,
+            Self::UNPACK_SEQUENCE => (arg.unwrap() as isize) - 1,
+            // The counts of values before and after the starred target
+            Self::UNPACK_EX => (arg.unwrap() & 0xFF) as isize + (arg.unwrap() >> 8) as isize,
+            Self::STORE_ATTR => -2,
+            Self::DELETE_ATTR => -1,
+            Self::LOAD_CONST
+            | Self::LOAD_NAME
+            | Self::LOAD_FAST
+            | Self::LOAD_CLOSURE
+            | Self::LOAD_DEREF => 1,
+            Self::LOAD_FAST_CHECK | Self::LOAD_FAST_AND_CLEAR => 1,
+            // The mapping is replaced by the value
+            Self::LOAD_FROM_DICT_OR_GLOBALS | Self::LOAD_FROM_DICT_OR_DEREF => 0,
+            Self::BUILD_TUPLE | Self::BUILD_LIST | Self::BUILD_SET => 1 - (arg.unwrap() as isize),
+            // The argument is the number of key/value pairs
+            Self::BUILD_MAP => 1 - 2 * (arg.unwrap() as isize),
+            // The low bit of the argument pushes `self` or NULL along with the attribute
+            Self::LOAD_ATTR => (arg.unwrap() & 0x01) as isize,
+            // `super`, the class and `self` are replaced by the attribute, and `self` or NULL if the
+            // low bit of the argument is set
+            Self::LOAD_SUPER_ATTR => -2 + (arg.unwrap() & 0x01) as isize,
+            // The low bit of the argument pushes a NULL before the global
+            Self::LOAD_GLOBAL => 1 + (arg.unwrap() & 0x01) as isize,
+            Self::COMPARE_OP => -1,
+            Self::IS_OP | Self::CONTAINS_OP => -1,
+            Self::IMPORT_NAME => -1,
+            Self::IMPORT_FROM => 1,
+            Self::JUMP_FORWARD => 0,
+            Self::JUMP_BACKWARD | Self::JUMP_BACKWARD_NO_INTERRUPT => 0,
+            Self::POP_JUMP_IF_FALSE
+            | Self::POP_JUMP_IF_TRUE
+            | Self::POP_JUMP_IF_NOT_NONE
+            | Self::POP_JUMP_IF_NONE => -1,
+            // The iterator is popped instead once it is exhausted
+            Self::FOR_ITER => 1,
+            // The iterator and the value left by FOR_ITER
+            Self::END_FOR => -2,
+            // The result replaces the value sent, the iterator is popped instead if it returns
+            Self::SEND => 0,
+            // The receiver below the value
+            Self::END_SEND => -1,
+            Self::CLEANUP_THROW => -1,
+            Self::RAISE_VARARGS => -(arg.unwrap() as isize),
+            // The NULL, callable, arguments and optional keyword arguments are replaced by the result
+            Self::CALL_FUNCTION_EX => -2 - (arg.unwrap() & 0x01) as isize,
+            // The arguments, the callable and `self` or NULL are replaced by the return value
+            Self::CALL => -1 - (arg.unwrap() as isize),
+            // Stores the keyword names for the next CALL
+            Self::KW_NAMES => 0,
+            Self::CALL_INTRINSIC_1 => 0,
+            Self::CALL_INTRINSIC_2 => -1,
+            // Each flag bit adds a value to be popped, the code object is replaced by the function
+            Self::MAKE_FUNCTION => -((arg.unwrap() & 0x0F).count_ones() as isize),
+            Self::BUILD_SLICE => 1 - (arg.unwrap() as isize),
+            // Bit 2 of the argument means a format spec is on the stack
+            Self::FORMAT_VALUE => {
+                if arg.unwrap() & 0x04 != 0 {
+                    -1
+                } else {
+                    0
+                }
+            }
+            // The values and the tuple of keys are replaced by the dict
+            Self::BUILD_CONST_KEY_MAP => -(arg.unwrap() as isize),
+            Self::BUILD_STRING => 1 - (arg.unwrap() as isize),
+            Self::RERAISE => -1,
+            Self::WITH_EXCEPT_START => 1,
+            // Pattern matching
+            Self::GET_LEN | Self::MATCH_MAPPING | Self::MATCH_SEQUENCE => 1,
+            Self::MATCH_KEYS => 1,
+            Self::MATCH_CLASS => -2,
+            // Frame setup
+            Self::MAKE_CELL | Self::COPY_FREE_VARS | Self::RESUME => 0,
+            // The prefix only widens the argument of the following instruction
+            Self::EXTENDED_ARG => 0,
+            // Inline cache entries are never executed
+            Self::CACHE => 0,
+            // The opcodes these replace are stored outside of the bytecode, so their effect is unknown
+            Self::INSTRUMENTED_LINE | Self::INSTRUMENTED_INSTRUCTION => 0,
+            // Specialized and instrumented opcodes have the stack effect of their base opcode
+            Self::INSTRUMENTED_LOAD_SUPER_ATTR
+            | Self::INSTRUMENTED_POP_JUMP_IF_NONE
+            | Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE
+            | Self::INSTRUMENTED_RESUME
+            | Self::INSTRUMENTED_CALL
+            | Self::INSTRUMENTED_RETURN_VALUE
+            | Self::INSTRUMENTED_YIELD_VALUE
+            | Self::INSTRUMENTED_CALL_FUNCTION_EX
+            | Self::INSTRUMENTED_JUMP_FORWARD
+            | Self::INSTRUMENTED_JUMP_BACKWARD
+            | Self::INSTRUMENTED_RETURN_CONST
+            | Self::INSTRUMENTED_FOR_ITER
+            | Self::INSTRUMENTED_POP_JUMP_IF_FALSE
+            | Self::INSTRUMENTED_POP_JUMP_IF_TRUE
+            | Self::INSTRUMENTED_END_FOR
+            | Self::INSTRUMENTED_END_SEND
+            | Self::BINARY_OP_ADD_FLOAT
+            | Self::BINARY_OP_ADD_INT
+            | Self::BINARY_OP_ADD_UNICODE
+            | Self::BINARY_OP_INPLACE_ADD_UNICODE
+            | Self::BINARY_OP_MULTIPLY_FLOAT
+            | Self::BINARY_OP_MULTIPLY_INT
+            | Self::BINARY_OP_SUBTRACT_FLOAT
+            | Self::BINARY_OP_SUBTRACT_INT
+            | Self::BINARY_SUBSCR_DICT
+            | Self::BINARY_SUBSCR_GETITEM
+            | Self::BINARY_SUBSCR_LIST_INT
+            | Self::BINARY_SUBSCR_TUPLE_INT
+            | Self::CALL_PY_EXACT_ARGS
+            | Self::CALL_PY_WITH_DEFAULTS
+            | Self::CALL_BOUND_METHOD_EXACT_ARGS
+            | Self::CALL_BUILTIN_CLASS
+            | Self::CALL_BUILTIN_FAST_WITH_KEYWORDS
+            | Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            | Self::CALL_NO_KW_BUILTIN_FAST
+            | Self::CALL_NO_KW_BUILTIN_O
+            | Self::CALL_NO_KW_ISINSTANCE
+            | Self::CALL_NO_KW_LEN
+            | Self::CALL_NO_KW_LIST_APPEND
+            | Self::CALL_NO_KW_METHOD_DESCRIPTOR_FAST
+            | Self::CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS
+            | Self::CALL_NO_KW_METHOD_DESCRIPTOR_O
+            | Self::CALL_NO_KW_STR_1
+            | Self::CALL_NO_KW_TUPLE_1
+            | Self::CALL_NO_KW_TYPE_1
+            | Self::COMPARE_OP_FLOAT
+            | Self::COMPARE_OP_INT
+            | Self::COMPARE_OP_STR
+            | Self::FOR_ITER_LIST
+            | Self::FOR_ITER_TUPLE
+            | Self::FOR_ITER_RANGE
+            | Self::FOR_ITER_GEN
+            | Self::LOAD_SUPER_ATTR_ATTR
+            | Self::LOAD_SUPER_ATTR_METHOD
+            | Self::LOAD_ATTR_CLASS
+            | Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN
+            | Self::LOAD_ATTR_INSTANCE_VALUE
+            | Self::LOAD_ATTR_MODULE
+            | Self::LOAD_ATTR_PROPERTY
+            | Self::LOAD_ATTR_SLOT
+            | Self::LOAD_ATTR_WITH_HINT
+            | Self::LOAD_ATTR_METHOD_LAZY_DICT
+            | Self::LOAD_ATTR_METHOD_NO_DICT
+            | Self::LOAD_ATTR_METHOD_WITH_VALUES
+            | Self::LOAD_CONST__LOAD_FAST
+            | Self::LOAD_FAST__LOAD_CONST
+            | Self::LOAD_FAST__LOAD_FAST
+            | Self::LOAD_GLOBAL_BUILTIN
+            | Self::LOAD_GLOBAL_MODULE
+            | Self::STORE_ATTR_INSTANCE_VALUE
+            | Self::STORE_ATTR_SLOT
+            | Self::STORE_ATTR_WITH_HINT
+            | Self::STORE_FAST__LOAD_FAST
+            | Self::STORE_FAST__STORE_FAST
+            | Self::STORE_SUBSCR_DICT
+            | Self::STORE_SUBSCR_LIST_INT
+            | Self::UNPACK_SEQUENCE_LIST
+            | Self::UNPACK_SEQUENCE_TUPLE
+            | Self::UNPACK_SEQUENCE_TWO_TUPLE
+            | Self::SEND_GEN => self.deoptimize().stack_adjustment(arg),
+        }
+    }
+}
@@ -0,0 +1,2206 @@
+pub use enum_primitive_derive::Primitive;
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
+
+use super::Encoding;
+use crate::error::ParseMnemonicError;
+
+/// Standard set of instruction mnemonics
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Mnemonic {
+    CACHE,
+    BEFORE_ASYNC_WITH,
+    BEFORE_WITH,
+
+    BINARY_SLICE,
+    BINARY_SUBSCR,
+    CHECK_EG_MATCH,
+    CHECK_EXC_MATCH,
+    CLEANUP_THROW,
+    DELETE_SUBSCR,
+    END_ASYNC_FOR,
+    END_FOR,
+    END_SEND,
+    EXIT_INIT_CHECK,
+    FORMAT_SIMPLE,
+    FORMAT_WITH_SPEC,
+    GET_AITER,
+    RESERVED,
+    GET_ANEXT,
+    GET_ITER,
+    GET_LEN,
+    GET_YIELD_FROM_ITER,
+    INTERPRETER_EXIT,
+    LOAD_ASSERTION_ERROR,
+    LOAD_BUILD_CLASS,
+    LOAD_LOCALS,
+    MAKE_FUNCTION,
+    MATCH_KEYS,
+    MATCH_MAPPING,
+    MATCH_SEQUENCE,
+    NOP,
+    POP_EXCEPT,
+    POP_TOP,
+    PUSH_EXC_INFO,
+    PUSH_NULL,
+    RETURN_GENERATOR,
+    RETURN_VALUE,
+    SETUP_ANNOTATIONS,
+    STORE_SLICE,
+    STORE_SUBSCR,
+    TO_BOOL,
+    UNARY_INVERT,
+    UNARY_NEGATIVE,
+    UNARY_NOT,
+    WITH_EXCEPT_START,
+
+    // Opcodes with arguments
+    BINARY_OP,
+    BUILD_CONST_KEY_MAP,
+    BUILD_LIST,
+    BUILD_MAP,
+    BUILD_SET,
+    BUILD_SLICE,
+    BUILD_STRING,
+    BUILD_TUPLE,
+    CALL,
+    CALL_FUNCTION_EX,
+    CALL_INTRINSIC_1,
+    CALL_INTRINSIC_2,
+    CALL_KW,
+    COMPARE_OP,
+    CONTAINS_OP,
+    CONVERT_VALUE,
+    COPY,
+    COPY_FREE_VARS,
+    DELETE_ATTR,
+    DELETE_DEREF,
+    DELETE_FAST,
+    DELETE_GLOBAL,
+    DELETE_NAME,
+    DICT_MERGE,
+    DICT_UPDATE,
+    ENTER_EXECUTOR,
+    EXTENDED_ARG,
+    FOR_ITER,
+    GET_AWAITABLE,
+    IMPORT_FROM,
+    IMPORT_NAME,
+    IS_OP,
+    JUMP_BACKWARD,
+    JUMP_BACKWARD_NO_INTERRUPT,
+    JUMP_FORWARD,
+    LIST_APPEND,
+    LIST_EXTEND,
+    LOAD_ATTR,
+    LOAD_CONST,
+    LOAD_DEREF,
+    LOAD_FAST,
+    LOAD_FAST_AND_CLEAR,
+    LOAD_FAST_CHECK,
+    LOAD_FAST_LOAD_FAST,
+    LOAD_FROM_DICT_OR_DEREF,
+    LOAD_FROM_DICT_OR_GLOBALS,
+    LOAD_GLOBAL,
+    LOAD_NAME,
+    LOAD_SUPER_ATTR,
+    MAKE_CELL,
+    MAP_ADD,
+    MATCH_CLASS,
+    POP_JUMP_IF_FALSE,
+    POP_JUMP_IF_NONE,
+    POP_JUMP_IF_NOT_NONE,
+    POP_JUMP_IF_TRUE,
+    RAISE_VARARGS,
+    RERAISE,
+    RETURN_CONST,
+    SEND,
+    SET_ADD,
+    SET_FUNCTION_ATTRIBUTE,
+    SET_UPDATE,
+    STORE_ATTR,
+    STORE_DEREF,
+    STORE_FAST,
+    STORE_FAST_LOAD_FAST,
+    STORE_FAST_STORE_FAST,
+    STORE_GLOBAL,
+    STORE_NAME,
+    SWAP,
+    UNPACK_EX,
+    UNPACK_SEQUENCE,
+    YIELD_VALUE,
+
+    RESUME,
+
+    // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+    INSTRUMENTED_RESUME,
+    INSTRUMENTED_END_FOR,
+    INSTRUMENTED_END_SEND,
+    INSTRUMENTED_RETURN_VALUE,
+    INSTRUMENTED_RETURN_CONST,
+    INSTRUMENTED_YIELD_VALUE,
+    INSTRUMENTED_LOAD_SUPER_ATTR,
+    INSTRUMENTED_FOR_ITER,
+    INSTRUMENTED_CALL,
+    INSTRUMENTED_CALL_KW,
+    INSTRUMENTED_CALL_FUNCTION_EX,
+    INSTRUMENTED_INSTRUCTION,
+    INSTRUMENTED_JUMP_FORWARD,
+    INSTRUMENTED_JUMP_BACKWARD,
+    INSTRUMENTED_POP_JUMP_IF_TRUE,
+    INSTRUMENTED_POP_JUMP_IF_FALSE,
+    INSTRUMENTED_POP_JUMP_IF_NONE,
+    INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+    INSTRUMENTED_LINE,
+
+    // Specialized opcodes, which only appear in code that has been executed
+    BINARY_OP_INPLACE_ADD_UNICODE,
+    BINARY_OP_ADD_FLOAT,
+    BINARY_OP_ADD_INT,
+    BINARY_OP_ADD_UNICODE,
+    BINARY_OP_MULTIPLY_FLOAT,
+    BINARY_OP_MULTIPLY_INT,
+    BINARY_OP_SUBTRACT_FLOAT,
+    BINARY_OP_SUBTRACT_INT,
+    BINARY_SUBSCR_DICT,
+    BINARY_SUBSCR_GETITEM,
+    BINARY_SUBSCR_LIST_INT,
+    BINARY_SUBSCR_STR_INT,
+    BINARY_SUBSCR_TUPLE_INT,
+    CALL_ALLOC_AND_ENTER_INIT,
+    CALL_BOUND_METHOD_EXACT_ARGS,
+    CALL_BOUND_METHOD_GENERAL,
+    CALL_BUILTIN_CLASS,
+    CALL_BUILTIN_FAST,
+    CALL_BUILTIN_FAST_WITH_KEYWORDS,
+    CALL_BUILTIN_O,
+    CALL_ISINSTANCE,
+    CALL_LEN,
+    CALL_LIST_APPEND,
+    CALL_METHOD_DESCRIPTOR_FAST,
+    CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS,
+    CALL_METHOD_DESCRIPTOR_NOARGS,
+    CALL_METHOD_DESCRIPTOR_O,
+    CALL_NON_PY_GENERAL,
+    CALL_PY_EXACT_ARGS,
+    CALL_PY_GENERAL,
+    CALL_STR_1,
+    CALL_TUPLE_1,
+    CALL_TYPE_1,
+    COMPARE_OP_FLOAT,
+    COMPARE_OP_INT,
+    COMPARE_OP_STR,
+    CONTAINS_OP_DICT,
+    CONTAINS_OP_SET,
+    FOR_ITER_GEN,
+    FOR_ITER_LIST,
+    FOR_ITER_RANGE,
+    FOR_ITER_TUPLE,
+    LOAD_ATTR_CLASS,
+    LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+    LOAD_ATTR_INSTANCE_VALUE,
+    LOAD_ATTR_METHOD_LAZY_DICT,
+    LOAD_ATTR_METHOD_NO_DICT,
+    LOAD_ATTR_METHOD_WITH_VALUES,
+    LOAD_ATTR_MODULE,
+    LOAD_ATTR_NONDESCRIPTOR_NO_DICT,
+    LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES,
+    LOAD_ATTR_PROPERTY,
+    LOAD_ATTR_SLOT,
+    LOAD_ATTR_WITH_HINT,
+    LOAD_GLOBAL_BUILTIN,
+    LOAD_GLOBAL_MODULE,
+    LOAD_SUPER_ATTR_ATTR,
+    LOAD_SUPER_ATTR_METHOD,
+    RESUME_CHECK,
+    SEND_GEN,
+    STORE_ATTR_INSTANCE_VALUE,
+    STORE_ATTR_SLOT,
+    STORE_ATTR_WITH_HINT,
+    STORE_SUBSCR_DICT,
+    STORE_SUBSCR_LIST_INT,
+    TO_BOOL_ALWAYS_TRUE,
+    TO_BOOL_BOOL,
+    TO_BOOL_INT,
+    TO_BOOL_LIST,
+    TO_BOOL_NONE,
+    TO_BOOL_STR,
+    UNPACK_SEQUENCE_LIST,
+    UNPACK_SEQUENCE_TUPLE,
+    UNPACK_SEQUENCE_TWO_TUPLE,
+}
+
+impl Mnemonic {
+    /// Every mnemonic, in declaration order
+    pub const ALL: &'static [Mnemonic] = &[
+        Self::CACHE,
+        Self::BEFORE_ASYNC_WITH,
+        Self::BEFORE_WITH,
+        Self::BINARY_SLICE,
+        Self::BINARY_SUBSCR,
+        Self::CHECK_EG_MATCH,
+        Self::CHECK_EXC_MATCH,
+        Self::CLEANUP_THROW,
+        Self::DELETE_SUBSCR,
+        Self::END_ASYNC_FOR,
+        Self::END_FOR,
+        Self::END_SEND,
+        Self::EXIT_INIT_CHECK,
+        Self::FORMAT_SIMPLE,
+        Self::FORMAT_WITH_SPEC,
+        Self::GET_AITER,
+        Self::RESERVED,
+        Self::GET_ANEXT,
+        Self::GET_ITER,
+        Self::GET_LEN,
+        Self::GET_YIELD_FROM_ITER,
+        Self::INTERPRETER_EXIT,
+        Self::LOAD_ASSERTION_ERROR,
+        Self::LOAD_BUILD_CLASS,
+        Self::LOAD_LOCALS,
+        Self::MAKE_FUNCTION,
+        Self::MATCH_KEYS,
+        Self::MATCH_MAPPING,
+        Self::MATCH_SEQUENCE,
+        Self::NOP,
+        Self::POP_EXCEPT,
+        Self::POP_TOP,
+        Self::PUSH_EXC_INFO,
+        Self::PUSH_NULL,
+        Self::RETURN_GENERATOR,
+        Self::RETURN_VALUE,
+        Self::SETUP_ANNOTATIONS,
+        Self::STORE_SLICE,
+        Self::STORE_SUBSCR,
+        Self::TO_BOOL,
+        Self::UNARY_INVERT,
+        Self::UNARY_NEGATIVE,
+        Self::UNARY_NOT,
+        Self::WITH_EXCEPT_START,
+        Self::BINARY_OP,
+        Self::BUILD_CONST_KEY_MAP,
+        Self::BUILD_LIST,
+        Self::BUILD_MAP,
+        Self::BUILD_SET,
+        Self::BUILD_SLICE,
+        Self::BUILD_STRING,
+        Self::BUILD_TUPLE,
+        Self::CALL,
+        Self::CALL_FUNCTION_EX,
+        Self::CALL_INTRINSIC_1,
+        Self::CALL_INTRINSIC_2,
+        Self::CALL_KW,
+        Self::COMPARE_OP,
+        Self::CONTAINS_OP,
+        Self::CONVERT_VALUE,
+        Self::COPY,
+        Self::COPY_FREE_VARS,
+        Self::DELETE_ATTR,
+        Self::DELETE_DEREF,
+        Self::DELETE_FAST,
+        Self::DELETE_GLOBAL,
+        Self::DELETE_NAME,
+        Self::DICT_MERGE,
+        Self::DICT_UPDATE,
+        Self::ENTER_EXECUTOR,
+        Self::EXTENDED_ARG,
+        Self::FOR_ITER,
+        Self::GET_AWAITABLE,
+        Self::IMPORT_FROM,
+        Self::IMPORT_NAME,
+        Self::IS_OP,
+        Self::JUMP_BACKWARD,
+        Self::JUMP_BACKWARD_NO_INTERRUPT,
+        Self::JUMP_FORWARD,
+        Self::LIST_APPEND,
+        Self::LIST_EXTEND,
+        Self::LOAD_ATTR,
+        Self::LOAD_CONST,
+        Self::LOAD_DEREF,
+        Self::LOAD_FAST,
+        Self::LOAD_FAST_AND_CLEAR,
+        Self::LOAD_FAST_CHECK,
+        Self::LOAD_FAST_LOAD_FAST,
+        Self::LOAD_FROM_DICT_OR_DEREF,
+        Self::LOAD_FROM_DICT_OR_GLOBALS,
+        Self::LOAD_GLOBAL,
+        Self::LOAD_NAME,
+        Self::LOAD_SUPER_ATTR,
+        Self::MAKE_CELL,
+        Self::MAP_ADD,
+        Self::MATCH_CLASS,
+        Self::POP_JUMP_IF_FALSE,
+        Self::POP_JUMP_IF_NONE,
+        Self::POP_JUMP_IF_NOT_NONE,
+        Self::POP_JUMP_IF_TRUE,
+        Self::RAISE_VARARGS,
+        Self::RERAISE,
+        Self::RETURN_CONST,
+        Self::SEND,
+        Self::SET_ADD,
+        Self::SET_FUNCTION_ATTRIBUTE,
+        Self::SET_UPDATE,
+        Self::STORE_ATTR,
+        Self::STORE_DEREF,
+        Self::STORE_FAST,
+        Self::STORE_FAST_LOAD_FAST,
+        Self::STORE_FAST_STORE_FAST,
+        Self::STORE_GLOBAL,
+        Self::STORE_NAME,
+        Self::SWAP,
+        Self::UNPACK_EX,
+        Self::UNPACK_SEQUENCE,
+        Self::YIELD_VALUE,
+        Self::RESUME,
+        Self::INSTRUMENTED_RESUME,
+        Self::INSTRUMENTED_END_FOR,
+        Self::INSTRUMENTED_END_SEND,
+        Self::INSTRUMENTED_RETURN_VALUE,
+        Self::INSTRUMENTED_RETURN_CONST,
+        Self::INSTRUMENTED_YIELD_VALUE,
+        Self::INSTRUMENTED_LOAD_SUPER_ATTR,
+        Self::INSTRUMENTED_FOR_ITER,
+        Self::INSTRUMENTED_CALL,
+        Self::INSTRUMENTED_CALL_KW,
+        Self::INSTRUMENTED_CALL_FUNCTION_EX,
+        Self::INSTRUMENTED_INSTRUCTION,
+        Self::INSTRUMENTED_JUMP_FORWARD,
+        Self::INSTRUMENTED_JUMP_BACKWARD,
+        Self::INSTRUMENTED_POP_JUMP_IF_TRUE,
+        Self::INSTRUMENTED_POP_JUMP_IF_FALSE,
+        Self::INSTRUMENTED_POP_JUMP_IF_NONE,
+        Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+        Self::INSTRUMENTED_LINE,
+        Self::BINARY_OP_INPLACE_ADD_UNICODE,
+        Self::BINARY_OP_ADD_FLOAT,
+        Self::BINARY_OP_ADD_INT,
+        Self::BINARY_OP_ADD_UNICODE,
+        Self::BINARY_OP_MULTIPLY_FLOAT,
+        Self::BINARY_OP_MULTIPLY_INT,
+        Self::BINARY_OP_SUBTRACT_FLOAT,
+        Self::BINARY_OP_SUBTRACT_INT,
+        Self::BINARY_SUBSCR_DICT,
+        Self::BINARY_SUBSCR_GETITEM,
+        Self::BINARY_SUBSCR_LIST_INT,
+        Self::BINARY_SUBSCR_STR_INT,
+        Self::BINARY_SUBSCR_TUPLE_INT,
+        Self::CALL_ALLOC_AND_ENTER_INIT,
+        Self::CALL_BOUND_METHOD_EXACT_ARGS,
+        Self::CALL_BOUND_METHOD_GENERAL,
+        Self::CALL_BUILTIN_CLASS,
+        Self::CALL_BUILTIN_FAST,
+        Self::CALL_BUILTIN_FAST_WITH_KEYWORDS,
+        Self::CALL_BUILTIN_O,
+        Self::CALL_ISINSTANCE,
+        Self::CALL_LEN,
+        Self::CALL_LIST_APPEND,
+        Self::CALL_METHOD_DESCRIPTOR_FAST,
+        Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS,
+        Self::CALL_METHOD_DESCRIPTOR_NOARGS,
+        Self::CALL_METHOD_DESCRIPTOR_O,
+        Self::CALL_NON_PY_GENERAL,
+        Self::CALL_PY_EXACT_ARGS,
+        Self::CALL_PY_GENERAL,
+        Self::CALL_STR_1,
+        Self::CALL_TUPLE_1,
+        Self::CALL_TYPE_1,
+        Self::COMPARE_OP_FLOAT,
+        Self::COMPARE_OP_INT,
+        Self::COMPARE_OP_STR,
+        Self::CONTAINS_OP_DICT,
+        Self::CONTAINS_OP_SET,
+        Self::FOR_ITER_GEN,
+        Self::FOR_ITER_LIST,
+        Self::FOR_ITER_RANGE,
+        Self::FOR_ITER_TUPLE,
+        Self::LOAD_ATTR_CLASS,
+        Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+        Self::LOAD_ATTR_INSTANCE_VALUE,
+        Self::LOAD_ATTR_METHOD_LAZY_DICT,
+        Self::LOAD_ATTR_METHOD_NO_DICT,
+        Self::LOAD_ATTR_METHOD_WITH_VALUES,
+        Self::LOAD_ATTR_MODULE,
+        Self::LOAD_ATTR_NONDESCRIPTOR_NO_DICT,
+        Self::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES,
+        Self::LOAD_ATTR_PROPERTY,
+        Self::LOAD_ATTR_SLOT,
+        Self::LOAD_ATTR_WITH_HINT,
+        Self::LOAD_GLOBAL_BUILTIN,
+        Self::LOAD_GLOBAL_MODULE,
+        Self::LOAD_SUPER_ATTR_ATTR,
+        Self::LOAD_SUPER_ATTR_METHOD,
+        Self::RESUME_CHECK,
+        Self::SEND_GEN,
+        Self::STORE_ATTR_INSTANCE_VALUE,
+        Self::STORE_ATTR_SLOT,
+        Self::STORE_ATTR_WITH_HINT,
+        Self::STORE_SUBSCR_DICT,
+        Self::STORE_SUBSCR_LIST_INT,
+        Self::TO_BOOL_ALWAYS_TRUE,
+        Self::TO_BOOL_BOOL,
+        Self::TO_BOOL_INT,
+        Self::TO_BOOL_LIST,
+        Self::TO_BOOL_NONE,
+        Self::TO_BOOL_STR,
+        Self::UNPACK_SEQUENCE_LIST,
+        Self::UNPACK_SEQUENCE_TUPLE,
+        Self::UNPACK_SEQUENCE_TWO_TUPLE,
+    ];
+
+    /// The canonical name of this mnemonic. This is the same string which
+    /// [`Mnemonic::from_str`] accepts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CACHE => "CACHE",
+            Self::BEFORE_ASYNC_WITH => "BEFORE_ASYNC_WITH",
+            Self::BEFORE_WITH => "BEFORE_WITH",
+            Self::BINARY_SLICE => "BINARY_SLICE",
+            Self::BINARY_SUBSCR => "BINARY_SUBSCR",
+            Self::CHECK_EG_MATCH => "CHECK_EG_MATCH",
+            Self::CHECK_EXC_MATCH => "CHECK_EXC_MATCH",
+            Self::CLEANUP_THROW => "CLEANUP_THROW",
+            Self::DELETE_SUBSCR => "DELETE_SUBSCR",
+            Self::END_ASYNC_FOR => "END_ASYNC_FOR",
+            Self::END_FOR => "END_FOR",
+            Self::END_SEND => "END_SEND",
+            Self::EXIT_INIT_CHECK => "EXIT_INIT_CHECK",
+            Self::FORMAT_SIMPLE => "FORMAT_SIMPLE",
+            Self::FORMAT_WITH_SPEC => "FORMAT_WITH_SPEC",
+            Self::GET_AITER => "GET_AITER",
+            Self::RESERVED => "RESERVED",
+            Self::GET_ANEXT => "GET_ANEXT",
+            Self::GET_ITER => "GET_ITER",
+            Self::GET_LEN => "GET_LEN",
+            Self::GET_YIELD_FROM_ITER => "GET_YIELD_FROM_ITER",
+            Self::INTERPRETER_EXIT => "INTERPRETER_EXIT",
+            Self::LOAD_ASSERTION_ERROR => "LOAD_ASSERTION_ERROR",
+            Self::LOAD_BUILD_CLASS => "LOAD_BUILD_CLASS",
+            Self::LOAD_LOCALS => "LOAD_LOCALS",
+            Self::MAKE_FUNCTION => "MAKE_FUNCTION",
+            Self::MATCH_KEYS => "MATCH_KEYS",
+            Self::MATCH_MAPPING => "MATCH_MAPPING",
+            Self::MATCH_SEQUENCE => "MATCH_SEQUENCE",
+            Self::NOP => "NOP",
+            Self::POP_EXCEPT => "POP_EXCEPT",
+            Self::POP_TOP => "POP_TOP",
+            Self::PUSH_EXC_INFO => "PUSH_EXC_INFO",
+            Self::PUSH_NULL => "PUSH_NULL",
+            Self::RETURN_GENERATOR => "RETURN_GENERATOR",
+            Self::RETURN_VALUE => "RETURN_VALUE",
+            Self::SETUP_ANNOTATIONS => "SETUP_ANNOTATIONS",
+            Self::STORE_SLICE => "STORE_SLICE",
+            Self::STORE_SUBSCR => "STORE_SUBSCR",
+            Self::TO_BOOL => "TO_BOOL",
+            Self::UNARY_INVERT => "UNARY_INVERT",
+            Self::UNARY_NEGATIVE => "UNARY_NEGATIVE",
+            Self::UNARY_NOT => "UNARY_NOT",
+            Self::WITH_EXCEPT_START => "WITH_EXCEPT_START",
+            Self::BINARY_OP => "BINARY_OP",
+            Self::BUILD_CONST_KEY_MAP => "BUILD_CONST_KEY_MAP",
+            Self::BUILD_LIST => "BUILD_LIST",
+            Self::BUILD_MAP => "BUILD_MAP",
+            Self::BUILD_SET => "BUILD_SET",
+            Self::BUILD_SLICE => "BUILD_SLICE",
+            Self::BUILD_STRING => "BUILD_STRING",
+            Self::BUILD_TUPLE => "BUILD_TUPLE",
+            Self::CALL => "CALL",
+            Self::CALL_FUNCTION_EX => "CALL_FUNCTION_EX",
+            Self::CALL_INTRINSIC_1 => "CALL_INTRINSIC_1",
+            Self::CALL_INTRINSIC_2 => "CALL_INTRINSIC_2",
+            Self::CALL_KW => "CALL_KW",
+            Self::COMPARE_OP => "COMPARE_OP",
+            Self::CONTAINS_OP => "CONTAINS_OP",
+            Self::CONVERT_VALUE => "CONVERT_VALUE",
+            Self::COPY => "COPY",
+            Self::COPY_FREE_VARS => "COPY_FREE_VARS",
+            Self::DELETE_ATTR => "DELETE_ATTR",
+            Self::DELETE_DEREF => "DELETE_DEREF",
+            Self::DELETE_FAST => "DELETE_FAST",
+            Self::DELETE_GLOBAL => "DELETE_GLOBAL",
+            Self::DELETE_NAME => "DELETE_NAME",
+            Self::DICT_MERGE => "DICT_MERGE",
+            Self::DICT_UPDATE => "DICT_UPDATE",
+            Self::ENTER_EXECUTOR => "ENTER_EXECUTOR",
+            Self::EXTENDED_ARG => "EXTENDED_ARG",
+            Self::FOR_ITER => "FOR_ITER",
+            Self::GET_AWAITABLE => "GET_AWAITABLE",
+            Self::IMPORT_FROM => "IMPORT_FROM",
+            Self::IMPORT_NAME => "IMPORT_NAME",
+            Self::IS_OP => "IS_OP",
+            Self::JUMP_BACKWARD => "JUMP_BACKWARD",
+            Self::JUMP_BACKWARD_NO_INTERRUPT => "JUMP_BACKWARD_NO_INTERRUPT",
+            Self::JUMP_FORWARD => "JUMP_FORWARD",
+            Self::LIST_APPEND => "LIST_APPEND",
+            Self::LIST_EXTEND => "LIST_EXTEND",
+            Self::LOAD_ATTR => "LOAD_ATTR",
+            Self::LOAD_CONST => "LOAD_CONST",
+            Self::LOAD_DEREF => "LOAD_DEREF",
+            Self::LOAD_FAST => "LOAD_FAST",
+            Self::LOAD_FAST_AND_CLEAR => "LOAD_FAST_AND_CLEAR",
+            Self::LOAD_FAST_CHECK => "LOAD_FAST_CHECK",
+            Self::LOAD_FAST_LOAD_FAST => "LOAD_FAST_LOAD_FAST",
+            Self::LOAD_FROM_DICT_OR_DEREF => "LOAD_FROM_DICT_OR_DEREF",
+            Self::LOAD_FROM_DICT_OR_GLOBALS => "LOAD_FROM_DICT_OR_GLOBALS",
+            Self::LOAD_GLOBAL => "LOAD_GLOBAL",
+            Self::LOAD_NAME => "LOAD_NAME",
+            Self::LOAD_SUPER_ATTR => "LOAD_SUPER_ATTR",
+            Self::MAKE_CELL => "MAKE_CELL",
+            Self::MAP_ADD => "MAP_ADD",
+            Self::MATCH_CLASS => "MATCH_CLASS",
+            Self::POP_JUMP_IF_FALSE => "POP_JUMP_IF_FALSE",
+            Self::POP_JUMP_IF_NONE => "POP_JUMP_IF_NONE",
+            Self::POP_JUMP_IF_NOT_NONE => "POP_JUMP_IF_NOT_NONE",
+            Self::POP_JUMP_IF_TRUE => "POP_JUMP_IF_TRUE",
+            Self::RAISE_VARARGS => "RAISE_VARARGS",
+            Self::RERAISE => "RERAISE",
+            Self::RETURN_CONST => "RETURN_CONST",
+            Self::SEND => "SEND",
+            Self::SET_ADD => "SET_ADD",
+            Self::SET_FUNCTION_ATTRIBUTE => "SET_FUNCTION_ATTRIBUTE",
+            Self::SET_UPDATE => "SET_UPDATE",
+            Self::STORE_ATTR => "STORE_ATTR",
+            Self::STORE_DEREF => "STORE_DEREF",
+            Self::STORE_FAST => "STORE_FAST",
+            Self::STORE_FAST_LOAD_FAST => "STORE_FAST_LOAD_FAST",
+            Self::STORE_FAST_STORE_FAST => "STORE_FAST_STORE_FAST",
+            Self::STORE_GLOBAL => "STORE_GLOBAL",
+            Self::STORE_NAME => "STORE_NAME",
+            Self::SWAP => "SWAP",
+            Self::UNPACK_EX => "UNPACK_EX",
+            Self::UNPACK_SEQUENCE => "UNPACK_SEQUENCE",
+            Self::YIELD_VALUE => "YIELD_VALUE",
+            Self::RESUME => "RESUME",
+            Self::INSTRUMENTED_RESUME => "INSTRUMENTED_RESUME",
+            Self::INSTRUMENTED_END_FOR => "INSTRUMENTED_END_FOR",
+            Self::INSTRUMENTED_END_SEND => "INSTRUMENTED_END_SEND",
+            Self::INSTRUMENTED_RETURN_VALUE => "INSTRUMENTED_RETURN_VALUE",
+            Self::INSTRUMENTED_RETURN_CONST => "INSTRUMENTED_RETURN_CONST",
+            Self::INSTRUMENTED_YIELD_VALUE => "INSTRUMENTED_YIELD_VALUE",
+            Self::INSTRUMENTED_LOAD_SUPER_ATTR => "INSTRUMENTED_LOAD_SUPER_ATTR",
+            Self::INSTRUMENTED_FOR_ITER => "INSTRUMENTED_FOR_ITER",
+            Self::INSTRUMENTED_CALL => "INSTRUMENTED_CALL",
+            Self::INSTRUMENTED_CALL_KW => "INSTRUMENTED_CALL_KW",
+            Self::INSTRUMENTED_CALL_FUNCTION_EX => "INSTRUMENTED_CALL_FUNCTION_EX",
+            Self::INSTRUMENTED_INSTRUCTION => "INSTRUMENTED_INSTRUCTION",
+            Self::INSTRUMENTED_JUMP_FORWARD => "INSTRUMENTED_JUMP_FORWARD",
+            Self::INSTRUMENTED_JUMP_BACKWARD => "INSTRUMENTED_JUMP_BACKWARD",
+            Self::INSTRUMENTED_POP_JUMP_IF_TRUE => "INSTRUMENTED_POP_JUMP_IF_TRUE",
+            Self::INSTRUMENTED_POP_JUMP_IF_FALSE => "INSTRUMENTED_POP_JUMP_IF_FALSE",
+            Self::INSTRUMENTED_POP_JUMP_IF_NONE => "INSTRUMENTED_POP_JUMP_IF_NONE",
+            Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => "INSTRUMENTED_POP_JUMP_IF_NOT_NONE",
+            Self::INSTRUMENTED_LINE => "INSTRUMENTED_LINE",
+            Self::BINARY_OP_INPLACE_ADD_UNICODE => "BINARY_OP_INPLACE_ADD_UNICODE",
+            Self::BINARY_OP_ADD_FLOAT => "BINARY_OP_ADD_FLOAT",
+            Self::BINARY_OP_ADD_INT => "BINARY_OP_ADD_INT",
+            Self::BINARY_OP_ADD_UNICODE => "BINARY_OP_ADD_UNICODE",
+            Self::BINARY_OP_MULTIPLY_FLOAT => "BINARY_OP_MULTIPLY_FLOAT",
+            Self::BINARY_OP_MULTIPLY_INT => "BINARY_OP_MULTIPLY_INT",
+            Self::BINARY_OP_SUBTRACT_FLOAT => "BINARY_OP_SUBTRACT_FLOAT",
+            Self::BINARY_OP_SUBTRACT_INT => "BINARY_OP_SUBTRACT_INT",
+            Self::BINARY_SUBSCR_DICT => "BINARY_SUBSCR_DICT",
+            Self::BINARY_SUBSCR_GETITEM => "BINARY_SUBSCR_GETITEM",
+            Self::BINARY_SUBSCR_LIST_INT => "BINARY_SUBSCR_LIST_INT",
+            Self::BINARY_SUBSCR_STR_INT => "BINARY_SUBSCR_STR_INT",
+            Self::BINARY_SUBSCR_TUPLE_INT => "BINARY_SUBSCR_TUPLE_INT",
+            Self::CALL_ALLOC_AND_ENTER_INIT => "CALL_ALLOC_AND_ENTER_INIT",
+            Self::CALL_BOUND_METHOD_EXACT_ARGS => "CALL_BOUND_METHOD_EXACT_ARGS",
+            Self::CALL_BOUND_METHOD_GENERAL => "CALL_BOUND_METHOD_GENERAL",
+            Self::CALL_BUILTIN_CLASS => "CALL_BUILTIN_CLASS",
+            Self::CALL_BUILTIN_FAST => "CALL_BUILTIN_FAST",
+            Self::CALL_BUILTIN_FAST_WITH_KEYWORDS => "CALL_BUILTIN_FAST_WITH_KEYWORDS",
+            Self::CALL_BUILTIN_O => "CALL_BUILTIN_O",
+            Self::CALL_ISINSTANCE => "CALL_ISINSTANCE",
+            Self::CALL_LEN => "CALL_LEN",
+            Self::CALL_LIST_APPEND => "CALL_LIST_APPEND",
+            Self::CALL_METHOD_DESCRIPTOR_FAST => "CALL_METHOD_DESCRIPTOR_FAST",
+            Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                "CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS"
+            }
+            Self::CALL_METHOD_DESCRIPTOR_NOARGS => "CALL_METHOD_DESCRIPTOR_NOARGS",
+            Self::CALL_METHOD_DESCRIPTOR_O => "CALL_METHOD_DESCRIPTOR_O",
+            Self::CALL_NON_PY_GENERAL => "CALL_NON_PY_GENERAL",
+            Self::CALL_PY_EXACT_ARGS => "CALL_PY_EXACT_ARGS",
+            Self::CALL_PY_GENERAL => "CALL_PY_GENERAL",
+            Self::CALL_STR_1 => "CALL_STR_1",
+            Self::CALL_TUPLE_1 => "CALL_TUPLE_1",
+            Self::CALL_TYPE_1 => "CALL_TYPE_1",
+            Self::COMPARE_OP_FLOAT => "COMPARE_OP_FLOAT",
+            Self::COMPARE_OP_INT => "COMPARE_OP_INT",
+            Self::COMPARE_OP_STR => "COMPARE_OP_STR",
+            Self::CONTAINS_OP_DICT => "CONTAINS_OP_DICT",
+            Self::CONTAINS_OP_SET => "CONTAINS_OP_SET",
+            Self::FOR_ITER_GEN => "FOR_ITER_GEN",
+            Self::FOR_ITER_LIST => "FOR_ITER_LIST",
+            Self::FOR_ITER_RANGE => "FOR_ITER_RANGE",
+            Self::FOR_ITER_TUPLE => "FOR_ITER_TUPLE",
+            Self::LOAD_ATTR_CLASS => "LOAD_ATTR_CLASS",
+            Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN => "LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN",
+            Self::LOAD_ATTR_INSTANCE_VALUE => "LOAD_ATTR_INSTANCE_VALUE",
+            Self::LOAD_ATTR_METHOD_LAZY_DICT => "LOAD_ATTR_METHOD_LAZY_DICT",
+            Self::LOAD_ATTR_METHOD_NO_DICT => "LOAD_ATTR_METHOD_NO_DICT",
+            Self::LOAD_ATTR_METHOD_WITH_VALUES => "LOAD_ATTR_METHOD_WITH_VALUES",
+            Self::LOAD_ATTR_MODULE => "LOAD_ATTR_MODULE",
+            Self::LOAD_ATTR_NONDESCRIPTOR_NO_DICT => "LOAD_ATTR_NONDESCRIPTOR_NO_DICT",
+            Self::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES => "LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES",
+            Self::LOAD_ATTR_PROPERTY => "LOAD_ATTR_PROPERTY",
+            Self::LOAD_ATTR_SLOT => "LOAD_ATTR_SLOT",
+            Self::LOAD_ATTR_WITH_HINT => "LOAD_ATTR_WITH_HINT",
+            Self::LOAD_GLOBAL_BUILTIN => "LOAD_GLOBAL_BUILTIN",
+            Self::LOAD_GLOBAL_MODULE => "LOAD_GLOBAL_MODULE",
+            Self::LOAD_SUPER_ATTR_ATTR => "LOAD_SUPER_ATTR_ATTR",
+            Self::LOAD_SUPER_ATTR_METHOD => "LOAD_SUPER_ATTR_METHOD",
+            Self::RESUME_CHECK => "RESUME_CHECK",
+            Self::SEND_GEN => "SEND_GEN",
+            Self::STORE_ATTR_INSTANCE_VALUE => "STORE_ATTR_INSTANCE_VALUE",
+            Self::STORE_ATTR_SLOT => "STORE_ATTR_SLOT",
+            Self::STORE_ATTR_WITH_HINT => "STORE_ATTR_WITH_HINT",
+            Self::STORE_SUBSCR_DICT => "STORE_SUBSCR_DICT",
+            Self::STORE_SUBSCR_LIST_INT => "STORE_SUBSCR_LIST_INT",
+            Self::TO_BOOL_ALWAYS_TRUE => "TO_BOOL_ALWAYS_TRUE",
+            Self::TO_BOOL_BOOL => "TO_BOOL_BOOL",
+            Self::TO_BOOL_INT => "TO_BOOL_INT",
+            Self::TO_BOOL_LIST => "TO_BOOL_LIST",
+            Self::TO_BOOL_NONE => "TO_BOOL_NONE",
+            Self::TO_BOOL_STR => "TO_BOOL_STR",
+            Self::UNPACK_SEQUENCE_LIST => "UNPACK_SEQUENCE_LIST",
+            Self::UNPACK_SEQUENCE_TUPLE => "UNPACK_SEQUENCE_TUPLE",
+            Self::UNPACK_SEQUENCE_TWO_TUPLE => "UNPACK_SEQUENCE_TWO_TUPLE",
+        }
+    }
+
+    /// The base mnemonic of a specialized or instrumented mnemonic. Other mnemonics are returned
+    /// unchanged, including `INSTRUMENTED_LINE`, `INSTRUMENTED_INSTRUCTION` and `ENTER_EXECUTOR`, which replace an
+    /// opcode that is stored outside of the bytecode.
+    pub fn deoptimize(&self) -> Mnemonic {
+        match self {
+            Self::BINARY_SUBSCR_DICT
+            | Self::BINARY_SUBSCR_GETITEM
+            | Self::BINARY_SUBSCR_LIST_INT
+            | Self::BINARY_SUBSCR_STR_INT
+            | Self::BINARY_SUBSCR_TUPLE_INT => Self::BINARY_SUBSCR,
+            Self::INSTRUMENTED_END_FOR => Self::END_FOR,
+            Self::INSTRUMENTED_END_SEND => Self::END_SEND,
+            Self::INSTRUMENTED_RETURN_VALUE => Self::RETURN_VALUE,
+            Self::STORE_SUBSCR_DICT | Self::STORE_SUBSCR_LIST_INT => Self::STORE_SUBSCR,
+            Self::TO_BOOL_ALWAYS_TRUE
+            | Self::TO_BOOL_BOOL
+            | Self::TO_BOOL_INT
+            | Self::TO_BOOL_LIST
+            | Self::TO_BOOL_NONE
+            | Self::TO_BOOL_STR => Self::TO_BOOL,
+            Self::BINARY_OP_INPLACE_ADD_UNICODE
+            | Self::BINARY_OP_ADD_FLOAT
+            | Self::BINARY_OP_ADD_INT
+            | Self::BINARY_OP_ADD_UNICODE
+            | Self::BINARY_OP_MULTIPLY_FLOAT
+            | Self::BINARY_OP_MULTIPLY_INT
+            | Self::BINARY_OP_SUBTRACT_FLOAT
+            | Self::BINARY_OP_SUBTRACT_INT => Self::BINARY_OP,
+            Self::CALL_ALLOC_AND_ENTER_INIT
+            | Self::CALL_BOUND_METHOD_EXACT_ARGS
+            | Self::CALL_BOUND_METHOD_GENERAL
+            | Self::CALL_BUILTIN_CLASS
+            | Self::CALL_BUILTIN_FAST
+            | Self::CALL_BUILTIN_FAST_WITH_KEYWORDS
+            | Self::CALL_BUILTIN_O
+            | Self::CALL_ISINSTANCE
+            | Self::CALL_LEN
+            | Self::CALL_LIST_APPEND
+            | Self::CALL_METHOD_DESCRIPTOR_FAST
+            | Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            | Self::CALL_METHOD_DESCRIPTOR_NOARGS
+            | Self::CALL_METHOD_DESCRIPTOR_O
+            | Self::CALL_NON_PY_GENERAL
+            | Self::CALL_PY_EXACT_ARGS
+            | Self::CALL_PY_GENERAL
+            | Self::CALL_STR_1
+            | Self::CALL_TUPLE_1
+            | Self::CALL_TYPE_1
+            | Self::INSTRUMENTED_CALL => Self::CALL,
+            Self::INSTRUMENTED_CALL_FUNCTION_EX => Self::CALL_FUNCTION_EX,
+            Self::INSTRUMENTED_CALL_KW => Self::CALL_KW,
+            Self::COMPARE_OP_FLOAT | Self::COMPARE_OP_INT | Self::COMPARE_OP_STR => {
+                Self::COMPARE_OP
+            }
+            Self::CONTAINS_OP_DICT | Self::CONTAINS_OP_SET => Self::CONTAINS_OP,
+            Self::FOR_ITER_GEN
+            | Self::FOR_ITER_LIST
+            | Self::FOR_ITER_RANGE
+            | Self::FOR_ITER_TUPLE
+            | Self::INSTRUMENTED_FOR_ITER => Self::FOR_ITER,
+            Self::INSTRUMENTED_JUMP_BACKWARD => Self::JUMP_BACKWARD,
+            Self::INSTRUMENTED_JUMP_FORWARD => Self::JUMP_FORWARD,
+            Self::LOAD_ATTR_CLASS
+            | Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN
+            | Self::LOAD_ATTR_INSTANCE_VALUE
+            | Self::LOAD_ATTR_METHOD_LAZY_DICT
+            | Self::LOAD_ATTR_METHOD_NO_DICT
+            | Self::LOAD_ATTR_METHOD_WITH_VALUES
+            | Self::LOAD_ATTR_MODULE
+            | Self::LOAD_ATTR_NONDESCRIPTOR_NO_DICT
+            | Self::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES
+            | Self::LOAD_ATTR_PROPERTY
+            | Self::LOAD_ATTR_SLOT
+            | Self::LOAD_ATTR_WITH_HINT => Self::LOAD_ATTR,
+            Self::LOAD_GLOBAL_BUILTIN | Self::LOAD_GLOBAL_MODULE => Self::LOAD_GLOBAL,
+            Self::LOAD_SUPER_ATTR_ATTR
+            | Self::LOAD_SUPER_ATTR_METHOD
+            | Self::INSTRUMENTED_LOAD_SUPER_ATTR => Self::LOAD_SUPER_ATTR,
+            Self::INSTRUMENTED_POP_JUMP_IF_FALSE => Self::POP_JUMP_IF_FALSE,
+            Self::INSTRUMENTED_POP_JUMP_IF_NONE => Self::POP_JUMP_IF_NONE,
+            Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => Self::POP_JUMP_IF_NOT_NONE,
+            Self::INSTRUMENTED_POP_JUMP_IF_TRUE => Self::POP_JUMP_IF_TRUE,
+            Self::INSTRUMENTED_RETURN_CONST => Self::RETURN_CONST,
+            Self::SEND_GEN => Self::SEND,
+            Self::STORE_ATTR_INSTANCE_VALUE
+            | Self::STORE_ATTR_SLOT
+            | Self::STORE_ATTR_WITH_HINT => Self::STORE_ATTR,
+            Self::UNPACK_SEQUENCE_LIST
+            | Self::UNPACK_SEQUENCE_TUPLE
+            | Self::UNPACK_SEQUENCE_TWO_TUPLE => Self::UNPACK_SEQUENCE,
+            Self::INSTRUMENTED_YIELD_VALUE => Self::YIELD_VALUE,
+            Self::RESUME_CHECK | Self::INSTRUMENTED_RESUME => Self::RESUME,
+            _ => *self,
+        }
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = ParseMnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CACHE" => Ok(Self::CACHE),
+            "BEFORE_ASYNC_WITH" => Ok(Self::BEFORE_ASYNC_WITH),
+            "BEFORE_WITH" => Ok(Self::BEFORE_WITH),
+            "BINARY_SLICE" => Ok(Self::BINARY_SLICE),
+            "BINARY_SUBSCR" => Ok(Self::BINARY_SUBSCR),
+            "CHECK_EG_MATCH" => Ok(Self::CHECK_EG_MATCH),
+            "CHECK_EXC_MATCH" => Ok(Self::CHECK_EXC_MATCH),
+            "CLEANUP_THROW" => Ok(Self::CLEANUP_THROW),
+            "DELETE_SUBSCR" => Ok(Self::DELETE_SUBSCR),
+            "END_ASYNC_FOR" => Ok(Self::END_ASYNC_FOR),
+            "END_FOR" => Ok(Self::END_FOR),
+            "END_SEND" => Ok(Self::END_SEND),
+            "EXIT_INIT_CHECK" => Ok(Self::EXIT_INIT_CHECK),
+            "FORMAT_SIMPLE" => Ok(Self::FORMAT_SIMPLE),
+            "FORMAT_WITH_SPEC" => Ok(Self::FORMAT_WITH_SPEC),
+            "GET_AITER" => Ok(Self::GET_AITER),
+            "RESERVED" => Ok(Self::RESERVED),
+            "GET_ANEXT" => Ok(Self::GET_ANEXT),
+            "GET_ITER" => Ok(Self::GET_ITER),
+            "GET_LEN" => Ok(Self::GET_LEN),
+            "GET_YIELD_FROM_ITER" => Ok(Self::GET_YIELD_FROM_ITER),
+            "INTERPRETER_EXIT" => Ok(Self::INTERPRETER_EXIT),
+            "LOAD_ASSERTION_ERROR" => Ok(Self::LOAD_ASSERTION_ERROR),
+            "LOAD_BUILD_CLASS" => Ok(Self::LOAD_BUILD_CLASS),
+            "LOAD_LOCALS" => Ok(Self::LOAD_LOCALS),
+            "MAKE_FUNCTION" => Ok(Self::MAKE_FUNCTION),
+            "MATCH_KEYS" => Ok(Self::MATCH_KEYS),
+            "MATCH_MAPPING" => Ok(Self::MATCH_MAPPING),
+            "MATCH_SEQUENCE" => Ok(Self::MATCH_SEQUENCE),
+            "NOP" => Ok(Self::NOP),
+            "POP_EXCEPT" => Ok(Self::POP_EXCEPT),
+            "POP_TOP" => Ok(Self::POP_TOP),
+            "PUSH_EXC_INFO" => Ok(Self::PUSH_EXC_INFO),
+            "PUSH_NULL" => Ok(Self::PUSH_NULL),
+            "RETURN_GENERATOR" => Ok(Self::RETURN_GENERATOR),
+            "RETURN_VALUE" => Ok(Self::RETURN_VALUE),
+            "SETUP_ANNOTATIONS" => Ok(Self::SETUP_ANNOTATIONS),
+            "STORE_SLICE" => Ok(Self::STORE_SLICE),
+            "STORE_SUBSCR" => Ok(Self::STORE_SUBSCR),
+            "TO_BOOL" => Ok(Self::TO_BOOL),
+            "UNARY_INVERT" => Ok(Self::UNARY_INVERT),
+            "UNARY_NEGATIVE" => Ok(Self::UNARY_NEGATIVE),
+            "UNARY_NOT" => Ok(Self::UNARY_NOT),
+            "WITH_EXCEPT_START" => Ok(Self::WITH_EXCEPT_START),
+            "BINARY_OP" => Ok(Self::BINARY_OP),
+            "BUILD_CONST_KEY_MAP" => Ok(Self::BUILD_CONST_KEY_MAP),
+            "BUILD_LIST" => Ok(Self::BUILD_LIST),
+            "BUILD_MAP" => Ok(Self::BUILD_MAP),
+            "BUILD_SET" => Ok(Self::BUILD_SET),
+            "BUILD_SLICE" => Ok(Self::BUILD_SLICE),
+            "BUILD_STRING" => Ok(Self::BUILD_STRING),
+            "BUILD_TUPLE" => Ok(Self::BUILD_TUPLE),
+            "CALL" => Ok(Self::CALL),
+            "CALL_FUNCTION_EX" => Ok(Self::CALL_FUNCTION_EX),
+            "CALL_INTRINSIC_1" => Ok(Self::CALL_INTRINSIC_1),
+            "CALL_INTRINSIC_2" => Ok(Self::CALL_INTRINSIC_2),
+            "CALL_KW" => Ok(Self::CALL_KW),
+            "COMPARE_OP" => Ok(Self::COMPARE_OP),
+            "CONTAINS_OP" => Ok(Self::CONTAINS_OP),
+            "CONVERT_VALUE" => Ok(Self::CONVERT_VALUE),
+            "COPY" => Ok(Self::COPY),
+            "COPY_FREE_VARS" => Ok(Self::COPY_FREE_VARS),
+            "DELETE_ATTR" => Ok(Self::DELETE_ATTR),
+            "DELETE_DEREF" => Ok(Self::DELETE_DEREF),
+            "DELETE_FAST" => Ok(Self::DELETE_FAST),
+            "DELETE_GLOBAL" => Ok(Self::DELETE_GLOBAL),
+            "DELETE_NAME" => Ok(Self::DELETE_NAME),
+            "DICT_MERGE" => Ok(Self::DICT_MERGE),
+            "DICT_UPDATE" => Ok(Self::DICT_UPDATE),
+            "ENTER_EXECUTOR" => Ok(Self::ENTER_EXECUTOR),
+            "EXTENDED_ARG" => Ok(Self::EXTENDED_ARG),
+            "FOR_ITER" => Ok(Self::FOR_ITER),
+            "GET_AWAITABLE" => Ok(Self::GET_AWAITABLE),
+            "IMPORT_FROM" => Ok(Self::IMPORT_FROM),
+            "IMPORT_NAME" => Ok(Self::IMPORT_NAME),
+            "IS_OP" => Ok(Self::IS_OP),
+            "JUMP_BACKWARD" => Ok(Self::JUMP_BACKWARD),
+            "JUMP_BACKWARD_NO_INTERRUPT" => Ok(Self::JUMP_BACKWARD_NO_INTERRUPT),
+            "JUMP_FORWARD" => Ok(Self::JUMP_FORWARD),
+            "LIST_APPEND" => Ok(Self::LIST_APPEND),
+            "LIST_EXTEND" => Ok(Self::LIST_EXTEND),
+            "LOAD_ATTR" => Ok(Self::LOAD_ATTR),
+            "LOAD_CONST" => Ok(Self::LOAD_CONST),
+            "LOAD_DEREF" => Ok(Self::LOAD_DEREF),
+            "LOAD_FAST" => Ok(Self::LOAD_FAST),
+            "LOAD_FAST_AND_CLEAR" => Ok(Self::LOAD_FAST_AND_CLEAR),
+            "LOAD_FAST_CHECK" => Ok(Self::LOAD_FAST_CHECK),
+            "LOAD_FAST_LOAD_FAST" => Ok(Self::LOAD_FAST_LOAD_FAST),
+            "LOAD_FROM_DICT_OR_DEREF" => Ok(Self::LOAD_FROM_DICT_OR_DEREF),
+            "LOAD_FROM_DICT_OR_GLOBALS" => Ok(Self::LOAD_FROM_DICT_OR_GLOBALS),
+            "LOAD_GLOBAL" => Ok(Self::LOAD_GLOBAL),
+            "LOAD_NAME" => Ok(Self::LOAD_NAME),
+            "LOAD_SUPER_ATTR" => Ok(Self::LOAD_SUPER_ATTR),
+            "MAKE_CELL" => Ok(Self::MAKE_CELL),
+            "MAP_ADD" => Ok(Self::MAP_ADD),
+            "MATCH_CLASS" => Ok(Self::MATCH_CLASS),
+            "POP_JUMP_IF_FALSE" => Ok(Self::POP_JUMP_IF_FALSE),
+            "POP_JUMP_IF_NONE" => Ok(Self::POP_JUMP_IF_NONE),
+            "POP_JUMP_IF_NOT_NONE" => Ok(Self::POP_JUMP_IF_NOT_NONE),
+            "POP_JUMP_IF_TRUE" => Ok(Self::POP_JUMP_IF_TRUE),
+            "RAISE_VARARGS" => Ok(Self::RAISE_VARARGS),
+            "RERAISE" => Ok(Self::RERAISE),
+            "RETURN_CONST" => Ok(Self::RETURN_CONST),
+            "SEND" => Ok(Self::SEND),
+            "SET_ADD" => Ok(Self::SET_ADD),
+            "SET_FUNCTION_ATTRIBUTE" => Ok(Self::SET_FUNCTION_ATTRIBUTE),
+            "SET_UPDATE" => Ok(Self::SET_UPDATE),
+            "STORE_ATTR" => Ok(Self::STORE_ATTR),
+            "STORE_DEREF" => Ok(Self::STORE_DEREF),
+            "STORE_FAST" => Ok(Self::STORE_FAST),
+            "STORE_FAST_LOAD_FAST" => Ok(Self::STORE_FAST_LOAD_FAST),
+            "STORE_FAST_STORE_FAST" => Ok(Self::STORE_FAST_STORE_FAST),
+            "STORE_GLOBAL" => Ok(Self::STORE_GLOBAL),
+            "STORE_NAME" => Ok(Self::STORE_NAME),
+            "SWAP" => Ok(Self::SWAP),
+            "UNPACK_EX" => Ok(Self::UNPACK_EX),
+            "UNPACK_SEQUENCE" => Ok(Self::UNPACK_SEQUENCE),
+            "YIELD_VALUE" => Ok(Self::YIELD_VALUE),
+            "RESUME" => Ok(Self::RESUME),
+            "INSTRUMENTED_RESUME" => Ok(Self::INSTRUMENTED_RESUME),
+            "INSTRUMENTED_END_FOR" => Ok(Self::INSTRUMENTED_END_FOR),
+            "INSTRUMENTED_END_SEND" => Ok(Self::INSTRUMENTED_END_SEND),
+            "INSTRUMENTED_RETURN_VALUE" => Ok(Self::INSTRUMENTED_RETURN_VALUE),
+            "INSTRUMENTED_RETURN_CONST" => Ok(Self::INSTRUMENTED_RETURN_CONST),
+            "INSTRUMENTED_YIELD_VALUE" => Ok(Self::INSTRUMENTED_YIELD_VALUE),
+            "INSTRUMENTED_LOAD_SUPER_ATTR" => Ok(Self::INSTRUMENTED_LOAD_SUPER_ATTR),
+            "INSTRUMENTED_FOR_ITER" => Ok(Self::INSTRUMENTED_FOR_ITER),
+            "INSTRUMENTED_CALL" => Ok(Self::INSTRUMENTED_CALL),
+            "INSTRUMENTED_CALL_KW" => Ok(Self::INSTRUMENTED_CALL_KW),
+            "INSTRUMENTED_CALL_FUNCTION_EX" => Ok(Self::INSTRUMENTED_CALL_FUNCTION_EX),
+            "INSTRUMENTED_INSTRUCTION" => Ok(Self::INSTRUMENTED_INSTRUCTION),
+            "INSTRUMENTED_JUMP_FORWARD" => Ok(Self::INSTRUMENTED_JUMP_FORWARD),
+            "INSTRUMENTED_JUMP_BACKWARD" => Ok(Self::INSTRUMENTED_JUMP_BACKWARD),
+            "INSTRUMENTED_POP_JUMP_IF_TRUE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_TRUE),
+            "INSTRUMENTED_POP_JUMP_IF_FALSE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_FALSE),
+            "INSTRUMENTED_POP_JUMP_IF_NONE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_NONE),
+            "INSTRUMENTED_POP_JUMP_IF_NOT_NONE" => Ok(Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE),
+            "INSTRUMENTED_LINE" => Ok(Self::INSTRUMENTED_LINE),
+            "BINARY_OP_INPLACE_ADD_UNICODE" => Ok(Self::BINARY_OP_INPLACE_ADD_UNICODE),
+            "BINARY_OP_ADD_FLOAT" => Ok(Self::BINARY_OP_ADD_FLOAT),
+            "BINARY_OP_ADD_INT" => Ok(Self::BINARY_OP_ADD_INT),
+            "BINARY_OP_ADD_UNICODE" => Ok(Self::BINARY_OP_ADD_UNICODE),
+            "BINARY_OP_MULTIPLY_FLOAT" => Ok(Self::BINARY_OP_MULTIPLY_FLOAT),
+            "BINARY_OP_MULTIPLY_INT" => Ok(Self::BINARY_OP_MULTIPLY_INT),
+            "BINARY_OP_SUBTRACT_FLOAT" => Ok(Self::BINARY_OP_SUBTRACT_FLOAT),
+            "BINARY_OP_SUBTRACT_INT" => Ok(Self::BINARY_OP_SUBTRACT_INT),
+            "BINARY_SUBSCR_DICT" => Ok(Self::BINARY_SUBSCR_DICT),
+            "BINARY_SUBSCR_GETITEM" => Ok(Self::BINARY_SUBSCR_GETITEM),
+            "BINARY_SUBSCR_LIST_INT" => Ok(Self::BINARY_SUBSCR_LIST_INT),
+            "BINARY_SUBSCR_STR_INT" => Ok(Self::BINARY_SUBSCR_STR_INT),
+            "BINARY_SUBSCR_TUPLE_INT" => Ok(Self::BINARY_SUBSCR_TUPLE_INT),
+            "CALL_ALLOC_AND_ENTER_INIT" => Ok(Self::CALL_ALLOC_AND_ENTER_INIT),
+            "CALL_BOUND_METHOD_EXACT_ARGS" => Ok(Self::CALL_BOUND_METHOD_EXACT_ARGS),
+            "CALL_BOUND_METHOD_GENERAL" => Ok(Self::CALL_BOUND_METHOD_GENERAL),
+            "CALL_BUILTIN_CLASS" => Ok(Self::CALL_BUILTIN_CLASS),
+            "CALL_BUILTIN_FAST" => Ok(Self::CALL_BUILTIN_FAST),
+            "CALL_BUILTIN_FAST_WITH_KEYWORDS" => Ok(Self::CALL_BUILTIN_FAST_WITH_KEYWORDS),
+            "CALL_BUILTIN_O" => Ok(Self::CALL_BUILTIN_O),
+            "CALL_ISINSTANCE" => Ok(Self::CALL_ISINSTANCE),
+            "CALL_LEN" => Ok(Self::CALL_LEN),
+            "CALL_LIST_APPEND" => Ok(Self::CALL_LIST_APPEND),
+            "CALL_METHOD_DESCRIPTOR_FAST" => Ok(Self::CALL_METHOD_DESCRIPTOR_FAST),
+            "CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS" => {
+                Ok(Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS)
+            }
+            "CALL_METHOD_DESCRIPTOR_NOARGS" => Ok(Self::CALL_METHOD_DESCRIPTOR_NOARGS),
+            "CALL_METHOD_DESCRIPTOR_O" => Ok(Self::CALL_METHOD_DESCRIPTOR_O),
+            "CALL_NON_PY_GENERAL" => Ok(Self::CALL_NON_PY_GENERAL),
+            "CALL_PY_EXACT_ARGS" => Ok(Self::CALL_PY_EXACT_ARGS),
+            "CALL_PY_GENERAL" => Ok(Self::CALL_PY_GENERAL),
+            "CALL_STR_1" => Ok(Self::CALL_STR_1),
+            "CALL_TUPLE_1" => Ok(Self::CALL_TUPLE_1),
+            "CALL_TYPE_1" => Ok(Self::CALL_TYPE_1),
+            "COMPARE_OP_FLOAT" => Ok(Self::COMPARE_OP_FLOAT),
+            "COMPARE_OP_INT" => Ok(Self::COMPARE_OP_INT),
+            "COMPARE_OP_STR" => Ok(Self::COMPARE_OP_STR),
+            "CONTAINS_OP_DICT" => Ok(Self::CONTAINS_OP_DICT),
+            "CONTAINS_OP_SET" => Ok(Self::CONTAINS_OP_SET),
+            "FOR_ITER_GEN" => Ok(Self::FOR_ITER_GEN),
+            "FOR_ITER_LIST" => Ok(Self::FOR_ITER_LIST),
+            "FOR_ITER_RANGE" => Ok(Self::FOR_ITER_RANGE),
+            "FOR_ITER_TUPLE" => Ok(Self::FOR_ITER_TUPLE),
+            "LOAD_ATTR_CLASS" => Ok(Self::LOAD_ATTR_CLASS),
+            "LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN" => Ok(Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN),
+            "LOAD_ATTR_INSTANCE_VALUE" => Ok(Self::LOAD_ATTR_INSTANCE_VALUE),
+            "LOAD_ATTR_METHOD_LAZY_DICT" => Ok(Self::LOAD_ATTR_METHOD_LAZY_DICT),
+            "LOAD_ATTR_METHOD_NO_DICT" => Ok(Self::LOAD_ATTR_METHOD_NO_DICT),
+            "LOAD_ATTR_METHOD_WITH_VALUES" => Ok(Self::LOAD_ATTR_METHOD_WITH_VALUES),
+            "LOAD_ATTR_MODULE" => Ok(Self::LOAD_ATTR_MODULE),
+            "LOAD_ATTR_NONDESCRIPTOR_NO_DICT" => Ok(Self::LOAD_ATTR_NONDESCRIPTOR_NO_DICT),
+            "LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES" => Ok(Self::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES),
+            "LOAD_ATTR_PROPERTY" => Ok(Self::LOAD_ATTR_PROPERTY),
+            "LOAD_ATTR_SLOT" => Ok(Self::LOAD_ATTR_SLOT),
+            "LOAD_ATTR_WITH_HINT" => Ok(Self::LOAD_ATTR_WITH_HINT),
+            "LOAD_GLOBAL_BUILTIN" => Ok(Self::LOAD_GLOBAL_BUILTIN),
+            "LOAD_GLOBAL_MODULE" => Ok(Self::LOAD_GLOBAL_MODULE),
+            "LOAD_SUPER_ATTR_ATTR" => Ok(Self::LOAD_SUPER_ATTR_ATTR),
+            "LOAD_SUPER_ATTR_METHOD" => Ok(Self::LOAD_SUPER_ATTR_METHOD),
+            "RESUME_CHECK" => Ok(Self::RESUME_CHECK),
+            "SEND_GEN" => Ok(Self::SEND_GEN),
+            "STORE_ATTR_INSTANCE_VALUE" => Ok(Self::STORE_ATTR_INSTANCE_VALUE),
+            "STORE_ATTR_SLOT" => Ok(Self::STORE_ATTR_SLOT),
+            "STORE_ATTR_WITH_HINT" => Ok(Self::STORE_ATTR_WITH_HINT),
+            "STORE_SUBSCR_DICT" => Ok(Self::STORE_SUBSCR_DICT),
+            "STORE_SUBSCR_LIST_INT" => Ok(Self::STORE_SUBSCR_LIST_INT),
+            "TO_BOOL_ALWAYS_TRUE" => Ok(Self::TO_BOOL_ALWAYS_TRUE),
+            "TO_BOOL_BOOL" => Ok(Self::TO_BOOL_BOOL),
+            "TO_BOOL_INT" => Ok(Self::TO_BOOL_INT),
+            "TO_BOOL_LIST" => Ok(Self::TO_BOOL_LIST),
+            "TO_BOOL_NONE" => Ok(Self::TO_BOOL_NONE),
+            "TO_BOOL_STR" => Ok(Self::TO_BOOL_STR),
+            "UNPACK_SEQUENCE_LIST" => Ok(Self::UNPACK_SEQUENCE_LIST),
+            "UNPACK_SEQUENCE_TUPLE" => Ok(Self::UNPACK_SEQUENCE_TUPLE),
+            "UNPACK_SEQUENCE_TWO_TUPLE" => Ok(Self::UNPACK_SEQUENCE_TWO_TUPLE),
+            _ => Err(ParseMnemonicError(s.to_string())),
+        }
+    }
+}
+
+/// Opcodes taken from https://github.com/python/cpython/blob/3.13/Include/opcode_ids.h.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone, Primitive)]
+#[allow(non_camel_case_types)]
+#[repr(u8)]
+pub enum Standard {
+    CACHE = 0,
+    BEFORE_ASYNC_WITH = 1,
+    BEFORE_WITH = 2,
+
+    BINARY_SLICE = 4,
+    BINARY_SUBSCR = 5,
+    CHECK_EG_MATCH = 6,
+    CHECK_EXC_MATCH = 7,
+    CLEANUP_THROW = 8,
+    DELETE_SUBSCR = 9,
+    END_ASYNC_FOR = 10,
+    END_FOR = 11,
+    END_SEND = 12,
+    EXIT_INIT_CHECK = 13,
+    FORMAT_SIMPLE = 14,
+    FORMAT_WITH_SPEC = 15,
+    GET_AITER = 16,
+    RESERVED = 17,
+    GET_ANEXT = 18,
+    GET_ITER = 19,
+    GET_LEN = 20,
+    GET_YIELD_FROM_ITER = 21,
+    INTERPRETER_EXIT = 22,
+    LOAD_ASSERTION_ERROR = 23,
+    LOAD_BUILD_CLASS = 24,
+    LOAD_LOCALS = 25,
+    MAKE_FUNCTION = 26,
+    MATCH_KEYS = 27,
+    MATCH_MAPPING = 28,
+    MATCH_SEQUENCE = 29,
+    NOP = 30,
+    POP_EXCEPT = 31,
+    POP_TOP = 32,
+    PUSH_EXC_INFO = 33,
+    PUSH_NULL = 34,
+    RETURN_GENERATOR = 35,
+    RETURN_VALUE = 36,
+    SETUP_ANNOTATIONS = 37,
+    STORE_SLICE = 38,
+    STORE_SUBSCR = 39,
+    TO_BOOL = 40,
+    UNARY_INVERT = 41,
+    UNARY_NEGATIVE = 42,
+    UNARY_NOT = 43,
+    WITH_EXCEPT_START = 44,
+
+    // Opcodes with arguments
+    BINARY_OP = 45,
+    BUILD_CONST_KEY_MAP = 46,
+    BUILD_LIST = 47,
+    BUILD_MAP = 48,
+    BUILD_SET = 49,
+    BUILD_SLICE = 50,
+    BUILD_STRING = 51,
+    BUILD_TUPLE = 52,
+    CALL = 53,
+    CALL_FUNCTION_EX = 54,
+    CALL_INTRINSIC_1 = 55,
+    CALL_INTRINSIC_2 = 56,
+    CALL_KW = 57,
+    COMPARE_OP = 58,
+    CONTAINS_OP = 59,
+    CONVERT_VALUE = 60,
+    COPY = 61,
+    COPY_FREE_VARS = 62,
+    DELETE_ATTR = 63,
+    DELETE_DEREF = 64,
+    DELETE_FAST = 65,
+    DELETE_GLOBAL = 66,
+    DELETE_NAME = 67,
+    DICT_MERGE = 68,
+    DICT_UPDATE = 69,
+    ENTER_EXECUTOR = 70,
+    EXTENDED_ARG = 71,
+    FOR_ITER = 72,
+    GET_AWAITABLE = 73,
+    IMPORT_FROM = 74,
+    IMPORT_NAME = 75,
+    IS_OP = 76,
+    JUMP_BACKWARD = 77,
+    JUMP_BACKWARD_NO_INTERRUPT = 78,
+    JUMP_FORWARD = 79,
+    LIST_APPEND = 80,
+    LIST_EXTEND = 81,
+    LOAD_ATTR = 82,
+    LOAD_CONST = 83,
+    LOAD_DEREF = 84,
+    LOAD_FAST = 85,
+    LOAD_FAST_AND_CLEAR = 86,
+    LOAD_FAST_CHECK = 87,
+    LOAD_FAST_LOAD_FAST = 88,
+    LOAD_FROM_DICT_OR_DEREF = 89,
+    LOAD_FROM_DICT_OR_GLOBALS = 90,
+    LOAD_GLOBAL = 91,
+    LOAD_NAME = 92,
+    LOAD_SUPER_ATTR = 93,
+    MAKE_CELL = 94,
+    MAP_ADD = 95,
+    MATCH_CLASS = 96,
+    POP_JUMP_IF_FALSE = 97,
+    POP_JUMP_IF_NONE = 98,
+    POP_JUMP_IF_NOT_NONE = 99,
+    POP_JUMP_IF_TRUE = 100,
+    RAISE_VARARGS = 101,
+    RERAISE = 102,
+    RETURN_CONST = 103,
+    SEND = 104,
+    SET_ADD = 105,
+    SET_FUNCTION_ATTRIBUTE = 106,
+    SET_UPDATE = 107,
+    STORE_ATTR = 108,
+    STORE_DEREF = 109,
+    STORE_FAST = 110,
+    STORE_FAST_LOAD_FAST = 111,
+    STORE_FAST_STORE_FAST = 112,
+    STORE_GLOBAL = 113,
+    STORE_NAME = 114,
+    SWAP = 115,
+    UNPACK_EX = 116,
+    UNPACK_SEQUENCE = 117,
+    YIELD_VALUE = 118,
+
+    RESUME = 149,
+
+    // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+    INSTRUMENTED_RESUME = 236,
+    INSTRUMENTED_END_FOR = 237,
+    INSTRUMENTED_END_SEND = 238,
+    INSTRUMENTED_RETURN_VALUE = 239,
+    INSTRUMENTED_RETURN_CONST = 240,
+    INSTRUMENTED_YIELD_VALUE = 241,
+    INSTRUMENTED_LOAD_SUPER_ATTR = 242,
+    INSTRUMENTED_FOR_ITER = 243,
+    INSTRUMENTED_CALL = 244,
+    INSTRUMENTED_CALL_KW = 245,
+    INSTRUMENTED_CALL_FUNCTION_EX = 246,
+    INSTRUMENTED_INSTRUCTION = 247,
+    INSTRUMENTED_JUMP_FORWARD = 248,
+    INSTRUMENTED_JUMP_BACKWARD = 249,
+    INSTRUMENTED_POP_JUMP_IF_TRUE = 250,
+    INSTRUMENTED_POP_JUMP_IF_FALSE = 251,
+    INSTRUMENTED_POP_JUMP_IF_NONE = 252,
+    INSTRUMENTED_POP_JUMP_IF_NOT_NONE = 253,
+    INSTRUMENTED_LINE = 254,
+
+    // Specialized opcodes, which only appear in code that has been executed
+    BINARY_OP_INPLACE_ADD_UNICODE = 3,
+    BINARY_OP_ADD_FLOAT = 150,
+    BINARY_OP_ADD_INT = 151,
+    BINARY_OP_ADD_UNICODE = 152,
+    BINARY_OP_MULTIPLY_FLOAT = 153,
+    BINARY_OP_MULTIPLY_INT = 154,
+    BINARY_OP_SUBTRACT_FLOAT = 155,
+    BINARY_OP_SUBTRACT_INT = 156,
+    BINARY_SUBSCR_DICT = 157,
+    BINARY_SUBSCR_GETITEM = 158,
+    BINARY_SUBSCR_LIST_INT = 159,
+    BINARY_SUBSCR_STR_INT = 160,
+    BINARY_SUBSCR_TUPLE_INT = 161,
+    CALL_ALLOC_AND_ENTER_INIT = 162,
+    CALL_BOUND_METHOD_EXACT_ARGS = 163,
+    CALL_BOUND_METHOD_GENERAL = 164,
+    CALL_BUILTIN_CLASS = 165,
+    CALL_BUILTIN_FAST = 166,
+    CALL_BUILTIN_FAST_WITH_KEYWORDS = 167,
+    CALL_BUILTIN_O = 168,
+    CALL_ISINSTANCE = 169,
+    CALL_LEN = 170,
+    CALL_LIST_APPEND = 171,
+    CALL_METHOD_DESCRIPTOR_FAST = 172,
+    CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS = 173,
+    CALL_METHOD_DESCRIPTOR_NOARGS = 174,
+    CALL_METHOD_DESCRIPTOR_O = 175,
+    CALL_NON_PY_GENERAL = 176,
+    CALL_PY_EXACT_ARGS = 177,
+    CALL_PY_GENERAL = 178,
+    CALL_STR_1 = 179,
+    CALL_TUPLE_1 = 180,
+    CALL_TYPE_1 = 181,
+    COMPARE_OP_FLOAT = 182,
+    COMPARE_OP_INT = 183,
+    COMPARE_OP_STR = 184,
+    CONTAINS_OP_DICT = 185,
+    CONTAINS_OP_SET = 186,
+    FOR_ITER_GEN = 187,
+    FOR_ITER_LIST = 188,
+    FOR_ITER_RANGE = 189,
+    FOR_ITER_TUPLE = 190,
+    LOAD_ATTR_CLASS = 191,
+    LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN = 192,
+    LOAD_ATTR_INSTANCE_VALUE = 193,
+    LOAD_ATTR_METHOD_LAZY_DICT = 194,
+    LOAD_ATTR_METHOD_NO_DICT = 195,
+    LOAD_ATTR_METHOD_WITH_VALUES = 196,
+    LOAD_ATTR_MODULE = 197,
+    LOAD_ATTR_NONDESCRIPTOR_NO_DICT = 198,
+    LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES = 199,
+    LOAD_ATTR_PROPERTY = 200,
+    LOAD_ATTR_SLOT = 201,
+    LOAD_ATTR_WITH_HINT = 202,
+    LOAD_GLOBAL_BUILTIN = 203,
+    LOAD_GLOBAL_MODULE = 204,
+    LOAD_SUPER_ATTR_ATTR = 205,
+    LOAD_SUPER_ATTR_METHOD = 206,
+    RESUME_CHECK = 207,
+    SEND_GEN = 208,
+    STORE_ATTR_INSTANCE_VALUE = 209,
+    STORE_ATTR_SLOT = 210,
+    STORE_ATTR_WITH_HINT = 211,
+    STORE_SUBSCR_DICT = 212,
+    STORE_SUBSCR_LIST_INT = 213,
+    TO_BOOL_ALWAYS_TRUE = 214,
+    TO_BOOL_BOOL = 215,
+    TO_BOOL_INT = 216,
+    TO_BOOL_LIST = 217,
+    TO_BOOL_NONE = 218,
+    TO_BOOL_STR = 219,
+    UNPACK_SEQUENCE_LIST = 220,
+    UNPACK_SEQUENCE_TUPLE = 221,
+    UNPACK_SEQUENCE_TWO_TUPLE = 222,
+}
+
+impl From<Mnemonic> for Standard {
+    fn from(mnemonic: Mnemonic) -> Self {
+        match mnemonic {
+            Mnemonic::CACHE => Self::CACHE,
+            Mnemonic::BEFORE_ASYNC_WITH => Self::BEFORE_ASYNC_WITH,
+            Mnemonic::BEFORE_WITH => Self::BEFORE_WITH,
+
+            Mnemonic::BINARY_SLICE => Self::BINARY_SLICE,
+            Mnemonic::BINARY_SUBSCR => Self::BINARY_SUBSCR,
+            Mnemonic::CHECK_EG_MATCH => Self::CHECK_EG_MATCH,
+            Mnemonic::CHECK_EXC_MATCH => Self::CHECK_EXC_MATCH,
+            Mnemonic::CLEANUP_THROW => Self::CLEANUP_THROW,
+            Mnemonic::DELETE_SUBSCR => Self::DELETE_SUBSCR,
+            Mnemonic::END_ASYNC_FOR => Self::END_ASYNC_FOR,
+            Mnemonic::END_FOR => Self::END_FOR,
+            Mnemonic::END_SEND => Self::END_SEND,
+            Mnemonic::EXIT_INIT_CHECK => Self::EXIT_INIT_CHECK,
+            Mnemonic::FORMAT_SIMPLE => Self::FORMAT_SIMPLE,
+            Mnemonic::FORMAT_WITH_SPEC => Self::FORMAT_WITH_SPEC,
+            Mnemonic::GET_AITER => Self::GET_AITER,
+            Mnemonic::RESERVED => Self::RESERVED,
+            Mnemonic::GET_ANEXT => Self::GET_ANEXT,
+            Mnemonic::GET_ITER => Self::GET_ITER,
+            Mnemonic::GET_LEN => Self::GET_LEN,
+            Mnemonic::GET_YIELD_FROM_ITER => Self::GET_YIELD_FROM_ITER,
+            Mnemonic::INTERPRETER_EXIT => Self::INTERPRETER_EXIT,
+            Mnemonic::LOAD_ASSERTION_ERROR => Self::LOAD_ASSERTION_ERROR,
+            Mnemonic::LOAD_BUILD_CLASS => Self::LOAD_BUILD_CLASS,
+            Mnemonic::LOAD_LOCALS => Self::LOAD_LOCALS,
+            Mnemonic::MAKE_FUNCTION => Self::MAKE_FUNCTION,
+            Mnemonic::MATCH_KEYS => Self::MATCH_KEYS,
+            Mnemonic::MATCH_MAPPING => Self::MATCH_MAPPING,
+            Mnemonic::MATCH_SEQUENCE => Self::MATCH_SEQUENCE,
+            Mnemonic::NOP => Self::NOP,
+            Mnemonic::POP_EXCEPT => Self::POP_EXCEPT,
+            Mnemonic::POP_TOP => Self::POP_TOP,
+            Mnemonic::PUSH_EXC_INFO => Self::PUSH_EXC_INFO,
+            Mnemonic::PUSH_NULL => Self::PUSH_NULL,
+            Mnemonic::RETURN_GENERATOR => Self::RETURN_GENERATOR,
+            Mnemonic::RETURN_VALUE => Self::RETURN_VALUE,
+            Mnemonic::SETUP_ANNOTATIONS => Self::SETUP_ANNOTATIONS,
+            Mnemonic::STORE_SLICE => Self::STORE_SLICE,
+            Mnemonic::STORE_SUBSCR => Self::STORE_SUBSCR,
+            Mnemonic::TO_BOOL => Self::TO_BOOL,
+            Mnemonic::UNARY_INVERT => Self::UNARY_INVERT,
+            Mnemonic::UNARY_NEGATIVE => Self::UNARY_NEGATIVE,
+            Mnemonic::UNARY_NOT => Self::UNARY_NOT,
+            Mnemonic::WITH_EXCEPT_START => Self::WITH_EXCEPT_START,
+
+            // Opcodes with arguments
+            Mnemonic::BINARY_OP => Self::BINARY_OP,
+            Mnemonic::BUILD_CONST_KEY_MAP => Self::BUILD_CONST_KEY_MAP,
+            Mnemonic::BUILD_LIST => Self::BUILD_LIST,
+            Mnemonic::BUILD_MAP => Self::BUILD_MAP,
+            Mnemonic::BUILD_SET => Self::BUILD_SET,
+            Mnemonic::BUILD_SLICE => Self::BUILD_SLICE,
+            Mnemonic::BUILD_STRING => Self::BUILD_STRING,
+            Mnemonic::BUILD_TUPLE => Self::BUILD_TUPLE,
+            Mnemonic::CALL => Self::CALL,
+            Mnemonic::CALL_FUNCTION_EX => Self::CALL_FUNCTION_EX,
+            Mnemonic::CALL_INTRINSIC_1 => Self::CALL_INTRINSIC_1,
+            Mnemonic::CALL_INTRINSIC_2 => Self::CALL_INTRINSIC_2,
+            Mnemonic::CALL_KW => Self::CALL_KW,
+            Mnemonic::COMPARE_OP => Self::COMPARE_OP,
+            Mnemonic::CONTAINS_OP => Self::CONTAINS_OP,
+            Mnemonic::CONVERT_VALUE => Self::CONVERT_VALUE,
+            Mnemonic::COPY => Self::COPY,
+            Mnemonic::COPY_FREE_VARS => Self::COPY_FREE_VARS,
+            Mnemonic::DELETE_ATTR => Self::DELETE_ATTR,
+            Mnemonic::DELETE_DEREF => Self::DELETE_DEREF,
+            Mnemonic::DELETE_FAST => Self::DELETE_FAST,
+            Mnemonic::DELETE_GLOBAL => Self::DELETE_GLOBAL,
+            Mnemonic::DELETE_NAME => Self::DELETE_NAME,
+            Mnemonic::DICT_MERGE => Self::DICT_MERGE,
+            Mnemonic::DICT_UPDATE => Self::DICT_UPDATE,
+            Mnemonic::ENTER_EXECUTOR => Self::ENTER_EXECUTOR,
+            Mnemonic::EXTENDED_ARG => Self::EXTENDED_ARG,
+            Mnemonic::FOR_ITER => Self::FOR_ITER,
+            Mnemonic::GET_AWAITABLE => Self::GET_AWAITABLE,
+            Mnemonic::IMPORT_FROM => Self::IMPORT_FROM,
+            Mnemonic::IMPORT_NAME => Self::IMPORT_NAME,
+            Mnemonic::IS_OP => Self::IS_OP,
+            Mnemonic::JUMP_BACKWARD => Self::JUMP_BACKWARD,
+            Mnemonic::JUMP_BACKWARD_NO_INTERRUPT => Self::JUMP_BACKWARD_NO_INTERRUPT,
+            Mnemonic::JUMP_FORWARD => Self::JUMP_FORWARD,
+            Mnemonic::LIST_APPEND => Self::LIST_APPEND,
+            Mnemonic::LIST_EXTEND => Self::LIST_EXTEND,
+            Mnemonic::LOAD_ATTR => Self::LOAD_ATTR,
+            Mnemonic::LOAD_CONST => Self::LOAD_CONST,
+            Mnemonic::LOAD_DEREF => Self::LOAD_DEREF,
+            Mnemonic::LOAD_FAST => Self::LOAD_FAST,
+            Mnemonic::LOAD_FAST_AND_CLEAR => Self::LOAD_FAST_AND_CLEAR,
+            Mnemonic::LOAD_FAST_CHECK => Self::LOAD_FAST_CHECK,
+            Mnemonic::LOAD_FAST_LOAD_FAST => Self::LOAD_FAST_LOAD_FAST,
+            Mnemonic::LOAD_FROM_DICT_OR_DEREF => Self::LOAD_FROM_DICT_OR_DEREF,
+            Mnemonic::LOAD_FROM_DICT_OR_GLOBALS => Self::LOAD_FROM_DICT_OR_GLOBALS,
+            Mnemonic::LOAD_GLOBAL => Self::LOAD_GLOBAL,
+            Mnemonic::LOAD_NAME => Self::LOAD_NAME,
+            Mnemonic::LOAD_SUPER_ATTR => Self::LOAD_SUPER_ATTR,
+            Mnemonic::MAKE_CELL => Self::MAKE_CELL,
+            Mnemonic::MAP_ADD => Self::MAP_ADD,
+            Mnemonic::MATCH_CLASS => Self::MATCH_CLASS,
+            Mnemonic::POP_JUMP_IF_FALSE => Self::POP_JUMP_IF_FALSE,
+            Mnemonic::POP_JUMP_IF_NONE => Self::POP_JUMP_IF_NONE,
+            Mnemonic::POP_JUMP_IF_NOT_NONE => Self::POP_JUMP_IF_NOT_NONE,
+            Mnemonic::POP_JUMP_IF_TRUE => Self::POP_JUMP_IF_TRUE,
+            Mnemonic::RAISE_VARARGS => Self::RAISE_VARARGS,
+            Mnemonic::RERAISE => Self::RERAISE,
+            Mnemonic::RETURN_CONST => Self::RETURN_CONST,
+            Mnemonic::SEND => Self::SEND,
+            Mnemonic::SET_ADD => Self::SET_ADD,
+            Mnemonic::SET_FUNCTION_ATTRIBUTE => Self::SET_FUNCTION_ATTRIBUTE,
+            Mnemonic::SET_UPDATE => Self::SET_UPDATE,
+            Mnemonic::STORE_ATTR => Self::STORE_ATTR,
+            Mnemonic::STORE_DEREF => Self::STORE_DEREF,
+            Mnemonic::STORE_FAST => Self::STORE_FAST,
+            Mnemonic::STORE_FAST_LOAD_FAST => Self::STORE_FAST_LOAD_FAST,
+            Mnemonic::STORE_FAST_STORE_FAST => Self::STORE_FAST_STORE_FAST,
+            Mnemonic::STORE_GLOBAL => Self::STORE_GLOBAL,
+            Mnemonic::STORE_NAME => Self::STORE_NAME,
+            Mnemonic::SWAP => Self::SWAP,
+            Mnemonic::UNPACK_EX => Self::UNPACK_EX,
+            Mnemonic::UNPACK_SEQUENCE => Self::UNPACK_SEQUENCE,
+            Mnemonic::YIELD_VALUE => Self::YIELD_VALUE,
+
+            Mnemonic::RESUME => Self::RESUME,
+
+            // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+            Mnemonic::INSTRUMENTED_RESUME => Self::INSTRUMENTED_RESUME,
+            Mnemonic::INSTRUMENTED_END_FOR => Self::INSTRUMENTED_END_FOR,
+            Mnemonic::INSTRUMENTED_END_SEND => Self::INSTRUMENTED_END_SEND,
+            Mnemonic::INSTRUMENTED_RETURN_VALUE => Self::INSTRUMENTED_RETURN_VALUE,
+            Mnemonic::INSTRUMENTED_RETURN_CONST => Self::INSTRUMENTED_RETURN_CONST,
+            Mnemonic::INSTRUMENTED_YIELD_VALUE => Self::INSTRUMENTED_YIELD_VALUE,
+            Mnemonic::INSTRUMENTED_LOAD_SUPER_ATTR => Self::INSTRUMENTED_LOAD_SUPER_ATTR,
+            Mnemonic::INSTRUMENTED_FOR_ITER => Self::INSTRUMENTED_FOR_ITER,
+            Mnemonic::INSTRUMENTED_CALL => Self::INSTRUMENTED_CALL,
+            Mnemonic::INSTRUMENTED_CALL_KW => Self::INSTRUMENTED_CALL_KW,
+            Mnemonic::INSTRUMENTED_CALL_FUNCTION_EX => Self::INSTRUMENTED_CALL_FUNCTION_EX,
+            Mnemonic::INSTRUMENTED_INSTRUCTION => Self::INSTRUMENTED_INSTRUCTION,
+            Mnemonic::INSTRUMENTED_JUMP_FORWARD => Self::INSTRUMENTED_JUMP_FORWARD,
+            Mnemonic::INSTRUMENTED_JUMP_BACKWARD => Self::INSTRUMENTED_JUMP_BACKWARD,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_TRUE => Self::INSTRUMENTED_POP_JUMP_IF_TRUE,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_FALSE => Self::INSTRUMENTED_POP_JUMP_IF_FALSE,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_NONE => Self::INSTRUMENTED_POP_JUMP_IF_NONE,
+            Mnemonic::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+            Mnemonic::INSTRUMENTED_LINE => Self::INSTRUMENTED_LINE,
+
+            // Specialized opcodes, which only appear in code that has been executed
+            Mnemonic::BINARY_OP_INPLACE_ADD_UNICODE => Self::BINARY_OP_INPLACE_ADD_UNICODE,
+            Mnemonic::BINARY_OP_ADD_FLOAT => Self::BINARY_OP_ADD_FLOAT,
+            Mnemonic::BINARY_OP_ADD_INT => Self::BINARY_OP_ADD_INT,
+            Mnemonic::BINARY_OP_ADD_UNICODE => Self::BINARY_OP_ADD_UNICODE,
+            Mnemonic::BINARY_OP_MULTIPLY_FLOAT => Self::BINARY_OP_MULTIPLY_FLOAT,
+            Mnemonic::BINARY_OP_MULTIPLY_INT => Self::BINARY_OP_MULTIPLY_INT,
+            Mnemonic::BINARY_OP_SUBTRACT_FLOAT => Self::BINARY_OP_SUBTRACT_FLOAT,
+            Mnemonic::BINARY_OP_SUBTRACT_INT => Self::BINARY_OP_SUBTRACT_INT,
+            Mnemonic::BINARY_SUBSCR_DICT => Self::BINARY_SUBSCR_DICT,
+            Mnemonic::BINARY_SUBSCR_GETITEM => Self::BINARY_SUBSCR_GETITEM,
+            Mnemonic::BINARY_SUBSCR_LIST_INT => Self::BINARY_SUBSCR_LIST_INT,
+            Mnemonic::BINARY_SUBSCR_STR_INT => Self::BINARY_SUBSCR_STR_INT,
+            Mnemonic::BINARY_SUBSCR_TUPLE_INT => Self::BINARY_SUBSCR_TUPLE_INT,
+            Mnemonic::CALL_ALLOC_AND_ENTER_INIT => Self::CALL_ALLOC_AND_ENTER_INIT,
+            Mnemonic::CALL_BOUND_METHOD_EXACT_ARGS => Self::CALL_BOUND_METHOD_EXACT_ARGS,
+            Mnemonic::CALL_BOUND_METHOD_GENERAL => Self::CALL_BOUND_METHOD_GENERAL,
+            Mnemonic::CALL_BUILTIN_CLASS => Self::CALL_BUILTIN_CLASS,
+            Mnemonic::CALL_BUILTIN_FAST => Self::CALL_BUILTIN_FAST,
+            Mnemonic::CALL_BUILTIN_FAST_WITH_KEYWORDS => Self::CALL_BUILTIN_FAST_WITH_KEYWORDS,
+            Mnemonic::CALL_BUILTIN_O => Self::CALL_BUILTIN_O,
+            Mnemonic::CALL_ISINSTANCE => Self::CALL_ISINSTANCE,
+            Mnemonic::CALL_LEN => Self::CALL_LEN,
+            Mnemonic::CALL_LIST_APPEND => Self::CALL_LIST_APPEND,
+            Mnemonic::CALL_METHOD_DESCRIPTOR_FAST => Self::CALL_METHOD_DESCRIPTOR_FAST,
+            Mnemonic::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            }
+            Mnemonic::CALL_METHOD_DESCRIPTOR_NOARGS => Self::CALL_METHOD_DESCRIPTOR_NOARGS,
+            Mnemonic::CALL_METHOD_DESCRIPTOR_O => Self::CALL_METHOD_DESCRIPTOR_O,
+            Mnemonic::CALL_NON_PY_GENERAL => Self::CALL_NON_PY_GENERAL,
+            Mnemonic::CALL_PY_EXACT_ARGS => Self::CALL_PY_EXACT_ARGS,
+            Mnemonic::CALL_PY_GENERAL => Self::CALL_PY_GENERAL,
+            Mnemonic::CALL_STR_1 => Self::CALL_STR_1,
+            Mnemonic::CALL_TUPLE_1 => Self::CALL_TUPLE_1,
+            Mnemonic::CALL_TYPE_1 => Self::CALL_TYPE_1,
+            Mnemonic::COMPARE_OP_FLOAT => Self::COMPARE_OP_FLOAT,
+            Mnemonic::COMPARE_OP_INT => Self::COMPARE_OP_INT,
+            Mnemonic::COMPARE_OP_STR => Self::COMPARE_OP_STR,
+            Mnemonic::CONTAINS_OP_DICT => Self::CONTAINS_OP_DICT,
+            Mnemonic::CONTAINS_OP_SET => Self::CONTAINS_OP_SET,
+            Mnemonic::FOR_ITER_GEN => Self::FOR_ITER_GEN,
+            Mnemonic::FOR_ITER_LIST => Self::FOR_ITER_LIST,
+            Mnemonic::FOR_ITER_RANGE => Self::FOR_ITER_RANGE,
+            Mnemonic::FOR_ITER_TUPLE => Self::FOR_ITER_TUPLE,
+            Mnemonic::LOAD_ATTR_CLASS => Self::LOAD_ATTR_CLASS,
+            Mnemonic::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN => Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+            Mnemonic::LOAD_ATTR_INSTANCE_VALUE => Self::LOAD_ATTR_INSTANCE_VALUE,
+            Mnemonic::LOAD_ATTR_METHOD_LAZY_DICT => Self::LOAD_ATTR_METHOD_LAZY_DICT,
+            Mnemonic::LOAD_ATTR_METHOD_NO_DICT => Self::LOAD_ATTR_METHOD_NO_DICT,
+            Mnemonic::LOAD_ATTR_METHOD_WITH_VALUES => Self::LOAD_ATTR_METHOD_WITH_VALUES,
+            Mnemonic::LOAD_ATTR_MODULE => Self::LOAD_ATTR_MODULE,
+            Mnemonic::LOAD_ATTR_NONDESCRIPTOR_NO_DICT => Self::LOAD_ATTR_NONDESCRIPTOR_NO_DICT,
+            Mnemonic::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES => {
+                Self::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES
+            }
+            Mnemonic::LOAD_ATTR_PROPERTY => Self::LOAD_ATTR_PROPERTY,
+            Mnemonic::LOAD_ATTR_SLOT => Self::LOAD_ATTR_SLOT,
+            Mnemonic::LOAD_ATTR_WITH_HINT => Self::LOAD_ATTR_WITH_HINT,
+            Mnemonic::LOAD_GLOBAL_BUILTIN => Self::LOAD_GLOBAL_BUILTIN,
+            Mnemonic::LOAD_GLOBAL_MODULE => Self::LOAD_GLOBAL_MODULE,
+            Mnemonic::LOAD_SUPER_ATTR_ATTR => Self::LOAD_SUPER_ATTR_ATTR,
+            Mnemonic::LOAD_SUPER_ATTR_METHOD => Self::LOAD_SUPER_ATTR_METHOD,
+            Mnemonic::RESUME_CHECK => Self::RESUME_CHECK,
+            Mnemonic::SEND_GEN => Self::SEND_GEN,
+            Mnemonic::STORE_ATTR_INSTANCE_VALUE => Self::STORE_ATTR_INSTANCE_VALUE,
+            Mnemonic::STORE_ATTR_SLOT => Self::STORE_ATTR_SLOT,
+            Mnemonic::STORE_ATTR_WITH_HINT => Self::STORE_ATTR_WITH_HINT,
+            Mnemonic::STORE_SUBSCR_DICT => Self::STORE_SUBSCR_DICT,
+            Mnemonic::STORE_SUBSCR_LIST_INT => Self::STORE_SUBSCR_LIST_INT,
+            Mnemonic::TO_BOOL_ALWAYS_TRUE => Self::TO_BOOL_ALWAYS_TRUE,
+            Mnemonic::TO_BOOL_BOOL => Self::TO_BOOL_BOOL,
+            Mnemonic::TO_BOOL_INT => Self::TO_BOOL_INT,
+            Mnemonic::TO_BOOL_LIST => Self::TO_BOOL_LIST,
+            Mnemonic::TO_BOOL_NONE => Self::TO_BOOL_NONE,
+            Mnemonic::TO_BOOL_STR => Self::TO_BOOL_STR,
+            Mnemonic::UNPACK_SEQUENCE_LIST => Self::UNPACK_SEQUENCE_LIST,
+            Mnemonic::UNPACK_SEQUENCE_TUPLE => Self::UNPACK_SEQUENCE_TUPLE,
+            Mnemonic::UNPACK_SEQUENCE_TWO_TUPLE => Self::UNPACK_SEQUENCE_TWO_TUPLE,
+        }
+    }
+}
+
+impl super::Opcode for Standard {
+    type Mnemonic = Mnemonic;
+
+    /// Whether or not this opcode has an argument
+    fn has_arg(&self) -> bool {
+        matches!(self.deoptimize() as u8, 45..=118 | 149)
+    }
+
+    /// Whether or not this opcode has an extended argument
+    fn has_extended_arg(&self) -> bool {
+        self.deoptimize() == Self::EXTENDED_ARG
+    }
+
+    /// Whether or not this opcode is the `EXTENDED_ARG` prefix
+    fn is_extended_arg(&self) -> bool {
+        self.deoptimize() == Self::EXTENDED_ARG
+    }
+
+    /// The `EXTENDED_ARG` prefix opcode of this opcode table
+    fn extended_arg() -> Self {
+        Self::EXTENDED_ARG
+    }
+
+    /// Whether or not this opcode has a constant parameter
+    fn has_const(&self) -> bool {
+        matches!(self.deoptimize(), Self::LOAD_CONST | Self::RETURN_CONST)
+    }
+
+    /// Whether or not this opcode is a boolean operation
+    fn has_comp(&self) -> bool {
+        matches!(self.deoptimize(), Self::COMPARE_OP)
+    }
+
+    /// Whether or not this opcode has a relative jump target
+    fn is_relative_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::FOR_ITER
+                | Self::JUMP_BACKWARD
+                | Self::JUMP_BACKWARD_NO_INTERRUPT
+                | Self::JUMP_FORWARD
+                | Self::POP_JUMP_IF_FALSE
+                | Self::POP_JUMP_IF_NONE
+                | Self::POP_JUMP_IF_NOT_NONE
+                | Self::POP_JUMP_IF_TRUE
+                | Self::SEND
+        )
+    }
+
+    /// Whether or not this opcode has an absolute jump target
+    fn is_absolute_jump(&self) -> bool {
+        false
+    }
+
+    /// Whether or not this opcode is another type of "special" jumping instruction
+    /// e.g. FOR_ITER, SETUP_LOOP, etc.
+    fn is_other_conditional_jump(&self) -> bool {
+        matches!(self.deoptimize(), Self::FOR_ITER | Self::SEND)
+    }
+
+    /// Whether or not this opcode is a conditional jump
+    fn is_conditional_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::POP_JUMP_IF_FALSE
+                | Self::POP_JUMP_IF_TRUE
+                | Self::POP_JUMP_IF_NOT_NONE
+                | Self::POP_JUMP_IF_NONE
+        )
+    }
+
+    /// Whether or not this opcode accesses an attribute by name
+    fn has_name(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::DELETE_ATTR
+                | Self::DELETE_GLOBAL
+                | Self::DELETE_NAME
+                | Self::IMPORT_FROM
+                | Self::IMPORT_NAME
+                | Self::LOAD_ATTR
+                | Self::LOAD_FROM_DICT_OR_GLOBALS
+                | Self::LOAD_GLOBAL
+                | Self::LOAD_NAME
+                | Self::LOAD_SUPER_ATTR
+                | Self::STORE_ATTR
+                | Self::STORE_GLOBAL
+                | Self::STORE_NAME
+        )
+    }
+
+    /// Whether or not this opcode accesses a local variable
+    fn has_local(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::DELETE_FAST
+                | Self::LOAD_FAST
+                | Self::LOAD_FAST_AND_CLEAR
+                | Self::LOAD_FAST_CHECK
+                | Self::LOAD_FAST_LOAD_FAST
+                | Self::STORE_FAST
+                | Self::STORE_FAST_LOAD_FAST
+                | Self::STORE_FAST_STORE_FAST
+        )
+    }
+
+    /// Whether or not this opcode accesses a free variable
+    fn has_free(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::DELETE_DEREF
+                | Self::LOAD_DEREF
+                | Self::LOAD_FROM_DICT_OR_DEREF
+                | Self::MAKE_CELL
+                | Self::STORE_DEREF
+        )
+    }
+
+    /// How instructions of this opcode table are laid out in bytecode
+    fn encoding() -> Encoding {
+        Encoding::Wordcode
+    }
+
+    /// The number of bytes each unit of a jump argument stands for
+    fn jump_unit() -> usize {
+        2
+    }
+
+    /// The number of inline cache code units which follow instructions with this opcode
+    fn cache_entries(&self) -> usize {
+        match self.deoptimize() {
+            Self::BINARY_SUBSCR
+            | Self::STORE_SUBSCR
+            | Self::BINARY_OP
+            | Self::COMPARE_OP
+            | Self::CONTAINS_OP
+            | Self::FOR_ITER
+            | Self::JUMP_BACKWARD
+            | Self::LOAD_SUPER_ATTR
+            | Self::POP_JUMP_IF_FALSE
+            | Self::POP_JUMP_IF_NONE
+            | Self::POP_JUMP_IF_NOT_NONE
+            | Self::POP_JUMP_IF_TRUE
+            | Self::SEND
+            | Self::UNPACK_SEQUENCE => 1,
+            Self::TO_BOOL | Self::CALL => 3,
+            Self::LOAD_GLOBAL | Self::STORE_ATTR => 4,
+            Self::LOAD_ATTR => 9,
+            _ => 0,
+        }
+    }
+
+    /// The `CACHE` opcode which fills inline cache entries
+    fn cache() -> Option<Self> {
+        Some(Self::CACHE)
+    }
+
+    /// Whether or not this opcode is a relative jump whose target precedes it
+    fn is_backward_jump(&self) -> bool {
+        matches!(
+            self.deoptimize(),
+            Self::JUMP_BACKWARD | Self::JUMP_BACKWARD_NO_INTERRUPT
+        )
+    }
+
+    /// The index which an argument of this opcode refers to
+    fn argument_index(&self, arg: u32) -> u32 {
+        match self.deoptimize() {
+            // The low bit of the argument pushes a NULL or `self` as well
+            Self::LOAD_GLOBAL | Self::LOAD_ATTR => arg >> 1,
+            // The low bits of the argument are the method and two-argument `super()` flags
+            Self::LOAD_SUPER_ATTR => arg >> 2,
+            _ => arg,
+        }
+    }
+
+    /// The indexes of the two locals which the argument of a superinstruction refers to
+    fn local_pair(&self, arg: u32) -> Option<(u32, u32)> {
+        match self.deoptimize() {
+            Self::LOAD_FAST_LOAD_FAST
+            | Self::STORE_FAST_LOAD_FAST
+            | Self::STORE_FAST_STORE_FAST => Some((arg >> 4, arg & 0xF)),
+            _ => None,
+        }
+    }
+
+    /// The base opcode of a specialized or instrumented opcode
+    fn deoptimize(&self) -> Self {
+        Self::from(self.mnemonic().deoptimize())
+    }
+
+    fn mnemonic(&self) -> Mnemonic {
+        match self {
+            Self::CACHE => Mnemonic::CACHE,
+            Self::BEFORE_ASYNC_WITH => Mnemonic::BEFORE_ASYNC_WITH,
+            Self::BEFORE_WITH => Mnemonic::BEFORE_WITH,
+
+            Self::BINARY_SLICE => Mnemonic::BINARY_SLICE,
+            Self::BINARY_SUBSCR => Mnemonic::BINARY_SUBSCR,
+            Self::CHECK_EG_MATCH => Mnemonic::CHECK_EG_MATCH,
+            Self::CHECK_EXC_MATCH => Mnemonic::CHECK_EXC_MATCH,
+            Self::CLEANUP_THROW => Mnemonic::CLEANUP_THROW,
+            Self::DELETE_SUBSCR => Mnemonic::DELETE_SUBSCR,
+            Self::END_ASYNC_FOR => Mnemonic::END_ASYNC_FOR,
+            Self::END_FOR => Mnemonic::END_FOR,
+            Self::END_SEND => Mnemonic::END_SEND,
+            Self::EXIT_INIT_CHECK => Mnemonic::EXIT_INIT_CHECK,
+            Self::FORMAT_SIMPLE => Mnemonic::FORMAT_SIMPLE,
+            Self::FORMAT_WITH_SPEC => Mnemonic::FORMAT_WITH_SPEC,
+            Self::GET_AITER => Mnemonic::GET_AITER,
+            Self::RESERVED => Mnemonic::RESERVED,
+            Self::GET_ANEXT => Mnemonic::GET_ANEXT,
+            Self::GET_ITER => Mnemonic::GET_ITER,
+            Self::GET_LEN => Mnemonic::GET_LEN,
+            Self::GET_YIELD_FROM_ITER => Mnemonic::GET_YIELD_FROM_ITER,
+            Self::INTERPRETER_EXIT => Mnemonic::INTERPRETER_EXIT,
+            Self::LOAD_ASSERTION_ERROR => Mnemonic::LOAD_ASSERTION_ERROR,
+            Self::LOAD_BUILD_CLASS => Mnemonic::LOAD_BUILD_CLASS,
+            Self::LOAD_LOCALS => Mnemonic::LOAD_LOCALS,
+            Self::MAKE_FUNCTION => Mnemonic::MAKE_FUNCTION,
+            Self::MATCH_KEYS => Mnemonic::MATCH_KEYS,
+            Self::MATCH_MAPPING => Mnemonic::MATCH_MAPPING,
+            Self::MATCH_SEQUENCE => Mnemonic::MATCH_SEQUENCE,
+            Self::NOP => Mnemonic::NOP,
+            Self::POP_EXCEPT => Mnemonic::POP_EXCEPT,
+            Self::POP_TOP => Mnemonic::POP_TOP,
+            Self::PUSH_EXC_INFO => Mnemonic::PUSH_EXC_INFO,
+            Self::PUSH_NULL => Mnemonic::PUSH_NULL,
+            Self::RETURN_GENERATOR => Mnemonic::RETURN_GENERATOR,
+            Self::RETURN_VALUE => Mnemonic::RETURN_VALUE,
+            Self::SETUP_ANNOTATIONS => Mnemonic::SETUP_ANNOTATIONS,
+            Self::STORE_SLICE => Mnemonic::STORE_SLICE,
+            Self::STORE_SUBSCR => Mnemonic::STORE_SUBSCR,
+            Self::TO_BOOL => Mnemonic::TO_BOOL,
+            Self::UNARY_INVERT => Mnemonic::UNARY_INVERT,
+            Self::UNARY_NEGATIVE => Mnemonic::UNARY_NEGATIVE,
+            Self::UNARY_NOT => Mnemonic::UNARY_NOT,
+            Self::WITH_EXCEPT_START => Mnemonic::WITH_EXCEPT_START,
+
+            // Opcodes with arguments
+            Self::BINARY_OP => Mnemonic::BINARY_OP,
+            Self::BUILD_CONST_KEY_MAP => Mnemonic::BUILD_CONST_KEY_MAP,
+            Self::BUILD_LIST => Mnemonic::BUILD_LIST,
+            Self::BUILD_MAP => Mnemonic::BUILD_MAP,
+            Self::BUILD_SET => Mnemonic::BUILD_SET,
+            Self::BUILD_SLICE => Mnemonic::BUILD_SLICE,
+            Self::BUILD_STRING => Mnemonic::BUILD_STRING,
+            Self::BUILD_TUPLE => Mnemonic::BUILD_TUPLE,
+            Self::CALL => Mnemonic::CALL,
+            Self::CALL_FUNCTION_EX => Mnemonic::CALL_FUNCTION_EX,
+            Self::CALL_INTRINSIC_1 => Mnemonic::CALL_INTRINSIC_1,
+            Self::CALL_INTRINSIC_2 => Mnemonic::CALL_INTRINSIC_2,
+            Self::CALL_KW => Mnemonic::CALL_KW,
+            Self::COMPARE_OP => Mnemonic::COMPARE_OP,
+            Self::CONTAINS_OP => Mnemonic::CONTAINS_OP,
+            Self::CONVERT_VALUE => Mnemonic::CONVERT_VALUE,
+            Self::COPY => Mnemonic::COPY,
+            Self::COPY_FREE_VARS => Mnemonic::COPY_FREE_VARS,
+            Self::DELETE_ATTR => Mnemonic::DELETE_ATTR,
+            Self::DELETE_DEREF => Mnemonic::DELETE_DEREF,
+            Self::DELETE_FAST => Mnemonic::DELETE_FAST,
+            Self::DELETE_GLOBAL => Mnemonic::DELETE_GLOBAL,
+            Self::DELETE_NAME => Mnemonic::DELETE_NAME,
+            Self::DICT_MERGE => Mnemonic::DICT_MERGE,
+            Self::DICT_UPDATE => Mnemonic::DICT_UPDATE,
+            Self::ENTER_EXECUTOR => Mnemonic::ENTER_EXECUTOR,
+            Self::EXTENDED_ARG => Mnemonic::EXTENDED_ARG,
+            Self::FOR_ITER => Mnemonic::FOR_ITER,
+            Self::GET_AWAITABLE => Mnemonic::GET_AWAITABLE,
+            Self::IMPORT_FROM => Mnemonic::IMPORT_FROM,
+            Self::IMPORT_NAME => Mnemonic::IMPORT_NAME,
+            Self::IS_OP => Mnemonic::IS_OP,
+            Self::JUMP_BACKWARD => Mnemonic::JUMP_BACKWARD,
+            Self::JUMP_BACKWARD_NO_INTERRUPT => Mnemonic::JUMP_BACKWARD_NO_INTERRUPT,
+            Self::JUMP_FORWARD => Mnemonic::JUMP_FORWARD,
+            Self::LIST_APPEND => Mnemonic::LIST_APPEND,
+            Self::LIST_EXTEND => Mnemonic::LIST_EXTEND,
+            Self::LOAD_ATTR => Mnemonic::LOAD_ATTR,
+            Self::LOAD_CONST => Mnemonic::LOAD_CONST,
+            Self::LOAD_DEREF => Mnemonic::LOAD_DEREF,
+            Self::LOAD_FAST => Mnemonic::LOAD_FAST,
+            Self::LOAD_FAST_AND_CLEAR => Mnemonic::LOAD_FAST_AND_CLEAR,
+            Self::LOAD_FAST_CHECK => Mnemonic::LOAD_FAST_CHECK,
+            Self::LOAD_FAST_LOAD_FAST => Mnemonic::LOAD_FAST_LOAD_FAST,
+            Self::LOAD_FROM_DICT_OR_DEREF => Mnemonic::LOAD_FROM_DICT_OR_DEREF,
+            Self::LOAD_FROM_DICT_OR_GLOBALS => Mnemonic::LOAD_FROM_DICT_OR_GLOBALS,
+            Self::LOAD_GLOBAL => Mnemonic::LOAD_GLOBAL,
+            Self::LOAD_NAME => Mnemonic::LOAD_NAME,
+            Self::LOAD_SUPER_ATTR => Mnemonic::LOAD_SUPER_ATTR,
+            Self::MAKE_CELL => Mnemonic::MAKE_CELL,
+            Self::MAP_ADD => Mnemonic::MAP_ADD,
+            Self::MATCH_CLASS => Mnemonic::MATCH_CLASS,
+            Self::POP_JUMP_IF_FALSE => Mnemonic::POP_JUMP_IF_FALSE,
+            Self::POP_JUMP_IF_NONE => Mnemonic::POP_JUMP_IF_NONE,
+            Self::POP_JUMP_IF_NOT_NONE => Mnemonic::POP_JUMP_IF_NOT_NONE,
+            Self::POP_JUMP_IF_TRUE => Mnemonic::POP_JUMP_IF_TRUE,
+            Self::RAISE_VARARGS => Mnemonic::RAISE_VARARGS,
+            Self::RERAISE => Mnemonic::RERAISE,
+            Self::RETURN_CONST => Mnemonic::RETURN_CONST,
+            Self::SEND => Mnemonic::SEND,
+            Self::SET_ADD => Mnemonic::SET_ADD,
+            Self::SET_FUNCTION_ATTRIBUTE => Mnemonic::SET_FUNCTION_ATTRIBUTE,
+            Self::SET_UPDATE => Mnemonic::SET_UPDATE,
+            Self::STORE_ATTR => Mnemonic::STORE_ATTR,
+            Self::STORE_DEREF => Mnemonic::STORE_DEREF,
+            Self::STORE_FAST => Mnemonic::STORE_FAST,
+            Self::STORE_FAST_LOAD_FAST => Mnemonic::STORE_FAST_LOAD_FAST,
+            Self::STORE_FAST_STORE_FAST => Mnemonic::STORE_FAST_STORE_FAST,
+            Self::STORE_GLOBAL => Mnemonic::STORE_GLOBAL,
+            Self::STORE_NAME => Mnemonic::STORE_NAME,
+            Self::SWAP => Mnemonic::SWAP,
+            Self::UNPACK_EX => Mnemonic::UNPACK_EX,
+            Self::UNPACK_SEQUENCE => Mnemonic::UNPACK_SEQUENCE,
+            Self::YIELD_VALUE => Mnemonic::YIELD_VALUE,
+
+            Self::RESUME => Mnemonic::RESUME,
+
+            // Instrumented opcodes, which replace the opcodes above while `sys.monitoring` is active
+            Self::INSTRUMENTED_RESUME => Mnemonic::INSTRUMENTED_RESUME,
+            Self::INSTRUMENTED_END_FOR => Mnemonic::INSTRUMENTED_END_FOR,
+            Self::INSTRUMENTED_END_SEND => Mnemonic::INSTRUMENTED_END_SEND,
+            Self::INSTRUMENTED_RETURN_VALUE => Mnemonic::INSTRUMENTED_RETURN_VALUE,
+            Self::INSTRUMENTED_RETURN_CONST => Mnemonic::INSTRUMENTED_RETURN_CONST,
+            Self::INSTRUMENTED_YIELD_VALUE => Mnemonic::INSTRUMENTED_YIELD_VALUE,
+            Self::INSTRUMENTED_LOAD_SUPER_ATTR => Mnemonic::INSTRUMENTED_LOAD_SUPER_ATTR,
+            Self::INSTRUMENTED_FOR_ITER => Mnemonic::INSTRUMENTED_FOR_ITER,
+            Self::INSTRUMENTED_CALL => Mnemonic::INSTRUMENTED_CALL,
+            Self::INSTRUMENTED_CALL_KW => Mnemonic::INSTRUMENTED_CALL_KW,
+            Self::INSTRUMENTED_CALL_FUNCTION_EX => Mnemonic::INSTRUMENTED_CALL_FUNCTION_EX,
+            Self::INSTRUMENTED_INSTRUCTION => Mnemonic::INSTRUMENTED_INSTRUCTION,
+            Self::INSTRUMENTED_JUMP_FORWARD => Mnemonic::INSTRUMENTED_JUMP_FORWARD,
+            Self::INSTRUMENTED_JUMP_BACKWARD => Mnemonic::INSTRUMENTED_JUMP_BACKWARD,
+            Self::INSTRUMENTED_POP_JUMP_IF_TRUE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_TRUE,
+            Self::INSTRUMENTED_POP_JUMP_IF_FALSE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_FALSE,
+            Self::INSTRUMENTED_POP_JUMP_IF_NONE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_NONE,
+            Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE => Mnemonic::INSTRUMENTED_POP_JUMP_IF_NOT_NONE,
+            Self::INSTRUMENTED_LINE => Mnemonic::INSTRUMENTED_LINE,
+
+            // Specialized opcodes, which only appear in code that has been executed
+            Self::BINARY_OP_INPLACE_ADD_UNICODE => Mnemonic::BINARY_OP_INPLACE_ADD_UNICODE,
+            Self::BINARY_OP_ADD_FLOAT => Mnemonic::BINARY_OP_ADD_FLOAT,
+            Self::BINARY_OP_ADD_INT => Mnemonic::BINARY_OP_ADD_INT,
+            Self::BINARY_OP_ADD_UNICODE => Mnemonic::BINARY_OP_ADD_UNICODE,
+            Self::BINARY_OP_MULTIPLY_FLOAT => Mnemonic::BINARY_OP_MULTIPLY_FLOAT,
+            Self::BINARY_OP_MULTIPLY_INT => Mnemonic::BINARY_OP_MULTIPLY_INT,
+            Self::BINARY_OP_SUBTRACT_FLOAT => Mnemonic::BINARY_OP_SUBTRACT_FLOAT,
+            Self::BINARY_OP_SUBTRACT_INT => Mnemonic::BINARY_OP_SUBTRACT_INT,
+            Self::BINARY_SUBSCR_DICT => Mnemonic::BINARY_SUBSCR_DICT,
+            Self::BINARY_SUBSCR_GETITEM => Mnemonic::BINARY_SUBSCR_GETITEM,
+            Self::BINARY_SUBSCR_LIST_INT => Mnemonic::BINARY_SUBSCR_LIST_INT,
+            Self::BINARY_SUBSCR_STR_INT => Mnemonic::BINARY_SUBSCR_STR_INT,
+            Self::BINARY_SUBSCR_TUPLE_INT => Mnemonic::BINARY_SUBSCR_TUPLE_INT,
+            Self::CALL_ALLOC_AND_ENTER_INIT => Mnemonic::CALL_ALLOC_AND_ENTER_INIT,
+            Self::CALL_BOUND_METHOD_EXACT_ARGS => Mnemonic::CALL_BOUND_METHOD_EXACT_ARGS,
+            Self::CALL_BOUND_METHOD_GENERAL => Mnemonic::CALL_BOUND_METHOD_GENERAL,
+            Self::CALL_BUILTIN_CLASS => Mnemonic::CALL_BUILTIN_CLASS,
+            Self::CALL_BUILTIN_FAST => Mnemonic::CALL_BUILTIN_FAST,
+            Self::CALL_BUILTIN_FAST_WITH_KEYWORDS => Mnemonic::CALL_BUILTIN_FAST_WITH_KEYWORDS,
+            Self::CALL_BUILTIN_O => Mnemonic::CALL_BUILTIN_O,
+            Self::CALL_ISINSTANCE => Mnemonic::CALL_ISINSTANCE,
+            Self::CALL_LEN => Mnemonic::CALL_LEN,
+            Self::CALL_LIST_APPEND => Mnemonic::CALL_LIST_APPEND,
+            Self::CALL_METHOD_DESCRIPTOR_FAST => Mnemonic::CALL_METHOD_DESCRIPTOR_FAST,
+            Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS => {
+                Mnemonic::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            }
+            Self::CALL_METHOD_DESCRIPTOR_NOARGS => Mnemonic::CALL_METHOD_DESCRIPTOR_NOARGS,
+            Self::CALL_METHOD_DESCRIPTOR_O => Mnemonic::CALL_METHOD_DESCRIPTOR_O,
+            Self::CALL_NON_PY_GENERAL => Mnemonic::CALL_NON_PY_GENERAL,
+            Self::CALL_PY_EXACT_ARGS => Mnemonic::CALL_PY_EXACT_ARGS,
+            Self::CALL_PY_GENERAL => Mnemonic::CALL_PY_GENERAL,
+            Self::CALL_STR_1 => Mnemonic::CALL_STR_1,
+            Self::CALL_TUPLE_1 => Mnemonic::CALL_TUPLE_1,
+            Self::CALL_TYPE_1 => Mnemonic::CALL_TYPE_1,
+            Self::COMPARE_OP_FLOAT => Mnemonic::COMPARE_OP_FLOAT,
+            Self::COMPARE_OP_INT => Mnemonic::COMPARE_OP_INT,
+            Self::COMPARE_OP_STR => Mnemonic::COMPARE_OP_STR,
+            Self::CONTAINS_OP_DICT => Mnemonic::CONTAINS_OP_DICT,
+            Self::CONTAINS_OP_SET => Mnemonic::CONTAINS_OP_SET,
+            Self::FOR_ITER_GEN => Mnemonic::FOR_ITER_GEN,
+            Self::FOR_ITER_LIST => Mnemonic::FOR_ITER_LIST,
+            Self::FOR_ITER_RANGE => Mnemonic::FOR_ITER_RANGE,
+            Self::FOR_ITER_TUPLE => Mnemonic::FOR_ITER_TUPLE,
+            Self::LOAD_ATTR_CLASS => Mnemonic::LOAD_ATTR_CLASS,
+            Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN => Mnemonic::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN,
+            Self::LOAD_ATTR_INSTANCE_VALUE => Mnemonic::LOAD_ATTR_INSTANCE_VALUE,
+            Self::LOAD_ATTR_METHOD_LAZY_DICT => Mnemonic::LOAD_ATTR_METHOD_LAZY_DICT,
+            Self::LOAD_ATTR_METHOD_NO_DICT => Mnemonic::LOAD_ATTR_METHOD_NO_DICT,
+            Self::LOAD_ATTR_METHOD_WITH_VALUES => Mnemonic::LOAD_ATTR_METHOD_WITH_VALUES,
+            Self::LOAD_ATTR_MODULE => Mnemonic::LOAD_ATTR_MODULE,
+            Self::LOAD_ATTR_NONDESCRIPTOR_NO_DICT => Mnemonic::LOAD_ATTR_NONDESCRIPTOR_NO_DICT,
+            Self::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES => {
+                Mnemonic::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES
+            }
+            Self::LOAD_ATTR_PROPERTY => Mnemonic::LOAD_ATTR_PROPERTY,
+            Self::LOAD_ATTR_SLOT => Mnemonic::LOAD_ATTR_SLOT,
+            Self::LOAD_ATTR_WITH_HINT => Mnemonic::LOAD_ATTR_WITH_HINT,
+            Self::LOAD_GLOBAL_BUILTIN => Mnemonic::LOAD_GLOBAL_BUILTIN,
+            Self::LOAD_GLOBAL_MODULE => Mnemonic::LOAD_GLOBAL_MODULE,
+            Self::LOAD_SUPER_ATTR_ATTR => Mnemonic::LOAD_SUPER_ATTR_ATTR,
+            Self::LOAD_SUPER_ATTR_METHOD => Mnemonic::LOAD_SUPER_ATTR_METHOD,
+            Self::RESUME_CHECK => Mnemonic::RESUME_CHECK,
+            Self::SEND_GEN => Mnemonic::SEND_GEN,
+            Self::STORE_ATTR_INSTANCE_VALUE => Mnemonic::STORE_ATTR_INSTANCE_VALUE,
+            Self::STORE_ATTR_SLOT => Mnemonic::STORE_ATTR_SLOT,
+            Self::STORE_ATTR_WITH_HINT => Mnemonic::STORE_ATTR_WITH_HINT,
+            Self::STORE_SUBSCR_DICT => Mnemonic::STORE_SUBSCR_DICT,
+            Self::STORE_SUBSCR_LIST_INT => Mnemonic::STORE_SUBSCR_LIST_INT,
+            Self::TO_BOOL_ALWAYS_TRUE => Mnemonic::TO_BOOL_ALWAYS_TRUE,
+            Self::TO_BOOL_BOOL => Mnemonic::TO_BOOL_BOOL,
+            Self::TO_BOOL_INT => Mnemonic::TO_BOOL_INT,
+            Self::TO_BOOL_LIST => Mnemonic::TO_BOOL_LIST,
+            Self::TO_BOOL_NONE => Mnemonic::TO_BOOL_NONE,
+            Self::TO_BOOL_STR => Mnemonic::TO_BOOL_STR,
+            Self::UNPACK_SEQUENCE_LIST => Mnemonic::UNPACK_SEQUENCE_LIST,
+            Self::UNPACK_SEQUENCE_TUPLE => Mnemonic::UNPACK_SEQUENCE_TUPLE,
+            Self::UNPACK_SEQUENCE_TWO_TUPLE => Mnemonic::UNPACK_SEQUENCE_TWO_TUPLE,
+        }
+    }
+}
+
+/// Pseudo-instructions used by the compiler. These are replaced with real instructions before
+/// bytecode is emitted, so they never appear in code objects.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u16)]
+pub enum Pseudo {
+    JUMP = 256,
+    JUMP_NO_INTERRUPT = 257,
+    LOAD_CLOSURE = 258,
+    LOAD_METHOD = 259,
+    LOAD_SUPER_METHOD = 260,
+    LOAD_ZERO_SUPER_ATTR = 261,
+    LOAD_ZERO_SUPER_METHOD = 262,
+    POP_BLOCK = 263,
+    SETUP_CLEANUP = 264,
+    SETUP_FINALLY = 265,
+    SETUP_WITH = 266,
+    STORE_FAST_MAYBE_NULL = 267,
+}
+
+impl Pseudo {
+    /// Every pseudo-instruction, in declaration order
+    pub const ALL: &'static [Pseudo] = &[
+        Self::JUMP,
+        Self::JUMP_NO_INTERRUPT,
+        Self::LOAD_CLOSURE,
+        Self::LOAD_METHOD,
+        Self::LOAD_SUPER_METHOD,
+        Self::LOAD_ZERO_SUPER_ATTR,
+        Self::LOAD_ZERO_SUPER_METHOD,
+        Self::POP_BLOCK,
+        Self::SETUP_CLEANUP,
+        Self::SETUP_FINALLY,
+        Self::SETUP_WITH,
+        Self::STORE_FAST_MAYBE_NULL,
+    ];
+
+    /// The canonical name of this pseudo-instruction
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::JUMP => "JUMP",
+            Self::JUMP_NO_INTERRUPT => "JUMP_NO_INTERRUPT",
+            Self::LOAD_CLOSURE => "LOAD_CLOSURE",
+            Self::LOAD_METHOD => "LOAD_METHOD",
+            Self::LOAD_SUPER_METHOD => "LOAD_SUPER_METHOD",
+            Self::LOAD_ZERO_SUPER_ATTR => "LOAD_ZERO_SUPER_ATTR",
+            Self::LOAD_ZERO_SUPER_METHOD => "LOAD_ZERO_SUPER_METHOD",
+            Self::POP_BLOCK => "POP_BLOCK",
+            Self::SETUP_CLEANUP => "SETUP_CLEANUP",
+            Self::SETUP_FINALLY => "SETUP_FINALLY",
+            Self::SETUP_WITH => "SETUP_WITH",
+            Self::STORE_FAST_MAYBE_NULL => "STORE_FAST_MAYBE_NULL",
+        }
+    }
+
+    /// The real instructions which this pseudo-instruction may be replaced with
+    pub fn targets(&self) -> &'static [Mnemonic] {
+        match self {
+            Self::JUMP => &[Mnemonic::JUMP_FORWARD, Mnemonic::JUMP_BACKWARD],
+            Self::JUMP_NO_INTERRUPT => {
+                &[Mnemonic::JUMP_FORWARD, Mnemonic::JUMP_BACKWARD_NO_INTERRUPT]
+            }
+            Self::LOAD_CLOSURE => &[Mnemonic::LOAD_FAST],
+            Self::LOAD_METHOD => &[Mnemonic::LOAD_ATTR],
+            Self::LOAD_SUPER_METHOD | Self::LOAD_ZERO_SUPER_ATTR | Self::LOAD_ZERO_SUPER_METHOD => {
+                &[Mnemonic::LOAD_SUPER_ATTR]
+            }
+            Self::POP_BLOCK | Self::SETUP_CLEANUP | Self::SETUP_FINALLY | Self::SETUP_WITH => {
+                &[Mnemonic::NOP]
+            }
+            Self::STORE_FAST_MAYBE_NULL => &[Mnemonic::STORE_FAST],
+        }
+    }
+}
+
+impl fmt::Display for Pseudo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Pseudo {
+    type Err = ParseMnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "JUMP" => Ok(Self::JUMP),
+            "JUMP_NO_INTERRUPT" => Ok(Self::JUMP_NO_INTERRUPT),
+            "LOAD_CLOSURE" => Ok(Self::LOAD_CLOSURE),
+            "LOAD_METHOD" => Ok(Self::LOAD_METHOD),
+            "LOAD_SUPER_METHOD" => Ok(Self::LOAD_SUPER_METHOD),
+            "LOAD_ZERO_SUPER_ATTR" => Ok(Self::LOAD_ZERO_SUPER_ATTR),
+            "LOAD_ZERO_SUPER_METHOD" => Ok(Self::LOAD_ZERO_SUPER_METHOD),
+            "POP_BLOCK" => Ok(Self::POP_BLOCK),
+            "SETUP_CLEANUP" => Ok(Self::SETUP_CLEANUP),
+            "SETUP_FINALLY" => Ok(Self::SETUP_FINALLY),
+            "SETUP_WITH" => Ok(Self::SETUP_WITH),
+            "STORE_FAST_MAYBE_NULL" => Ok(Self::STORE_FAST_MAYBE_NULL),
+            _ => Err(ParseMnemonicError(s.to_string())),
+        }
+    }
+}
+
+impl super::StackEffect for Mnemonic {
+    fn stack_adjustment(&self, arg: Option<u32>) -> isize {
+        match self {
+            // Meta instructions
+            Self::NOP => 0,
+            Self::RESERVED => 0,
+            Self::POP_TOP => -1,
+            Self::PUSH_NULL => 1,
+            Self::SWAP => 0,
+            Self::COPY => 1,
+            // Unary ops
+            Self::UNARY_NEGATIVE
+            | Self::UNARY_NOT
+            | Self::UNARY_INVERT
+            | Self::GET_ITER
+            | Self::GET_YIELD_FROM_ITER
+            | Self::GET_AITER
+            | Self::GET_AWAITABLE => 0,
+            Self::TO_BOOL => 0,
+            // Binary ops
+            Self::BINARY_OP => -1,
+            Self::BINARY_SUBSCR => -1,
+            // The container, start and stop are replaced by the slice
+            Self::BINARY_SLICE => -2,
+            Self::STORE_SLICE => -4,
+            Self::STORE_SUBSCR => -3,
+            Self::DELETE_SUBSCR => -2,
+            Self::LIST_APPEND | Self::SET_ADD => -1,
+            Self::MAP_ADD => -2,
+            Self::LIST_EXTEND | Self::SET_UPDATE | Self::DICT_MERGE | Self::DICT_UPDATE => -1,
+            Self::LOAD_BUILD_CLASS => 1,
+            Self::LOAD_LOCALS => 1,
+            Self::LOAD_ASSERTION_ERROR => 1,
+            Self::SETUP_ANNOTATIONS => 0,
+            Self::RETURN_VALUE => -1,
+            Self::RETURN_CONST => 0,
+            // Returns the value to the caller of the interpreter
+            Self::INTERPRETER_EXIT => -1,
+            Self::YIELD_VALUE => 0,
+            Self::RETURN_GENERATOR => 1,
+            // The previous exception is pushed below the one being handled
+            Self::PUSH_EXC_INFO => 1,
+            // Restores the previous exception
+            Self::POP_EXCEPT => -1,
+            // The exception type is replaced by the result of the match
+            Self::CHECK_EXC_MATCH | Self::CHECK_EG_MATCH => 0,
+            // The exception and the iterator
+            Self::END_ASYNC_FOR => -2,
+            // The result of `__enter__` is pushed above the exit method
+            Self::BEFORE_WITH | Self::BEFORE_ASYNC_WITH | Self::GET_ANEXT => 1,
+            Self::STORE_NAME | Self::STORE_FAST | Self::STORE_DEREF | Self::STORE_GLOBAL => -1,
+            Self::DELETE_NAME | Self::DELETE_FAST | Self::DELETE_DEREF | Self::DELETE_GLOBAL => 0,
+            Self::UNPACK_SEQUENCE => (arg.unwrap() as isize) - 1,
+            // The counts of values before and after the starred target
+            Self::UNPACK_EX => (arg.unwrap() & 0xFF) as isize + (arg.unwrap() >> 8) as isize,
+            Self::STORE_ATTR => -2,
+            Self::DELETE_ATTR => -1,
+            Self::LOAD_CONST | Self::LOAD_NAME | Self::LOAD_FAST | Self::LOAD_DEREF => 1,
+            Self::LOAD_FAST_CHECK | Self::LOAD_FAST_AND_CLEAR => 1,
+            // Superinstructions which each access two locals
+            Self::LOAD_FAST_LOAD_FAST => 2,
+            Self::STORE_FAST_LOAD_FAST => 0,
+            Self::STORE_FAST_STORE_FAST => -2,
+            // The mapping is replaced by the value
+            Self::LOAD_FROM_DICT_OR_GLOBALS | Self::LOAD_FROM_DICT_OR_DEREF => 0,
+            Self::BUILD_TUPLE | Self::BUILD_LIST | Self::BUILD_SET => 1 - (arg.unwrap() as isize),
+            // The argument is the number of key/value pairs
+            Self::BUILD_MAP => 1 - 2 * (arg.unwrap() as isize),
+            // The low bit of the argument pushes `self` or NULL along with the attribute
+            Self::LOAD_ATTR => (arg.unwrap() & 0x01) as isize,
+            // `super`, the class and `self` are replaced by the attribute, and `self` or NULL if the
+            // low bit of the argument is set
+            Self::LOAD_SUPER_ATTR => -2 + (arg.unwrap() & 0x01) as isize,
+            // The low bit of the argument pushes a NULL before the global
+            Self::LOAD_GLOBAL => 1 + (arg.unwrap() & 0x01) as isize,
+            Self::COMPARE_OP => -1,
+            Self::IS_OP | Self::CONTAINS_OP => -1,
+            Self::IMPORT_NAME => -1,
+            Self::IMPORT_FROM => 1,
+            Self::JUMP_FORWARD => 0,
+            Self::JUMP_BACKWARD | Self::JUMP_BACKWARD_NO_INTERRUPT => 0,
+            Self::POP_JUMP_IF_FALSE
+            | Self::POP_JUMP_IF_TRUE
+            | Self::POP_JUMP_IF_NOT_NONE
+            | Self::POP_JUMP_IF_NONE => -1,
+            // The iterator is popped instead once it is exhausted
+            Self::FOR_ITER => 1,
+            // The value left by FOR_ITER
+            Self::END_FOR => -1,
+            // The result replaces the value sent, the iterator is popped instead if it returns
+            Self::SEND => 0,
+            // The receiver below the value
+            Self::END_SEND => -1,
+            Self::CLEANUP_THROW => -1,
+            Self::RAISE_VARARGS => -(arg.unwrap() as isize),
+            // The NULL, callable, arguments and optional keyword arguments are replaced by the result
+            Self::CALL_FUNCTION_EX => -2 - (arg.unwrap() & 0x01) as isize,
+            // The arguments, the callable and `self` or NULL are replaced by the return value
+            Self::CALL => -1 - (arg.unwrap() as isize),
+            // The same as CALL plus the tuple of keyword names
+            Self::CALL_KW => -2 - (arg.unwrap() as isize),
+            // The result of `__init__`
+            Self::EXIT_INIT_CHECK => -1,
+            Self::CALL_INTRINSIC_1 => 0,
+            Self::CALL_INTRINSIC_2 => -1,
+            // The code object is replaced by the function
+            Self::MAKE_FUNCTION => 0,
+            // The argument selects the attribute the value is stored in
+            Self::SET_FUNCTION_ATTRIBUTE => -1,
+            Self::BUILD_SLICE => 1 - (arg.unwrap() as isize),
+            // The values and the tuple of keys are replaced by the dict
+            Self::BUILD_CONST_KEY_MAP => -(arg.unwrap() as isize),
+            Self::BUILD_STRING => 1 - (arg.unwrap() as isize),
+            // f-string formatting
+            Self::CONVERT_VALUE | Self::FORMAT_SIMPLE => 0,
+            Self::FORMAT_WITH_SPEC => -1,
+            Self::RERAISE => -1,
+            Self::WITH_EXCEPT_START => 1,
+            // Pattern matching
+            Self::GET_LEN | Self::MATCH_MAPPING | Self::MATCH_SEQUENCE => 1,
+            Self::MATCH_KEYS => 1,
+            Self::MATCH_CLASS => -2,
+            // Frame setup
+            Self::MAKE_CELL | Self::COPY_FREE_VARS | Self::RESUME => 0,
+            // The prefix only widens the argument of the following instruction
+            Self::EXTENDED_ARG => 0,
+            // Inline cache entries are never executed
+            Self::CACHE => 0,
+            // The opcodes these replace are stored outside of the bytecode, so their effect is unknown
+            Self::INSTRUMENTED_LINE | Self::INSTRUMENTED_INSTRUCTION | Self::ENTER_EXECUTOR => 0,
+            // Specialized and instrumented opcodes have the stack effect of their base opcode
+            Self::INSTRUMENTED_RESUME
+            | Self::INSTRUMENTED_END_FOR
+            | Self::INSTRUMENTED_END_SEND
+            | Self::INSTRUMENTED_RETURN_VALUE
+            | Self::INSTRUMENTED_RETURN_CONST
+            | Self::INSTRUMENTED_YIELD_VALUE
+            | Self::INSTRUMENTED_LOAD_SUPER_ATTR
+            | Self::INSTRUMENTED_FOR_ITER
+            | Self::INSTRUMENTED_CALL
+            | Self::INSTRUMENTED_CALL_KW
+            | Self::INSTRUMENTED_CALL_FUNCTION_EX
+            | Self::INSTRUMENTED_JUMP_FORWARD
+            | Self::INSTRUMENTED_JUMP_BACKWARD
+            | Self::INSTRUMENTED_POP_JUMP_IF_TRUE
+            | Self::INSTRUMENTED_POP_JUMP_IF_FALSE
+            | Self::INSTRUMENTED_POP_JUMP_IF_NONE
+            | Self::INSTRUMENTED_POP_JUMP_IF_NOT_NONE
+            | Self::BINARY_OP_INPLACE_ADD_UNICODE
+            | Self::BINARY_OP_ADD_FLOAT
+            | Self::BINARY_OP_ADD_INT
+            | Self::BINARY_OP_ADD_UNICODE
+            | Self::BINARY_OP_MULTIPLY_FLOAT
+            | Self::BINARY_OP_MULTIPLY_INT
+            | Self::BINARY_OP_SUBTRACT_FLOAT
+            | Self::BINARY_OP_SUBTRACT_INT
+            | Self::BINARY_SUBSCR_DICT
+            | Self::BINARY_SUBSCR_GETITEM
+            | Self::BINARY_SUBSCR_LIST_INT
+            | Self::BINARY_SUBSCR_STR_INT
+            | Self::BINARY_SUBSCR_TUPLE_INT
+            | Self::CALL_ALLOC_AND_ENTER_INIT
+            | Self::CALL_BOUND_METHOD_EXACT_ARGS
+            | Self::CALL_BOUND_METHOD_GENERAL
+            | Self::CALL_BUILTIN_CLASS
+            | Self::CALL_BUILTIN_FAST
+            | Self::CALL_BUILTIN_FAST_WITH_KEYWORDS
+            | Self::CALL_BUILTIN_O
+            | Self::CALL_ISINSTANCE
+            | Self::CALL_LEN
+            | Self::CALL_LIST_APPEND
+            | Self::CALL_METHOD_DESCRIPTOR_FAST
+            | Self::CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS
+            | Self::CALL_METHOD_DESCRIPTOR_NOARGS
+            | Self::CALL_METHOD_DESCRIPTOR_O
+            | Self::CALL_NON_PY_GENERAL
+            | Self::CALL_PY_EXACT_ARGS
+            | Self::CALL_PY_GENERAL
+            | Self::CALL_STR_1
+            | Self::CALL_TUPLE_1
+            | Self::CALL_TYPE_1
+            | Self::COMPARE_OP_FLOAT
+            | Self::COMPARE_OP_INT
+            | Self::COMPARE_OP_STR
+            | Self::CONTAINS_OP_DICT
+            | Self::CONTAINS_OP_SET
+            | Self::FOR_ITER_GEN
+            | Self::FOR_ITER_LIST
+            | Self::FOR_ITER_RANGE
+            | Self::FOR_ITER_TUPLE
+            | Self::LOAD_ATTR_CLASS
+            | Self::LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN
+            | Self::LOAD_ATTR_INSTANCE_VALUE
+            | Self::LOAD_ATTR_METHOD_LAZY_DICT
+            | Self::LOAD_ATTR_METHOD_NO_DICT
+            | Self::LOAD_ATTR_METHOD_WITH_VALUES
+            | Self::LOAD_ATTR_MODULE
+            | Self::LOAD_ATTR_NONDESCRIPTOR_NO_DICT
+            | Self::LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES
+            | Self::LOAD_ATTR_PROPERTY
+            | Self::LOAD_ATTR_SLOT
+            | Self::LOAD_ATTR_WITH_HINT
+            | Self::LOAD_GLOBAL_BUILTIN
+            | Self::LOAD_GLOBAL_MODULE
+            | Self::LOAD_SUPER_ATTR_ATTR
+            | Self::LOAD_SUPER_ATTR_METHOD
+            | Self::RESUME_CHECK
+            | Self::SEND_GEN
+            | Self::STORE_ATTR_INSTANCE_VALUE
+            | Self::STORE_ATTR_SLOT
+            | Self::STORE_ATTR_WITH_HINT
+            | Self::STORE_SUBSCR_DICT
+            | Self::STORE_SUBSCR_LIST_INT
+            | Self::TO_BOOL_ALWAYS_TRUE
+            | Self::TO_BOOL_BOOL
+            | Self::TO_BOOL_INT
+            | Self::TO_BOOL_LIST
+            | Self::TO_BOOL_NONE
+            | Self::TO_BOOL_STR
+            | Self::UNPACK_SEQUENCE_LIST
+            | Self::UNPACK_SEQUENCE_TUPLE
+            | Self::UNPACK_SEQUENCE_TWO_TUPLE => self.deoptimize().stack_adjustment(arg),
+        }
+    }
+}
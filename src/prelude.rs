@@ -1,3 +1,6 @@
-pub use crate::error::DecodeError;
-pub use crate::opcode::{Instruction, Opcode};
-pub use crate::{decode, decode_py27};
+pub use crate::disassembler::{Disassembler, ExtendedDisassembler, Sweep, SweepItem};
+pub use crate::error::{AssembleError, DecodeError};
+pub use crate::opcode::{ExtendedInstruction, Instruction, Opcode, StackEffect};
+pub use crate::{decode_extended_slice, decode_slice};
+#[cfg(feature = "std")]
+pub use crate::{decode, decode_extended, decode_py27};
@@ -0,0 +1,508 @@
+//! Parsing of the header that precedes the marshalled module in a `.pyc` file.
+//!
+//! The header layout depends on the Python version that wrote the file:
+//!
+//! | Version   | Layout                                                       | Size |
+//! |-----------|--------------------------------------------------------------|------|
+//! | < 3.3     | magic, modification time                                     | 8    |
+//! | 3.3 - 3.6 | magic, modification time, source size                        | 12   |
+//! | >= 3.7    | magic, flags, modification time and source size *or* a hash  | 16   |
+//!
+//! The version is determined from the magic number, so the magic number must be one which is
+//! known to [`python_version`].
+
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "mmap")]
+use std::fs::File;
+#[cfg(feature = "mmap")]
+use std::path::Path;
+
+#[cfg(feature = "mmap")]
+use crate::error::LoadError;
+use crate::error::PycError;
+use crate::marshal::lazy::{self, LazyCode};
+use crate::marshal::{Limits, PyObject, Reader, Writer};
+
+/// A Python `major.minor` version
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PythonVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl PythonVersion {
+    pub const fn new(major: u8, minor: u8) -> Self {
+        PythonVersion { major, minor }
+    }
+}
+
+impl fmt::Display for PythonVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Magic number ranges and the Python version which produced them, taken from the history in
+/// CPython's `Lib/importlib/_bootstrap_external.py`
+const MAGIC_NUMBERS: &[(u16, u16, PythonVersion)] = &[
+    (20121, 20121, PythonVersion::new(1, 5)),
+    (50428, 50428, PythonVersion::new(1, 6)),
+    (50823, 50823, PythonVersion::new(2, 0)),
+    (60202, 60202, PythonVersion::new(2, 1)),
+    (60717, 60717, PythonVersion::new(2, 2)),
+    (62011, 62021, PythonVersion::new(2, 3)),
+    (62041, 62061, PythonVersion::new(2, 4)),
+    (62071, 62131, PythonVersion::new(2, 5)),
+    (62151, 62161, PythonVersion::new(2, 6)),
+    (62171, 62211, PythonVersion::new(2, 7)),
+    (3000, 3131, PythonVersion::new(3, 0)),
+    (3141, 3151, PythonVersion::new(3, 1)),
+    (3160, 3180, PythonVersion::new(3, 2)),
+    (3190, 3230, PythonVersion::new(3, 3)),
+    (3250, 3310, PythonVersion::new(3, 4)),
+    (3320, 3351, PythonVersion::new(3, 5)),
+    (3360, 3379, PythonVersion::new(3, 6)),
+    (3390, 3399, PythonVersion::new(3, 7)),
+    (3400, 3419, PythonVersion::new(3, 8)),
+    (3420, 3429, PythonVersion::new(3, 9)),
+    (3430, 3449, PythonVersion::new(3, 10)),
+    (3450, 3499, PythonVersion::new(3, 11)),
+    (3500, 3549, PythonVersion::new(3, 12)),
+    (3550, 3599, PythonVersion::new(3, 13)),
+    (3600, 3649, PythonVersion::new(3, 14)),
+];
+
+/// Looks up the Python version that writes `.pyc` files with the given magic number
+pub fn python_version(magic: u16) -> Option<PythonVersion> {
+    MAGIC_NUMBERS
+        .iter()
+        .find(|(start, end, _)| (*start..=*end).contains(&magic))
+        .map(|(_, _, version)| *version)
+}
+
+/// How the interpreter decides whether a `.pyc` file is up to date with its source
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Validation {
+    /// The source file's modification time, and since Python 3.3 its size modulo 2**32
+    Timestamp {
+        mtime: u32,
+        source_size: Option<u32>,
+    },
+    /// A SipHash of the source file (PEP 552, Python 3.7+). When `check_source` is false the
+    /// interpreter does not validate the hash against the source.
+    Hash { check_source: bool, source_hash: u64 },
+}
+
+/// A parsed `.pyc` header
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PycHeader {
+    /// The magic number, without the trailing `\r\n`
+    pub magic: u16,
+    /// The Python version the magic number belongs to
+    pub version: PythonVersion,
+    /// The PEP 552 flags field. Always 0 before Python 3.7.
+    pub flags: u32,
+    pub validation: Validation,
+}
+
+/// PEP 552 flag which marks the `.pyc` file as hash-based
+const FLAG_HASH_BASED: u32 = 0b01;
+/// PEP 552 flag which marks a hash-based `.pyc` file as needing its hash checked
+const FLAG_CHECK_SOURCE: u32 = 0b10;
+
+#[allow(clippy::len_without_is_empty)]
+impl PycHeader {
+    /// Parses the header at the start of `data`
+    pub fn parse(data: &[u8]) -> Result<PycHeader, PycError> {
+        let magic_bytes = data.get(..4).ok_or(PycError::TooShort {
+            expected: 4,
+            actual: data.len(),
+        })?;
+        if magic_bytes[2..] != *b"\r\n" {
+            return Err(PycError::InvalidMagic([
+                magic_bytes[0],
+                magic_bytes[1],
+                magic_bytes[2],
+                magic_bytes[3],
+            ]));
+        }
+
+        let magic = u16::from_le_bytes([magic_bytes[0], magic_bytes[1]]);
+        let version = python_version(magic).ok_or(PycError::UnknownMagic(magic))?;
+
+        let len = header_len(version);
+        if data.len() < len {
+            return Err(PycError::TooShort {
+                expected: len,
+                actual: data.len(),
+            });
+        }
+
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ])
+        };
+
+        let (flags, validation) = if version >= PythonVersion::new(3, 7) {
+            let flags = read_u32(4);
+            let validation = if flags & FLAG_HASH_BASED != 0 {
+                let mut source_hash = [0u8; 8];
+                source_hash.copy_from_slice(&data[8..16]);
+
+                Validation::Hash {
+                    check_source: flags & FLAG_CHECK_SOURCE != 0,
+                    source_hash: u64::from_le_bytes(source_hash),
+                }
+            } else {
+                Validation::Timestamp {
+                    mtime: read_u32(8),
+                    source_size: Some(read_u32(12)),
+                }
+            };
+
+            (flags, validation)
+        } else {
+            let validation = Validation::Timestamp {
+                mtime: read_u32(4),
+                source_size: if version >= PythonVersion::new(3, 3) {
+                    Some(read_u32(8))
+                } else {
+                    None
+                },
+            };
+
+            (0, validation)
+        };
+
+        Ok(PycHeader {
+            magic,
+            version,
+            flags,
+            validation,
+        })
+    }
+
+    /// The size of this header in bytes
+    pub fn len(&self) -> usize {
+        header_len(self.version)
+    }
+
+    /// Appends the serialized header to `dest`. Fields which do not exist in this header's
+    /// layout, such as the source size before Python 3.3, are not written.
+    pub fn encode_into(&self, dest: &mut Vec<u8>) {
+        dest.extend_from_slice(&self.magic.to_le_bytes());
+        dest.extend_from_slice(b"\r\n");
+
+        if self.version >= PythonVersion::new(3, 7) {
+            dest.extend_from_slice(&self.flags.to_le_bytes());
+        }
+
+        match self.validation {
+            Validation::Timestamp { mtime, source_size } => {
+                dest.extend_from_slice(&mtime.to_le_bytes());
+                if self.version >= PythonVersion::new(3, 3) {
+                    dest.extend_from_slice(&source_size.unwrap_or(0).to_le_bytes());
+                }
+            }
+            Validation::Hash { source_hash, .. } => {
+                dest.extend_from_slice(&source_hash.to_le_bytes())
+            }
+        }
+    }
+
+    /// Serializes the header
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(self.len());
+        self.encode_into(&mut data);
+
+        data
+    }
+}
+
+fn header_len(version: PythonVersion) -> usize {
+    if version >= PythonVersion::new(3, 7) {
+        16
+    } else if version >= PythonVersion::new(3, 3) {
+        12
+    } else {
+        8
+    }
+}
+
+/// Parses the header of a `.pyc` file and returns it along with the marshalled code object
+/// that follows it
+pub fn parse(data: &[u8]) -> Result<(PycHeader, &[u8]), PycError> {
+    let header = PycHeader::parse(data)?;
+    Ok((header, &data[header.len()..]))
+}
+
+/// Parses the header of a `.pyc` file and deserializes the module code object that follows it,
+/// enforcing `limits` while doing so
+pub fn load(data: &[u8], limits: Limits) -> Result<(PycHeader, PyObject), PycError> {
+    let (header, payload) = parse(data)?;
+    let module = Reader::with_limits(payload, header.version, limits).read_object()?;
+
+    Ok((header, module))
+}
+
+/// Serializes a `.pyc` file made up of `header` followed by the marshalled `module`. A Python 2
+/// file which was read with [`load`] is written back out unchanged.
+///
+/// The module is written in the marshal format of the header's version. Only some versions can
+/// be written, see [`Writer`].
+pub fn write(header: &PycHeader, module: &PyObject) -> Result<Vec<u8>, PycError> {
+    let mut writer = Writer::new(header.version);
+    writer.write_object(module)?;
+
+    let mut data = header.encode();
+    data.extend_from_slice(writer.data());
+
+    Ok(data)
+}
+
+/// Finds every code object in a `.pyc` file without deserializing it. See
+/// [`marshal::lazy`](crate::marshal::lazy) for details.
+pub fn lazy_code_objects(
+    data: &[u8],
+    limits: Limits,
+) -> Result<(PycHeader, Vec<LazyCode<'_>>), PycError> {
+    let (header, payload) = parse(data)?;
+    let codes = lazy::code_objects(payload, header.version, limits)?;
+
+    Ok((header, codes))
+}
+
+/// A `.pyc` file which has been mapped into memory. The header is parsed when the file is
+/// opened, and the code objects are indexed on demand with [`MappedPyc::code_objects`], which
+/// borrows from the mapping.
+#[cfg(feature = "mmap")]
+pub struct MappedPyc {
+    map: memmap2::Mmap,
+    header: PycHeader,
+}
+
+#[cfg(feature = "mmap")]
+impl MappedPyc {
+    /// Maps the file at `path` into memory and parses its header.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, see [`memmap2::Mmap`].
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self, LoadError> {
+        let file = File::open(path)?;
+        let map = memmap2::Mmap::map(&file)?;
+        let header = PycHeader::parse(&map)?;
+
+        Ok(MappedPyc { map, header })
+    }
+
+    pub fn header(&self) -> &PycHeader {
+        &self.header
+    }
+
+    /// The contents of the whole file
+    pub fn data(&self) -> &[u8] {
+        &self.map
+    }
+
+    /// The marshalled module code object which follows the header
+    pub fn payload(&self) -> &[u8] {
+        &self.map[self.header.len()..]
+    }
+
+    /// Finds every code object in the file, borrowing their contents from the mapping
+    pub fn code_objects(&self, limits: Limits) -> Result<Vec<LazyCode<'_>>, PycError> {
+        Ok(lazy::code_objects(
+            self.payload(),
+            self.header.version,
+            limits,
+        )?)
+    }
+
+    /// Deserializes the module code object
+    pub fn load(&self, limits: Limits) -> Result<PyObject, PycError> {
+        Ok(Reader::with_limits(self.payload(), self.header.version, limits).read_object()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::MarshalError;
+
+    #[test]
+    fn magic_number_boundaries() {
+        let cases = [
+            (62161, Some(PythonVersion::new(2, 6))),
+            (62171, Some(PythonVersion::new(2, 7))),
+            (62211, Some(PythonVersion::new(2, 7))),
+            (62212, None),
+            (3131, Some(PythonVersion::new(3, 0))),
+            (3132, None),
+            (3379, Some(PythonVersion::new(3, 6))),
+            (3394, Some(PythonVersion::new(3, 7))),
+            (3413, Some(PythonVersion::new(3, 8))),
+            (3425, Some(PythonVersion::new(3, 9))),
+            (3439, Some(PythonVersion::new(3, 10))),
+            (3495, Some(PythonVersion::new(3, 11))),
+            (3531, Some(PythonVersion::new(3, 12))),
+            (3571, Some(PythonVersion::new(3, 13))),
+            (3649, Some(PythonVersion::new(3, 14))),
+            (3650, None),
+        ];
+        for (magic, version) in cases {
+            assert_eq!(python_version(magic), version, "{}", magic);
+        }
+    }
+
+    /// Checks that `data` parses to `validation` and encodes back to the same bytes
+    fn check_header(data: &[u8], version: PythonVersion, validation: Validation) {
+        let header = PycHeader::parse(data).unwrap();
+        assert_eq!(header.version, version);
+        assert_eq!(header.validation, validation);
+        assert_eq!(header.len(), data.len());
+        assert_eq!(header.encode(), data);
+    }
+
+    // Headers written by `py_compile` for a source file with an mtime of 1700000000
+    #[test]
+    fn header_layouts() {
+        check_header(
+            &[3, 243, 13, 10, 0, 241, 83, 101],
+            PythonVersion::new(2, 7),
+            Validation::Timestamp {
+                mtime: 1700000000,
+                source_size: None,
+            },
+        );
+        check_header(
+            &[51, 13, 13, 10, 0, 241, 83, 101, 6, 0, 0, 0],
+            PythonVersion::new(3, 6),
+            Validation::Timestamp {
+                mtime: 1700000000,
+                source_size: Some(6),
+            },
+        );
+        check_header(
+            &[203, 13, 13, 10, 0, 0, 0, 0, 0, 241, 83, 101, 6, 0, 0, 0],
+            PythonVersion::new(3, 12),
+            Validation::Timestamp {
+                mtime: 1700000000,
+                source_size: Some(6),
+            },
+        );
+        check_header(
+            &[203, 13, 13, 10, 3, 0, 0, 0, 21, 34, 86, 25, 23, 245, 223, 8],
+            PythonVersion::new(3, 12),
+            Validation::Hash {
+                check_source: true,
+                source_hash: 0x08df_f517_1956_2215,
+            },
+        );
+        check_header(
+            &[203, 13, 13, 10, 1, 0, 0, 0, 21, 34, 86, 25, 23, 245, 223, 8],
+            PythonVersion::new(3, 12),
+            Validation::Hash {
+                check_source: false,
+                source_hash: 0x08df_f517_1956_2215,
+            },
+        );
+    }
+
+    #[test]
+    fn header_errors() {
+        assert_eq!(
+            PycHeader::parse(&[3, 243, 13]),
+            Err(PycError::TooShort {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            PycHeader::parse(&[3, 243, 10, 13]),
+            Err(PycError::InvalidMagic([3, 243, 10, 13]))
+        );
+        assert_eq!(
+            PycHeader::parse(&[0, 0, 13, 10]),
+            Err(PycError::UnknownMagic(0))
+        );
+        assert_eq!(
+            PycHeader::parse(&[203, 13, 13, 10, 0, 0, 0, 0, 0, 241, 83, 101]),
+            Err(PycError::TooShort {
+                expected: 16,
+                actual: 12
+            })
+        );
+    }
+
+    #[test]
+    fn write_rejects_python3() {
+        let mut data = 3531u16.to_le_bytes().to_vec();
+        data.extend([b'\r', b'\n']);
+        data.extend([0; 12]);
+        let header = PycHeader::parse(&data).unwrap();
+        assert_eq!(header.version, PythonVersion::new(3, 12));
+
+        assert_eq!(
+            write(&header, &PyObject::None),
+            Err(PycError::Marshal(MarshalError::UnsupportedVersion(
+                PythonVersion::new(3, 12)
+            )))
+        );
+    }
+
+    /// Whether `obj` or any object nested inside it satisfies `f`
+    fn contains(obj: &PyObject, f: &dyn Fn(&PyObject) -> bool) -> bool {
+        f(obj)
+            || match obj {
+                PyObject::Tuple(items)
+                | PyObject::List(items)
+                | PyObject::Set(items)
+                | PyObject::FrozenSet(items) => items.iter().any(|item| contains(item, f)),
+                PyObject::Dict(entries) => entries
+                    .iter()
+                    .any(|(key, value)| contains(key, f) || contains(value, f)),
+                PyObject::Code(code) => code
+                    .consts
+                    .iter()
+                    .chain(&code.names)
+                    .chain(&code.varnames)
+                    .any(|item| contains(item, f)),
+                _ => false,
+            }
+    }
+
+    #[test]
+    fn py27_round_trips() {
+        // Compiled from testdata/py27/sample.py, and from the Python 2.7 standard library. Both
+        // refer to interned names through string references.
+        let files: [&[u8]; 2] = [
+            include_bytes!("../testdata/py27/sample.pyc"),
+            include_bytes!("../testdata/py27/colorsys.pyc"),
+        ];
+        for data in files {
+            let (header, module) = load(data, Limits::default()).unwrap();
+            assert_eq!(header.version, PythonVersion::new(2, 7));
+            assert_eq!(write(&header, &module).unwrap(), data);
+        }
+
+        let (_, module) = load(files[0], Limits::default()).unwrap();
+        let code = module.as_code().unwrap();
+        assert_eq!(code.walk().count(), 8);
+        for f in [
+            |obj: &PyObject| matches!(obj, PyObject::Long(_)),
+            |obj: &PyObject| matches!(obj, PyObject::Int64(_)),
+            |obj: &PyObject| matches!(obj, PyObject::Float(_)),
+            |obj: &PyObject| matches!(obj, PyObject::Complex { .. }),
+            |obj: &PyObject| matches!(obj, PyObject::Unicode(_)),
+        ] {
+            assert!(contains(&module, &f));
+        }
+    }
+}
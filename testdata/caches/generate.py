@@ -0,0 +1,32 @@
+"""Lists the instructions and inline cache entries of every code object in sample.py, as
+`dis.get_instructions(show_caches=True)` yields them.
+
+Run with Python 3.11 or 3.12, which are the versions whose dis can show cache entries:
+    python3.X generate.py > py3X.txt
+"""
+import dis
+import opcode
+import os
+import types
+
+here = os.path.dirname(os.path.abspath(__file__))
+source = open(os.path.join(here, 'sample.py')).read()
+
+
+def describe(code):
+    print('code %s %s' % (code.co_name, code.co_code.hex()))
+    for instr in dis.get_instructions(code, show_caches=True):
+        assert instr.opname != 'EXTENDED_ARG'
+        if instr.opname == 'CACHE':
+            print('cache %d' % instr.offset)
+            continue
+        jump = instr.opcode in opcode.hasjrel or instr.opcode in opcode.hasjabs
+        print('instr %d %s %s %s' % (instr.offset, instr.opname,
+                                     '-' if instr.arg is None else instr.arg,
+                                     instr.argval if jump else '-'))
+    for const in code.co_consts:
+        if isinstance(const, types.CodeType):
+            describe(const)
+
+
+describe(compile(source, 'sample.py', 'exec'))
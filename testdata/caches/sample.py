@@ -0,0 +1,35 @@
+import math
+
+LIMIT = 10
+
+
+class Point:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+    def norm(self):
+        return math.sqrt(self.x * self.x + self.y * self.y)
+
+
+class Labeled(Point):
+    def __init__(self, x, y, label):
+        super().__init__(x, y)
+        self.label = label
+
+
+def walk(points):
+    total = 0.0
+    for point in points:
+        if point.norm() > LIMIT:
+            break
+        total += point.norm()
+    while total > 1:
+        total /= 2
+    return [p.label for p in points if isinstance(p, Labeled)], total
+
+
+def store(values, key):
+    values[key] = len(values)
+    first, second = values[key], values.get(key)
+    return first is second or first == second
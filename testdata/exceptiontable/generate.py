@@ -0,0 +1,27 @@
+"""Describes the exception table of every code object in sample.py, for checking the decoder.
+
+A function with a long try block is appended to the sample, so that the varints of its entries
+take up more than one byte. Run with each version since 3.11:
+    python3.X generate.py > py3X.txt
+"""
+import dis
+import os
+import types
+
+here = os.path.dirname(os.path.abspath(__file__))
+source = open(os.path.join(here, 'sample.py')).read()
+source += '\n\ndef long_try(x):\n    try:\n%s    except Exception:\n        pass\n' % (
+    '        x = x + 1\n' * 1500)
+
+
+def describe(code):
+    print('code %s %s' % (code.co_name, code.co_exceptiontable.hex() or '-'))
+    for entry in dis._parse_exception_table(code):
+        print('entry %d %d %d %d %d' % (entry.start, entry.end, entry.target, entry.depth,
+                                        entry.lasti))
+    for const in code.co_consts:
+        if isinstance(const, types.CodeType):
+            describe(const)
+
+
+describe(compile(source, 'sample.py', 'exec'))
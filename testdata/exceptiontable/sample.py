@@ -0,0 +1,43 @@
+import contextlib
+
+
+def handlers(path):
+    try:
+        f = open(path)
+    except FileNotFoundError:
+        return None
+    except (OSError, ValueError) as e:
+        raise RuntimeError(path) from e
+    else:
+        with f:
+            return f.read()
+    finally:
+        print('done')
+
+
+def nested(values):
+    total = 0
+    for value in values:
+        try:
+            try:
+                total += int(value)
+            except ValueError:
+                continue
+        finally:
+            total += 1
+    return total
+
+
+def generator(items):
+    with contextlib.suppress(KeyError):
+        for item in items:
+            yield item
+
+
+async def coroutine(lock, items):
+    async with lock:
+        async for item in items:
+            try:
+                await item
+            except* TypeError:
+                pass
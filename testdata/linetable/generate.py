@@ -0,0 +1,23 @@
+import sys, dis, types
+src_path, out_path = sys.argv[1], sys.argv[2]
+src = open(src_path).read()
+top = compile(src, 'lines.py', 'exec')
+py3 = sys.version_info[0] >= 3
+def hexs(b):
+    return ''.join('%02x' % c for c in bytearray(b))
+def n(x):
+    return '-' if x is None else str(x)
+out = []
+stack = [top]
+while stack:
+    co = stack.pop(0)
+    table = co.co_linetable if sys.version_info >= (3, 10) else co.co_lnotab
+    out.append('code %d %d %s' % (co.co_firstlineno, len(co.co_code), hexs(table) or '-'))
+    if sys.version_info < (3, 13):
+        out.append('starts ' + ' '.join('%d:%d' % s for s in dis.findlinestarts(co)))
+    if sys.version_info >= (3, 10):
+        out.append('lines ' + ' '.join('%d-%d:%s' % (a, b, n(l)) for a, b, l in co.co_lines()))
+    if sys.version_info >= (3, 11):
+        out.append('positions ' + ' '.join(','.join(n(x) for x in p) for p in co.co_positions()))
+    stack.extend(c for c in co.co_consts if isinstance(c, types.CodeType))
+open(out_path, 'w').write('\n'.join(out) + '\n')
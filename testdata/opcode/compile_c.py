@@ -0,0 +1,255 @@
+"""Writes the opcode fixtures of Python 2.5-3.5, without needing an interpreter for each version.
+
+The opcodes are transcribed from Lib/opcode.py and the stack effects from opcode_stack_effect in
+Python/compile.c of each release. Run with any Python:
+    python3 compile_c.py
+"""
+import os
+
+HAVE_ARGUMENT = 90
+ARGS = [0, 1, 2, 3, 258]
+# The arguments the compiler emits for opcodes which only accept a few
+VALID_ARGS = {'BUILD_SLICE': [2, 3]}
+
+OPCODES_27 = """
+STOP_CODE 0 POP_TOP 1 ROT_TWO 2 ROT_THREE 3 DUP_TOP 4 ROT_FOUR 5 NOP 9 UNARY_POSITIVE 10
+UNARY_NEGATIVE 11 UNARY_NOT 12 UNARY_CONVERT 13 UNARY_INVERT 15 BINARY_POWER 19
+BINARY_MULTIPLY 20 BINARY_DIVIDE 21 BINARY_MODULO 22 BINARY_ADD 23 BINARY_SUBTRACT 24
+BINARY_SUBSCR 25 BINARY_FLOOR_DIVIDE 26 BINARY_TRUE_DIVIDE 27 INPLACE_FLOOR_DIVIDE 28
+INPLACE_TRUE_DIVIDE 29 SLICE+0 30 SLICE+1 31 SLICE+2 32 SLICE+3 33 STORE_SLICE+0 40
+STORE_SLICE+1 41 STORE_SLICE+2 42 STORE_SLICE+3 43 DELETE_SLICE+0 50 DELETE_SLICE+1 51
+DELETE_SLICE+2 52 DELETE_SLICE+3 53 STORE_MAP 54 INPLACE_ADD 55 INPLACE_SUBTRACT 56
+INPLACE_MULTIPLY 57 INPLACE_DIVIDE 58 INPLACE_MODULO 59 STORE_SUBSCR 60 DELETE_SUBSCR 61
+BINARY_LSHIFT 62 BINARY_RSHIFT 63 BINARY_AND 64 BINARY_XOR 65 BINARY_OR 66 INPLACE_POWER 67
+GET_ITER 68 PRINT_EXPR 70 PRINT_ITEM 71 PRINT_NEWLINE 72 PRINT_ITEM_TO 73 PRINT_NEWLINE_TO 74
+INPLACE_LSHIFT 75 INPLACE_RSHIFT 76 INPLACE_AND 77 INPLACE_XOR 78 INPLACE_OR 79 BREAK_LOOP 80
+WITH_CLEANUP 81 LOAD_LOCALS 82 RETURN_VALUE 83 IMPORT_STAR 84 EXEC_STMT 85 YIELD_VALUE 86
+POP_BLOCK 87 END_FINALLY 88 BUILD_CLASS 89 STORE_NAME 90 DELETE_NAME 91 UNPACK_SEQUENCE 92
+FOR_ITER 93 LIST_APPEND 94 STORE_ATTR 95 DELETE_ATTR 96 STORE_GLOBAL 97 DELETE_GLOBAL 98
+DUP_TOPX 99 LOAD_CONST 100 LOAD_NAME 101 BUILD_TUPLE 102 BUILD_LIST 103 BUILD_SET 104
+BUILD_MAP 105 LOAD_ATTR 106 COMPARE_OP 107 IMPORT_NAME 108 IMPORT_FROM 109 JUMP_FORWARD 110
+JUMP_IF_FALSE_OR_POP 111 JUMP_IF_TRUE_OR_POP 112 JUMP_ABSOLUTE 113 POP_JUMP_IF_FALSE 114
+POP_JUMP_IF_TRUE 115 LOAD_GLOBAL 116 CONTINUE_LOOP 119 SETUP_LOOP 120 SETUP_EXCEPT 121
+SETUP_FINALLY 122 LOAD_FAST 124 STORE_FAST 125 DELETE_FAST 126 RAISE_VARARGS 130
+CALL_FUNCTION 131 MAKE_FUNCTION 132 BUILD_SLICE 133 MAKE_CLOSURE 134 LOAD_CLOSURE 135
+LOAD_DEREF 136 STORE_DEREF 137 CALL_FUNCTION_VAR 140 CALL_FUNCTION_KW 141
+CALL_FUNCTION_VAR_KW 142 SETUP_WITH 143 EXTENDED_ARG 145 SET_ADD 146 MAP_ADD 147
+"""
+
+OPCODES_30 = """
+STOP_CODE 0 POP_TOP 1 ROT_TWO 2 ROT_THREE 3 DUP_TOP 4 ROT_FOUR 5 NOP 9 UNARY_POSITIVE 10
+UNARY_NEGATIVE 11 UNARY_NOT 12 UNARY_INVERT 15 SET_ADD 17 LIST_APPEND 18 BINARY_POWER 19
+BINARY_MULTIPLY 20 BINARY_MODULO 22 BINARY_ADD 23 BINARY_SUBTRACT 24 BINARY_SUBSCR 25
+BINARY_FLOOR_DIVIDE 26 BINARY_TRUE_DIVIDE 27 INPLACE_FLOOR_DIVIDE 28 INPLACE_TRUE_DIVIDE 29
+STORE_MAP 54 INPLACE_ADD 55 INPLACE_SUBTRACT 56 INPLACE_MULTIPLY 57 INPLACE_MODULO 59
+STORE_SUBSCR 60 DELETE_SUBSCR 61 BINARY_LSHIFT 62 BINARY_RSHIFT 63 BINARY_AND 64 BINARY_XOR 65
+BINARY_OR 66 INPLACE_POWER 67 GET_ITER 68 STORE_LOCALS 69 PRINT_EXPR 70 LOAD_BUILD_CLASS 71
+INPLACE_LSHIFT 75 INPLACE_RSHIFT 76 INPLACE_AND 77 INPLACE_XOR 78 INPLACE_OR 79 BREAK_LOOP 80
+WITH_CLEANUP 81 RETURN_VALUE 83 IMPORT_STAR 84 YIELD_VALUE 86 POP_BLOCK 87 END_FINALLY 88
+POP_EXCEPT 89 STORE_NAME 90 DELETE_NAME 91 UNPACK_SEQUENCE 92 FOR_ITER 93 UNPACK_EX 94
+STORE_ATTR 95 DELETE_ATTR 96 STORE_GLOBAL 97 DELETE_GLOBAL 98 DUP_TOPX 99 LOAD_CONST 100
+LOAD_NAME 101 BUILD_TUPLE 102 BUILD_LIST 103 BUILD_SET 104 BUILD_MAP 105 LOAD_ATTR 106
+COMPARE_OP 107 IMPORT_NAME 108 IMPORT_FROM 109 JUMP_FORWARD 110 JUMP_IF_FALSE 111
+JUMP_IF_TRUE 112 JUMP_ABSOLUTE 113 LOAD_GLOBAL 116 CONTINUE_LOOP 119 SETUP_LOOP 120
+SETUP_EXCEPT 121 SETUP_FINALLY 122 LOAD_FAST 124 STORE_FAST 125 DELETE_FAST 126
+RAISE_VARARGS 130 CALL_FUNCTION 131 MAKE_FUNCTION 132 BUILD_SLICE 133 MAKE_CLOSURE 134
+LOAD_CLOSURE 135 LOAD_DEREF 136 STORE_DEREF 137 CALL_FUNCTION_VAR 140 CALL_FUNCTION_KW 141
+CALL_FUNCTION_VAR_KW 142 EXTENDED_ARG 143
+"""
+
+
+def parse(text):
+    fields = text.split()
+    return dict(zip(fields[::2], map(int, fields[1::2])))
+
+
+def opcodes_26():
+    opcodes = parse(OPCODES_27)
+    for name in ('BUILD_SET', 'POP_JUMP_IF_FALSE', 'POP_JUMP_IF_TRUE', 'SETUP_WITH', 'SET_ADD',
+                 'MAP_ADD', 'JUMP_IF_FALSE_OR_POP', 'JUMP_IF_TRUE_OR_POP'):
+        del opcodes[name]
+    for name in ('BUILD_MAP', 'LOAD_ATTR', 'COMPARE_OP', 'IMPORT_NAME', 'IMPORT_FROM'):
+        opcodes[name] -= 1
+    opcodes.update(LIST_APPEND=18, JUMP_IF_FALSE=111, JUMP_IF_TRUE=112, EXTENDED_ARG=143)
+    return opcodes
+
+
+def opcodes_25():
+    opcodes = opcodes_26()
+    del opcodes['STORE_MAP']
+    return opcodes
+
+
+def edit(opcodes, remove=(), add=()):
+    opcodes = dict(opcodes)
+    for name in remove:
+        del opcodes[name]
+    opcodes.update(add)
+    return opcodes
+
+
+def opcodes_3():
+    """The opcodes of Python 3.0-3.5, each release edited from the one before"""
+    opcodes = {(3, 0): parse(OPCODES_30)}
+    opcodes[3, 1] = edit(
+        opcodes[3, 0],
+        remove=['SET_ADD', 'LIST_APPEND', 'JUMP_IF_FALSE', 'JUMP_IF_TRUE'],
+        add=dict(JUMP_IF_FALSE_OR_POP=111, JUMP_IF_TRUE_OR_POP=112, POP_JUMP_IF_FALSE=114,
+                 POP_JUMP_IF_TRUE=115, LIST_APPEND=145, SET_ADD=146, MAP_ADD=147))
+    opcodes[3, 2] = edit(
+        opcodes[3, 1], remove=['ROT_FOUR', 'DUP_TOPX'],
+        add=dict(DUP_TOP_TWO=5, DELETE_DEREF=138, SETUP_WITH=143, EXTENDED_ARG=144))
+    opcodes[3, 3] = edit(opcodes[3, 2], remove=['STOP_CODE'], add=dict(YIELD_FROM=72))
+    opcodes[3, 4] = edit(opcodes[3, 3], remove=['STORE_LOCALS'], add=dict(LOAD_CLASSDEREF=148))
+    opcodes[3, 5] = edit(
+        opcodes[3, 4], remove=['STORE_MAP', 'WITH_CLEANUP'],
+        add=dict(BINARY_MATRIX_MULTIPLY=16, INPLACE_MATRIX_MULTIPLY=17, GET_AITER=50,
+                 GET_ANEXT=51, BEFORE_ASYNC_WITH=52, GET_YIELD_FROM_ITER=69, GET_AWAITABLE=73,
+                 WITH_CLEANUP_START=81, WITH_CLEANUP_FINISH=82, BUILD_LIST_UNPACK=149,
+                 BUILD_MAP_UNPACK=150, BUILD_MAP_UNPACK_WITH_CALL=151, BUILD_TUPLE_UNPACK=152,
+                 BUILD_SET_UNPACK=153, SETUP_ASYNC_WITH=154))
+    return opcodes
+
+
+def nargs(arg):
+    return arg % 256 + 2 * (arg // 256)
+
+
+def stack_effect_2(version, name, arg):
+    """opcode_stack_effect of Python 2.5-2.7, or None for opcodes it does not handle"""
+    fixed = {
+        'POP_TOP': -1, 'ROT_TWO': 0, 'ROT_THREE': 0, 'DUP_TOP': 1, 'ROT_FOUR': 0,
+        'SLICE+0': 0, 'SLICE+1': -1, 'SLICE+2': -1, 'SLICE+3': -2,
+        'STORE_SLICE+0': -2, 'STORE_SLICE+1': -3, 'STORE_SLICE+2': -3, 'STORE_SLICE+3': -4,
+        'DELETE_SLICE+0': -1, 'DELETE_SLICE+1': -2, 'DELETE_SLICE+2': -2, 'DELETE_SLICE+3': -3,
+        'STORE_SUBSCR': -3, 'STORE_MAP': -2, 'DELETE_SUBSCR': -2, 'GET_ITER': 0,
+        'PRINT_EXPR': -1, 'PRINT_ITEM': -1, 'PRINT_NEWLINE': 0, 'PRINT_ITEM_TO': -2,
+        'PRINT_NEWLINE_TO': -1, 'BREAK_LOOP': 0, 'WITH_CLEANUP': -1, 'LOAD_LOCALS': 1,
+        'RETURN_VALUE': -1, 'IMPORT_STAR': -1, 'EXEC_STMT': -3, 'YIELD_VALUE': 0,
+        'POP_BLOCK': 0, 'BUILD_CLASS': -2, 'STORE_NAME': -1, 'DELETE_NAME': 0, 'FOR_ITER': 1,
+        'STORE_ATTR': -2, 'DELETE_ATTR': -1, 'STORE_GLOBAL': -1, 'DELETE_GLOBAL': 0,
+        'LOAD_CONST': 1, 'LOAD_NAME': 1, 'BUILD_MAP': 1, 'LOAD_ATTR': 0, 'COMPARE_OP': -1,
+        'IMPORT_NAME': -1, 'IMPORT_FROM': 1, 'JUMP_FORWARD': 0, 'JUMP_IF_TRUE': 0,
+        'JUMP_IF_FALSE': 0, 'JUMP_IF_TRUE_OR_POP': 0, 'JUMP_IF_FALSE_OR_POP': 0,
+        'JUMP_ABSOLUTE': 0, 'POP_JUMP_IF_FALSE': -1, 'POP_JUMP_IF_TRUE': -1, 'LOAD_GLOBAL': 1,
+        'CONTINUE_LOOP': 0, 'SETUP_LOOP': 0, 'SETUP_EXCEPT': 3, 'SETUP_FINALLY': 3,
+        'LOAD_FAST': 1, 'STORE_FAST': -1, 'DELETE_FAST': 0, 'LOAD_CLOSURE': 1, 'LOAD_DEREF': 1,
+        'STORE_DEREF': -1, 'SETUP_WITH': 4, 'SET_ADD': -1, 'MAP_ADD': -2,
+        'LIST_APPEND': -1 if version >= (2, 7) else -2,
+        'END_FINALLY': -3 if version >= (2, 7) else -1,
+    }
+    if name in fixed:
+        return fixed[name]
+    if name.startswith('UNARY_'):
+        return 0
+    if name.startswith(('BINARY_', 'INPLACE_')):
+        return -1
+    if name == 'UNPACK_SEQUENCE':
+        return arg - 1
+    if name == 'DUP_TOPX':
+        return arg
+    if name in ('BUILD_TUPLE', 'BUILD_LIST', 'BUILD_SET'):
+        return 1 - arg
+    if name == 'RAISE_VARARGS':
+        return -arg
+    if name == 'CALL_FUNCTION':
+        return -nargs(arg)
+    if name in ('CALL_FUNCTION_VAR', 'CALL_FUNCTION_KW'):
+        return -nargs(arg) - 1
+    if name == 'CALL_FUNCTION_VAR_KW':
+        return -nargs(arg) - 2
+    if name == 'MAKE_FUNCTION':
+        return -arg
+    if name == 'MAKE_CLOSURE':
+        return -arg - 1
+    if name == 'BUILD_SLICE':
+        return -2 if arg == 3 else -1
+    return None
+
+
+def stack_effect_3(version, name, arg):
+    """opcode_stack_effect of Python 3.0-3.5, or None for opcodes it does not handle"""
+    fixed = {
+        'POP_TOP': -1, 'ROT_TWO': 0, 'ROT_THREE': 0, 'ROT_FOUR': 0, 'DUP_TOP': 1,
+        'DUP_TOP_TWO': 2, 'STORE_SUBSCR': -3, 'DELETE_SUBSCR': -2, 'STORE_MAP': -2,
+        'GET_ITER': 0, 'GET_YIELD_FROM_ITER': 0, 'GET_AITER': 0, 'GET_ANEXT': 1,
+        'GET_AWAITABLE': 0, 'BEFORE_ASYNC_WITH': 1, 'PRINT_EXPR': -1, 'LOAD_BUILD_CLASS': 1,
+        'STORE_LOCALS': -1, 'BREAK_LOOP': 0, 'WITH_CLEANUP': -1, 'WITH_CLEANUP_START': 1,
+        'WITH_CLEANUP_FINISH': -1, 'RETURN_VALUE': -1, 'IMPORT_STAR': -1, 'YIELD_VALUE': 0,
+        'YIELD_FROM': -1, 'POP_BLOCK': 0, 'POP_EXCEPT': 0, 'END_FINALLY': -1, 'STORE_NAME': -1,
+        'DELETE_NAME': 0, 'FOR_ITER': 1, 'STORE_ATTR': -2, 'DELETE_ATTR': -1,
+        'STORE_GLOBAL': -1, 'DELETE_GLOBAL': 0, 'LOAD_CONST': 1, 'LOAD_NAME': 1, 'LOAD_ATTR': 0,
+        'COMPARE_OP': -1, 'IMPORT_NAME': -1, 'IMPORT_FROM': 1, 'JUMP_FORWARD': 0,
+        'JUMP_IF_TRUE': 0, 'JUMP_IF_FALSE': 0, 'JUMP_IF_TRUE_OR_POP': 0,
+        'JUMP_IF_FALSE_OR_POP': 0, 'JUMP_ABSOLUTE': 0, 'POP_JUMP_IF_FALSE': -1,
+        'POP_JUMP_IF_TRUE': -1, 'LOAD_GLOBAL': 1, 'CONTINUE_LOOP': 0, 'SETUP_LOOP': 0,
+        'SETUP_EXCEPT': 6, 'SETUP_FINALLY': 6, 'SETUP_ASYNC_WITH': 6, 'SETUP_WITH': 7,
+        'LOAD_FAST': 1, 'STORE_FAST': -1, 'DELETE_FAST': 0, 'LOAD_CLOSURE': 1, 'LOAD_DEREF': 1,
+        'LOAD_CLASSDEREF': 1, 'STORE_DEREF': -1, 'DELETE_DEREF': 0, 'MAP_ADD': -2,
+        'LIST_APPEND': -1 if version >= (3, 1) else -2,
+        'SET_ADD': -1 if version >= (3, 1) else -2,
+    }
+    if name in fixed:
+        return fixed[name]
+    if name.startswith('UNARY_'):
+        return 0
+    if name.startswith(('BINARY_', 'INPLACE_')):
+        return -1
+    if name == 'UNPACK_SEQUENCE':
+        return arg - 1
+    if name == 'UNPACK_EX':
+        return (arg & 0xFF) + (arg >> 8)
+    if name == 'DUP_TOPX':
+        return arg
+    if name in ('BUILD_TUPLE', 'BUILD_LIST', 'BUILD_SET', 'BUILD_TUPLE_UNPACK',
+                'BUILD_LIST_UNPACK', 'BUILD_SET_UNPACK', 'BUILD_MAP_UNPACK'):
+        return 1 - arg
+    if name == 'BUILD_MAP_UNPACK_WITH_CALL':
+        return 1 - (arg & 0xFF)
+    if name == 'BUILD_MAP':
+        return 1 - 2 * arg if version >= (3, 5) else 1
+    if name == 'RAISE_VARARGS':
+        return -arg
+    if name == 'CALL_FUNCTION':
+        return -nargs(arg)
+    if name in ('CALL_FUNCTION_VAR', 'CALL_FUNCTION_KW'):
+        return -nargs(arg) - 1
+    if name == 'CALL_FUNCTION_VAR_KW':
+        return -nargs(arg) - 2
+    # The qualified name is passed along with the code object since Python 3.3
+    qualname = 1 if version >= (3, 3) else 0
+    if name == 'MAKE_FUNCTION':
+        return -nargs(arg) - ((arg >> 16) & 0xFFFF) - qualname
+    if name == 'MAKE_CLOSURE':
+        return -1 - nargs(arg) - ((arg >> 16) & 0xFFFF) - qualname
+    if name == 'BUILD_SLICE':
+        return -2 if arg == 3 else -1
+    return None
+
+
+def write(path, version, opcodes, stack_effect):
+    lines = [
+        '# Python %d.%d opcodes from Lib/opcode.py, with stack effects from Python/compile.c.'
+        % version,
+        '# Generated by compile_c.py.',
+        'code_unit 1',
+        'jump_unit 1',
+    ]
+    by_number = sorted(opcodes.items(), key=lambda item: item[1])
+    lines += ['opcode %s %d' % item for item in by_number]
+    for name, number in by_number:
+        args = VALID_ARGS.get(name, ARGS) if number >= HAVE_ARGUMENT else [None]
+        for arg in args:
+            effect = stack_effect(version, name, arg)
+            if effect is not None:
+                lines.append('effect %s %s %d' % (name, '-' if arg is None else arg, effect))
+    with open(path, 'w') as f:
+        f.write('\n'.join(lines) + '\n')
+
+
+here = os.path.dirname(os.path.abspath(__file__))
+for version, opcodes in (((2, 5), opcodes_25()), ((2, 6), opcodes_26()),
+                         ((2, 7), parse(OPCODES_27))):
+    write(os.path.join(here, 'py%d%d.txt' % version), version, opcodes, stack_effect_2)
+for version, opcodes in sorted(opcodes_3().items()):
+    write(os.path.join(here, 'py%d%d.txt' % version), version, opcodes, stack_effect_3)
@@ -0,0 +1,109 @@
+"""Writes the opcode fixture of the running Python version, which must be 3.6 or later.
+
+Run with each version:
+    python3.X generate.py > py3X.txt
+"""
+import dis
+import opcode
+import sys
+
+ARGS = [0, 1, 2, 3, 4, 5, 7, 8, 15, 258]
+# The arguments the compiler emits for opcodes which only accept a few
+VALID_ARGS = {'BUILD_SLICE': [2, 3]}
+
+
+def units():
+    """The size in bytes of a code unit and of the unit jump arguments count in"""
+    code = compile('if x:\n    y = 1\nz = 2', '<units>', 'exec')
+    instructions = list(dis.get_instructions(code))
+    code_unit = instructions[1].offset - instructions[0].offset
+    for instr, next_instr in zip(instructions, instructions[1:]):
+        if instr.opcode in opcode.hasjabs:
+            return code_unit, instr.argval // instr.arg
+        if instr.opcode in opcode.hasjrel:
+            return code_unit, (instr.argval - next_instr.offset) // instr.arg
+
+
+def base_opcodes(opcodes):
+    """The opcode each specialized or instrumented opcode stands in for. sys.monitoring swaps an
+    opcode for the INSTRUMENTED_ opcode of the same name."""
+    bases = {}
+    for base, specialized in getattr(opcode, '_specializations', {}).items():
+        bases.update((name, base) for name in specialized)
+    for name in opcodes:
+        if name.startswith('INSTRUMENTED_') and name[len('INSTRUMENTED_'):] in opcodes:
+            bases[name] = name[len('INSTRUMENTED_'):]
+    return bases
+
+
+def cache_entries(name):
+    entries = getattr(opcode, '_inline_cache_entries', {})
+    if isinstance(entries, dict):
+        return entries.get(name, 0)
+    return entries[opcode.opmap[name]] if entries else 0
+
+
+def has_arg(number):
+    if hasattr(opcode, 'has_arg'):
+        return opcode.has_arg(number)
+    return number >= opcode.HAVE_ARGUMENT
+
+
+def local_pairs():
+    """An example argument of each superinstruction which refers to two locals, with the indexes
+    of the locals"""
+    def f(a, b):
+        # The compiler only combines instructions of the same line
+        x = a; y = b
+        a, b = b, a
+        return a + b, x, y
+
+    pairs = {}
+    for instr in dis.get_instructions(f):
+        if isinstance(instr.argval, tuple) and len(instr.argval) == 2:
+            first, second = (f.__code__.co_varnames.index(name) for name in instr.argval)
+            pairs.setdefault(instr.opname, (instr.arg, first, second))
+    return pairs
+
+
+# Specialized opcodes are left out of opcode.opmap since Python 3.11, and pseudo opcodes which
+# never appear in bytecode are numbered from 256
+opcodes = dict(getattr(dis, '_all_opmap', opcode.opmap))
+opcodes.update(getattr(opcode, '_specialized_opmap', {}))
+opcodes = sorted(((name, number) for name, number in opcodes.items() if number < 256),
+                 key=lambda item: item[1])
+bases = base_opcodes(dict(opcodes))
+pairs = local_pairs()
+
+print('# Python %d.%d opcodes from the opcode module, with stack effects from dis.stack_effect.'
+      % sys.version_info[:2])
+print('# Generated by generate.py.')
+print('code_unit %d\njump_unit %d' % units())
+
+for name, number in opcodes:
+    print('opcode %s %d' % (name, number))
+
+for name, number in opcodes:
+    if name in bases:
+        print('base %s %s' % (name, bases[name]))
+
+for name, number in opcodes:
+    entries = cache_entries(bases.get(name, name))
+    if entries:
+        print('cache %s %d' % (name, entries))
+
+for name, number in opcodes:
+    pair = pairs.get(bases.get(name, name))
+    if pair:
+        print('pair %s %d %d %d' % ((name,) + pair))
+
+for name, number in opcodes:
+    if name in bases:
+        continue
+    args = VALID_ARGS.get(name, ARGS) if has_arg(number) else [None]
+    for arg in args:
+        try:
+            effect = dis.stack_effect(number, arg)
+        except ValueError:
+            continue
+        print('effect %s %s %d' % (name, '-' if arg is None else arg, effect))
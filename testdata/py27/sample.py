@@ -0,0 +1,36 @@
+"""Fixture for marshal round trips"""
+import os
+
+BIG = 2 ** 100
+NEGATIVE = -(2 ** 70)
+WIDE = 1 << 40
+RATIO = 0.1
+SPECIAL = (float('inf'), -0.0, 1e300)
+Z = 1.5 - 2j
+TEXT = u'h\xe9llo'
+DATA = 'a\x00b'
+FROZEN = frozenset([1, 2])
+
+
+class Greeter(object):
+    greeting = 'hello'
+
+    def __init__(self, name):
+        self.name = name
+
+    def greet(self, punctuation='!'):
+        return '%s %s%s' % (self.greeting, self.name, punctuation)
+
+
+def counter(start=0):
+    count = [start]
+
+    def step(by=1):
+        count[0] += by
+        return count[0]
+
+    return step
+
+
+square = lambda x: x * x
+names = {name: len(name) for name in os.listdir('.') if name}
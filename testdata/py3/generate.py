@@ -0,0 +1,38 @@
+"""Describes every code object in a pyc file, for checking the marshal reader.
+
+Run with the Python version which wrote the file:
+    python3.X generate.py py3X.pyc > py3X.txt
+"""
+import marshal
+import sys
+import types
+
+header_len = 16 if sys.version_info >= (3, 7) else 12
+module = marshal.loads(open(sys.argv[1], 'rb').read()[header_len:])
+
+
+def names(values):
+    return ','.join(values) or '-'
+
+
+def describe(code, parent):
+    path = parent + '.' + code.co_name if parent else code.co_name
+    print('code ' + path)
+    for field in ('argcount', 'posonlyargcount', 'kwonlyargcount', 'nlocals', 'stacksize',
+                  'flags', 'firstlineno'):
+        print('%s %d' % (field, getattr(code, 'co_' + field, 0)))
+    for field in ('names', 'varnames', 'freevars', 'cellvars'):
+        print('%s %s' % (field, names(getattr(code, 'co_' + field))))
+    print('filename ' + code.co_filename)
+    print('qualname ' + getattr(code, 'co_qualname', '-'))
+    print('consts %d' % len(code.co_consts))
+    print('code ' + code.co_code.hex())
+    table = code.co_linetable if sys.version_info >= (3, 10) else code.co_lnotab
+    print('linetable ' + (table.hex() or '-'))
+    print('exceptiontable ' + (getattr(code, 'co_exceptiontable', b'').hex() or '-'))
+    for const in code.co_consts:
+        if isinstance(const, types.CodeType):
+            describe(const, path)
+
+
+describe(module, '')
@@ -0,0 +1,39 @@
+"""Fixture for Python 3 marshal layouts"""
+import os
+
+BIG = 1267650600228229401496703205376
+RATIO = 0.1
+Z = 1.5 - 2j
+TEXT = 'h\xe9llo'
+DATA = b'a\x00b'
+FROZEN = frozenset({1, 2})
+PAIR = ('spam', 'spam')
+
+
+class Greeter:
+    greeting = 'hello'
+
+    def __init__(self, name, /, *, loud=False):
+        self.name = name
+        self.loud = loud
+
+    def greet(self, punctuation='!'):
+        try:
+            return f'{self.greeting} {self.name}{punctuation}'
+        except AttributeError:
+            return None
+
+
+def counter(start=0):
+    count = start
+
+    def step(by=1):
+        nonlocal count
+        count += by
+        return count
+
+    return step
+
+
+square = lambda x: x * x
+names = {name: len(name) for name in os.listdir('.') if name}